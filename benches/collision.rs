@@ -0,0 +1,117 @@
+// benches/collision.rs
+// 衝突判定まわりの処理（ボール同士の衝突判定と物理演算ステップ）の実行時間を計測するベンチマークです。
+// `cargo bench` で実行します。メインのバイナリには依存せず、ライブラリの公開 API だけを使います。
+//
+// 現在のボール同士の衝突判定は全ペアを調べる総当たり（O(n²)）のみです。
+// グリッドによる空間分割を導入したら、同じフィクスチャで比較できるようにグループを追加してください。
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use my_specs_game::config::{self, Config};
+use my_specs_game::systems::{CollisionSystem, PhysicsSystem};
+use my_specs_game::Simulation;
+use specs::RunNow;
+
+/// 計測するボールの数です。
+const BALL_COUNTS: [usize; 3] = [10, 100, 1000];
+
+/// ボールの半径（cm）です。
+const RADIUS: f32 = 2.85;
+
+/// 隣り合うボールの中心間距離（cm）です。半径の 2 倍より少しだけ広くし、
+/// 数ステップのうちに多くのボールが衝突するようにします。
+const SPACING: f32 = RADIUS * 2.2;
+
+/// `count` 個のボールを格子状に並べた設定を作ります。
+///
+/// 乱数は使わず、ボールの番号から決まる速度を与えるので、毎回同じ配置になります。
+///
+/// # 引数
+/// * `count` - ボールの数
+/// * `solver` - ボール同士の衝突の処理方法（`collision_solver` の値）
+///
+/// # 戻り値
+/// ベンチマーク用の設定
+fn fixture_config(count: usize, solver: &str) -> Config {
+    let columns = (count as f32).sqrt().ceil() as usize;
+    let rows = count.div_ceil(columns);
+    let width = SPACING * (columns + 1) as f32;
+    let height = SPACING * (rows + 1) as f32;
+    let mut yaml = format!(
+        "dt: 0.01\n\
+         table: {{ width: {width}, height: {height} }}\n\
+         ball: {{ radius: {RADIUS}, mass: 0.17, restitution: 0.9 }}\n\
+         friction_xy: [0.5, 0.5]\n\
+         collision_solver: {solver}\n\
+         systems: {{ logging: false }}\n\
+         entities:\n"
+    );
+    for i in 0..count {
+        let x = SPACING * ((i % columns) + 1) as f32;
+        let y = SPACING * ((i / columns) + 1) as f32;
+        // 番号ごとに向きの異なる速度（m/s）を与えます。
+        let angle = i as f32 * 2.399_963;
+        let (vx, vy) = (angle.cos() * 2.0, angle.sin() * 2.0);
+        let kind = if i == 0 { "Cue" } else { "Object" };
+        yaml.push_str(&format!(
+            "  - {{ kind: {kind}, x: {x}, y: {y}, vx: {vx}, vy: {vy} }}\n"
+        ));
+    }
+    config::load_config_from_reader(yaml.as_bytes(), config::Format::Yaml)
+        .expect("ベンチマーク用の設定が読み込めません")
+}
+
+/// ボール同士とテーブルとの衝突判定（CollisionSystem）を総当たりで計測します。
+fn bench_ball_collisions(c: &mut Criterion) {
+    bench_collision_solver(c, "collision/brute_force", "Serial");
+}
+
+/// 衝突しているペアをバッチに分けて並列に処理する場合（collision_solver: Colored）の
+/// CollisionSystem を、同じフィクスチャで計測します。
+fn bench_colored_collisions(c: &mut Criterion) {
+    bench_collision_solver(c, "collision/colored", "Colored");
+}
+
+/// `solver` の処理方法で CollisionSystem を計測し、`name` のグループとして記録します。
+fn bench_collision_solver(c: &mut Criterion, name: &str, solver: &str) {
+    let mut group = c.benchmark_group(name);
+    for count in BALL_COUNTS {
+        let config = fixture_config(count, solver);
+        group.bench_with_input(BenchmarkId::from_parameter(count), &config, |b, config| {
+            b.iter_batched(
+                || Simulation::new(config).unwrap(),
+                |simulation| {
+                    CollisionSystem::default().run_now(simulation.world());
+                    simulation
+                },
+                BatchSize::LargeInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+/// 摩擦と位置の更新を行う物理演算ステップ（PhysicsSystem）を計測します。
+fn bench_physics_step(c: &mut Criterion) {
+    let mut group = c.benchmark_group("physics_step");
+    for count in BALL_COUNTS {
+        let config = fixture_config(count, "Serial");
+        group.bench_with_input(BenchmarkId::from_parameter(count), &config, |b, config| {
+            b.iter_batched(
+                || Simulation::new(config).unwrap(),
+                |simulation| {
+                    PhysicsSystem.run_now(simulation.world());
+                    simulation
+                },
+                BatchSize::LargeInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_ball_collisions,
+    bench_colored_collisions,
+    bench_physics_step
+);
+criterion_main!(benches);
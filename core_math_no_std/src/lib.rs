@@ -0,0 +1,12 @@
+// core_math_no_std/src/lib.rs
+//
+// このファイルでは、本体のクレートの src/core_math.rs とその下のファイルを、そのまま #![no_std] のクレートに取り込みます。
+// core_math が std や specs の機能を使うと、このクレートのビルドが失敗します。
+#![no_std]
+
+#[path = "../../src"]
+mod src {
+    pub mod core_math;
+}
+
+pub use src::core_math::*;
@@ -0,0 +1,79 @@
+// examples/animate.rs
+//
+// 設定ファイルに従ってシミュレーションを実行し、その様子をアニメーション GIF として書き出すプログラムです。
+//
+// 実行例:
+//   cargo run --example animate -- config.yaml break.gif
+//   cargo run --example animate -- config.yaml break.gif 3   # 最初の 3 コマだけを書き出す
+//
+// すべてのボールが静止するか、コマ数の上限（省略時は MAX_FRAMES）に達するまで実行します。
+use my_specs_game::{config, Simulation};
+use std::error::Error;
+use std::fs::File;
+use std::process::ExitCode;
+
+/// 1 cm あたりのピクセル数です。
+const PIXELS_PER_CM: f32 = 2.0;
+/// 1 コマあたりのシミュレーション上の時間（秒）の目安です（25 コマ毎秒）。
+const FRAME_INTERVAL: f32 = 0.04;
+/// コマ数の上限の既定値です。
+const MAX_FRAMES: usize = 500;
+/// GIF の色の減色の速さ（1 が最も高品質で遅く、30 が最も速い）です。
+const QUANTIZE_SPEED: i32 = 10;
+
+fn main() -> Result<ExitCode, Box<dyn Error>> {
+    let mut args = std::env::args().skip(1);
+    let (Some(config_path), Some(output_path)) = (args.next(), args.next()) else {
+        eprintln!("usage: animate <config> <output.gif> [max_frames]");
+        return Ok(ExitCode::FAILURE);
+    };
+    let max_frames = match args.next() {
+        Some(frames) => frames.parse()?,
+        None => MAX_FRAMES,
+    };
+    let config = config::load_config(&config_path)?;
+    let mut simulation = Simulation::new(&config)?;
+
+    // 1 コマの間に進めるステップ数と、GIF に記録する 1 コマの表示時間（1/100 秒単位）
+    let steps_per_frame = ((FRAME_INTERVAL / config.dt).round() as usize).max(1);
+    let delay = (steps_per_frame as f32 * config.dt * 100.0)
+        .round()
+        .max(1.0) as u16;
+
+    let first = simulation.render_raster(PIXELS_PER_CM);
+    let mut encoder = gif::Encoder::new(
+        File::create(&output_path)?,
+        first.width as u16,
+        first.height as u16,
+        &[],
+    )?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+
+    let mut frame = first;
+    let mut frames = 0;
+    loop {
+        let mut gif_frame = gif::Frame::from_rgba_speed(
+            frame.width as u16,
+            frame.height as u16,
+            &mut frame.rgba,
+            QUANTIZE_SPEED,
+        );
+        gif_frame.delay = delay;
+        encoder.write_frame(&gif_frame)?;
+        frames += 1;
+        if frames >= max_frames || simulation.is_at_rest() || simulation.is_aborted() {
+            break;
+        }
+        for _ in 0..steps_per_frame {
+            simulation.step();
+        }
+        frame = simulation.render_raster(PIXELS_PER_CM);
+    }
+    println!(
+        "{} frames ({} steps) written to {}",
+        frames,
+        simulation.steps(),
+        output_path
+    );
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,36 @@
+// examples/verify_replay.rs
+//
+// 記録済みの実行結果（JsonSink の JSON Lines）と設定ファイルを読み込んで再実行し、
+// 物理演算の結果が記録と一致するかを検証するプログラムです。
+//
+// 実行例:
+//   cargo run --example verify_replay -- examples/fixtures/replay_break.yaml examples/fixtures/replay_break.jsonl
+//
+// 一致しない場合は、最初に一致しなかったステップとボールを表示して終了コード 1 で終了します。
+use my_specs_game::config;
+use my_specs_game::simulation::{load_recording, verify_replay};
+use std::error::Error;
+use std::process::ExitCode;
+
+/// 位置（cm）と速度（cm/s）の各成分の許容誤差です。
+const TOLERANCE: f32 = 1.0e-3;
+
+fn main() -> Result<ExitCode, Box<dyn Error>> {
+    let mut args = std::env::args().skip(1);
+    let (Some(config_path), Some(recording_path)) = (args.next(), args.next()) else {
+        eprintln!("usage: verify_replay <config> <recording.jsonl>");
+        return Ok(ExitCode::FAILURE);
+    };
+    let config = config::load_config(&config_path)?;
+    let recording = load_recording(&recording_path)?;
+    match verify_replay(&config, &recording, TOLERANCE)? {
+        Ok(()) => {
+            println!("{} steps match the recording", recording.len());
+            Ok(ExitCode::SUCCESS)
+        }
+        Err(divergence) => {
+            eprintln!("{}", divergence);
+            Ok(ExitCode::FAILURE)
+        }
+    }
+}
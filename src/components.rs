@@ -6,3 +6,33 @@ pub mod ball;
 pub use ball::Ball;
 pub mod table;
 pub use table::Table;
+pub mod pocket;
+pub use pocket::Pocket;
+pub mod ball_kind;
+pub use ball_kind::BallKind;
+pub mod collision_mask;
+pub use collision_mask::CollisionMask;
+pub mod ball_id;
+pub use ball_id::BallId;
+pub mod trail;
+pub use trail::Trail;
+pub mod color;
+pub use color::Color;
+pub mod spin;
+pub use spin::Spin;
+pub mod pocketing;
+pub use pocketing::Pocketing;
+pub mod pocketed;
+pub use pocketed::Pocketed;
+pub mod render;
+pub use render::Render;
+pub mod sleeping;
+pub use sleeping::Sleeping;
+pub mod still_steps;
+pub use still_steps::StillSteps;
+pub mod peg;
+pub use peg::Peg;
+pub mod cushion_segment;
+pub use cushion_segment::CushionSegment;
+pub mod register_all;
+pub use register_all::register_all_components;
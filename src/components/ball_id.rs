@@ -0,0 +1,18 @@
+// components/ball_id.rs
+use serde::{Deserialize, Serialize};
+use specs::prelude::*;
+
+/// ボールを識別する番号を表すコンポーネントです。
+///
+/// `cue_ball` と `object_balls` で指定した場合、手球は BallId(0) で、的球は `object_balls.positions` の並び順に
+/// BallId(1), BallId(2), ... が割り当てられます。`entities` セクションを使う場合は、
+/// ボールの種類によらずその並び順に BallId(0), BallId(1), ... が割り当てられるため、
+/// 手球を探すときは BallId ではなく BallKind を使います。
+/// エンティティの ID とは異なり、実行ごとに変わらない安定した識別子として使えます。
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct BallId(pub u32);
+
+// Component トレイトの実装。VecStorage を用います。
+impl Component for BallId {
+    type Storage = VecStorage<Self>;
+}
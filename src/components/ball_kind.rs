@@ -0,0 +1,17 @@
+// components/ball_kind.rs
+use serde::{Deserialize, Serialize};
+use specs::prelude::*;
+
+/// ボールの種類（手球か的球か）を表すコンポーネントです。
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BallKind {
+    /// 手球（cue ball）
+    Cue,
+    /// 的球（object ball）
+    Object,
+}
+
+// Component トレイトの実装。VecStorage を用います。
+impl Component for BallKind {
+    type Storage = VecStorage<Self>;
+}
@@ -0,0 +1,28 @@
+// components/collision_mask.rs
+use specs::prelude::*;
+
+/// ボール同士の衝突判定に使うレイヤー（ビットマスク）を表すコンポーネントです。
+///
+/// 2 つのボールのマスクのビット積（AND）が 0 の場合、そのボール同士は衝突せずにすり抜けます。
+/// テーブルの壁との衝突には影響しません。
+/// このコンポーネントを持たないボールは、すべてのビットが立ったマスク（u32::MAX）として扱います。
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CollisionMask(pub u32);
+
+impl Default for CollisionMask {
+    fn default() -> Self {
+        CollisionMask(u32::MAX)
+    }
+}
+
+impl CollisionMask {
+    /// 2 つのマスクが重なっている（ビット積が 0 でない）かどうかを返します。
+    pub fn overlaps(self, other: CollisionMask) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+// Component トレイトの実装。VecStorage を用います。
+impl Component for CollisionMask {
+    type Storage = VecStorage<Self>;
+}
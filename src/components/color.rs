@@ -0,0 +1,71 @@
+// components/color.rs
+use crate::components::{BallId, BallKind};
+use specs::prelude::*;
+
+/// 描画（SVG や PNG への書き出し）に用いるボールの色を表すコンポーネントです。
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Color {
+    /// 赤成分
+    pub r: u8,
+    /// 緑成分
+    pub g: u8,
+    /// 青成分
+    pub b: u8,
+}
+
+/// 的球に色が指定されていない場合に、BallId に応じて順に割り当てる既定の色です。
+/// BallId(1) から順に、黄・青・赤・紫・橙・緑・えんじ・黒を繰り返します。
+const PALETTE: [Color; 8] = [
+    Color::rgb(0xf5, 0xc5, 0x18),
+    Color::rgb(0x1f, 0x4e, 0xb4),
+    Color::rgb(0xd3, 0x2f, 0x2f),
+    Color::rgb(0x6a, 0x1b, 0x9a),
+    Color::rgb(0xef, 0x6c, 0x00),
+    Color::rgb(0x2e, 0x7d, 0x32),
+    Color::rgb(0x80, 0x1b, 0x1b),
+    Color::rgb(0x10, 0x10, 0x10),
+];
+
+/// 手球（BallKind::Cue）の既定の色（白）です。
+const CUE_COLOR: Color = Color::rgb(0xff, 0xff, 0xff);
+
+impl Color {
+    /// RGB の各成分から色を生成します。
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b }
+    }
+
+    /// `#rrggbb` 形式（先頭の `#` は省略可）の 16 進文字列から色を生成します。
+    ///
+    /// # 戻り値
+    /// 解釈できた場合は Some(Color)、書式が不正な場合は None を返します。
+    pub fn from_hex(hex: &str) -> Option<Color> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if digits.len() != 6 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).ok();
+        Some(Color::rgb(channel(0)?, channel(2)?, channel(4)?))
+    }
+
+    /// ボールの種類と BallId に応じた既定の色を返します。手球は白、的球は palette() の色です。
+    pub fn default_for(kind: BallKind, id: BallId) -> Color {
+        match kind {
+            BallKind::Cue => CUE_COLOR,
+            BallKind::Object => Color::palette(id),
+        }
+    }
+
+    /// BallId に応じた既定の色を返します。手球は白、的球はパレットを順に繰り返します。
+    pub fn palette(id: BallId) -> Color {
+        match id.0 {
+            0 => CUE_COLOR,
+            n => PALETTE[(n as usize - 1) % PALETTE.len()],
+        }
+    }
+}
+
+// Component トレイトの実装。VecStorage を用います。
+impl Component for Color {
+    type Storage = VecStorage<Self>;
+}
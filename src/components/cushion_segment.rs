@@ -0,0 +1,22 @@
+// components/cushion_segment.rs
+use crate::components::Position;
+use specs::prelude::*;
+
+/// 台の上に置かれた線分のクッション（斜めのレールなど）を表すコンポーネントです。
+/// クッションは動かず、ボールとの衝突では質量が無限大であるものとしてボールだけを跳ね返します。
+/// ボールの中心から線分までの距離がボールの半径になった時点で接触とみなし、線分の端は半円として扱います。
+/// 線分のクッションとの衝突は固定ステップの CollisionSystem でのみ処理されるため、イベント駆動モードと組み合わせた設定は検証で拒否されます。
+#[derive(Debug, Copy, Clone)]
+pub struct CushionSegment {
+    /// 線分の始点（cm）
+    pub start: Position,
+    /// 線分の終点（cm）
+    pub end: Position,
+    /// クッションの反発係数
+    pub restitution: f32,
+}
+
+// Component トレイトの実装。VecStorage を用います。
+impl Component for CushionSegment {
+    type Storage = VecStorage<Self>;
+}
@@ -0,0 +1,19 @@
+// components/peg.rs
+use specs::prelude::*;
+
+/// 位置が固定された円形の障害物（ペグ）を表すコンポーネントです。
+/// ペグの中心位置は Position コンポーネントで表します。ペグは動かず、
+/// ボールとの衝突では質量が無限大であるものとしてボールだけを跳ね返します。
+/// ペグとの衝突は固定ステップの CollisionSystem でのみ処理されるため、イベント駆動モードと組み合わせた設定は検証で拒否されます。
+#[derive(Debug, Copy, Clone)]
+pub struct Peg {
+    /// ペグの半径（cm）
+    pub radius: f32,
+    /// ペグの反発係数
+    pub restitution: f32,
+}
+
+// Component トレイトの実装。VecStorage を用います。
+impl Component for Peg {
+    type Storage = VecStorage<Self>;
+}
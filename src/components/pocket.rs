@@ -0,0 +1,17 @@
+// components/pocket.rs
+use specs::prelude::*;
+
+/// ポケット（球が落ちる穴）を表すコンポーネントです。
+/// ポケットの中心位置は Position コンポーネントで表します。
+#[derive(Debug, Copy, Clone)]
+pub struct Pocket {
+    /// ポケットの半径（cm）
+    pub radius: f32,
+    /// ポケットの番号（設定の `pockets.positions` での順番、0 始まり）
+    pub index: usize,
+}
+
+// Component トレイトの実装。VecStorage を用います。
+impl Component for Pocket {
+    type Storage = VecStorage<Self>;
+}
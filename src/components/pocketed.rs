@@ -0,0 +1,20 @@
+// components/pocketed.rs
+use serde::{Deserialize, Serialize};
+use specs::prelude::*;
+
+/// ボールが最後に入ったポケットと、そのステップを表すマーカーです。
+///
+/// PocketSystem が、ボールを削除する（または落下の演出を始める、フットスポットに戻す）前に付けます。
+/// フットスポットに戻された的球では、次にポケットに入るまで残ります。
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pocketed {
+    /// 入ったポケットの番号（設定の `pockets.positions` での順番、0 始まり）
+    pub pocket: usize,
+    /// ポケットに入ったステップの番号（0 始まり）
+    pub step: usize,
+}
+
+// Component トレイトの実装。VecStorage を用います。
+impl Component for Pocketed {
+    type Storage = VecStorage<Self>;
+}
@@ -0,0 +1,23 @@
+// components/pocketing.rs
+use serde::{Deserialize, Serialize};
+use specs::prelude::*;
+
+/// ポケットに落ちつつあるボールの状態を表すコンポーネントです。
+///
+/// 落下の演出が有効な場合、ポケットに入ったボールはすぐには削除されず、
+/// このコンポーネントを付けられて数ステップかけてポケットの中心へ引き寄せられながら縮み、
+/// その後に削除されます。落下中のボールは Velocity を持たず、移動や衝突の対象になりません。
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+pub struct Pocketing {
+    /// 引き寄せられる先（ポケットの中心）
+    pub target: (f32, f32),
+    /// 削除されるまでの残りのステップ数
+    pub remaining: u32,
+    /// 落下にかける全体のステップ数
+    pub duration: u32,
+}
+
+// Component トレイトの実装。VecStorage を用います。
+impl Component for Pocketing {
+    type Storage = VecStorage<Self>;
+}
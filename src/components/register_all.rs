@@ -0,0 +1,34 @@
+// components/register_all.rs
+use crate::components::{
+    Ball, BallId, BallKind, CollisionMask, Color, CushionSegment, Peg, Pocket, Pocketed, Pocketing,
+    Position, Render, Sleeping, Spin, StillSteps, Table, Trail, Velocity,
+};
+use specs::prelude::*;
+
+/// このクレートが定義するすべてのコンポーネントを World に登録する関数です。
+///
+/// 新しいコンポーネントを追加したときは、ここに登録を追加するだけで、
+/// Simulation をはじめ World を構築するすべての箇所で使えるようになります。
+///
+/// # 引数
+/// - `world`: ECS の World への可変参照
+pub fn register_all_components(world: &mut World) {
+    world.register::<Position>();
+    world.register::<Velocity>();
+    world.register::<Ball>();
+    world.register::<Table>();
+    world.register::<Pocket>();
+    world.register::<BallKind>();
+    world.register::<CollisionMask>();
+    world.register::<BallId>();
+    world.register::<Trail>();
+    world.register::<Color>();
+    world.register::<Spin>();
+    world.register::<Pocketing>();
+    world.register::<Pocketed>();
+    world.register::<Render>();
+    world.register::<Sleeping>();
+    world.register::<StillSteps>();
+    world.register::<Peg>();
+    world.register::<CushionSegment>();
+}
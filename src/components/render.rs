@@ -0,0 +1,21 @@
+// components/render.rs
+use specs::prelude::*;
+
+/// 描画（書き出し）時の見た目の調整を表すコンポーネントです。
+/// 物理的な半径には影響しません。このコンポーネントを持たないボールは scale = 1.0 として扱います。
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Render {
+    /// 描画時の半径の倍率
+    pub scale: f32,
+}
+
+impl Default for Render {
+    fn default() -> Self {
+        Render { scale: 1.0 }
+    }
+}
+
+// Component トレイトの実装。VecStorage を用います。
+impl Component for Render {
+    type Storage = VecStorage<Self>;
+}
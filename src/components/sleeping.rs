@@ -0,0 +1,14 @@
+// components/sleeping.rs
+use specs::prelude::*;
+
+/// 静止が続いたため、物理演算と衝突判定の対象から外されている（休止中の）ボールを表すマーカーです。
+///
+/// 動いているボールがぶつかって速さが静止とみなす上限を超えると取り除かれ、
+/// そのボールに接している休止中のボールも同時に起こされます。
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Sleeping;
+
+// Component トレイトの実装。データを持たないマーカーのため NullStorage を用います。
+impl Component for Sleeping {
+    type Storage = NullStorage<Self>;
+}
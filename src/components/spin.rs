@@ -0,0 +1,20 @@
+// components/spin.rs
+use specs::prelude::*;
+
+/// ボールの回転（角速度）を表すコンポーネントです。単位は rad/s です。
+///
+/// x, y はテーブル面に平行な軸まわりの回転（押し球・引き球の回転）、
+/// z は鉛直軸まわりの回転（左右のひねり）を表します。
+/// ボールが転がっている（滑っていない）とき、x, y 成分は速度と
+/// `vx = radius × y`, `vy = -radius × x` の関係を満たします。
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Spin {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+// Component トレイトの実装。VecStorage を用います。
+impl Component for Spin {
+    type Storage = VecStorage<Self>;
+}
@@ -0,0 +1,14 @@
+// components/still_steps.rs
+use specs::prelude::*;
+
+/// ボールの速さが静止とみなす上限以下のまま続いたステップ数を表すコンポーネントです。
+/// この値が設定されたステップ数に達すると、ボールは休止（Sleeping）します。
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct StillSteps {
+    pub count: u32,
+}
+
+// Component トレイトの実装。VecStorage を用います。
+impl Component for StillSteps {
+    type Storage = VecStorage<Self>;
+}
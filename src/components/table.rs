@@ -1,4 +1,5 @@
 // components/table.rs
+use crate::config::TableShape;
 use specs::prelude::*;
 
 /// ビリヤード台を表すコンポーネントです。
@@ -8,6 +9,8 @@ pub struct Table {
     pub width: f32,
     /// テーブルの高さ（cm）
     pub height: f32,
+    /// クッションの形状
+    pub shape: TableShape,
 }
 
 // Component トレイトの実装。VecStorage を用います。
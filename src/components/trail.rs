@@ -0,0 +1,36 @@
+// components/trail.rs
+use specs::prelude::*;
+use std::collections::VecDeque;
+
+/// ボールが最近たどった位置の履歴（軌跡）を保持するコンポーネントです。
+/// 描画時に、残像（モーションブラー）のような軌跡を描くために使います。
+#[derive(Debug, Clone)]
+pub struct Trail {
+    /// 過去の位置 (x, y)。古いものが先頭、最新のものが末尾に並びます。
+    pub points: VecDeque<(f32, f32)>,
+    /// 保持する位置の最大数
+    pub capacity: usize,
+}
+
+impl Trail {
+    /// 指定した容量の空の軌跡を生成します。
+    pub fn new(capacity: usize) -> Trail {
+        Trail {
+            points: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// 新しい位置を追加し、容量を超えた分は古いものから取り除きます。
+    pub fn push(&mut self, point: (f32, f32)) {
+        self.points.push_back(point);
+        while self.points.len() > self.capacity {
+            self.points.pop_front();
+        }
+    }
+}
+
+// Component トレイトの実装。VecStorage を用います。
+impl Component for Trail {
+    type Storage = VecStorage<Self>;
+}
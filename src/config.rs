@@ -1,15 +1,34 @@
 // src/config.rs
 //
-// このファイルでは、YAML から読み込む設定情報の構造体と、
-// 設定ファイルを読み込む関数 load_config を定義しています。
+// このファイルでは、YAML（または JSON）から読み込む設定情報の構造体と、
+// 設定ファイルを読み込む関数 load_config / load_config_from_reader を定義しています。
 
-use serde::Deserialize;
+use crate::components::{BallId, BallKind};
+use crate::entities::triangle_rack;
+use crate::simulation::ShotSpin;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
 use std::error::Error;
 use std::fs::File;
-use std::io::BufReader;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+pub mod builder;
+pub use builder::ConfigBuilder;
+pub mod config_patch;
+pub use config_patch::ConfigPatch;
+
+/// `max_balls_strict` で上限を指定しない場合に、読み込み時に許容するエンティティの総数の上限です。
+/// 設定の誤りで膨大な数のボールやペグを生成し、メモリを使い果たすことを防ぎます。
+pub const DEFAULT_BALL_LIMIT: usize = 10_000;
+
+/// `threads` に指定できるスレッド数の上限を、CPU のコア数の何倍とするかです。
+/// 誤って膨大なスレッド数を指定し、スレッドの起動でメモリや時間を使い果たすことを防ぎます。
+pub const MAX_THREADS_PER_CORE: usize = 4;
 
 /// シミュレーションに必要な各種設定情報を保持する構造体です。
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Config {
     // シミュレーションの時間刻み（秒）
     pub dt: f32,
@@ -17,21 +36,739 @@ pub struct Config {
     pub table: TableConfig,
     // 手球、的球共通の物理パラメータ
     pub ball: BallConfig,
-    // 手球（cue ball）の初期位置・初速度情報
-    pub cue_ball: CueBallConfig,
-    // 的球の配置情報
+    // 手球（cue ball）の初期位置・初速度情報（entities を使う場合は省略可）
+    #[serde(default)]
+    pub cue_ball: Option<CueBallConfig>,
+    // 的球の配置情報（entities を使う場合は省略可）
+    #[serde(default)]
     pub object_balls: ObjectBallsConfig,
+    // 生成するボールの一覧（省略時は cue_ball と object_balls から生成）
+    #[serde(default)]
+    pub entities: Vec<EntityConfig>,
+    // 入力値の単位（省略時は速度を m/s として扱う）
+    #[serde(default)]
+    pub units: UnitsConfig,
+    // 摩擦による減速度 (x 軸, y 軸)（m/s^2、省略時は摩擦なし）
+    #[serde(default)]
+    pub friction_xy: Option<(f32, f32)>,
+    // 摩擦が異なる円形の領域の一覧（省略時はなし）
+    #[serde(default)]
+    pub friction_regions: Vec<FrictionRegionConfig>,
+    // 低速のボールにだけ働く追加の減速（省略時はなし）
+    #[serde(default)]
+    pub low_speed_damping: Option<LowSpeedDampingConfig>,
+    // 衝突処理の後にすべてのボールに適用する速さの上限（units.velocity の単位、省略時は上限なし）
+    #[serde(default)]
+    pub max_speed: Option<f32>,
+    // すべてのボールに働く一様な加速度 (x 軸, y 軸)（m/s^2、省略時は重力なし）
+    #[serde(default)]
+    pub gravity_xy: Option<(f32, f32)>,
+    // ボールが台の上を滑るときの滑り摩擦係数（省略時は 0 で、回転と並進は結合しない）
+    #[serde(default)]
+    pub slip_friction: Option<f32>,
+    // ボールの中心がほぼ同一点とみなす距離（cm、省略時は 0.001）
+    #[serde(default)]
+    pub collision_epsilon: Option<f32>,
+    // ボール同士の接点に働く摩擦係数（省略時は 0 で、接線方向の力は働かない）
+    #[serde(default)]
+    pub ball_friction: Option<f32>,
+    // ボール同士のめり込みを位置の補正で解消する反復の上限回数（省略時は 0 で、補正しない）
+    #[serde(default)]
+    pub solver_iterations: Option<usize>,
+    // 壁で跳ね返るときに壁に沿った速度に掛ける係数（省略時は 1.0 で、接線方向の速度は変化しない）
+    #[serde(default)]
+    pub cushion_tangential_damping: Option<f32>,
+    // ボールとクッションの接点に働く摩擦係数（省略時は 0 で、クッションとの摩擦による回転は生じない）
+    #[serde(default)]
+    pub cushion_friction: Option<f32>,
+    // 衝突回数によるクッションの反発係数の変化（省略時は変化なし）
+    #[serde(default)]
+    pub cushion_wear: Option<CushionWearConfig>,
+    // テーブルの個体差として、クッションの反発係数とラシャの摩擦に掛ける倍率を乱数で決める設定（省略時は個体差なし）
+    #[serde(default)]
+    pub table_defects: Option<TableDefectsConfig>,
+    // イベント駆動モードで 1 つのボールが 1 秒あたりに関与できる衝突数の上限（省略時は 1000）
+    #[serde(default)]
+    pub max_events_per_second: Option<f32>,
+    // すべての衝突の反発係数を上書きする値（1.0 で完全弾性、0.0 で完全非弾性、省略時は上書きしない）
+    #[serde(default)]
+    pub restitution_override: Option<f32>,
+    // ポケットの配置情報（省略時はポケットなし）
+    #[serde(default)]
+    pub pockets: Option<PocketsConfig>,
+    // 格子状に並べるペグの配置情報（省略時はペグなし）
+    #[serde(default)]
+    pub pegs: Option<PegGridConfig>,
+    // 斜めのレールなどに使う、線分のクッションの一覧（省略時はなし）
+    #[serde(default)]
+    pub cushion_segments: Vec<CushionSegmentConfig>,
+    // シミュレーション結果の出力先（省略時はログ出力のみ）
+    #[serde(default)]
+    pub output: OutputConfig,
+    // 位置・速度に NaN や無限大が現れた場合の扱い（省略時は Reset）
+    #[serde(default)]
+    pub non_finite_policy: NonFinitePolicy,
+    // デバッグビルドで各ステップ後に物理的な不変条件を検査する場合の許容誤差（省略時は検査しない）
+    #[serde(default)]
+    pub debug_invariants: Option<DebugInvariantsConfig>,
+    // 起動時に同じ設定から 2 回実行して結果が一致するかを確かめる自己検査（省略時は検査しない）
+    #[serde(default)]
+    pub determinism_check: Option<DeterminismCheckConfig>,
+    // ボールが静止しているとみなす速さの上限（units.velocity の単位、省略時は 0.1 cm/s）
+    #[serde(default)]
+    pub rest_threshold: Option<f32>,
+    // 速さが rest_threshold 以下のまま、このステップ数だけ続いたボールを休止させる（省略時は 0 で、休止しない）
+    #[serde(default)]
+    pub sleep_steps: u32,
+    // true の場合、テーブルからはみ出した初期位置を、壁との衝突として記録せずにクッションにちょうど接する位置へ移し、警告をログ出力する
+    // （省略時は false で、読み込み時にエラーとする）
+    #[serde(default)]
+    pub clamp_initial_positions: bool,
+    // 乱数の種（整数、または OS のエントロピーから選ぶ "random"。省略時は 0）。同じ種からは毎回同じ結果になる
+    #[serde(default)]
+    pub seed: SeedConfig,
+    // ボールの配置を自動で生成するシナリオ（省略時は manual で、entities などに記述した配置を使う）
+    #[serde(default)]
+    pub scenario: Scenario,
+    // scenario が break の場合に、手球をヘッドスポットからフットスポットへ向けて打ち出す速さ（units.velocity の単位）
+    #[serde(default)]
+    pub break_speed: Option<f32>,
+    // 的球の初期位置に加える揺らぎの標準偏差（cm、省略時は 0 で揺らがない）
+    #[serde(default)]
+    pub rack_jitter: f32,
+    // ボール同士をつなぐ距離の拘束の一覧（省略時は拘束なし、stepping が EventDriven の場合は指定できない）
+    #[serde(default)]
+    pub constraints: Vec<ConstraintConfig>,
+    // Simulation::run_shots で順に撞くショットの一覧（省略時はなし）
+    #[serde(default)]
+    pub shots: Vec<ShotConfig>,
+    // ショットの前に手球がテーブル上にない場合に、手球を置き直す位置（省略時は HeadSpot）
+    #[serde(default)]
+    pub ball_in_hand: BallInHand,
+    // 各ショットの後に、ボールが静止するのを待つ最大のステップ数（省略時は 10000）
+    #[serde(default)]
+    pub shot_max_steps: Option<usize>,
+    // ボールの種類の組み合わせごとの衝突の有無（省略時はすべての組み合わせが衝突）
+    #[serde(default)]
+    pub collision_rules: Vec<CollisionRuleConfig>,
+    // true の場合、的球同士の衝突を判定せず、手球と的球、ボールと壁の衝突だけを処理する
+    // （狙いの確認用の軽い近似で、collision_rules より優先する。省略時は false）
+    #[serde(default)]
+    pub cue_only_collisions: bool,
+    // ボールの数の見込み。各ステップで使う領域をあらかじめこの数だけ確保する（省略時は確保しない）
+    #[serde(default)]
+    pub max_balls: Option<usize>,
+    // true の場合、エンティティ（ボール、ペグ、ポケット、線分のクッション）の総数が max_balls を超える設定を読み込み時にエラーとする
+    // （省略時は false で、既定の上限 DEFAULT_BALL_LIMIT を超える場合のみエラーとする）
+    #[serde(default)]
+    pub max_balls_strict: bool,
+    // 各ボールの軌跡として保持する位置の数（省略時は 0 で、軌跡を記録しない）
+    #[serde(default)]
+    pub trail_capacity: usize,
+    // テーブルとボールの配置を ASCII のグリッドで標準出力に表示する設定（省略時は表示しない）
+    #[serde(default)]
+    pub terminal_render: Option<TerminalRenderConfig>,
+    // ログと出力先に書き出すボールの絞り込み（省略時はすべてのボール）
+    #[serde(default)]
+    pub log_filter: LogFilter,
+    // 実行するシステムの選択（省略時はすべて実行）
+    #[serde(default)]
+    pub systems: SystemsConfig,
+    // true の場合、1 を超える反発係数（跳ね返るたびに速くなる）を許可する（省略時は false で、0 以上 1 以下に限る）
+    #[serde(default)]
+    pub allow_energized: bool,
+    // 反発係数の異なるボール同士の衝突に用いる反発係数の決め方（省略時は Min）
+    #[serde(default)]
+    pub restitution_blend: RestitutionBlend,
+    // 同じステップで壁とボールの両方に接したときの処理の順序（省略時は WallFirst）
+    #[serde(default)]
+    pub collision_priority: CollisionPriority,
+    // ボール同士の衝突のインパルスを処理する方法（省略時は Serial）
+    #[serde(default)]
+    pub collision_solver: CollisionSolver,
+    // parallel フィーチャーが有効な場合に、システムを実行するスレッドプールのスレッド数（0 で CPU のコア数、省略時は 0）
+    // （CPU のコア数の MAX_THREADS_PER_CORE 倍を超える値は読み込み時にエラーとする）
+    #[serde(default)]
+    pub threads: usize,
+    // ボール同士の接触の扱い方（省略時は Impulse）
+    #[serde(default)]
+    pub contact_model: ContactModel,
+    // contact_model が Spring の場合に、1 ステップを分割してばねの力を計算する回数（省略時は 10）
+    #[serde(default)]
+    pub contact_substeps: Option<usize>,
+    // 速いボールが 1 ステップで他のボールや壁をすり抜けないよう、ステップを自動で分割する設定（省略時は分割しない）
+    #[serde(default)]
+    pub adaptive_substeps: Option<AdaptiveSubstepsConfig>,
+    // 時間の進め方（省略時は FixedStep）
+    #[serde(default)]
+    pub stepping: SteppingMode,
+    // 入力座標の原点の取り方（省略時は Corner）
+    #[serde(default)]
+    pub coordinate_origin: CoordinateOrigin,
+}
+
+impl Config {
+    /// 生成するボールの一覧を、割り当てる BallId とともに返します。
+    ///
+    /// `entities` が指定されている場合は、その並び順に BallId(0), BallId(1), ... を割り当てます。
+    /// 指定されていない場合は、従来どおり手球に BallId(0)、`object_balls.positions` の
+    /// i 番目（0 始まり）の的球に BallId(i + 1) を割り当てます。
+    /// ただし `scenario` が break の場合は、`cue_ball` と `object_balls` の代わりに break_entities() の
+    /// 配置を使います。
+    pub fn entity_list(&self) -> Vec<(BallId, EntityConfig)> {
+        if !self.entities.is_empty() {
+            return self
+                .entities
+                .iter()
+                .enumerate()
+                .map(|(index, entry)| (BallId(index as u32), entry.clone()))
+                .collect();
+        }
+        if self.scenario == Scenario::Break {
+            return self
+                .break_entities()
+                .into_iter()
+                .enumerate()
+                .map(|(index, entry)| (BallId(index as u32), entry))
+                .collect();
+        }
+        let cue = self
+            .cue_ball
+            .as_ref()
+            .map(|cue| (BallId(0), cue.to_entity_config()));
+        let objects = self
+            .object_balls
+            .positions
+            .iter()
+            .enumerate()
+            .map(|(index, pos)| (BallId(index as u32 + 1), pos.to_object_entity_config()));
+        cue.into_iter().chain(objects).collect()
+    }
+
+    /// ブレイクショットのシナリオ（`scenario: break`）のボールの一覧を返します。
+    ///
+    /// 先頭が手球で、ヘッドスポットに置き、フットスポットへ向けて `break_speed` の初速度を与えます。
+    /// 続く 15 個の的球は、先頭のボールをフットスポットに置き、手球から見て奥へ向かって
+    /// 5 行の三角形に並べます。座標は `coordinate_origin` に従う設定ファイル上の座標で返します。
+    pub fn break_entities(&self) -> Vec<EntityConfig> {
+        let head = self.table.head_spot(self.coordinate_origin);
+        let foot = self.table.foot_spot(self.coordinate_origin);
+        // ヘッドスポットからフットスポットへ向かう向き（2 点が一致する場合は +y）
+        let (dx, dy) = (foot.0 - head.0, foot.1 - head.1);
+        let distance = (dx * dx + dy * dy).sqrt();
+        let direction = if distance > 0.0 {
+            (dx / distance, dy / distance)
+        } else {
+            (0.0, 1.0)
+        };
+        let speed = self.break_speed.unwrap_or(0.0);
+        let ball = |kind: BallKind, (x, y): (f32, f32), (vx, vy): (f32, f32)| {
+            let (x, y) = self.coordinate_origin.from_corner(x, y, &self.table);
+            EntityConfig {
+                kind,
+                x,
+                y,
+                vx,
+                vy,
+                collision_mask: None,
+                color: None,
+                spin: None,
+                radius: None,
+                mass: None,
+                restitution: None,
+            }
+        };
+        let cue = ball(
+            BallKind::Cue,
+            head,
+            (direction.0 * speed, direction.1 * speed),
+        );
+        let rack = triangle_rack(foot, direction, self.ball.radius, 5)
+            .into_iter()
+            .map(|spot| ball(BallKind::Object, spot, (0.0, 0.0)));
+        std::iter::once(cue).chain(rack).collect()
+    }
+
+    /// 設定から生成されるエンティティ（ボール、ペグ、ポケット、線分のクッション）の総数を返します。
+    ///
+    /// ボールの数は Config::entity_list() と同じ規則で数えますが、一覧そのものは作りません。
+    /// ペグの数は行数と列数の積で、桁あふれする場合は usize::MAX とします。
+    pub fn entity_count(&self) -> usize {
+        let balls = if !self.entities.is_empty() {
+            self.entities.len()
+        } else if self.scenario == Scenario::Break {
+            self.break_entities().len()
+        } else {
+            usize::from(self.cue_ball.is_some()) + self.object_balls.positions.len()
+        };
+        let pegs = self
+            .pegs
+            .as_ref()
+            .map_or(0, |pegs| pegs.rows.saturating_mul(pegs.columns));
+        let pockets = self
+            .pockets
+            .as_ref()
+            .map_or(0, |pockets| pockets.positions.len());
+        balls
+            .saturating_add(pegs)
+            .saturating_add(pockets)
+            .saturating_add(self.cushion_segments.len())
+    }
+
+    /// 読み込み時に許容するエンティティ（ボール、ペグ、ポケット、線分のクッション）の総数の上限を返します。
+    ///
+    /// `max_balls_strict` が true で `max_balls` が指定されている場合はその値、
+    /// それ以外の場合は DEFAULT_BALL_LIMIT です。
+    pub fn ball_limit(&self) -> usize {
+        match (self.max_balls, self.max_balls_strict) {
+            (Some(max_balls), true) => max_balls,
+            _ => DEFAULT_BALL_LIMIT,
+        }
+    }
+
+    /// ボールが静止しているとみなす速さの上限を、内部の単位（cm/s）に変換して返します。
+    ///
+    /// # 戻り値
+    /// `rest_threshold` を `units.velocity` の単位として変換した値。省略されている場合は None
+    pub fn rest_threshold_cm_per_s(&self) -> Option<f32> {
+        self.rest_threshold
+            .map(|threshold| self.units.velocity.to_cm_per_s(threshold))
+    }
+}
+
+/// 汎用のボールの生成情報を保持する構造体です。
+///
+/// 省略した物理パラメータ（半径・質量・反発係数）は、`ball` セクションの値を使います。
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EntityConfig {
+    // ボールの種類（Cue または Object）
+    pub kind: BallKind,
+    // 初期位置（cm）
+    pub x: f32,
+    pub y: f32,
+    // 初速度（units.velocity の単位、省略時は 0）
+    #[serde(default)]
+    pub vx: f32,
+    #[serde(default)]
+    pub vy: f32,
+    // ボール同士の衝突レイヤー（省略時はすべてのボールと衝突）
+    #[serde(default)]
+    pub collision_mask: Option<u32>,
+    // 描画に用いる色（"#rrggbb" 形式、省略時は既定の色）
+    #[serde(default)]
+    pub color: Option<String>,
+    // 初期の回転 [x, y, z]（units.spin の単位、省略時は回転なし）
+    #[serde(default)]
+    pub spin: Option<(f32, f32, f32)>,
+    // 半径（cm）の上書き
+    #[serde(default)]
+    pub radius: Option<f32>,
+    // 質量の上書き
+    #[serde(default)]
+    pub mass: Option<f32>,
+    // 反発係数の上書き
+    #[serde(default)]
+    pub restitution: Option<f32>,
+}
+
+/// 実行するシステムの選択を保持する構造体です。省略した項目は、log_energy を除いて true（実行する）になります。
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct SystemsConfig {
+    // 速度に基づく位置の更新（false の場合、ボールは静止したまま）
+    pub physics: bool,
+    // 壁やボール同士の衝突処理
+    pub collision: bool,
+    // ポケットの判定
+    pub pockets: bool,
+    // ボールの位置のログ出力
+    pub logging: bool,
+    // 全ボールの運動エネルギーの合計の、1 ステップにつき 1 行のログ出力（省略時は false）
+    pub log_energy: bool,
+    // 統計情報の集計
+    pub stats: bool,
+}
+
+impl Default for SystemsConfig {
+    fn default() -> Self {
+        SystemsConfig {
+            physics: true,
+            collision: true,
+            pockets: true,
+            logging: true,
+            log_energy: false,
+            stats: true,
+        }
+    }
+}
+
+/// ログ出力と出力先（CSV/JSON など）に書き出すボールの絞り込み条件を表す列挙型です。
+///
+/// YAML では `log_filter: Cue` や `log_filter: !Id 3`、JSON では `"log_filter": {"Id": 3}` のように記述します。
+/// World にもそのままリソースとして登録されます。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum LogFilter {
+    /// すべてのボールを出力します（既定値）。
+    #[default]
+    All,
+    /// 手球のみを出力します。
+    Cue,
+    /// 的球のみを出力します。
+    Object,
+    /// 指定した BallId のボールのみを出力します。
+    Id(u32),
+}
+
+impl LogFilter {
+    /// 指定したボールが出力の対象かどうかを返します。
+    pub fn matches(self, id: BallId, kind: BallKind) -> bool {
+        match self {
+            LogFilter::All => true,
+            LogFilter::Cue => kind == BallKind::Cue,
+            LogFilter::Object => kind == BallKind::Object,
+            LogFilter::Id(target) => id.0 == target,
+        }
+    }
+}
+
+/// シミュレーションの時間の進め方を表す列挙型です。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SteppingMode {
+    /// 固定の時間刻み dt ごとに積分し、重なったボールを衝突として処理します（既定値）。
+    #[default]
+    FixedStep,
+    /// 各ステップ内で衝突時刻まで正確に進め、衝突を解決することを繰り返します。
+    /// 摩擦のない等速直線運動を前提とするため、摩擦や重力などの速度を変える設定とは組み合わせられません。
+    EventDriven,
+}
+
+/// 位置や速度に NaN や無限大が現れた場合の扱いを表す列挙型です。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum NonFinitePolicy {
+    /// 該当するボールを静止させ、位置が不正な場合はテーブルの中央に戻します（既定値）。
+    #[default]
+    Reset,
+    /// シミュレーションの実行を中断します。
+    Abort,
+}
+
+/// 同じステップで壁とボールの両方に接したボールについて、どちらとの衝突を先に処理するかを表す列挙型です。
+///
+/// 先に処理した衝突で速度が変わると、後の衝突の判定や結果も変わります。
+/// 例えば壁に向かって進むボールが、同じステップで静止したボールにも接している場合、
+/// WallFirst では壁で跳ね返ってから（壁から離れる向きの速度で）ボールとの衝突を判定し、
+/// BallFirst ではボールとの衝突で速度を受け渡してから壁との衝突を判定します。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum CollisionPriority {
+    /// 壁との衝突を先に処理します（既定値）。
+    #[default]
+    WallFirst,
+    /// ボール同士の衝突を先に処理します。
+    BallFirst,
+}
+
+/// ボール同士の接触の扱い方を表す列挙型です。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+pub enum ContactModel {
+    /// 衝突の瞬間にインパルスを与えて速度を変えます（既定値）。
+    #[default]
+    Impulse,
+    /// 重なったボール同士に、重なりの深さに比例する反発力と、法線方向の相対速度に比例する減衰力を
+    /// PhysicsSystem で働かせます。`stiffness` はばね定数（N/m）、`damping` は減衰係数（N·s/m）です。
+    /// 力を安定して積分するため、1 ステップを `contact_substeps` 回に分割して計算します。
+    /// イベント駆動モード（EventDriven）とは組み合わせられません。
+    Spring { stiffness: f32, damping: f32 },
+}
+
+/// ボール同士の衝突のインパルスを求めて速度に加える方法を表す列挙型です。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum CollisionSolver {
+    /// すべてのペアを 1 つずつ順に処理します（既定値）。
+    #[default]
+    Serial,
+    /// 衝突しているペアを同じボールを含まないバッチに分け、バッチを決まった順に、各バッチの中のペアを
+    /// 並列に処理します（`parallel` フィーチャーが無効な場合は順に処理します）。
+    /// 各ボールへの変化は Serial と同じ順序で加わるため、結果は Serial と完全に一致します。
+    Colored,
+}
+
+/// 反発係数の異なるボール同士が衝突したときに、衝突に用いる反発係数の決め方を表す列挙型です。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum RestitutionBlend {
+    /// 両者のうち小さい方を用います（既定値）。
+    #[default]
+    Min,
+    /// 両者のうち大きい方を用います。
+    Max,
+    /// 両者の平均を用います。
+    Average,
+    /// 両者の積を用います。
+    Product,
+}
+
+impl RestitutionBlend {
+    /// 2 つのボールの反発係数から、衝突に用いる反発係数を求めます。
+    pub fn combine(self, a: f32, b: f32) -> f32 {
+        match self {
+            RestitutionBlend::Min => a.min(b),
+            RestitutionBlend::Max => a.max(b),
+            RestitutionBlend::Average => (a + b) / 2.0,
+            RestitutionBlend::Product => a * b,
+        }
+    }
+}
+
+/// CollisionSystem の処理の段階を表す列挙型です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollisionPhase {
+    /// テーブルの壁との衝突
+    Walls,
+    /// ボール同士の衝突
+    Balls,
+}
+
+impl CollisionPriority {
+    /// 優先順位に従って、衝突処理の段階を実行する順に返します。
+    pub fn phases(self) -> [CollisionPhase; 2] {
+        match self {
+            CollisionPriority::WallFirst => [CollisionPhase::Walls, CollisionPhase::Balls],
+            CollisionPriority::BallFirst => [CollisionPhase::Balls, CollisionPhase::Walls],
+        }
+    }
+}
+
+/// 乱数の種の指定を表す列挙型です。
+///
+/// 設定ファイルでは整数か文字列 `"random"` で指定します。`"random"` の場合は設定の読み込み時に
+/// OS のエントロピーから種を選んでログに出力し、Fixed に置き換えます。ログに出力された種を
+/// 指定し直すと、同じ実行を再現できます。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum SeedConfig {
+    /// 固定の種
+    Fixed(u64),
+    /// 実行ごとに選ぶ種
+    Keyword(SeedKeyword),
+}
+
+/// 乱数の種を整数以外で指定するときのキーワードです。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SeedKeyword {
+    /// OS のエントロピーから種を選びます。
+    #[serde(rename = "random")]
+    Random,
+}
+
+impl Default for SeedConfig {
+    fn default() -> Self {
+        SeedConfig::Fixed(0)
+    }
+}
+
+impl SeedConfig {
+    /// 乱数の種の値を返します。
+    ///
+    /// `"random"` の場合は、呼び出すたびに OS のエントロピーから新しい種を選びます。
+    /// 設定ファイルから読み込んだ Config では読み込み時に Fixed へ置き換え済みのため、常に同じ値になります。
+    pub fn value(self) -> u64 {
+        match self {
+            SeedConfig::Fixed(seed) => seed,
+            SeedConfig::Keyword(SeedKeyword::Random) => {
+                // RandomState は OS のエントロピーで初期化された鍵を持つため、そのハッシュ値を種に使います。
+                let mut hasher = RandomState::new().build_hasher();
+                hasher.write_u64(0);
+                hasher.finish()
+            }
+        }
+    }
+}
+
+/// 1 m あたりの cm の数です。内部では長さを cm で扱います。
+pub const CM_PER_M: f32 = 100.0;
+
+/// 設定ファイルに記述する値の単位の設定を保持する構造体です。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct UnitsConfig {
+    // 初速度の単位（省略時は MPerS）
+    pub velocity: VelocityUnit,
+    // 初期の回転の単位（省略時は RadPerS）
+    pub spin: SpinUnit,
+}
+
+/// 設定ファイルに記述する回転（角速度）の単位を表す列挙型です。内部では常に rad/s に変換して扱います。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SpinUnit {
+    /// ラジアン毎秒（内部の単位と同じ、既定値）
+    #[default]
+    RadPerS,
+    /// 回転毎秒
+    RevPerS,
+}
+
+impl SpinUnit {
+    /// この単位で表した角速度を、内部の単位（rad/s）に変換します。
+    pub fn to_rad_per_s(self, value: f32) -> f32 {
+        match self {
+            SpinUnit::RadPerS => value,
+            // 1 回転 = 2π rad
+            SpinUnit::RevPerS => value * std::f32::consts::TAU,
+        }
+    }
+}
+
+/// 設定ファイルに記述する速度の単位を表す列挙型です。内部では常に cm/s に変換して扱います。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum VelocityUnit {
+    /// センチメートル毎秒（内部の単位と同じ）
+    CmPerS,
+    /// メートル毎秒（既定値）
+    #[default]
+    MPerS,
+    /// キロメートル毎時
+    KmH,
+}
+
+impl VelocityUnit {
+    /// この単位で表した速度を、内部の単位（cm/s）に変換します。
+    pub fn to_cm_per_s(self, value: f32) -> f32 {
+        match self {
+            VelocityUnit::CmPerS => value,
+            VelocityUnit::MPerS => value * CM_PER_M,
+            // 1 km/h = 1000 m / 3600 s
+            VelocityUnit::KmH => value * CM_PER_M * 1000.0 / 3600.0,
+        }
+    }
+}
+
+/// 設定ファイルに記述する座標の原点の取り方を表す列挙型です。
+///
+/// 内部表現は常にテーブルの隅（左下）を原点とした座標に正規化されます。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum CoordinateOrigin {
+    /// テーブルの隅を原点とします（壁は 0 と width/height の位置）。
+    #[default]
+    Corner,
+    /// テーブルの中心を原点とします（壁は ±width/2, ±height/2 の位置）。
+    Center,
+}
+
+impl CoordinateOrigin {
+    /// 設定ファイル上の座標 (x, y) を、隅を原点とした内部座標に変換します。
+    ///
+    /// # 引数
+    /// - `x`, `y`: 設定ファイルに記述された座標
+    /// - `table`: テーブルの寸法情報
+    ///
+    /// # 戻り値
+    /// 内部表現（隅原点）の座標 (x, y) を返します。
+    pub fn to_corner(self, x: f32, y: f32, table: &TableConfig) -> (f32, f32) {
+        match self {
+            CoordinateOrigin::Corner => (x, y),
+            CoordinateOrigin::Center => (x + table.width / 2.0, y + table.height / 2.0),
+        }
+    }
+
+    /// 隅を原点とした内部座標 (x, y) を、設定ファイル上の座標に変換します（to_corner() の逆変換）。
+    pub fn from_corner(self, x: f32, y: f32, table: &TableConfig) -> (f32, f32) {
+        match self {
+            CoordinateOrigin::Corner => (x, y),
+            CoordinateOrigin::Center => (x - table.width / 2.0, y - table.height / 2.0),
+        }
+    }
+}
+
+/// ボールの配置を自動で生成するシナリオを表す列挙型です。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Scenario {
+    /// 設定ファイルに記述した配置を使います（既定値）。
+    #[default]
+    #[serde(rename = "manual")]
+    Manual,
+    /// フットスポットに 15 個の的球を三角形に並べ、ヘッドスポットの手球を
+    /// `break_speed` でフットスポットへ向けて打ち出すブレイクショットです。
+    #[serde(rename = "break")]
+    Break,
 }
 
 /// テーブルの寸法情報を保持する構造体です。
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct TableConfig {
     pub width: f32,
     pub height: f32,
+    // クッションの形状（省略時は Rectangle）
+    #[serde(default)]
+    pub shape: TableShape,
+    // フットスポットの位置（省略時は横方向中央、縦方向 3/4 の位置）
+    #[serde(default)]
+    pub foot_spot: Option<PositionConfig>,
+    // ヘッドスポットの位置（省略時は横方向中央、縦方向 1/4 の位置）
+    #[serde(default)]
+    pub head_spot: Option<PositionConfig>,
+}
+
+impl TableConfig {
+    /// フットスポットの位置を、隅を原点とした内部座標で返します。
+    ///
+    /// 設定で省略されている場合は、テーブルの横方向中央・縦方向 3/4 の位置を返します。
+    pub fn foot_spot(&self, origin: CoordinateOrigin) -> (f32, f32) {
+        match &self.foot_spot {
+            Some(spot) => origin.to_corner(spot.x, spot.y, self),
+            None => (self.width / 2.0, self.height * 0.75),
+        }
+    }
+
+    /// ヘッドスポットの位置を、隅を原点とした内部座標で返します。
+    ///
+    /// 設定で省略されている場合は、テーブルの横方向中央・縦方向 1/4 の位置を返します。
+    pub fn head_spot(&self, origin: CoordinateOrigin) -> (f32, f32) {
+        match &self.head_spot {
+            Some(spot) => origin.to_corner(spot.x, spot.y, self),
+            None => (self.width / 2.0, self.height * 0.25),
+        }
+    }
+
+    /// 隅を原点とした座標 (x, y) にある半径 `radius` のボールを、クッションの内側に収まる
+    /// 最も近い位置へ移した座標を返します。すでに内側にある場合は、そのままの座標を返します。
+    ///
+    /// 長方形のテーブルでは各座標を `[radius, 寸法 - radius]` の範囲に収め、
+    /// 円形のテーブルでは中心からの距離が `半径 - radius` 以下になるよう中心に向かって移します。
+    pub fn clamp_ball(&self, x: f32, y: f32, radius: f32) -> (f32, f32) {
+        match self.shape {
+            TableShape::Rectangle => (
+                x.clamp(radius, (self.width - radius).max(radius)),
+                y.clamp(radius, (self.height - radius).max(radius)),
+            ),
+            TableShape::Circle {
+                radius: table_radius,
+            } => {
+                let (cx, cy) = (self.width / 2.0, self.height / 2.0);
+                let (dx, dy) = (x - cx, y - cy);
+                let distance = (dx * dx + dy * dy).sqrt();
+                let limit = (table_radius - radius).max(0.0);
+                if distance <= limit {
+                    (x, y)
+                } else {
+                    (cx + dx / distance * limit, cy + dy / distance * limit)
+                }
+            }
+        }
+    }
+}
+
+/// テーブルのクッションの形状を表す列挙型です。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+pub enum TableShape {
+    /// 横幅 width、高さ height の長方形です（既定値）。
+    #[default]
+    Rectangle,
+    /// テーブルの中心（width / 2, height / 2）を中心とする、半径 radius（cm）の円形です。
+    /// width と height は、円を囲む範囲として 2 × radius 以上にしてください。
+    Circle { radius: f32 },
 }
 
 /// ボールの物理特性を保持する構造体です。（手球、的球共通）
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct BallConfig {
     pub radius: f32,
     pub mass: f32,
@@ -39,37 +776,712 @@ pub struct BallConfig {
 }
 
 /// 手球の初期位置・初速度情報を保持する構造体です。
-#[derive(Debug, Deserialize)]
+///
+/// 初速度は、成分（`vx`, `vy`）か、速さと向き（`speed`, `angle_deg`）のどちらか一方で指定します。
+/// 角度は +x 方向を 0 度とし、反時計回り（+y 方向が 90 度）に測ります。
+#[derive(Debug, Deserialize, Serialize)]
 pub struct CueBallConfig {
     pub x: f32,
     pub y: f32,
-    pub vx: f32,
-    pub vy: f32,
+    // 初速度の成分（units.velocity の単位）
+    #[serde(default)]
+    pub vx: Option<f32>,
+    #[serde(default)]
+    pub vy: Option<f32>,
+    // 初速度の速さ（units.velocity の単位）と向き（度）
+    #[serde(default)]
+    pub speed: Option<f32>,
+    #[serde(default)]
+    pub angle_deg: Option<f32>,
+    // 初期の回転 [x, y, z]（units.spin の単位、省略時は回転なし）
+    #[serde(default)]
+    pub spin: Option<(f32, f32, f32)>,
+    // ボール同士の衝突レイヤー（省略時はすべてのボールと衝突）
+    #[serde(default)]
+    pub collision_mask: Option<u32>,
+}
+
+impl CueBallConfig {
+    /// 初速度 (vx, vy)（units.velocity の単位）を求めます。
+    ///
+    /// # 戻り値
+    /// `vx` と `vy` の組、または `speed` と `angle_deg` の組のどちらか一方だけが
+    /// 指定されている場合は初速度、そうでない場合は Error を返します。
+    pub fn velocity(&self) -> Result<(f32, f32), Box<dyn Error>> {
+        match (self.vx, self.vy, self.speed, self.angle_deg) {
+            (Some(vx), Some(vy), None, None) => Ok((vx, vy)),
+            (None, None, Some(speed), Some(angle_deg)) => {
+                let angle = angle_deg.to_radians();
+                Ok((speed * angle.cos(), speed * angle.sin()))
+            }
+            _ => Err("cue_ball: specify either vx and vy, or speed and angle_deg".into()),
+        }
+    }
+
+    /// 汎用のボールの生成情報に変換します。
+    ///
+    /// 初速度の指定は読み込み時に検証済みのため、ここでは不正な指定を 0 として扱います。
+    pub fn to_entity_config(&self) -> EntityConfig {
+        let (vx, vy) = self.velocity().unwrap_or((0.0, 0.0));
+        EntityConfig {
+            kind: BallKind::Cue,
+            x: self.x,
+            y: self.y,
+            vx,
+            vy,
+            collision_mask: self.collision_mask,
+            color: None,
+            spin: self.spin,
+            radius: None,
+            mass: None,
+            restitution: None,
+        }
+    }
 }
 
 /// 的球の配置情報を保持する構造体です。
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct ObjectBallsConfig {
     pub positions: Vec<PositionConfig>,
 }
 
+/// 2 つのボールをばねでつなぐ距離の拘束の設定を保持する構造体です。
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ConstraintConfig {
+    // つなぐボールの BallId の番号
+    pub a: u32,
+    pub b: u32,
+    // 力が働かない中心間距離（cm）
+    pub rest_length: f32,
+    // ばね定数（距離のずれ 1 cm あたりの力）
+    pub stiffness: f32,
+}
+
+/// ボールの種類の組み合わせごとに衝突させるかどうかの設定を保持する構造体です。
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CollisionRuleConfig {
+    // 対象とするボールの種類の組み合わせ（順序は問わない）
+    pub kinds: (BallKind, BallKind),
+    // true で衝突する、false ですり抜ける
+    pub enabled: bool,
+}
+
+/// 摩擦が異なる円形の領域の設定を保持する構造体です。
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FrictionRegionConfig {
+    // 中心の位置（coordinate_origin に従う）
+    pub x: f32,
+    pub y: f32,
+    // 半径（cm）
+    pub radius: f32,
+    // 領域内で摩擦に掛ける倍率
+    pub multiplier: f32,
+}
+
+/// 低速のボールにだけ働く追加の減速の設定を保持する構造体です。
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LowSpeedDampingConfig {
+    // 追加の減速が働き始める速さ（units.velocity の単位）
+    pub threshold: f32,
+    // 追加の減速度（m/s^2）
+    pub deceleration: f32,
+}
+
+/// ステップの自動分割の設定を保持する構造体です。
+///
+/// 各ステップの初めに、最も速いボールが 1 回の分割で進む距離が、最も小さいボールの半径の
+/// `max_step_fraction` 倍以下になるよう分割数を決め、運動と衝突処理をその回数だけ繰り返します。
+/// イベント駆動モード（EventDriven）とは組み合わせられません。
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct AdaptiveSubstepsConfig {
+    // 1 回の分割でボールが進んでよい距離の、最も小さいボールの半径に対する割合（省略時は 0.5）
+    #[serde(default)]
+    pub max_step_fraction: Option<f32>,
+    // 1 ステップの分割数の上限（省略時は 64）
+    #[serde(default)]
+    pub max_substeps: Option<usize>,
+}
+
+/// 端末への ASCII 表示の設定を保持する構造体です。
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct TerminalRenderConfig {
+    // グリッドの横の文字数（省略時は 40）
+    #[serde(default)]
+    pub columns: Option<usize>,
+    // グリッドの縦の文字数（省略時は 20）
+    #[serde(default)]
+    pub rows: Option<usize>,
+    // 表示するステップの間隔（省略時は 1 で、毎ステップ表示する）
+    #[serde(default)]
+    pub every: Option<usize>,
+}
+
+/// Simulation::run_shots で撞く 1 回のショットを保持する構造体です。
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct ShotConfig {
+    // 撞く向き（+x 軸から反時計回りの角度、度）
+    pub angle_deg: f32,
+    // 手球の初速度の大きさ（units.velocity の単位）
+    pub speed: f32,
+    // 撞点の種類（省略時は Stun）
+    #[serde(default)]
+    pub spin: ShotSpin,
+}
+
+/// ショットの前に手球がテーブル上にない（ポケットに入った）場合に、手球を置き直す位置を表す列挙型です。
+///
+/// どちらの場合も、他のボールと重なるときは find_free_spot で近くの空いた位置に置きます。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum BallInHand {
+    /// ヘッドスポットに置きます（既定値）。
+    #[default]
+    HeadSpot,
+    /// シミュレーションを生成したときの手球の位置に置きます。
+    InitialPosition,
+}
+
+/// 衝突回数によるクッションの反発係数の変化の設定を保持する構造体です。
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CushionWearConfig {
+    // 衝突 1 回あたりの反発係数の変化量
+    pub rate: f32,
+    // 実効的な反発係数の下限
+    pub min: f32,
+    // 実効的な反発係数の上限
+    pub max: f32,
+}
+
+/// テーブルの個体差を乱数で決める設定を保持する構造体です。
+/// 倍率は seed から決まるため、同じ seed では毎回同じ値になります。
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct TableDefectsConfig {
+    // 壁ごとのクッションの反発係数に掛ける倍率の分布（省略時は常に 1.0）
+    #[serde(default)]
+    pub cushion_restitution: DefectDistribution,
+    // ラシャの摩擦（friction_xy と slip_friction）に掛ける倍率の分布（省略時は常に 1.0）
+    #[serde(default)]
+    pub cloth_friction: DefectDistribution,
+}
+
+/// 個体差の倍率を引く正規分布を表す構造体です。
+#[derive(Debug, Default, Clone, Copy, Deserialize, Serialize)]
+pub struct DefectDistribution {
+    // 平均（省略時は 1.0）
+    #[serde(default)]
+    pub mean: Option<f32>,
+    // 標準偏差（省略時は 0 で、常に平均の値になる）
+    #[serde(default)]
+    pub std_dev: f32,
+}
+
+/// ポケットの配置情報を保持する構造体です。
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PocketsConfig {
+    // ポケットの半径（cm）
+    pub radius: f32,
+    // 各ポケットの中心位置
+    pub positions: Vec<PositionConfig>,
+    // true の場合、ポケットされた的球を削除せずフットスポットに戻します。
+    #[serde(default)]
+    pub respot: bool,
+    // 削除するボールを、中心へ引き寄せながら縮めて落とす演出にかけるステップ数（省略時は 0 で、すぐに削除）
+    #[serde(default)]
+    pub drop_steps: u32,
+}
+
+/// 格子状に並べるペグの配置情報を保持する構造体です。
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PegGridConfig {
+    // 1 行目の 1 列目のペグの中心位置（coordinate_origin に従う）
+    pub x: f32,
+    pub y: f32,
+    // 行と列の数
+    pub rows: usize,
+    pub columns: usize,
+    // 列同士（x 軸方向）と行同士（y 軸方向）の間隔（cm）
+    pub spacing_x: f32,
+    pub spacing_y: f32,
+    // ペグの半径（cm）
+    pub radius: f32,
+    // ペグの反発係数（省略時は 1.0）
+    #[serde(default)]
+    pub restitution: Option<f32>,
+    // true の場合、奇数行を列の間隔の半分だけずらして互い違いに並べる
+    #[serde(default)]
+    pub stagger: bool,
+}
+
+/// 線分のクッション 1 つの設定を保持する構造体です。
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CushionSegmentConfig {
+    // 線分の始点（coordinate_origin に従う）
+    pub x1: f32,
+    pub y1: f32,
+    // 線分の終点（coordinate_origin に従う）
+    pub x2: f32,
+    pub y2: f32,
+    // クッションの反発係数（省略時は 1.0）
+    #[serde(default)]
+    pub restitution: Option<f32>,
+}
+
+/// 不変条件の検査の許容誤差を保持する構造体です。
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DebugInvariantsConfig {
+    // ボール同士のめり込み、およびテーブルからのはみ出しの許容量（cm）
+    pub penetration_tolerance: f32,
+    // 外力が働かないステップでの、全運動量の変化の許容割合
+    pub momentum_tolerance: f32,
+}
+
+/// 起動時の決定性の自己検査の設定を保持する構造体です。
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DeterminismCheckConfig {
+    // 2 回の実行それぞれで進めるステップ数
+    pub steps: usize,
+    // 結果が一致しなかった場合の扱い（省略時は Warn）
+    #[serde(default)]
+    pub on_mismatch: MismatchPolicy,
+}
+
+/// 決定性の自己検査で結果が一致しなかった場合の扱いを表す列挙型です。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum MismatchPolicy {
+    /// 警告をログに出力し、そのまま実行を続けます（既定値）。
+    #[default]
+    Warn,
+    /// エラーとして扱い、実行を中止します。
+    Error,
+}
+
+/// シミュレーション結果の出力先を保持する構造体です。
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct OutputConfig {
+    // CSV 形式で書き出すファイルのパス
+    #[serde(default)]
+    pub csv: Option<String>,
+    // JSON Lines 形式で書き出すファイルのパス
+    #[serde(default)]
+    pub json: Option<String>,
+    // CSV 出力に付随して、ボール同士の衝突の一覧を書き出すファイルのパス
+    #[serde(default)]
+    pub collisions_csv: Option<String>,
+    // JSON 出力に付随して、ボール同士の衝突の一覧を書き出すファイルのパス
+    #[serde(default)]
+    pub collisions_json: Option<String>,
+    // CSV・JSON の各ファイルをフラッシュする間隔（ステップ数、省略時は 0 で、実行の終了時のみ）
+    #[serde(default)]
+    pub flush_every: usize,
+}
+
 /// 各ボールの初期位置情報を保持する構造体です。
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct PositionConfig {
     pub x: f32,
     pub y: f32,
+    // ボール同士の衝突レイヤー（的球のみ使用、省略時はすべてのボールと衝突）
+    #[serde(default)]
+    pub collision_mask: Option<u32>,
+    // 描画に用いる色（"#rrggbb" 形式、的球のみ使用、省略時は BallId に応じた既定の色）
+    #[serde(default)]
+    pub color: Option<String>,
+    // 初期の回転 [x, y, z]（units.spin の単位、省略時は回転なし）
+    #[serde(default)]
+    pub spin: Option<(f32, f32, f32)>,
+}
+
+impl PositionConfig {
+    /// 静止した的球として、汎用のボールの生成情報に変換します。
+    pub fn to_object_entity_config(&self) -> EntityConfig {
+        EntityConfig {
+            kind: BallKind::Object,
+            x: self.x,
+            y: self.y,
+            vx: 0.0,
+            vy: 0.0,
+            collision_mask: self.collision_mask,
+            color: self.color.clone(),
+            spin: self.spin,
+            radius: None,
+            mass: None,
+            restitution: None,
+        }
+    }
 }
 
-/// 指定されたパスから YAML 設定ファイルを読み込み、Config を返す関数です。
+/// 設定ファイルの書式を表す列挙型です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// YAML 形式
+    Yaml,
+    /// JSON 形式
+    Json,
+}
+
+impl Format {
+    /// ファイルの拡張子から書式を推定します。
+    ///
+    /// 拡張子が `json` の場合は Json、それ以外（`yaml`, `yml`, 拡張子なし等）は Yaml とみなします。
+    pub fn from_path(path: &Path) -> Format {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Format::Json,
+            _ => Format::Yaml,
+        }
+    }
+}
+
+/// 指定されたパスから設定ファイルを読み込み、Config を返す関数です。
+///
+/// 書式はファイルの拡張子から推定し、読み込みは load_config_from_reader に委譲します。
 ///
 /// # 引数
 /// - `path`: 設定ファイルのパス
 ///
 /// # 戻り値
-/// 読み込みに成功した場合は Config、失敗した場合は Error を返します。
+/// 読み込みに成功した場合は Config、読み込みや内容の検証に失敗した場合は Error を返します。
 pub fn load_config(path: &str) -> Result<Config, Box<dyn Error>> {
     let file = File::open(path)?;
     let reader = BufReader::new(file);
-    let config: Config = serde_yaml::from_reader(reader)?;
+    load_config_from_reader(reader, Format::from_path(Path::new(path)))
+}
+
+/// 任意の読み込み元（標準入力やメモリ上のバイト列など）から設定を読み込み、Config を返す関数です。
+///
+/// # 引数
+/// - `reader`: 設定を読み込む Read の実装（例: `std::io::stdin().lock()`）
+/// - `format`: 設定の書式
+///
+/// # 戻り値
+/// 読み込みに成功した場合は Config、読み込みや内容の検証に失敗した場合は Error を返します。
+pub fn load_config_from_reader<R: Read>(
+    reader: R,
+    format: Format,
+) -> Result<Config, Box<dyn Error>> {
+    let config: Config = match format {
+        Format::Yaml => serde_yaml::from_reader(reader)?,
+        Format::Json => serde_json::from_reader(reader)?,
+    };
+    validate(config)
+}
+
+/// 読み込んだ設定の内容を検証し、乱数の種などを確定させた Config を返す関数です。
+///
+/// 設定ファイルからの読み込みと ConfigBuilder の両方で、同じ検証を行うために使います。
+///
+/// # 引数
+/// - `config`: 検証する設定
+///
+/// # 戻り値
+/// 検証に成功した場合は Config、内容に誤りがある場合は Error を返します。
+pub(crate) fn validate(mut config: Config) -> Result<Config, Box<dyn Error>> {
+    // 乱数の種が "random" の場合は、ここで種を選んでログに出力し、実行の途中で変わらないよう固定します。
+    if let SeedConfig::Keyword(SeedKeyword::Random) = config.seed {
+        let seed = config.seed.value();
+        tracing::info!(
+            "Using random seed {} (set `seed: {}` to reproduce this run)",
+            seed,
+            seed
+        );
+        config.seed = SeedConfig::Fixed(seed);
+    }
+    // 誤った設定で膨大な数のエンティティを生成しないよう、エンティティの総数は上限以下である必要があります。
+    // 一覧を作る前に数だけを確かめ、上限を超える設定ではエンティティもその一覧も作りません。
+    let count = config.entity_count();
+    if count > config.ball_limit() {
+        return Err(match (config.max_balls, config.max_balls_strict) {
+            (Some(max_balls), true) => format!(
+                "{count} entities (balls, pegs, pockets and cushion segments) exceed max_balls ({max_balls})"
+            ),
+            _ => format!(
+                "{count} entities (balls, pegs, pockets and cushion segments) exceed the default limit of {DEFAULT_BALL_LIMIT} (set max_balls and max_balls_strict to allow more)"
+            ),
+        }
+        .into());
+    }
+    // スレッドプールのスレッド数は、CPU のコア数の MAX_THREADS_PER_CORE 倍以下である必要があります。
+    let cores = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let max_threads = cores * MAX_THREADS_PER_CORE;
+    if config.threads > max_threads {
+        return Err(format!(
+            "threads ({}) exceeds the limit of {max_threads} ({MAX_THREADS_PER_CORE} per CPU core)",
+            config.threads
+        )
+        .into());
+    }
+    // 手球の初速度は、成分か速さと向きのどちらか一方で指定されている必要があります。
+    if let Some(cue_ball) = &config.cue_ball {
+        cue_ball.velocity()?;
+    }
+    // ブレイクショットのシナリオでは、手球を打ち出す速さを指定する必要があります。
+    if config.scenario == Scenario::Break
+        && config.entities.is_empty()
+        && config.break_speed.is_none()
+    {
+        return Err("scenario: break requires break_speed".into());
+    }
+    // 時間刻みは正の有限の値である必要があります。負の値では時間を表せず、0 ではボールが動きません。
+    if !(config.dt.is_finite() && config.dt > 0.0) {
+        return Err(format!("dt must be finite and positive: {}", config.dt).into());
+    }
+    // ボールの質量は、共通の値も個別の上書きも、正の有限の値である必要があります。
+    if !(config.ball.mass.is_finite() && config.ball.mass > 0.0) {
+        return Err(format!(
+            "ball.mass must be finite and positive: {}",
+            config.ball.mass
+        )
+        .into());
+    }
+    for (id, entry) in config.entity_list() {
+        if let Some(mass) = entry.mass {
+            if !(mass.is_finite() && mass > 0.0) {
+                return Err(
+                    format!("Ball {}: mass must be finite and positive: {}", id.0, mass).into(),
+                );
+            }
+        }
+    }
+    // ボールの半径は、共通の値も個別の上書きも、正の有限の値である必要があります。
+    if config.ball.radius <= 0.0 || !config.ball.radius.is_finite() {
+        return Err(format!(
+            "ball.radius must be finite and positive: {}",
+            config.ball.radius
+        )
+        .into());
+    }
+    for (id, entry) in config.entity_list() {
+        if let Some(radius) = entry.radius {
+            if radius <= 0.0 || !radius.is_finite() {
+                return Err(format!(
+                    "Ball {}: radius must be finite and positive: {}",
+                    id.0, radius
+                )
+                .into());
+            }
+        }
+    }
+    // 円形のテーブルの半径は、正の有限の値である必要があります。
+    if let TableShape::Circle { radius } = config.table.shape {
+        if !(radius.is_finite() && radius > 0.0) {
+            return Err(format!(
+                "table.shape: Circle radius must be finite and positive: {radius}"
+            )
+            .into());
+        }
+    }
+    // ポケットの半径は正の有限の値である必要があります。
+    if let Some(pockets) = &config.pockets {
+        if !(pockets.radius.is_finite() && pockets.radius > 0.0) {
+            return Err(format!(
+                "pockets.radius must be finite and positive: {}",
+                pockets.radius
+            )
+            .into());
+        }
+    }
+    // ペグの半径は正の有限の値で、行と列の数は 1 以上である必要があります。
+    if let Some(pegs) = &config.pegs {
+        if !(pegs.radius.is_finite() && pegs.radius > 0.0) {
+            return Err(format!("pegs.radius must be finite and positive: {}", pegs.radius).into());
+        }
+        if pegs.rows == 0 || pegs.columns == 0 {
+            return Err("pegs.rows and pegs.columns must be at least 1".into());
+        }
+    }
+    // 初速度は、内部の単位に変換した後も有限の値である必要があります。
+    for (id, entry) in config.entity_list() {
+        let vx = config.units.velocity.to_cm_per_s(entry.vx);
+        let vy = config.units.velocity.to_cm_per_s(entry.vy);
+        if !vx.is_finite() || !vy.is_finite() {
+            return Err(format!("Ball {}: initial velocity is not finite", id.0).into());
+        }
+    }
+    // 静止とみなす速さの上限は、内部の単位に変換した後も 0 以上の有限の値である必要があります。
+    if let Some(threshold) = config.rest_threshold_cm_per_s() {
+        if !threshold.is_finite() || threshold < 0.0 {
+            return Err(format!(
+                "rest_threshold must be finite and non-negative: {}",
+                threshold
+            )
+            .into());
+        }
+    }
+    // 速さの上限は、内部の単位に変換した後も正の値である必要があります。
+    if let Some(max_speed) = config.max_speed {
+        let max_speed = config.units.velocity.to_cm_per_s(max_speed);
+        if max_speed.is_nan() || max_speed <= 0.0 {
+            return Err(format!("max_speed must be positive: {}", max_speed).into());
+        }
+    }
+    // ばねの接触のばね定数は正、減衰係数は 0 以上の有限の値で、分割数は 1 以上である必要があります。
+    if let ContactModel::Spring { stiffness, damping } = config.contact_model {
+        if !(stiffness.is_finite() && stiffness > 0.0) {
+            return Err(format!("contact_model: stiffness must be positive: {}", stiffness).into());
+        }
+        if !(damping.is_finite() && damping >= 0.0) {
+            return Err(format!("contact_model: damping must not be negative: {}", damping).into());
+        }
+    }
+    if config.contact_substeps == Some(0) {
+        return Err("contact_substeps must be at least 1".into());
+    }
+    // ステップの自動分割の割合は正の有限の値で、分割数の上限は 1 以上である必要があります。
+    if let Some(adaptive) = &config.adaptive_substeps {
+        if let Some(fraction) = adaptive.max_step_fraction {
+            if !(fraction.is_finite() && fraction > 0.0) {
+                return Err(format!(
+                    "adaptive_substeps.max_step_fraction must be positive: {fraction}"
+                )
+                .into());
+            }
+        }
+        if adaptive.max_substeps == Some(0) {
+            return Err("adaptive_substeps.max_substeps must be at least 1".into());
+        }
+    }
+    // ショットの向きは有限の値、速さは正の有限の値である必要があります。
+    for (index, shot) in config.shots.iter().enumerate() {
+        if !shot.angle_deg.is_finite() {
+            return Err(format!(
+                "shots[{index}].angle_deg must be finite: {}",
+                shot.angle_deg
+            )
+            .into());
+        }
+        if !(shot.speed.is_finite() && shot.speed > 0.0) {
+            return Err(format!("shots[{index}].speed must be positive: {}", shot.speed).into());
+        }
+    }
+    if config.shot_max_steps == Some(0) {
+        return Err("shot_max_steps must be at least 1".into());
+    }
+    // イベント駆動モードは、ペグと線分のクッションとの衝突時刻を求めないため、これらとは組み合わせられません。
+    // 距離の拘束とばねの接触、ステップの自動分割も固定ステップのモードでのみ働かせるため、同様に組み合わせられません。
+    if config.stepping == SteppingMode::EventDriven {
+        if config.pegs.is_some() {
+            return Err("pegs are not supported with stepping: EventDriven".into());
+        }
+        if !config.cushion_segments.is_empty() {
+            return Err("cushion_segments are not supported with stepping: EventDriven".into());
+        }
+        if !config.constraints.is_empty() {
+            return Err("constraints are not supported with stepping: EventDriven".into());
+        }
+        if let ContactModel::Spring { .. } = config.contact_model {
+            return Err("contact_model: Spring is not supported with stepping: EventDriven".into());
+        }
+        if config.adaptive_substeps.is_some() {
+            return Err("adaptive_substeps is not supported with stepping: EventDriven".into());
+        }
+    }
+    // イベント駆動モードは摩擦のない等速直線運動を前提とするため、PhysicsSystem で速度を変える設定は、
+    // 効果のある値では組み合わせられません（0 や空の一覧は指定しても構いません）。
+    if config.stepping == SteppingMode::EventDriven {
+        let nonzero = |v: Option<f32>| v.is_some_and(|v| v != 0.0);
+        let nonzero_xy = |v: Option<(f32, f32)>| v.is_some_and(|(x, y)| x != 0.0 || y != 0.0);
+        let physics = [
+            ("friction_xy", nonzero_xy(config.friction_xy)),
+            ("gravity_xy", nonzero_xy(config.gravity_xy)),
+            ("slip_friction", nonzero(config.slip_friction)),
+            ("ball_friction", nonzero(config.ball_friction)),
+            ("cushion_friction", nonzero(config.cushion_friction)),
+            ("low_speed_damping", config.low_speed_damping.is_some()),
+            ("friction_regions", !config.friction_regions.is_empty()),
+        ];
+        if let Some((name, _)) = physics.iter().find(|(_, used)| *used) {
+            return Err(format!(
+                "{name} is not supported with stepping: EventDriven (it assumes frictionless motion)"
+            )
+            .into());
+        }
+    }
+    // 線分のクッションの端点は有限の値である必要があります。
+    for (index, segment) in config.cushion_segments.iter().enumerate() {
+        let ends = [segment.x1, segment.y1, segment.x2, segment.y2];
+        if !ends.iter().all(|v| v.is_finite()) {
+            return Err(format!("cushion_segments[{index}] endpoints must be finite").into());
+        }
+    }
+    // 端末への表示の文字数と間隔は 1 以上である必要があります。
+    if let Some(render) = &config.terminal_render {
+        for (name, value) in [
+            ("columns", render.columns),
+            ("rows", render.rows),
+            ("every", render.every),
+        ] {
+            if value == Some(0) {
+                return Err(format!("terminal_render.{name} must be at least 1").into());
+            }
+        }
+    }
+    // 反発係数は 0 以上 1 以下である必要があります。allow_energized の場合は 1 を超える値も許可します。
+    let mut restitutions = vec![("ball.restitution".to_string(), config.ball.restitution)];
+    for (id, entry) in config.entity_list() {
+        if let Some(e) = entry.restitution {
+            restitutions.push((format!("Ball {} restitution", id.0), e));
+        }
+    }
+    restitutions.extend(
+        config
+            .restitution_override
+            .map(|e| ("restitution_override".to_string(), e)),
+    );
+    if let Some(wear) = &config.cushion_wear {
+        restitutions.push(("cushion_wear.min".to_string(), wear.min));
+        restitutions.push(("cushion_wear.max".to_string(), wear.max));
+    }
+    if let Some(e) = config.pegs.as_ref().and_then(|pegs| pegs.restitution) {
+        restitutions.push(("pegs.restitution".to_string(), e));
+    }
+    for (index, segment) in config.cushion_segments.iter().enumerate() {
+        if let Some(e) = segment.restitution {
+            restitutions.push((format!("cushion_segments[{index}].restitution"), e));
+        }
+    }
+    for (name, e) in restitutions {
+        if !e.is_finite() || e < 0.0 {
+            return Err(format!("{name} must be finite and non-negative: {e}").into());
+        }
+        if e > 1.0 && !config.allow_energized {
+            return Err(format!(
+                "{name} is {e}, greater than 1 (set allow_energized to let bounces add energy)"
+            )
+            .into());
+        }
+    }
+    // 個体差の分布の平均と標準偏差は、0 以上の有限の値である必要があります。
+    if let Some(defects) = &config.table_defects {
+        for (name, dist) in [
+            ("cushion_restitution", defects.cushion_restitution),
+            ("cloth_friction", defects.cloth_friction),
+        ] {
+            for (field, value) in [
+                ("mean", dist.mean.unwrap_or(1.0)),
+                ("std_dev", dist.std_dev),
+            ] {
+                if !value.is_finite() || value < 0.0 {
+                    return Err(format!(
+                        "table_defects.{name}.{field} must be finite and non-negative: {value}"
+                    )
+                    .into());
+                }
+            }
+        }
+    }
+    // 初期位置を補正しない場合は、すべてのボールがクッションの内側に収まっている必要があります。
+    if !config.clamp_initial_positions {
+        for (id, entry) in config.entity_list() {
+            let (x, y) = config
+                .coordinate_origin
+                .to_corner(entry.x, entry.y, &config.table);
+            let radius = entry.radius.unwrap_or(config.ball.radius);
+            if config.table.clamp_ball(x, y, radius) != (x, y) {
+                return Err(format!(
+                    "Ball {} at ({}, {}) is outside the table (set clamp_initial_positions to move it inside)",
+                    id.0, entry.x, entry.y
+                )
+                .into());
+            }
+        }
+    }
     Ok(config)
 }
@@ -0,0 +1,77 @@
+// src/config/builder.rs
+//
+// このファイルでは、設定ファイルを用意せずにコードから Config を組み立てる ConfigBuilder を定義します。
+
+use super::{validate, Config};
+use serde_json::{json, Map, Value};
+use std::error::Error;
+
+/// コードから Config を組み立てるためのビルダーです。
+///
+/// 指定した値は設定ファイルと同じキーの値として扱われ、`build` で設定ファイルと同じ既定値の補完と検証を行います。
+/// 時間刻み、台の寸法、ボールの物理特性は必須で、指定しない場合は `build` が Error を返します。
+#[derive(Debug, Default, Clone)]
+pub struct ConfigBuilder {
+    // 設定ファイルのトップレベルに相当するキーと値
+    values: Map<String, Value>,
+    // 追加した的球の位置の一覧
+    object_balls: Vec<Value>,
+}
+
+impl ConfigBuilder {
+    /// 何も指定していないビルダーを作成します。
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// シミュレーションの時間刻み（秒）を指定します。
+    pub fn dt(mut self, dt: f32) -> Self {
+        self.values.insert("dt".into(), json!(dt));
+        self
+    }
+
+    /// ビリヤード台の横幅と高さ（cm）を指定します。
+    pub fn table(mut self, width: f32, height: f32) -> Self {
+        self.values
+            .insert("table".into(), json!({ "width": width, "height": height }));
+        self
+    }
+
+    /// 手球、的球共通の半径（cm）、質量（kg）、反発係数を指定します。
+    pub fn ball(mut self, radius: f32, mass: f32, restitution: f32) -> Self {
+        self.values.insert(
+            "ball".into(),
+            json!({ "radius": radius, "mass": mass, "restitution": restitution }),
+        );
+        self
+    }
+
+    /// 手球の初期位置（coordinate_origin の座標系）と初速度（units.velocity の単位）を指定します。
+    pub fn cue(mut self, x: f32, y: f32, vx: f32, vy: f32) -> Self {
+        self.values.insert(
+            "cue_ball".into(),
+            json!({ "x": x, "y": y, "vx": vx, "vy": vy }),
+        );
+        self
+    }
+
+    /// 的球を 1 つ、指定した位置（coordinate_origin の座標系）に追加します。
+    pub fn add_object_ball(mut self, x: f32, y: f32) -> Self {
+        self.object_balls.push(json!({ "x": x, "y": y }));
+        self
+    }
+
+    /// 指定した値から Config を組み立て、設定ファイルを読み込む場合と同じ検証を行います。
+    ///
+    /// # 戻り値
+    /// 組み立てと検証に成功した場合は Config、必須の値がない場合や内容に誤りがある場合は Error を返します。
+    pub fn build(self) -> Result<Config, Box<dyn Error>> {
+        let mut values = self.values;
+        values.insert(
+            "object_balls".into(),
+            json!({ "positions": self.object_balls }),
+        );
+        let config: Config = serde_json::from_value(Value::Object(values))?;
+        validate(config)
+    }
+}
@@ -0,0 +1,59 @@
+// src/config/config_patch.rs
+//
+// このファイルでは、実行中のシミュレーションに適用する設定の差分 ConfigPatch を定義します。
+
+use serde::Deserialize;
+use std::error::Error;
+
+/// 実行中のシミュレーションに `Simulation::apply_config_patch` で適用する、設定の差分です。
+///
+/// エンティティを作り直さずに変更できるパラメータだけを持ち、指定しなかった（None の）項目は変更しません。
+/// 各項目の単位は Config の同名のキーと同じです。ボールの数や配置など構造に関わる項目は含めず、
+/// YAML などから読み込む場合もこれ以外のキーは Error になります（テーブルの寸法の変更には
+/// `Simulation::resize_table` を使います）。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigPatch {
+    // シミュレーションの時間刻み（秒）
+    #[serde(default)]
+    pub dt: Option<f32>,
+    // 摩擦による減速度 (x 軸, y 軸)（m/s^2）
+    #[serde(default)]
+    pub friction_xy: Option<(f32, f32)>,
+    // すべての衝突の反発係数を上書きする値（0 以上 1 以下）
+    #[serde(default)]
+    pub restitution_override: Option<f32>,
+    // すべてのボールに働く一様な加速度 (x 軸, y 軸)（m/s^2）
+    #[serde(default)]
+    pub gravity_xy: Option<(f32, f32)>,
+}
+
+impl ConfigPatch {
+    /// 差分の値が適用できる範囲にあるかを検証します。
+    ///
+    /// # 戻り値
+    /// すべての値が有効な場合は Ok、そうでない場合は理由を表す Error を返します。
+    pub fn validate(&self) -> Result<(), Box<dyn Error>> {
+        if let Some(dt) = self.dt {
+            if !(dt.is_finite() && dt > 0.0) {
+                return Err(format!("dt must be positive: {dt}").into());
+            }
+        }
+        for (name, value) in [
+            ("friction_xy", self.friction_xy),
+            ("gravity_xy", self.gravity_xy),
+        ] {
+            if let Some((x, y)) = value {
+                if !(x.is_finite() && y.is_finite()) {
+                    return Err(format!("{name} must be finite: ({x}, {y})").into());
+                }
+            }
+        }
+        if let Some(e) = self.restitution_override {
+            if !(0.0..=1.0).contains(&e) {
+                return Err(format!("restitution_override must be between 0 and 1: {e}").into());
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+pub mod body;
+pub use body::Body;
+pub mod ball_impulse;
+pub use ball_impulse::ball_collision_impulse;
+pub mod spring_contact;
+pub use spring_contact::spring_contact_force;
+pub mod rect_collision;
+pub use rect_collision::reflect_in_rect;
+pub mod friction;
+pub use friction::{apply_friction, apply_low_speed_damping, apply_slip_friction, rolling_spin};
+pub mod elastic;
+pub use elastic::elastic_2ball;
+pub mod segment_contact;
+pub use segment_contact::segment_contact;
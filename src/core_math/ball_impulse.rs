@@ -0,0 +1,57 @@
+// src/core_math/ball_impulse.rs
+//
+// このファイルでは、2 つのボールの衝突で A に与えるインパルスを求める純粋関数を定義します。
+
+use super::Body;
+
+/// ボール A とボール B の状態から、衝突が発生している場合に A に与えるインパルスを求める純粋関数です。
+/// B には符号を反転したインパルスを与えます。
+///
+/// # 引数
+/// - `a`, `b`: 2 つのボールの状態
+/// - `restitution`: このペアの衝突に用いる実効的な反発係数
+/// - `epsilon`: 中心がほぼ同一点とみなす中心間距離（cm）
+///
+/// # 戻り値
+/// 衝突している場合は (impulse_x, impulse_y) を返します。
+/// 衝突していない場合、すでに離れつつある場合、中心間距離が `epsilon` 未満で
+/// 法線が定まらない場合、または位置が NaN の場合は None を返します。
+pub fn ball_collision_impulse(
+    a: &Body,
+    b: &Body,
+    restitution: f32,
+    epsilon: f32,
+) -> Option<(f32, f32)> {
+    let dx = b.x - a.x;
+    let dy = b.y - a.y;
+    let dist_sq = dx * dx + dy * dy;
+    let radius_sum = a.radius + b.radius; // 各ボールの半径の和
+
+    // 衝突していなければ、ほぼ同一点で法線が定まらない場合、または位置が NaN の場合は何も返さない
+    if dist_sq >= radius_sum * radius_sum
+        || dist_sq < epsilon * epsilon
+        || dist_sq == 0.0
+        || dist_sq.is_nan()
+    {
+        return None;
+    }
+
+    let distance = libm::sqrtf(dist_sq);
+    let nx = dx / distance;
+    let ny = dy / distance;
+
+    // 相対速度（a の速度 - b の速度）
+    let rvx = a.vx - b.vx;
+    let rvy = a.vy - b.vy;
+    let vel_along_normal = rvx * nx + rvy * ny;
+
+    // 法線は A から B の向きなので、vel_along_normal が負ならすでに分離しており何もしない
+    if vel_along_normal < 0.0 {
+        return None;
+    }
+
+    // インパルスの大きさを計算
+    let impulse_mag = -(1.0 + restitution) * vel_along_normal / (1.0 / a.mass + 1.0 / b.mass);
+
+    Some((impulse_mag * nx, impulse_mag * ny))
+}
@@ -0,0 +1,20 @@
+// src/core_math/body.rs
+//
+// このファイルでは、core_math の関数に渡す、1 つのボールの状態をまとめた構造体を定義します。
+
+/// core_math の関数に渡す、1 つのボールの位置・速度・質量・半径です。単位は cm、cm/s です。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Body {
+    /// 位置 x
+    pub x: f32,
+    /// 位置 y
+    pub y: f32,
+    /// 速度 x
+    pub vx: f32,
+    /// 速度 y
+    pub vy: f32,
+    /// 質量
+    pub mass: f32,
+    /// 半径
+    pub radius: f32,
+}
@@ -0,0 +1,45 @@
+// src/core_math/elastic.rs
+//
+// このファイルでは、2 つのボールの完全弾性衝突（反発係数 1）の結果を直接求める純粋関数を定義します。
+
+/// 2 つのボールが法線 `normal` に沿って完全弾性衝突した後の速度を求める純粋関数です。
+///
+/// 法線方向の速度成分は 1 次元の弾性衝突の式
+/// `va' = ((ma − mb)·va + 2·mb·vb) / (ma + mb)` で入れ替え、接線方向の成分はそのまま残します。
+/// 接近しているかどうかは判定しないため、衝突が起きる前提で呼び出してください。
+/// ball_collision_impulse() に反発係数 1 を与えた結果を検算するための基準として使えます。
+///
+/// # 引数
+/// - `ma`, `mb`: ボール A と B の質量（kg）
+/// - `va`, `vb`: 衝突前のボール A と B の速度 (vx, vy)（cm/s）
+/// - `normal`: 衝突の法線の向き。長さは問わず、内部で正規化します
+///
+/// # 戻り値
+/// 衝突後のボール A と B の速度 (va', vb')。
+/// `normal` の長さが 0 または有限でない場合は、法線が定まらないため速度をそのまま返します。
+pub fn elastic_2ball(
+    ma: f32,
+    va: (f32, f32),
+    mb: f32,
+    vb: (f32, f32),
+    normal: (f32, f32),
+) -> ((f32, f32), (f32, f32)) {
+    let length = libm::sqrtf(normal.0 * normal.0 + normal.1 * normal.1);
+    if !length.is_finite() || length == 0.0 {
+        return (va, vb);
+    }
+    let nx = normal.0 / length;
+    let ny = normal.1 / length;
+
+    // 法線方向の速度成分
+    let an = va.0 * nx + va.1 * ny;
+    let bn = vb.0 * nx + vb.1 * ny;
+    let total = ma + mb;
+    let an_after = ((ma - mb) * an + 2.0 * mb * bn) / total;
+    let bn_after = ((mb - ma) * bn + 2.0 * ma * an) / total;
+
+    (
+        (va.0 + (an_after - an) * nx, va.1 + (an_after - an) * ny),
+        (vb.0 + (bn_after - bn) * nx, vb.1 + (bn_after - bn) * ny),
+    )
+}
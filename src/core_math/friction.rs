@@ -0,0 +1,98 @@
+// src/core_math/friction.rs
+//
+// このファイルでは、摩擦による速度と回転の変化を求める純粋関数（積分の 1 ステップ分）を定義します。
+
+/// 摩擦による減速を適用した新しい速度を返す純粋関数です。
+///
+/// 減速度は速度の向きと逆向きに働き、各軸の成分には軸ごとの減速度 `friction` を
+/// 速度の向きの成分で按分した値が掛かります。
+/// 減速によって速度成分の符号が反転する場合は 0 で止めます。
+pub fn apply_friction(vel: (f32, f32), friction: (f32, f32), dt: f32) -> (f32, f32) {
+    let (vx, vy) = vel;
+    let speed = libm::sqrtf(vx * vx + vy * vy);
+    if speed == 0.0 {
+        return vel;
+    }
+    // 速度の向きの単位ベクトル成分に応じて、各軸の減速量を求めます。
+    let dvx = friction.0 * (libm::fabsf(vx) / speed) * dt;
+    let dvy = friction.1 * (libm::fabsf(vy) / speed) * dt;
+    (
+        signum(vx) * (libm::fabsf(vx) - dvx).max(0.0),
+        signum(vy) * (libm::fabsf(vy) - dvy).max(0.0),
+    )
+}
+
+/// 速さが `threshold` 未満の場合に、速度の向きと逆向きに `deceleration` の減速を加えた
+/// 新しい速度を返す純粋関数です。減速によって向きが反転する場合は 0 で止めます。
+pub fn apply_low_speed_damping(
+    vel: (f32, f32),
+    threshold: f32,
+    deceleration: f32,
+    dt: f32,
+) -> (f32, f32) {
+    let (vx, vy) = vel;
+    let speed = libm::sqrtf(vx * vx + vy * vy);
+    if speed == 0.0 || speed >= threshold {
+        return vel;
+    }
+    let scale = (speed - deceleration * dt).max(0.0) / speed;
+    (vx * scale, vy * scale)
+}
+
+/// 滑り摩擦による並進と回転の変化を、1 つの更新としてまとめて計算する純粋関数です。
+///
+/// 接点の滑り速度 `u = (vx - radius × spin.1, vy + radius × spin.0)` と逆向きに
+/// 大きさ `mu × m × g` の摩擦力が接点に働くとして、並進の減速と回転のトルクを同時に与えます。
+/// 摩擦力は接点を通るため、接点まわりの角運動量は保存されます。
+/// 均質な球（慣性モーメント 2/5 m r^2）では滑り速度は向きを保ったまま
+/// `7/2 × mu × g` の割合で減少し、0 になった時点で転がり状態になります。
+///
+/// # 引数
+/// - `vel`: 速度 (vx, vy)
+/// - `spin`: 回転 (x, y, z)（rad/s）
+/// - `mu_g`: 滑り摩擦係数と重力加速度の積（cm/s^2）
+///
+/// # 戻り値
+/// 新しい速度と回転、および滑っていた時間（dt 以下、最初から転がっている場合は 0）を返します。
+pub fn apply_slip_friction(
+    vel: (f32, f32),
+    spin: (f32, f32, f32),
+    radius: f32,
+    mu_g: f32,
+    dt: f32,
+) -> ((f32, f32), (f32, f32, f32), f32) {
+    let ux = vel.0 - radius * spin.1;
+    let uy = vel.1 + radius * spin.0;
+    let slip_speed = libm::sqrtf(ux * ux + uy * uy);
+    if slip_speed == 0.0 {
+        return (vel, spin, 0.0);
+    }
+    // 転がり状態になるまでの時間と、このステップで滑っている時間
+    let time_to_roll = 2.0 * slip_speed / (7.0 * mu_g);
+    let t = dt.min(time_to_roll);
+    let (dir_x, dir_y) = (ux / slip_speed, uy / slip_speed);
+
+    let new_vel = (vel.0 - mu_g * dir_x * t, vel.1 - mu_g * dir_y * t);
+    // トルク r × F（r は中心から接点へのベクトル）を慣性モーメントで割った角加速度
+    let angular = 2.5 * mu_g / radius * t;
+    let new_spin = (spin.0 - angular * dir_y, spin.1 + angular * dir_x, spin.2);
+    if t < dt {
+        // 転がり状態に達した場合は、丸め誤差による滑りが残らないよう回転を並進に合わせます。
+        return (new_vel, rolling_spin(new_vel, new_spin, radius), t);
+    }
+    (new_vel, new_spin, t)
+}
+
+/// 速度に対して滑らずに転がる回転を返す純粋関数です。鉛直軸まわりの回転（z）は保ちます。
+pub fn rolling_spin(vel: (f32, f32), spin: (f32, f32, f32), radius: f32) -> (f32, f32, f32) {
+    (-vel.1 / radius, vel.0 / radius, spin.2)
+}
+
+/// f32::signum と同じ結果（0.0 は 1.0、-0.0 は -1.0、NaN は NaN）を、core と libm だけで返します。
+fn signum(v: f32) -> f32 {
+    if v.is_nan() {
+        v
+    } else {
+        libm::copysignf(1.0, v)
+    }
+}
@@ -0,0 +1,71 @@
+// src/core_math/rect_collision.rs
+//
+// このファイルでは、長方形のテーブルの壁との衝突を処理する純粋関数を定義します。
+
+/// 幅 `size.0`、高さ `size.1` の長方形（左下の隅が原点）の壁との衝突を処理する純粋関数です。
+///
+/// 壁の外側にはみ出したボールは壁の内側へ押し戻し、速度が壁の外側へ向かっている場合のみ、
+/// 法線方向の成分を反転させてその壁の反発係数を掛け、壁に沿った成分に `tangential_damping` を掛けます。
+/// 壁の並び順は左・右・下・上で、`restitution` と戻り値の衝突の有無はこの順に並びます。
+///
+/// # 引数
+/// - `pos`, `vel`: ボールの位置と速度
+/// - `radius`: ボールの半径
+/// - `size`: テーブルの (幅, 高さ)
+/// - `restitution`: 壁ごとの反発係数
+/// - `tangential_damping`: 跳ね返るときに壁に沿った速度成分に掛ける係数（1.0 で変化なし）
+///
+/// # 戻り値
+/// 新しい位置と速度、および壁ごとに跳ね返ったかどうかを返します。
+pub fn reflect_in_rect(
+    pos: (f32, f32),
+    vel: (f32, f32),
+    radius: f32,
+    size: (f32, f32),
+    restitution: &[f32; 4],
+    tangential_damping: f32,
+) -> ((f32, f32), (f32, f32), [bool; 4]) {
+    let (mut x, mut y) = pos;
+    let (mut vx, mut vy) = vel;
+    let (width, height) = size;
+    let mut hits = [false; 4];
+
+    // 左側の壁との衝突
+    if x - radius < 0.0 {
+        x = radius;
+        if vx < 0.0 {
+            vx = -vx * restitution[0];
+            vy *= tangential_damping;
+            hits[0] = true;
+        }
+    }
+    // 右側の壁との衝突
+    if x + radius > width {
+        x = width - radius;
+        if vx > 0.0 {
+            vx = -vx * restitution[1];
+            vy *= tangential_damping;
+            hits[1] = true;
+        }
+    }
+    // 下側の壁との衝突
+    if y - radius < 0.0 {
+        y = radius;
+        if vy < 0.0 {
+            vy = -vy * restitution[2];
+            vx *= tangential_damping;
+            hits[2] = true;
+        }
+    }
+    // 上側の壁との衝突
+    if y + radius > height {
+        y = height - radius;
+        if vy > 0.0 {
+            vy = -vy * restitution[3];
+            vx *= tangential_damping;
+            hits[3] = true;
+        }
+    }
+
+    ((x, y), (vx, vy), hits)
+}
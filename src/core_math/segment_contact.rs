@@ -0,0 +1,56 @@
+// src/core_math/segment_contact.rs
+//
+// このファイルでは、ボールと線分のクッションの接触を、ボールの半径を考慮して判定する純粋関数を定義します。
+
+/// 中心 (`x`, `y`)、半径 `radius` のボールが、`start` から `end` までの線分に接触しているかを判定する純粋関数です。
+///
+/// 線分上でボールの中心に最も近い点を求め、その点から中心までの距離が `radius` 未満の場合に接触とみなします。
+/// 最も近い点は線分の範囲に収めるため、線分の端では端点を中心とした半円に沿って判定され、
+/// レールの端に当たったボールは端点から中心へ向かう向きに跳ね返ります。
+///
+/// # 引数
+/// - `x`, `y`: ボールの中心（cm）
+/// - `radius`: ボールの半径（cm）
+/// - `start`, `end`: 線分の始点と終点 (x, y)（cm）
+/// - `epsilon`: 中心が線分上にあるとみなす距離（cm）
+///
+/// # 戻り値
+/// 接触している場合は (nx, ny, depth) を返します。(nx, ny) は線分の最も近い点からボールの中心へ向かう単位ベクトル、
+/// `depth` はめり込みの深さ（`radius` − 距離、cm）です。
+/// 中心が線分から `epsilon` 未満の距離にあり向きが定まらない場合は、法線を始点から終点への向きを
+/// 反時計回りに 90 度回した向き（線分の長さが 0 の場合は +x 方向）とします。
+/// 接触していない場合は None を返します。
+pub fn segment_contact(
+    x: f32,
+    y: f32,
+    radius: f32,
+    start: (f32, f32),
+    end: (f32, f32),
+    epsilon: f32,
+) -> Option<(f32, f32, f32)> {
+    let (sx, sy) = (end.0 - start.0, end.1 - start.1);
+    let length_sq = sx * sx + sy * sy;
+    // 線分上で中心に最も近い点の、始点からの割合（0 から 1 の範囲に収めます）
+    let t = if length_sq > 0.0 {
+        (((x - start.0) * sx + (y - start.1) * sy) / length_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let dx = x - (start.0 + t * sx);
+    let dy = y - (start.1 + t * sy);
+    let dist_sq = dx * dx + dy * dy;
+    if dist_sq >= radius * radius {
+        return None;
+    }
+
+    let distance = libm::sqrtf(dist_sq);
+    let (nx, ny) = if distance >= epsilon && distance > 0.0 {
+        (dx / distance, dy / distance)
+    } else if length_sq > 0.0 {
+        let length = libm::sqrtf(length_sq);
+        (-sy / length, sx / length)
+    } else {
+        (1.0, 0.0)
+    };
+    Some((nx, ny, radius - distance))
+}
@@ -0,0 +1,48 @@
+// src/core_math/spring_contact.rs
+//
+// このファイルでは、重なった 2 つのボールの間に働くばねの力を求める純粋関数を定義します。
+
+use super::Body;
+
+/// ボール A とボール B が重なっている場合に、B に働くばねの力を求める純粋関数です。
+/// A には符号を反転した力を働かせます。
+///
+/// 力の大きさは `stiffness × 重なりの深さ − damping × 法線方向の相対速度` で、
+/// ボール同士を引き寄せる向きの力にはなりません。
+///
+/// # 引数
+/// - `a`, `b`: 2 つのボールの状態
+/// - `stiffness`: ばね定数（N/m）
+/// - `damping`: 減衰係数（N·s/m）
+/// - `epsilon`: 中心がほぼ同一点とみなす中心間距離（cm）
+///
+/// # 戻り値
+/// 重なっている場合は (force_x, force_y) を返します。単位は kg·cm/s² です。
+/// 重なっていない場合、中心間距離が `epsilon` 未満で法線が定まらない場合、または位置が NaN の場合は None を返します。
+pub fn spring_contact_force(
+    a: &Body,
+    b: &Body,
+    stiffness: f32,
+    damping: f32,
+    epsilon: f32,
+) -> Option<(f32, f32)> {
+    let dx = b.x - a.x;
+    let dy = b.y - a.y;
+    let dist_sq = dx * dx + dy * dy;
+    let radius_sum = a.radius + b.radius;
+    if dist_sq >= radius_sum * radius_sum
+        || dist_sq < epsilon * epsilon
+        || dist_sq == 0.0
+        || dist_sq.is_nan()
+    {
+        return None;
+    }
+
+    let distance = libm::sqrtf(dist_sq);
+    let nx = dx / distance;
+    let ny = dy / distance;
+    // 法線方向の相対速度（b の速度 - a の速度）。近づいている場合は負になります。
+    let approach = (b.vx - a.vx) * nx + (b.vy - a.vy) * ny;
+    let magnitude = (stiffness * (radius_sum - distance) - damping * approach).max(0.0);
+    Some((magnitude * nx, magnitude * ny))
+}
@@ -1,6 +1,22 @@
+pub mod ball;
+pub use ball::create_ball;
+pub mod create_entities;
+pub use create_entities::create_entities;
 pub mod cue_ball;
 pub use cue_ball::create_cue_ball;
 pub mod object_balls;
 pub use object_balls::create_object_balls;
 pub mod table;
 pub use table::create_table;
+pub mod pockets;
+pub use pockets::create_pockets;
+pub mod placement;
+pub use placement::find_free_spot;
+pub mod rack_jitter;
+pub use rack_jitter::jitter_rack;
+pub mod rack;
+pub use rack::triangle_rack;
+pub mod pegs;
+pub use pegs::create_peg_grid;
+pub mod cushion_segments;
+pub use cushion_segments::create_cushion_segments;
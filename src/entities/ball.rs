@@ -0,0 +1,100 @@
+// src/entities/ball.rs
+//
+// このファイルでは、汎用の生成情報から 1 つのボールのエンティティを生成する関数を定義します。
+
+use crate::components::{Ball, BallId, CollisionMask, Color, Position, Spin, Trail, Velocity};
+use crate::config::{Config, EntityConfig};
+use crate::resources::BallRegistry;
+use specs::prelude::*;
+use tracing::warn;
+
+/// 汎用の生成情報から、ボールのエンティティを 1 つ生成する関数です。
+///
+/// 手球・的球の生成関数や create_entities は、すべてこの関数を通してボールを生成します。
+///
+/// # 引数
+/// - `world`: ECS の World への可変参照
+/// - `config`: 設定情報（座標の原点、共通の物理パラメータ、軌跡の容量など）
+/// - `entry`: 生成するボールの情報
+/// - `ball_id`: 割り当てる BallId
+///
+/// # 戻り値
+/// 生成されたエンティティを返します。
+pub fn create_ball(
+    world: &mut World,
+    config: &Config,
+    entry: &EntityConfig,
+    ball_id: BallId,
+) -> Entity {
+    // 設定ファイル上の座標を、隅を原点とした内部座標に変換します。
+    let (x, y) = config
+        .coordinate_origin
+        .to_corner(entry.x, entry.y, &config.table);
+    let radius = entry.radius.unwrap_or(config.ball.radius);
+    // 設定で指定されている場合は、テーブルからはみ出した位置をクッションの内側に収めます。
+    // 収めた位置はクッションにちょうど接する位置のため、最初のステップで壁との衝突として扱われることはありません。
+    let (x, y) = if config.clamp_initial_positions {
+        let clamped = config.table.clamp_ball(x, y, radius);
+        if clamped != (x, y) {
+            warn!(
+                "Ball {}: initial position ({}, {}) overlaps the cushion, moved by ({}, {})",
+                ball_id.0,
+                entry.x,
+                entry.y,
+                clamped.0 - x,
+                clamped.1 - y
+            );
+        }
+        clamped
+    } else {
+        (x, y)
+    };
+    // 色が指定されていない（または解釈できない）場合は、種類と BallId に応じた既定の色を使います。
+    let color = match entry.color.as_deref() {
+        Some(hex) => Color::from_hex(hex).unwrap_or_else(|| {
+            warn!("Ball {}: invalid color {:?}, using palette", ball_id.0, hex);
+            Color::default_for(entry.kind, ball_id)
+        }),
+        None => Color::default_for(entry.kind, ball_id),
+    };
+    let mut builder = world
+        .create_entity()
+        .with(Position { x, y })
+        .with(Velocity {
+            // 設定の単位から内部の単位（cm/s）に変換します。
+            x: config.units.velocity.to_cm_per_s(entry.vx),
+            y: config.units.velocity.to_cm_per_s(entry.vy),
+        })
+        .with(Ball {
+            radius,
+            mass: entry.mass.unwrap_or(config.ball.mass),
+            restitution: entry.restitution.unwrap_or(config.ball.restitution),
+        })
+        .with(entry.kind)
+        .with(ball_id)
+        .with(color)
+        .with(entry.spin.map_or_else(Spin::default, |(x, y, z)| {
+            // 設定の単位から内部の単位（rad/s）に変換します。
+            let spin = config.units.spin;
+            Spin {
+                x: spin.to_rad_per_s(x),
+                y: spin.to_rad_per_s(y),
+                z: spin.to_rad_per_s(z),
+            }
+        }));
+    // 衝突レイヤーが指定されている場合のみ CollisionMask を付与します。
+    if let Some(mask) = entry.collision_mask {
+        builder = builder.with(CollisionMask(mask));
+    }
+    // 軌跡の容量が指定されている場合のみ Trail を付与します。
+    if config.trail_capacity > 0 {
+        builder = builder.with(Trail::new(config.trail_capacity));
+    }
+    let entity = builder.build();
+    // BallId からエンティティを引けるよう、対応表に登録します。
+    world
+        .entry::<BallRegistry>()
+        .or_insert_with(BallRegistry::default)
+        .insert(ball_id, entity);
+    entity
+}
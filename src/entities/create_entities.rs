@@ -0,0 +1,34 @@
+// src/entities/create_entities.rs
+//
+// このファイルでは、設定からテーブルとすべてのボールのエンティティをまとめて生成する関数を定義します。
+
+use super::{create_ball, create_table, jitter_rack};
+use crate::config::Config;
+use crate::resources::SimRng;
+use specs::prelude::*;
+
+/// テーブルと、設定に記載されたすべてのボールのエンティティを生成する関数です。
+///
+/// ボールの一覧と BallId の割り当ては Config::entity_list() に従います。
+/// `entities` セクションがない設定では、`cue_ball` と `object_balls` から従来どおりに生成されます。
+/// `rack_jitter` が指定されている場合は、World の SimRng を使って的球の初期位置を揺らします。
+///
+/// # 引数
+/// - `world`: ECS の World への可変参照
+/// - `config`: 設定情報
+///
+/// # 戻り値
+/// 生成されたボールのエンティティの Vec を、BallId の順に返します。
+pub fn create_entities(world: &mut World, config: &Config) -> Vec<Entity> {
+    create_table(world, config);
+    let entries = {
+        let mut rng = world
+            .entry::<SimRng>()
+            .or_insert_with(|| SimRng::new(config.seed.value()));
+        jitter_rack(config.entity_list(), config, &mut rng)
+    };
+    entries
+        .iter()
+        .map(|(ball_id, entry)| create_ball(world, config, entry, *ball_id))
+        .collect()
+}
@@ -0,0 +1,37 @@
+// src/entities/cushion_segments.rs
+//
+// このファイルでは、線分のクッション（斜めのレールなど）のエンティティを生成する関数を定義します。
+
+use crate::components::{CushionSegment, Position};
+use crate::config::Config;
+use specs::prelude::*;
+
+/// 設定された線分のクッションごとにエンティティを生成する関数です。
+///
+/// # 引数
+/// - `world`: ECS の World への可変参照
+/// - `config`: 設定情報
+///
+/// # 戻り値
+/// 生成されたエンティティの Vec を、設定の順で返します。線分のクッションの設定がない場合は空の Vec を返します。
+pub fn create_cushion_segments(world: &mut World, config: &Config) -> Vec<Entity> {
+    // 設定ファイル上の座標を、隅を原点とした内部座標に変換します。
+    let to_corner = |x: f32, y: f32| {
+        let (x, y) = config.coordinate_origin.to_corner(x, y, &config.table);
+        Position { x, y }
+    };
+    config
+        .cushion_segments
+        .iter()
+        .map(|segment| {
+            world
+                .create_entity()
+                .with(CushionSegment {
+                    start: to_corner(segment.x1, segment.y1),
+                    end: to_corner(segment.x2, segment.y2),
+                    restitution: segment.restitution.unwrap_or(1.0),
+                })
+                .build()
+        })
+        .collect()
+}
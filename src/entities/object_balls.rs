@@ -2,12 +2,18 @@
 //
 // このファイルでは、的球（object balls）のエンティティを生成する関数を定義します。
 
-use crate::components::{Ball, Position, Velocity};
+use super::create_ball;
+use crate::components::BallId;
 use crate::config::Config;
 use specs::prelude::*;
 
 /// 的球エンティティを生成する関数です。
 ///
+/// 的球は `config.object_balls.positions` の並び順に 1 つずつ生成され、
+/// i 番目（0 始まり）の位置の的球には BallId(i + 1) が割り当てられることを保証します。
+/// 戻り値の Vec も同じ順序で並びます。`positions` が空の場合は何も生成せず、空の Vec を返します
+/// （手球だけのシミュレーションも、すべてのシステムでそのまま実行できます）。
+///
 /// # 引数
 /// - `world`: ECS の World への可変参照
 /// - `config`: 設定情報
@@ -15,23 +21,15 @@ use specs::prelude::*;
 /// # 戻り値
 /// 生成されたエンティティの Vec を返します。
 pub fn create_object_balls(world: &mut World, config: &Config) -> Vec<Entity> {
-    let mut entities = Vec::new();
     // config.object_balls.positions に記載された各座標で的球を生成します。
-    for pos_config in &config.object_balls.positions {
-        let entity = world
-            .create_entity()
-            .with(Position {
-                x: pos_config.x,
-                y: pos_config.y,
-            })
-            .with(Velocity { x: 0.0, y: 0.0 })
-            .with(Ball {
-                radius: config.ball.radius,
-                mass: config.ball.mass,
-                restitution: config.ball.restitution,
-            })
-            .build();
-        entities.push(entity);
-    }
-    entities
+    config
+        .object_balls
+        .positions
+        .iter()
+        .enumerate()
+        .map(|(index, pos_config)| {
+            let entry = pos_config.to_object_entity_config();
+            create_ball(world, config, &entry, BallId(index as u32 + 1))
+        })
+        .collect()
 }
@@ -0,0 +1,52 @@
+// src/entities/pegs.rs
+//
+// このファイルでは、格子状に並んだペグ（固定された円形の障害物）のエンティティを生成する関数を定義します。
+
+use crate::components::{Peg, Position};
+use crate::config::Config;
+use specs::prelude::*;
+
+/// 設定された格子の各点にペグエンティティを生成する関数です。
+///
+/// 1 行目の 1 列目のペグを `pegs.x`, `pegs.y` に置き、列は +x 方向に `spacing_x`、
+/// 行は +y 方向に `spacing_y` ずつずらして並べます。`stagger` が true の場合は、
+/// 奇数行（0 始まり）を `spacing_x` の半分だけ +x 方向にずらし、互い違いに並べます。
+///
+/// # 引数
+/// - `world`: ECS の World への可変参照
+/// - `config`: 設定情報
+///
+/// # 戻り値
+/// 生成されたエンティティの Vec を、行ごとに列の順で返します。ペグの設定がない場合は空の Vec を返します。
+pub fn create_peg_grid(world: &mut World, config: &Config) -> Vec<Entity> {
+    let Some(pegs) = &config.pegs else {
+        return Vec::new();
+    };
+    // 設定ファイル上の座標を、隅を原点とした内部座標に変換します。
+    let (origin_x, origin_y) = config
+        .coordinate_origin
+        .to_corner(pegs.x, pegs.y, &config.table);
+    let mut entities = Vec::with_capacity(pegs.rows * pegs.columns);
+    for row in 0..pegs.rows {
+        let offset = if pegs.stagger && row % 2 == 1 {
+            pegs.spacing_x / 2.0
+        } else {
+            0.0
+        };
+        for column in 0..pegs.columns {
+            let entity = world
+                .create_entity()
+                .with(Position {
+                    x: origin_x + offset + column as f32 * pegs.spacing_x,
+                    y: origin_y + row as f32 * pegs.spacing_y,
+                })
+                .with(Peg {
+                    radius: pegs.radius,
+                    restitution: pegs.restitution.unwrap_or(1.0),
+                })
+                .build();
+            entities.push(entity);
+        }
+    }
+    entities
+}
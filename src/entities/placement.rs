@@ -0,0 +1,82 @@
+// src/entities/placement.rs
+//
+// このファイルでは、ボールを他のボールと重ならない位置に配置するための関数を定義します。
+
+use crate::components::Table;
+use crate::config::TableShape;
+
+/// 空き位置を探すときに、1 つの方向へずらす回数の上限です。
+const MAX_SEARCH_STEPS: usize = 100_000;
+
+/// 指定した位置を起点に、他のボールと重ならず、テーブル内に収まる最も近い位置を探す関数です。
+///
+/// 起点から縦方向（+y、フットスポットの後方）に少しずつずらして空き位置を探し、
+/// 見つからない場合は反対方向（-y）に探します。
+/// テーブル内に収まるかどうかは、テーブルの形状（長方形または円形）のクッションの内側かどうかで判定します。
+/// 半径が 0 以下や有限でない場合は探さずに起点を返し、ずらす回数は各方向で MAX_SEARCH_STEPS 回までとします。
+///
+/// # 引数
+/// - `spot`: 配置の起点となる位置 (x, y)
+/// - `radius`: 配置するボールの半径
+/// - `others`: 他のボールの (x, y, radius) の一覧
+/// - `table`: テーブル情報
+///
+/// # 戻り値
+/// 配置可能な位置 (x, y) を返します。空き位置がない場合は起点をそのまま返します。
+pub fn find_free_spot(
+    spot: (f32, f32),
+    radius: f32,
+    others: &[(f32, f32, f32)],
+    table: &Table,
+) -> (f32, f32) {
+    // 1 回にずらす距離（半径の 1/10）
+    let step = radius * 0.1;
+    if step <= 0.0 || !step.is_finite() {
+        return spot;
+    }
+    let is_free = |x: f32, y: f32| {
+        others.iter().all(|&(ox, oy, or)| {
+            let dx = x - ox;
+            let dy = y - oy;
+            dx * dx + dy * dy >= (radius + or) * (radius + or)
+        })
+    };
+    // ボール全体がクッションの内側に収まるかどうか
+    let fits = |x: f32, y: f32| match table.shape {
+        TableShape::Rectangle => {
+            x - radius >= 0.0
+                && x + radius <= table.width
+                && y - radius >= 0.0
+                && y + radius <= table.height
+        }
+        TableShape::Circle { radius: rim } => {
+            let dx = x - table.width / 2.0;
+            let dy = y - table.height / 2.0;
+            dx * dx + dy * dy <= (rim - radius) * (rim - radius) && radius <= rim
+        }
+    };
+
+    // まず起点から後方（+y）に向かって探します。
+    let mut y = spot.1;
+    for _ in 0..MAX_SEARCH_STEPS {
+        if y + radius > table.height {
+            break;
+        }
+        if fits(spot.0, y) && is_free(spot.0, y) {
+            return (spot.0, y);
+        }
+        y += step;
+    }
+    // 後方に空きがなければ、前方（-y）に向かって探します。
+    let mut y = spot.1 - step;
+    for _ in 0..MAX_SEARCH_STEPS {
+        if y - radius < 0.0 {
+            break;
+        }
+        if fits(spot.0, y) && is_free(spot.0, y) {
+            return (spot.0, y);
+        }
+        y -= step;
+    }
+    spot
+}
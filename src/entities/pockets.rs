@@ -0,0 +1,39 @@
+// src/entities/pockets.rs
+//
+// このファイルでは、ポケットのエンティティを生成する関数を定義します。
+
+use crate::components::{Pocket, Position};
+use crate::config::Config;
+use specs::prelude::*;
+
+/// ポケットエンティティを生成する関数です。
+///
+/// # 引数
+/// - `world`: ECS の World への可変参照
+/// - `config`: 設定情報
+///
+/// # 戻り値
+/// 生成されたエンティティの Vec を返します。ポケットの設定がない場合は空の Vec を返します。
+pub fn create_pockets(world: &mut World, config: &Config) -> Vec<Entity> {
+    let Some(pockets) = &config.pockets else {
+        return Vec::new();
+    };
+    let mut entities = Vec::new();
+    // config.pockets.positions に記載された各座標にポケットを生成します。
+    for (index, pos_config) in pockets.positions.iter().enumerate() {
+        // 設定ファイル上の座標を、隅を原点とした内部座標に変換します。
+        let (x, y) = config
+            .coordinate_origin
+            .to_corner(pos_config.x, pos_config.y, &config.table);
+        let entity = world
+            .create_entity()
+            .with(Position { x, y })
+            .with(Pocket {
+                radius: pockets.radius,
+                index,
+            })
+            .build();
+        entities.push(entity);
+    }
+    entities
+}
@@ -0,0 +1,40 @@
+// src/entities/rack.rs
+//
+// このファイルでは、的球を三角形に並べたラックの配置を求める関数を定義します。
+
+/// 先頭のボールを `apex` に置き、`direction` の向きに行を重ねた三角形のラックの配置を返す関数です。
+///
+/// k 行目（0 始まり）には k + 1 個のボールが並び、隣り合うボールはちょうど接するように置きます。
+/// 配置は 1 行目から順に、各行の中では `direction` を左に 90 度回した向きの順に並びます。
+///
+/// # 引数
+/// - `apex`: 先頭のボールの中心 (x, y)
+/// - `direction`: 行を重ねていく向き（長さ 1 のベクトル）
+/// - `radius`: ボールの半径
+/// - `rows`: 行の数（標準的なラックは 5 行 15 個）
+///
+/// # 戻り値
+/// 各ボールの中心 (x, y) の一覧を返します。
+pub fn triangle_rack(
+    apex: (f32, f32),
+    direction: (f32, f32),
+    radius: f32,
+    rows: usize,
+) -> Vec<(f32, f32)> {
+    // 行の間隔は、接する 3 つのボールの中心が正三角形をなすよう半径の √3 倍です。
+    let row_spacing = radius * 3f32.sqrt();
+    // 行の中でボールを並べる向き（direction を左に 90 度回した向き）
+    let side = (-direction.1, direction.0);
+    let mut positions = Vec::with_capacity(rows * (rows + 1) / 2);
+    for row in 0..rows {
+        let depth = row as f32 * row_spacing;
+        for i in 0..=row {
+            let offset = (i as f32 - row as f32 / 2.0) * 2.0 * radius;
+            positions.push((
+                apex.0 + direction.0 * depth + side.0 * offset,
+                apex.1 + direction.1 * depth + side.1 * offset,
+            ));
+        }
+    }
+    positions
+}
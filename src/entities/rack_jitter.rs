@@ -0,0 +1,59 @@
+// src/entities/rack_jitter.rs
+//
+// このファイルでは、的球の初期位置に小さな乱数の揺らぎを加える関数を定義します。
+
+use crate::components::{BallId, BallKind};
+use crate::config::{Config, EntityConfig};
+use crate::resources::SimRng;
+
+/// 1 つの的球について、重ならない位置を引き直す回数の上限です。
+/// 上限までに見つからない場合は、設定どおりの位置のままにします。
+const MAX_ATTEMPTS: usize = 16;
+
+/// 的球の初期位置に、標準偏差 `config.rack_jitter`（cm）の正規分布に従う揺らぎを加えます。
+///
+/// 揺らいだ位置が他のボールと重なる場合やテーブルからはみ出す場合は引き直すため、
+/// 設定どおりの配置が重なっていなければ、揺らぎを加えた後の配置も重なりません。
+/// 手球は動かしません。`rack_jitter` が 0 以下の場合は、設定どおりの一覧をそのまま返します。
+///
+/// # 引数
+/// - `entries`: Config::entity_list() で得たボールの一覧
+/// - `config`: 設定情報（揺らぎの大きさ、テーブル、座標の原点、共通の半径）
+/// - `rng`: 揺らぎに使う乱数生成器
+///
+/// # 戻り値
+/// 揺らぎを加えたボールの一覧を返します。
+pub fn jitter_rack(
+    mut entries: Vec<(BallId, EntityConfig)>,
+    config: &Config,
+    rng: &mut SimRng,
+) -> Vec<(BallId, EntityConfig)> {
+    if config.rack_jitter <= 0.0 {
+        return entries;
+    }
+    let radius_of = |entry: &EntityConfig| entry.radius.unwrap_or(config.ball.radius);
+    for i in 0..entries.len() {
+        if entries[i].1.kind != BallKind::Object {
+            continue;
+        }
+        let radius = radius_of(&entries[i].1);
+        for _ in 0..MAX_ATTEMPTS {
+            let (dx, dy) = rng.next_gaussian_pair();
+            let x = entries[i].1.x + dx * config.rack_jitter;
+            let y = entries[i].1.y + dy * config.rack_jitter;
+            let (cx, cy) = config.coordinate_origin.to_corner(x, y, &config.table);
+            let inside = config.table.clamp_ball(cx, cy, radius) == (cx, cy);
+            let free = entries.iter().enumerate().all(|(j, (_, other))| {
+                let (ox, oy) = (x - other.x, y - other.y);
+                let min = radius + radius_of(other);
+                j == i || ox * ox + oy * oy >= min * min
+            });
+            if inside && free {
+                entries[i].1.x = x;
+                entries[i].1.y = y;
+                break;
+            }
+        }
+    }
+    entries
+}
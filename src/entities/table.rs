@@ -15,6 +15,7 @@ pub fn create_table(world: &mut World, config: &Config) -> Entity {
         .with(Table {
             width: config.table.width,
             height: config.table.height,
+            shape: config.table.shape,
         })
         .build()
 }
@@ -0,0 +1,8 @@
+pub mod collision_event;
+pub use collision_event::CollisionEvent;
+pub mod ball_collision;
+pub use ball_collision::BallCollision;
+pub mod pocket_event;
+pub use pocket_event::PocketEvent;
+pub mod contact;
+pub use contact::Contact;
@@ -0,0 +1,25 @@
+// src/events/ball_collision.rs
+//
+// このファイルでは、解決されたボール同士の衝突 1 件を表す記録を定義します。
+
+use crate::components::BallId;
+use serde::Serialize;
+
+/// ステップ内で解決された、ボール同士の衝突 1 件の記録です。
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct BallCollision {
+    /// 衝突したボールの一方
+    pub id_a: BallId,
+    /// 衝突したボールのもう一方
+    pub id_b: BallId,
+    /// やり取りされたインパルスの大きさ（kg·cm/s）
+    pub impulse: f32,
+    /// A から B の向きの法線ベクトル x 成分
+    pub normal_x: f32,
+    /// A から B の向きの法線ベクトル y 成分
+    pub normal_y: f32,
+    /// 衝突の計算に用いた実効的な反発係数（RestitutionBlend と RestitutionOverride を適用した値）
+    pub effective_restitution: f32,
+    /// 衝突の直前に 2 つのボールが法線方向に近づく速さ（cm/s、A の速度 − B の速度の法線方向の成分で、0 以上）
+    pub impact_speed: f32,
+}
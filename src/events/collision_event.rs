@@ -0,0 +1,38 @@
+// src/events/collision_event.rs
+//
+// このファイルでは、衝突の種類と当事者を表すイベントを定義します。
+
+use crate::components::BallId;
+use crate::resources::Wall;
+
+/// 衝突（ボールと壁、またはボール同士）を表すイベントです。
+///
+/// 効果音や振動の強さの調整に使えるよう、衝突の瞬間の法線と、法線方向に近づく速さを持ちます。
+/// 法線はいずれも長さ 1 で、壁との衝突ではテーブルの内側から見た壁の外向き（ボールが壁に向かう向き）、
+/// ボール同士の衝突では `a` の中心から `b` の中心への向きです。近づく速さ（cm/s）は、
+/// 衝突の直前の速度（ボール同士では `a` の速度 − `b` の速度）の法線方向の成分で、常に 0 以上です。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CollisionEvent {
+    /// ボールと壁（クッション）の衝突
+    Wall {
+        /// 衝突したボール
+        ball: BallId,
+        /// 衝突した壁
+        wall: Wall,
+        /// 壁の外向きの法線
+        normal: (f32, f32),
+        /// 衝突の直前に壁へ近づく速さ（cm/s）
+        impact_speed: f32,
+    },
+    /// ボール同士の衝突
+    Ball {
+        /// 衝突したボールの一方
+        a: BallId,
+        /// 衝突したボールのもう一方
+        b: BallId,
+        /// `a` の中心から `b` の中心への向きの法線
+        normal: (f32, f32),
+        /// 衝突の直前に 2 つのボールが近づく速さ（cm/s）
+        impact_speed: f32,
+    },
+}
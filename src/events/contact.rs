@@ -0,0 +1,21 @@
+// src/events/contact.rs
+//
+// このファイルでは、ステップ終了時点で接触している（めり込んでいる）ボールのペア 1 組を表す記録を定義します。
+
+use crate::components::BallId;
+use serde::Serialize;
+
+/// ステップ終了時点で互いにめり込んでいる、ボールのペア 1 組の接触情報です。
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Contact {
+    /// 接触しているボールの一方（BallId の小さい方）
+    pub id_a: BallId,
+    /// 接触しているボールのもう一方
+    pub id_b: BallId,
+    /// A から B の向きの法線ベクトル x 成分
+    pub normal_x: f32,
+    /// A から B の向きの法線ベクトル y 成分
+    pub normal_y: f32,
+    /// めり込みの深さ（半径の和 - 中心間距離、cm）
+    pub penetration: f32,
+}
@@ -0,0 +1,17 @@
+// src/events/pocket_event.rs
+//
+// このファイルでは、ボールがポケットに入ったこと 1 件を表す記録を定義します。
+
+use crate::components::BallId;
+use serde::Serialize;
+
+/// ステップ内でボールがポケットに入ったこと 1 件の記録です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PocketEvent {
+    /// ポケットに入ったボール
+    pub id: BallId,
+    /// 入ったポケットの番号（設定の `pockets.positions` での順番、0 始まり）
+    pub pocket: usize,
+    /// ポケットに入ったステップの番号（0 始まり）
+    pub step: usize,
+}
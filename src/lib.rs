@@ -0,0 +1,32 @@
+// lib.rs
+// ここでは、各モジュール（config, entities, components, systems など）をライブラリとして公開します。
+// main.rs や外部のプログラムから利用できます。
+use std::time::Duration;
+
+pub mod config;
+// エンティティ生成モジュール：entities 以下に各関数を個別ファイルに分割
+pub mod entities;
+// components 以下の各ファイルをモジュールとして読み込みます。
+pub mod components;
+
+pub mod systems;
+// リソース（World 全体で共有するデータ）を定義するモジュール
+pub mod resources;
+// 衝突などのイベントを定義するモジュール
+pub mod events;
+// 衝突時刻の計算など、ECS に依存しない純粋な計算関数をまとめたモジュール
+pub mod math;
+// specs と std に依存しない（core と libm だけを使う）物理計算の純粋関数をまとめたモジュール
+// （std を使わずにビルドできることは、core_math_no_std クレートを使って tests/core_math.rs で確かめます）
+pub mod core_math;
+// シミュレーション結果の出力先（シンク）を定義するモジュール
+pub mod output;
+// World とディスパッチャをまとめてシミュレーションを進めるモジュール
+pub mod simulation;
+pub use simulation::Simulation;
+
+/// シミュレーションの時間刻み（dt）を保持するリソースです。
+#[derive(Default)]
+pub struct TimeDelta {
+    pub dt: Duration,
+}
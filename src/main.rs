@@ -1,24 +1,10 @@
 // main.rs
-// ここでは、各モジュール（config, entities, components, systems）を読み込みます。
-use specs::prelude::*;
+// ここでは、ライブラリ（my_specs_game）の各モジュールを用いてシミュレーションを実行します。
+use my_specs_game::config;
+use my_specs_game::output::{CsvSink, JsonSink};
+use my_specs_game::simulation::check_determinism;
+use my_specs_game::Simulation;
 use std::error::Error;
-use std::time::Duration;
-use tracing;
-use tracing_subscriber;
-
-mod config;
-// エンティティ生成モジュール：entities 以下に各関数を個別ファイルに分割
-mod entities;
-// components 以下の各ファイルをモジュールとして読み込みます。
-mod components;
-
-mod systems;
-
-/// シミュレーションの時間刻み（dt）を保持するリソースです。
-#[derive(Default)]
-pub struct TimeDelta {
-    pub dt: Duration,
-}
 
 fn main() -> Result<(), Box<dyn Error>> {
     // --- tracing の初期化 ---
@@ -26,56 +12,68 @@ fn main() -> Result<(), Box<dyn Error>> {
     tracing_subscriber::fmt::init();
 
     // --- 1. YAML ファイルから設定情報を読み込みます ---
-    // config.yaml に定義されたシミュレーションパラメータを読み込みます。
-    let config = config::load_config("config.yaml").expect("Failed to load config.yaml");
+    // 第1引数で設定ファイルのパスを指定できます（省略時は config.yaml）。
+    // パスに "-" を指定した場合は、標準入力から YAML 形式の設定を読み込みます。
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "config.yaml".to_string());
+    let config = if path == "-" {
+        config::load_config_from_reader(std::io::stdin().lock(), config::Format::Yaml)
+            .expect("Failed to load config from stdin")
+    } else {
+        config::load_config(&path).unwrap_or_else(|e| panic!("Failed to load {}: {}", path, e))
+    };
     tracing::info!("Loaded configuration: {:?}", config);
 
-    // --- 2. ECS の World を生成します ---
-    let mut world = World::new();
-
-    // --- 3. 各コンポーネントを World に登録します ---
-    world.register::<components::Position>();
-    world.register::<components::Velocity>();
-    world.register::<components::Ball>();
-    world.register::<components::Table>();
-
-    // --- 4. シミュレーションの時間刻み dt をリソースとして World に登録します ---
-    world.insert(TimeDelta {
-        dt: Duration::from_secs_f32(config.dt),
-    });
+    // --- 設定されている場合は、同じ設定から 2 回実行した結果が一致するかを確かめます ---
+    if let Some(check) = &config.determinism_check {
+        match check_determinism(&config, check.steps)? {
+            Ok(hash) => tracing::info!(
+                "Determinism check passed after {} steps (state hash {:016x})",
+                check.steps,
+                hash
+            ),
+            Err(e) => match check.on_mismatch {
+                config::MismatchPolicy::Warn => tracing::warn!("{}", e),
+                config::MismatchPolicy::Error => return Err(e.into()),
+            },
+        }
+    }
 
-    // --- 5. エンティティ生成関数を用いて、各エンティティ（テーブル、手球、的球）を作成します ---
-    // テーブル（ビリヤード台）エンティティを作成
-    entities::create_table(&mut world, &config);
-    // 手球（cue ball）エンティティを作成
-    entities::create_cue_ball(&mut world, &config);
-    // 的球（object ball）エンティティを作成
-    entities::create_object_balls(&mut world, &config);
+    // --- 2. World・エンティティ・システムをまとめたシミュレーションを生成します ---
+    let mut simulation = Simulation::new(&config)?;
 
-    // --- システムディスパッチャの構築 ---
-    // システムの実行順序は、Physics → Collision → Print とします。
-    let mut dispatcher = DispatcherBuilder::new()
-        .with(systems::PhysicsSystem, "physics_system", &[])
-        .with(
-            systems::CollisionSystem,
-            "collision_system",
-            &["physics_system"],
-        )
-        .with(
-            systems::LoggingSystem,
-            "print_system",
-            &["collision_system"],
-        )
-        .build();
+    // --- 3. 設定に応じて出力先（シンク）を登録します ---
+    if let Some(path) = &config.output.csv {
+        let mut sink = CsvSink::create(path)?.with_flush_every(config.output.flush_every);
+        if let Some(collisions) = &config.output.collisions_csv {
+            sink = sink.with_collisions_file(collisions)?;
+        }
+        simulation.add_sink(Box::new(sink));
+    }
+    if let Some(path) = &config.output.json {
+        let mut sink = JsonSink::create(path)?.with_flush_every(config.output.flush_every);
+        if let Some(collisions) = &config.output.collisions_json {
+            sink = sink.with_collisions_file(collisions)?;
+        }
+        simulation.add_sink(Box::new(sink));
+    }
 
     // --- シミュレーションループ ---
-    let steps = 10;
-    for step in 0..steps {
-        tracing::info!("--- Time step {} ---", step);
-        // 各システムを順次実行します。
-        dispatcher.dispatch(&world);
-        // エンティティの生成／削除などの更新処理を実行します。
-        world.maintain();
+    // ショットの一覧がある場合は、静止するたびに順に撞き、それ以外は決まったステップ数だけ進めます。
+    if config.shots.is_empty() {
+        let steps = 10;
+        simulation.run(steps);
+    } else {
+        for (index, outcome) in simulation.run_shots().iter().enumerate() {
+            tracing::info!(
+                "Shot {}: {} steps, settled: {}, pocketed: {:?}",
+                index + 1,
+                outcome.steps,
+                outcome.settled,
+                outcome.pocketed.iter().map(|id| id.0).collect::<Vec<_>>()
+            );
+        }
     }
 
     Ok(())
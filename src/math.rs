@@ -0,0 +1,12 @@
+pub mod time_of_impact;
+pub use time_of_impact::{ball_time_of_impact, wall_time_of_impact};
+pub mod next_event;
+pub use next_event::{next_event, MovingBall};
+pub mod bisection_time_of_impact;
+pub use bisection_time_of_impact::bisection_time_of_impact;
+pub mod swept_aabb;
+pub use swept_aabb::{swept_aabb, Aabb};
+pub mod contact_coloring;
+pub use contact_coloring::color_contacts;
+pub mod stopping_point;
+pub use stopping_point::stopping_point;
@@ -0,0 +1,79 @@
+// src/math/bisection_time_of_impact.rs
+//
+// このファイルでは、衝突までの時間を解析的に求められない障害物に対して、
+// 符号付き距離関数の二分探索により接触時刻を求める純粋関数を定義します。
+
+use crate::components::Position;
+
+/// 接触を含む区間を探すために、始点から終点までを等間隔に調べる回数です。
+/// 薄い障害物を 1 ステップで通り抜けてしまう（トンネリング）のを防ぎます。
+const SAMPLES: usize = 16;
+
+/// ボールが静止した障害物に接触するまでの時間を、二分探索で求める純粋関数です。
+///
+/// ボールは 1 ステップの間に `start` から `end` まで直線的に移動するものとし、
+/// 各時刻の中心位置での `signed_distance`（障害物の表面までの符号付き距離。内側で負）が
+/// 半径以下になる最初の時刻を探します。まず区間を等間隔に調べて接触を含む小区間を見つけ、
+/// その小区間を `tolerance` 以下になるまで二分して接触時刻を絞り込みます。
+///
+/// # 引数
+/// - `start`, `end`: ステップの開始時と終了時のボールの中心位置
+/// - `radius`: ボールの半径
+/// - `dt`: ステップの時間（秒）
+/// - `signed_distance`: 障害物の符号付き距離関数
+/// - `tolerance`: 求める時刻の許容誤差（秒）
+///
+/// # 戻り値
+/// 接触する場合は、ステップの開始から接触までの時間（秒、接触時刻以前の側の値）を返します。
+/// 開始時点ですでに接触している場合は 0 を、ステップ内に接触しない場合は None を返します。
+pub fn bisection_time_of_impact<F>(
+    start: Position,
+    end: Position,
+    radius: f32,
+    dt: f32,
+    signed_distance: F,
+    tolerance: f32,
+) -> Option<f32>
+where
+    F: Fn(Position) -> f32,
+{
+    // 時刻 t でのボール表面から障害物までの距離（接触していれば 0 以下）
+    let gap = |t: f32| {
+        let s = if dt > 0.0 { t / dt } else { 1.0 };
+        let p = Position {
+            x: start.x + (end.x - start.x) * s,
+            y: start.y + (end.y - start.y) * s,
+        };
+        signed_distance(p) - radius
+    };
+    if gap(0.0) <= 0.0 {
+        return Some(0.0);
+    }
+
+    // 接触を含む最初の小区間を探します。
+    let mut lo = 0.0;
+    let mut hi = None;
+    for i in 1..=SAMPLES {
+        let t = dt * i as f32 / SAMPLES as f32;
+        if gap(t) <= 0.0 {
+            hi = Some(t);
+            break;
+        }
+        lo = t;
+    }
+    let mut hi = hi?;
+
+    // 小区間を二分し、接触時刻を絞り込みます。
+    while hi - lo > tolerance {
+        let mid = 0.5 * (lo + hi);
+        if mid <= lo || mid >= hi {
+            break;
+        }
+        if gap(mid) <= 0.0 {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    Some(lo)
+}
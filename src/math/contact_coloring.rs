@@ -0,0 +1,33 @@
+// src/math/contact_coloring.rs
+//
+// このファイルでは、ボール同士の接触を、同じボールを含まない組（バッチ）に分ける純粋関数を定義します。
+
+/// ボールの番号のペアの列 `pairs` を、同じボールを 2 回以上含まないバッチに分ける純粋関数です。
+///
+/// ペアを `pairs` の順に調べ、両方のボールがすでに属しているバッチのどれよりも後のバッチのうち、
+/// 最も前のものに入れます。このため、あるボールを含むペアは `pairs` での順序のまま
+/// 前のバッチから順に現れ、バッチを前から順に処理すれば、各ボールへの変化は `pairs` の順に
+/// 1 つずつ処理した場合と同じ順序で加わります。
+///
+/// # 引数
+/// - `pairs`: ボールの番号（0 以上 `count` 未満）のペアの列
+/// - `count`: ボールの数
+///
+/// # 戻り値
+/// 各バッチに属するペアの `pairs` での位置の一覧。バッチは処理する順に並び、
+/// 各バッチの中の位置は昇順に並びます。
+pub fn color_contacts(pairs: &[(usize, usize)], count: usize) -> Vec<Vec<usize>> {
+    // 各ボールを含むペアを、次に入れてよい最も前のバッチの番号
+    let mut next = vec![0; count];
+    let mut batches: Vec<Vec<usize>> = Vec::new();
+    for (index, &(a, b)) in pairs.iter().enumerate() {
+        let batch = next[a].max(next[b]);
+        if batch == batches.len() {
+            batches.push(Vec::new());
+        }
+        batches[batch].push(index);
+        next[a] = batch + 1;
+        next[b] = batch + 1;
+    }
+    batches
+}
@@ -0,0 +1,96 @@
+// src/math/next_event.rs
+//
+// このファイルでは、複数のボールとテーブルの壁について、
+// 次に起きる衝突とその時刻を求める純粋関数を定義します。
+
+use super::{ball_time_of_impact, wall_time_of_impact};
+use crate::components::{BallId, BallKind, CollisionMask, Position, Table, Velocity};
+use crate::events::CollisionEvent;
+use crate::resources::CollisionRules;
+
+/// 衝突時刻の計算に必要な、あるボールの状態をまとめた構造体です。
+#[derive(Debug, Clone, Copy)]
+pub struct MovingBall {
+    /// ボールの識別番号
+    pub id: BallId,
+    /// 位置
+    pub pos: Position,
+    /// 速度
+    pub vel: Velocity,
+    /// 半径
+    pub radius: f32,
+    /// 衝突レイヤー
+    pub mask: CollisionMask,
+    /// ボールの種類
+    pub kind: BallKind,
+}
+
+/// すべてのボールのペアと壁について、次に起きる衝突とその時刻を求める純粋関数です。
+///
+/// 摩擦のない等速直線運動を仮定します。同時刻の衝突が複数ある場合は、
+/// `balls` の並び順で先に現れるもの（壁との衝突を優先）を返します。
+/// イベントの法線と近づく速さは、衝突する時刻の位置と（変わらない）速度から求めます。
+/// 衝突レイヤーが重ならないペアと、種類の組み合わせの規則で無効なペアは衝突しないものとして扱います。
+///
+/// # 引数
+/// - `balls`: 各ボールの状態
+/// - `table`: テーブル情報（None の場合は壁との衝突を考慮しません）
+/// - `rules`: ボールの種類の組み合わせごとの衝突の有無
+///
+/// # 戻り値
+/// 衝突までの時間（秒）と衝突イベントを返します。衝突が起きない場合は None を返します。
+pub fn next_event(
+    balls: &[MovingBall],
+    table: Option<&Table>,
+    rules: &CollisionRules,
+) -> Option<(f32, CollisionEvent)> {
+    let mut next: Option<(f32, CollisionEvent)> = None;
+    let mut consider = |t: f32, event: CollisionEvent| {
+        if next.is_none_or(|(best, _)| t < best) {
+            next = Some((t, event));
+        }
+    };
+    for (i, a) in balls.iter().enumerate() {
+        // 壁との衝突
+        if let Some((t, wall, normal)) =
+            table.and_then(|table| wall_time_of_impact(a.pos, a.vel, a.radius, table))
+        {
+            consider(
+                t,
+                CollisionEvent::Wall {
+                    ball: a.id,
+                    wall,
+                    normal,
+                    impact_speed: (a.vel.x * normal.0 + a.vel.y * normal.1).abs(),
+                },
+            );
+        }
+        // ボール同士の衝突（i < j のペアのみ）
+        for b in &balls[i + 1..] {
+            if !a.mask.overlaps(b.mask) || !rules.allows(a.kind, b.kind) {
+                continue;
+            }
+            if let Some(t) = ball_time_of_impact(a.pos, a.vel, a.radius, b.pos, b.vel, b.radius) {
+                let dx = (b.pos.x + b.vel.x * t) - (a.pos.x + a.vel.x * t);
+                let dy = (b.pos.y + b.vel.y * t) - (a.pos.y + a.vel.y * t);
+                let distance = (dx * dx + dy * dy).sqrt();
+                let normal = if distance > 0.0 {
+                    (dx / distance, dy / distance)
+                } else {
+                    (1.0, 0.0)
+                };
+                let approach = (a.vel.x - b.vel.x) * normal.0 + (a.vel.y - b.vel.y) * normal.1;
+                consider(
+                    t,
+                    CollisionEvent::Ball {
+                        a: a.id,
+                        b: b.id,
+                        normal,
+                        impact_speed: approach.abs(),
+                    },
+                );
+            }
+        }
+    }
+    next
+}
@@ -0,0 +1,85 @@
+// src/math/stopping_point.rs
+//
+// このファイルでは、摩擦だけを受けて転がるボールが止まる位置を、シミュレーションを行わずに
+// 求める純粋関数を定義します。
+
+use crate::components::{Position, Velocity};
+use crate::resources::Friction;
+
+/// 異方的な摩擦で移動距離を数値積分するときの、区間の分割数（偶数）です。
+const QUADRATURE_INTERVALS: usize = 64;
+
+/// 摩擦による減速だけを受けて転がるボールが止まる位置を返す純粋関数です。
+///
+/// 衝突、重力、滑り摩擦、摩擦の異なる領域、低速のボールへの追加の減速は考えません。
+/// 摩擦が等方的（`friction.x == friction.y`）な場合、ボールは向きを変えずに一定の減速度 f で止まるため、
+/// 止まるまでの距離は `v² / (2 f)` になります。
+/// 異方的な場合は軌跡が曲がりますが、core_math::apply_friction() の減速では
+/// `|vy| ∝ |vx|^(friction.y / friction.x)` の関係が保たれるため、各軸の移動距離を 1 次元の積分として
+/// シンプソン法で求めます。
+///
+/// PhysicsSystem は速度を更新してから位置を進めるため、シミュレーションで止まる位置は、この位置より
+/// 最大で 1 ステップ分の移動量（`v × dt`）の半分ほど手前になります。
+///
+/// # 引数
+/// - `pos`: ボールの位置（隅を原点とした内部座標、cm）
+/// - `vel`: ボールの速度（cm/s）
+/// - `friction`: 摩擦による軸ごとの減速度（cm/s^2）
+///
+/// # 戻り値
+/// 止まる位置。静止している場合は `pos` をそのまま返し、動いている軸の摩擦が 0 以下で
+/// いつまでも止まらない場合は None を返します。
+pub fn stopping_point(pos: Position, vel: Velocity, friction: &Friction) -> Option<Position> {
+    let (ax, ay) = (vel.x.abs(), vel.y.abs());
+    // 減速度が正でない（NaN を含む）軸に動いている場合は止まりません。
+    let decelerates = |f: f32| f > 0.0;
+    if (ax > 0.0 && !decelerates(friction.x)) || (ay > 0.0 && !decelerates(friction.y)) {
+        return None;
+    }
+    let (dx, dy) = if ax == 0.0 || ay == 0.0 {
+        // 1 つの軸に沿って進む場合は、その軸の減速度で止まります。
+        (
+            if ax > 0.0 {
+                ax * ax / (2.0 * friction.x)
+            } else {
+                0.0
+            },
+            if ay > 0.0 {
+                ay * ay / (2.0 * friction.y)
+            } else {
+                0.0
+            },
+        )
+    } else if friction.x == friction.y {
+        let speed = (ax * ax + ay * ay).sqrt();
+        let scale = speed / (2.0 * friction.x);
+        (ax * scale, ay * scale)
+    } else {
+        // 速度が (ax u, ay u^k)（k = friction.y / friction.x、u は 1 から 0 へ減る）と表せることから、
+        // x の移動距離は ax / fx × ∫ |v| du、y の移動距離も x と y を入れ替えた同じ形の積分になります。
+        let k = friction.y / friction.x;
+        let distance = |a: f32, b: f32, exponent: f32, f: f32| {
+            a / f * simpson(|u| (a * a * u * u + b * b * u.powf(2.0 * exponent)).sqrt())
+        };
+        (
+            distance(ax, ay, k, friction.x),
+            distance(ay, ax, 1.0 / k, friction.y),
+        )
+    };
+    Some(Position {
+        x: pos.x + dx.copysign(vel.x),
+        y: pos.y + dy.copysign(vel.y),
+    })
+}
+
+/// 区間 [0, 1] での `f` の積分を、QUADRATURE_INTERVALS 個の区間のシンプソン法で求めます。
+fn simpson(f: impl Fn(f32) -> f32) -> f32 {
+    let h = 1.0 / QUADRATURE_INTERVALS as f32;
+    let inner: f32 = (1..QUADRATURE_INTERVALS)
+        .map(|i| {
+            let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+            weight * f(i as f32 * h)
+        })
+        .sum();
+    (f(0.0) + inner + f(1.0)) * h / 3.0
+}
@@ -0,0 +1,48 @@
+// src/math/swept_aabb.rs
+//
+// このファイルでは、1 ステップの間にボールが通過する範囲を囲む、座標軸に平行な境界矩形
+// （swept AABB）を求める純粋関数を定義します。格子や sweep-and-prune による広域判定
+// （broadphase）で、明らかに衝突しないペアを正確な判定の前に除外するために使います。
+
+use crate::components::Position;
+
+/// 座標軸に平行な境界矩形（axis-aligned bounding box）を表す構造体です。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
+    /// x 座標の最小値（cm）
+    pub min_x: f32,
+    /// y 座標の最小値（cm）
+    pub min_y: f32,
+    /// x 座標の最大値（cm）
+    pub max_x: f32,
+    /// y 座標の最大値（cm）
+    pub max_y: f32,
+}
+
+impl Aabb {
+    /// 2 つの矩形が重なっているかどうかを返します。辺が接しているだけの場合も重なっているとみなします。
+    pub fn overlaps(&self, other: &Aabb) -> bool {
+        self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+}
+
+/// ボールが `start` から `end` まで直線的に移動する間に通過する範囲を囲む境界矩形を返す純粋関数です。
+///
+/// 始点と終点の各座標の最小値・最大値を、ボールの半径だけ広げた矩形になります。
+/// 2 つのボールの swept AABB が重ならない場合、そのステップ内で 2 つのボールが接触することはありません。
+///
+/// # 引数
+/// - `start`: ステップの開始時の位置
+/// - `end`: ステップの終了時の位置
+/// - `radius`: ボールの半径
+pub fn swept_aabb(start: Position, end: Position, radius: f32) -> Aabb {
+    Aabb {
+        min_x: start.x.min(end.x) - radius,
+        min_y: start.y.min(end.y) - radius,
+        max_x: start.x.max(end.x) + radius,
+        max_y: start.y.max(end.y) + radius,
+    }
+}
@@ -0,0 +1,136 @@
+// src/math/time_of_impact.rs
+//
+// このファイルでは、等速直線運動を仮定して、次に衝突が起きるまでの時間（time of impact）を
+// 解析的に求める純粋関数を定義します。
+
+use crate::components::{Position, Table, Velocity};
+use crate::config::TableShape;
+use crate::resources::Wall;
+
+/// ボールがテーブルの壁に接触するまでの時間と、その壁、接点での外向きの法線を返す純粋関数です。
+///
+/// 摩擦のない等速直線運動を仮定します。すでに壁にめり込んでいて壁に向かって
+/// 進んでいる場合は 0 を返します。どの壁にも向かっていない場合は None を返します。
+/// テーブルの形状が円形（TableShape::Circle）の場合は、中心からの距離が `円の半径 − radius` になる時刻を求め、
+/// 壁は接点の法線に最も近い向きのもの（Wall::from_normal）とします。
+///
+/// # 引数
+/// - `pos`, `vel`: ボールの位置と速度
+/// - `radius`: ボールの半径
+/// - `table`: テーブル情報
+///
+/// # 戻り値
+/// (衝突までの時間（秒）, 壁, 接点での外向きの法線) を返します。
+pub fn wall_time_of_impact(
+    pos: Position,
+    vel: Velocity,
+    radius: f32,
+    table: &Table,
+) -> Option<(f32, Wall, (f32, f32))> {
+    if let TableShape::Circle { radius: rim } = table.shape {
+        let center = (table.width / 2.0, table.height / 2.0);
+        return circle_time_of_impact(pos, vel, radius, center, rim);
+    }
+    // 各軸について、速度の向きにある壁までの時間を求めます。
+    let x_hit = if vel.x < 0.0 {
+        Some(((pos.x - radius) / -vel.x, Wall::Left))
+    } else if vel.x > 0.0 {
+        Some(((table.width - radius - pos.x) / vel.x, Wall::Right))
+    } else {
+        None
+    };
+    let y_hit = if vel.y < 0.0 {
+        Some(((pos.y - radius) / -vel.y, Wall::Bottom))
+    } else if vel.y > 0.0 {
+        Some(((table.height - radius - pos.y) / vel.y, Wall::Top))
+    } else {
+        None
+    };
+
+    // 早い方の壁を採用します。
+    [x_hit, y_hit]
+        .into_iter()
+        .flatten()
+        .map(|(t, wall)| (t.max(0.0), wall, wall.normal()))
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+}
+
+/// 中心 `center`、半径 `rim` の円形のクッションに、ボールが内側から接触するまでの時間を求めます。
+///
+/// 中心からボールの中心までの距離が `rim − radius` になる 2 つの時刻のうち、遅い方（外へ向かって
+/// 円を横切る時刻）を求めます。すでに円の外側にはみ出していて外へ向かっている場合は 0 を返します。
+/// 止まっている場合は None を返します。
+fn circle_time_of_impact(
+    pos: Position,
+    vel: Velocity,
+    radius: f32,
+    center: (f32, f32),
+    rim: f32,
+) -> Option<(f32, Wall, (f32, f32))> {
+    let (dx, dy) = (pos.x - center.0, pos.y - center.1);
+    let limit = rim - radius;
+    // |d + v t|^2 = limit^2 を t について解きます。
+    let a = vel.x * vel.x + vel.y * vel.y;
+    if a == 0.0 {
+        return None;
+    }
+    let half_b = dx * vel.x + dy * vel.y;
+    let c = dx * dx + dy * dy - limit * limit;
+    let t = if c >= 0.0 && half_b > 0.0 {
+        0.0
+    } else {
+        let discriminant = (half_b * half_b - a * c).max(0.0);
+        ((-half_b + discriminant.sqrt()) / a).max(0.0)
+    };
+    // 接点での外向きの法線
+    let (hx, hy) = (dx + vel.x * t, dy + vel.y * t);
+    let distance = (hx * hx + hy * hy).sqrt();
+    let normal = if distance > 0.0 {
+        (hx / distance, hy / distance)
+    } else {
+        let speed = a.sqrt();
+        (vel.x / speed, vel.y / speed)
+    };
+    Some((t, Wall::from_normal(normal), normal))
+}
+
+/// 2 つのボールが接触するまでの時間を返す純粋関数です。
+///
+/// 摩擦のない等速直線運動を仮定し、中心間距離が半径の和に等しくなる最初の時刻を求めます。
+/// すでに重なっていて互いに近づいている場合は 0 を返します。
+/// 離れつつある場合や、すれ違って接触しない場合は None を返します。
+///
+/// # 引数
+/// - `pa`, `va`, `ra`: ボール A の位置・速度・半径
+/// - `pb`, `vb`, `rb`: ボール B の位置・速度・半径
+pub fn ball_time_of_impact(
+    pa: Position,
+    va: Velocity,
+    ra: f32,
+    pb: Position,
+    vb: Velocity,
+    rb: f32,
+) -> Option<f32> {
+    // A から見た B の相対位置と相対速度
+    let px = pb.x - pa.x;
+    let py = pb.y - pa.y;
+    let vx = vb.x - va.x;
+    let vy = vb.y - va.y;
+    let radius_sum = ra + rb;
+
+    // |p + v t| = radius_sum を t について解く 2 次方程式 a t^2 + 2 b t + c = 0
+    let a = vx * vx + vy * vy;
+    let b = px * vx + py * vy;
+    let c = px * px + py * py - radius_sum * radius_sum;
+
+    // 相対的に静止している、または離れつつある場合は接触しません。
+    if a == 0.0 || b >= 0.0 {
+        return None;
+    }
+    let discriminant = b * b - a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let t = (-b - discriminant.sqrt()) / a;
+    Some(t.max(0.0))
+}
@@ -0,0 +1,20 @@
+pub mod world_snapshot;
+pub use world_snapshot::{BallSnapshot, WorldSnapshot};
+pub mod output_sink;
+pub use output_sink::OutputSink;
+pub mod memory_sink;
+pub use memory_sink::MemorySink;
+pub mod csv_sink;
+pub use csv_sink::CsvSink;
+pub mod json_sink;
+pub use json_sink::JsonSink;
+pub mod overlay;
+pub use overlay::Overlay;
+pub mod svg_export;
+pub use svg_export::render_svg;
+pub mod raster;
+pub use raster::{render_raster, RasterFrame};
+pub mod resample;
+pub use resample::resample_trajectory;
+pub mod terminal;
+pub use terminal::render_ascii;
@@ -0,0 +1,125 @@
+// src/output/csv_sink.rs
+//
+// このファイルでは、各ステップのボールの状態を CSV 形式で書き出す出力先を定義します。
+
+use super::{OutputSink, WorldSnapshot};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use tracing::error;
+
+/// 各ステップのボールの状態を CSV 形式で書き出す出力先です。
+///
+/// 1 行に 1 ボールの状態を `step,time,id,kind,x,y,vx,vy` の列で出力します。
+/// with_collisions() で別の書き込み先を指定すると、解決されたボール同士の衝突を
+/// `step,id_a,id_b,impulse,normal_x,normal_y,effective_restitution` の列で併せて出力します。
+pub struct CsvSink<W: Write> {
+    writer: W,
+    collisions: Option<W>,
+    /// 書き込み先をフラッシュする間隔（ステップ数、0 の場合は finish() の時のみ）
+    flush_every: usize,
+    /// 最後にフラッシュしてから書き出したステップ数
+    unflushed: usize,
+}
+
+impl CsvSink<BufWriter<File>> {
+    /// 指定したパスにファイルを作成し、CsvSink を生成します。
+    pub fn create(path: &str) -> io::Result<Self> {
+        CsvSink::new(BufWriter::new(File::create(path)?))
+    }
+
+    /// 指定したパスにファイルを作成し、衝突の一覧の書き込み先とします。
+    pub fn with_collisions_file(self, path: &str) -> io::Result<Self> {
+        self.with_collisions(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<W: Write> CsvSink<W> {
+    /// 任意の書き込み先から CsvSink を生成し、ヘッダ行を書き出します。
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writeln!(writer, "step,time,id,kind,x,y,vx,vy")?;
+        Ok(CsvSink {
+            writer,
+            collisions: None,
+            flush_every: 0,
+            unflushed: 0,
+        })
+    }
+
+    /// 衝突の一覧の書き込み先を指定し、そのヘッダ行を書き出します。
+    pub fn with_collisions(mut self, mut writer: W) -> io::Result<Self> {
+        writeln!(
+            writer,
+            "step,id_a,id_b,impulse,normal_x,normal_y,effective_restitution"
+        )?;
+        self.collisions = Some(writer);
+        Ok(self)
+    }
+
+    /// 書き込み先をフラッシュする間隔をステップ数で指定します。
+    ///
+    /// `steps` ステップ書き出すごとにフラッシュし、実行が途中で止まった場合もそこまでの結果が
+    /// ファイルに残るようにします。0 を指定すると、finish() の時のみフラッシュします（既定値）。
+    pub fn with_flush_every(mut self, steps: usize) -> Self {
+        self.flush_every = steps;
+        self
+    }
+
+    /// 指定した間隔に達した場合に、すべての書き込み先をフラッシュします。
+    fn flush_if_due(&mut self) -> io::Result<()> {
+        self.unflushed += 1;
+        if self.flush_every == 0 || self.unflushed < self.flush_every {
+            return Ok(());
+        }
+        self.unflushed = 0;
+        self.writer.flush()?;
+        if let Some(writer) = &mut self.collisions {
+            writer.flush()?;
+        }
+        Ok(())
+    }
+
+    /// 1 ステップ分の行を書き出します。
+    fn write_step(&mut self, step: usize, snapshot: &WorldSnapshot) -> io::Result<()> {
+        let t = snapshot.elapsed.as_secs_f32();
+        for b in &snapshot.balls {
+            writeln!(
+                self.writer,
+                "{},{},{},{:?},{},{},{},{}",
+                step, t, b.id.0, b.kind, b.x, b.y, b.vx, b.vy
+            )?;
+        }
+        if let Some(writer) = &mut self.collisions {
+            for c in &snapshot.collisions {
+                writeln!(
+                    writer,
+                    "{},{},{},{},{},{},{}",
+                    step,
+                    c.id_a.0,
+                    c.id_b.0,
+                    c.impulse,
+                    c.normal_x,
+                    c.normal_y,
+                    c.effective_restitution
+                )?;
+            }
+        }
+        self.flush_if_due()
+    }
+}
+
+impl<W: Write> OutputSink for CsvSink<W> {
+    fn on_step(&mut self, step: usize, snapshot: &WorldSnapshot) {
+        if let Err(e) = self.write_step(step, snapshot) {
+            error!("Failed to write CSV output: {}", e);
+        }
+    }
+
+    fn finish(&mut self) {
+        if let Err(e) = self.writer.flush() {
+            error!("Failed to flush CSV output: {}", e);
+        }
+        if let Some(Err(e)) = self.collisions.as_mut().map(|w| w.flush()) {
+            error!("Failed to flush CSV collision output: {}", e);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+// src/output/json_sink.rs
+//
+// このファイルでは、各ステップのスナップショットを JSON Lines 形式で書き出す出力先を定義します。
+
+use super::{OutputSink, WorldSnapshot};
+use crate::events::BallCollision;
+use serde::Serialize;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use tracing::error;
+
+/// 各ステップのスナップショットを、1 行 1 オブジェクトの JSON（JSON Lines）で書き出す出力先です。
+/// with_collisions() で別の書き込み先を指定すると、解決されたボール同士の衝突を
+/// 1 件ずつ、ステップ番号を付けた JSON Lines として併せて出力します。
+pub struct JsonSink<W: Write> {
+    writer: W,
+    collisions: Option<W>,
+    /// 書き込み先をフラッシュする間隔（ステップ数、0 の場合は finish() の時のみ）
+    flush_every: usize,
+    /// 最後にフラッシュしてから書き出したステップ数
+    unflushed: usize,
+}
+
+/// 衝突の一覧のストリームに書き出す 1 行分のレコードです。
+#[derive(Serialize)]
+struct CollisionRecord<'a> {
+    step: usize,
+    #[serde(flatten)]
+    collision: &'a BallCollision,
+}
+
+impl JsonSink<BufWriter<File>> {
+    /// 指定したパスにファイルを作成し、JsonSink を生成します。
+    pub fn create(path: &str) -> io::Result<Self> {
+        Ok(JsonSink::new(BufWriter::new(File::create(path)?)))
+    }
+
+    /// 指定したパスにファイルを作成し、衝突の一覧の書き込み先とします。
+    pub fn with_collisions_file(self, path: &str) -> io::Result<Self> {
+        Ok(self.with_collisions(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> JsonSink<W> {
+    /// 任意の書き込み先から JsonSink を生成します。
+    pub fn new(writer: W) -> Self {
+        JsonSink {
+            writer,
+            collisions: None,
+            flush_every: 0,
+            unflushed: 0,
+        }
+    }
+
+    /// 衝突の一覧の書き込み先を指定します。
+    pub fn with_collisions(mut self, writer: W) -> Self {
+        self.collisions = Some(writer);
+        self
+    }
+
+    /// 書き込み先をフラッシュする間隔をステップ数で指定します。
+    ///
+    /// `steps` ステップ書き出すごとにフラッシュし、実行が途中で止まった場合もそこまでの結果が
+    /// ファイルに残るようにします。0 を指定すると、finish() の時のみフラッシュします（既定値）。
+    pub fn with_flush_every(mut self, steps: usize) -> Self {
+        self.flush_every = steps;
+        self
+    }
+
+    /// 指定した間隔に達した場合に、すべての書き込み先をフラッシュします。
+    fn flush_if_due(&mut self) -> io::Result<()> {
+        self.unflushed += 1;
+        if self.flush_every == 0 || self.unflushed < self.flush_every {
+            return Ok(());
+        }
+        self.unflushed = 0;
+        self.writer.flush()?;
+        if let Some(writer) = &mut self.collisions {
+            writer.flush()?;
+        }
+        Ok(())
+    }
+
+    /// 1 ステップ分のスナップショットを 1 行の JSON として書き出します。
+    fn write_step(&mut self, step: usize, snapshot: &WorldSnapshot) -> io::Result<()> {
+        serde_json::to_writer(&mut self.writer, snapshot)?;
+        writeln!(self.writer)?;
+        if let Some(writer) = &mut self.collisions {
+            for collision in &snapshot.collisions {
+                serde_json::to_writer(&mut *writer, &CollisionRecord { step, collision })?;
+                writeln!(writer)?;
+            }
+        }
+        self.flush_if_due()
+    }
+}
+
+impl<W: Write> OutputSink for JsonSink<W> {
+    fn on_step(&mut self, step: usize, snapshot: &WorldSnapshot) {
+        if let Err(e) = self.write_step(step, snapshot) {
+            error!("Failed to write JSON output: {}", e);
+        }
+    }
+
+    fn finish(&mut self) {
+        if let Err(e) = self.writer.flush() {
+            error!("Failed to flush JSON output: {}", e);
+        }
+        if let Some(Err(e)) = self.collisions.as_mut().map(|w| w.flush()) {
+            error!("Failed to flush JSON collision output: {}", e);
+        }
+    }
+}
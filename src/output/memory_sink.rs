@@ -0,0 +1,33 @@
+// src/output/memory_sink.rs
+//
+// このファイルでは、スナップショットをメモリ上に蓄積する出力先を定義します。
+
+use super::{OutputSink, WorldSnapshot};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// 受け取ったスナップショットをメモリ上の Vec に蓄積する出力先です。
+///
+/// Simulation に登録した後も結果を参照できるよう、蓄積先は共有参照（Rc<RefCell<..>>）で保持します。
+#[derive(Debug, Default, Clone)]
+pub struct MemorySink {
+    snapshots: Rc<RefCell<Vec<WorldSnapshot>>>,
+}
+
+impl MemorySink {
+    /// 空の MemorySink を生成します。
+    pub fn new() -> MemorySink {
+        MemorySink::default()
+    }
+
+    /// 蓄積先への共有参照を返します。Simulation に登録する前に取得しておきます。
+    pub fn snapshots(&self) -> Rc<RefCell<Vec<WorldSnapshot>>> {
+        Rc::clone(&self.snapshots)
+    }
+}
+
+impl OutputSink for MemorySink {
+    fn on_step(&mut self, _step: usize, snapshot: &WorldSnapshot) {
+        self.snapshots.borrow_mut().push(snapshot.clone());
+    }
+}
@@ -0,0 +1,21 @@
+// src/output/output_sink.rs
+//
+// このファイルでは、シミュレーション結果の出力先を表すトレイトを定義します。
+
+use super::WorldSnapshot;
+
+/// シミュレーション結果の出力先（シンク）を表すトレイトです。
+///
+/// Simulation::add_sink で登録すると、各ステップの終了時に on_step が呼び出されます。
+/// コンソール、ファイル、メモリなど、出力先ごとにこのトレイトを実装します。
+pub trait OutputSink {
+    /// 各ステップの終了時に呼び出されます。
+    ///
+    /// # 引数
+    /// - `step`: ステップ番号（0 始まり）
+    /// - `snapshot`: ステップ終了時点の World の状態
+    fn on_step(&mut self, step: usize, snapshot: &WorldSnapshot);
+
+    /// シミュレーションの終了時に呼び出されます。ファイルのフラッシュなどに使います。
+    fn finish(&mut self) {}
+}
@@ -0,0 +1,16 @@
+// src/output/overlay.rs
+//
+// このファイルでは、書き出す画像のボールの上に重ねて描く補助表示の構造体を定義します。
+
+/// 狙いを定める UI のために、ボールの上に重ねて描く補助表示です。
+///
+/// 手球の中心から狙いの向きへの線（エイムライン）と、予測した最初の接触位置にある
+/// 手球の輪郭（ゴーストボール）を描きます。予測は呼び出し側で行い、その結果を設定します。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Overlay {
+    /// 狙いの向き（+x 軸から反時計回りの角度、ラジアン）
+    pub aim: f32,
+    /// 最初に的球へ接触するときの手球の中心位置（隅を原点とした内部座標、cm）。
+    /// 接触が予測されない場合は None で、エイムラインをテーブルの端まで描きます。
+    pub contact: Option<(f32, f32)>,
+}
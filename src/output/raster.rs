@@ -0,0 +1,136 @@
+// src/output/raster.rs
+//
+// このファイルでは、World の現在の状態を RGBA のピクセル列（1 コマ分の画像）として描く関数を定義します。
+// PNG や GIF などの画像形式への変換は、この結果を使って呼び出し側で行います。
+
+use crate::components::{Ball, BallId, BallKind, Color, Peg, Pocket, Position, Render, Table};
+use crate::config::TableShape;
+use specs::prelude::*;
+
+/// テーブルの外側の背景の色です。
+const BACKGROUND_COLOR: Color = Color::rgb(0x3e, 0x27, 0x23);
+/// テーブルの面の色です。
+const CLOTH_COLOR: Color = Color::rgb(0x0b, 0x66, 0x23);
+/// ポケットの色です。
+const POCKET_COLOR: Color = Color::rgb(0x00, 0x00, 0x00);
+/// ペグの色です。
+const PEG_COLOR: Color = Color::rgb(0xc0, 0xc0, 0xc0);
+
+/// render_raster() が描いた 1 コマ分の画像です。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RasterFrame {
+    /// 幅（ピクセル）
+    pub width: u32,
+    /// 高さ（ピクセル）
+    pub height: u32,
+    /// 左上から行ごとに並べた、1 ピクセルあたり 4 バイト（R, G, B, A）のピクセル列
+    pub rgba: Vec<u8>,
+}
+
+impl RasterFrame {
+    /// すべてのピクセルを `color` で塗りつぶした画像を生成します。
+    fn filled(width: u32, height: u32, color: Color) -> RasterFrame {
+        let rgba = [color.r, color.g, color.b, 0xff].repeat((width * height) as usize);
+        RasterFrame {
+            width,
+            height,
+            rgba,
+        }
+    }
+
+    /// 中心 (cx, cy)、半径 `radius`（いずれもピクセル、y 軸は下向き）の円を `color` で塗ります。
+    /// 中心がその円の内側にあるピクセルを塗り、画像の外側にはみ出す部分は描きません。
+    fn fill_circle(&mut self, cx: f32, cy: f32, radius: f32, color: Color) {
+        let x_min = (cx - radius).floor().max(0.0) as u32;
+        let y_min = (cy - radius).floor().max(0.0) as u32;
+        let x_max = ((cx + radius).ceil().max(0.0) as u32).min(self.width);
+        let y_max = ((cy + radius).ceil().max(0.0) as u32).min(self.height);
+        for y in y_min..y_max {
+            for x in x_min..x_max {
+                let dx = x as f32 + 0.5 - cx;
+                let dy = y as f32 + 0.5 - cy;
+                if dx * dx + dy * dy <= radius * radius {
+                    let i = ((y * self.width + x) * 4) as usize;
+                    self.rgba[i..i + 4].copy_from_slice(&[color.r, color.g, color.b, 0xff]);
+                }
+            }
+        }
+    }
+}
+
+/// World の現在の状態（テーブル、ポケット、ペグ、ボール）を RGBA の画像として描きます。
+///
+/// 画像の大きさはテーブルの寸法（cm）に `pixels_per_cm` を掛けたもの（最小 1 ピクセル）です。
+/// render_svg() と同じく y 軸を反転し、ボールは BallId の昇順に描きます。
+///
+/// # 引数
+/// - `world`: 描く World
+/// - `pixels_per_cm`: 1 cm あたりのピクセル数
+///
+/// # 戻り値
+/// 描いた画像
+pub fn render_raster(world: &World, pixels_per_cm: f32) -> RasterFrame {
+    let table = world.read_storage::<Table>();
+    let pos = world.read_storage::<Position>();
+    let ball = world.read_storage::<Ball>();
+    let pocket = world.read_storage::<Pocket>();
+    let peg = world.read_storage::<Peg>();
+    let id = world.read_storage::<BallId>();
+    let kind = world.read_storage::<BallKind>();
+    let color = world.read_storage::<Color>();
+    let render = world.read_storage::<Render>();
+
+    let table = (&table).join().next().copied();
+    let (width, height) = table.map_or((0.0, 0.0), |t| (t.width, t.height));
+    let mut frame = RasterFrame::filled(
+        ((width * pixels_per_cm).round() as u32).max(1),
+        ((height * pixels_per_cm).round() as u32).max(1),
+        BACKGROUND_COLOR,
+    );
+    // 内部座標（cm、y 軸が上向き）をピクセルの座標（y 軸が下向き）に変換します。
+    let to_pixel = |p: &Position| (p.x * pixels_per_cm, (height - p.y) * pixels_per_cm);
+
+    if let Some(table) = table {
+        match table.shape {
+            TableShape::Rectangle => {
+                frame = RasterFrame::filled(frame.width, frame.height, CLOTH_COLOR);
+            }
+            TableShape::Circle { radius } => {
+                let center = Position {
+                    x: width / 2.0,
+                    y: height / 2.0,
+                };
+                let (cx, cy) = to_pixel(&center);
+                frame.fill_circle(cx, cy, radius * pixels_per_cm, CLOTH_COLOR);
+            }
+        }
+    }
+    for (p, pocket) in (&pos, &pocket).join() {
+        let (cx, cy) = to_pixel(p);
+        frame.fill_circle(cx, cy, pocket.radius * pixels_per_cm, POCKET_COLOR);
+    }
+    for (p, peg) in (&pos, &peg).join() {
+        let (cx, cy) = to_pixel(p);
+        frame.fill_circle(cx, cy, peg.radius * pixels_per_cm, PEG_COLOR);
+    }
+    // ボールは BallId の昇順に描き、重なった場合は BallId の大きいボールが上になるようにします。
+    let mut balls: Vec<_> = (
+        &pos,
+        &ball,
+        &id,
+        kind.maybe(),
+        color.maybe(),
+        render.maybe(),
+    )
+        .join()
+        .collect();
+    balls.sort_by_key(|(_, _, id, _, _, _)| **id);
+    for (p, b, id, k, c, r) in balls {
+        let k = k.copied().unwrap_or(BallKind::Object);
+        let c = c.copied().unwrap_or_else(|| Color::default_for(k, *id));
+        let scale = r.map_or(1.0, |r| r.scale);
+        let (cx, cy) = to_pixel(p);
+        frame.fill_circle(cx, cy, b.radius * scale * pixels_per_cm, c);
+    }
+    frame
+}
@@ -0,0 +1,92 @@
+// src/output/resample.rs
+//
+// このファイルでは、記録したスナップショットの列を一定の時間間隔に並べ直す関数を定義します。
+
+use crate::output::{BallSnapshot, WorldSnapshot};
+use std::time::Duration;
+
+/// 記録したスナップショットの列を、最初のスナップショットの時刻から `interval` ごとの時刻に並べ直した列を返します。
+///
+/// 各時刻の状態は、SimClock の経過時間（`elapsed`）でその時刻を挟む 2 つのスナップショットから求め、
+/// 両方にあるボールの位置・速度・描画の倍率は線形に補間します。
+/// ポケットに入るなどして後のスナップショットにないボールは、後のスナップショットの時刻の直前まで
+/// 前のスナップショットの状態のまま残し、後のスナップショットで現れたボールはその時刻から含めます。
+/// 並べ直したスナップショットの `step` は前のスナップショットのものとし、`collisions` は空にします。
+///
+/// # 引数
+/// - `snapshots`: 経過時間の順に並んだスナップショットの列
+/// - `interval`: 並べ直す時間の間隔
+///
+/// # 戻り値
+/// 最後のスナップショットの時刻までの、時間間隔の揃ったスナップショットの列。
+/// `snapshots` が空の場合や `interval` が 0 の場合は空の Vec を返します。
+pub fn resample_trajectory(snapshots: &[WorldSnapshot], interval: Duration) -> Vec<WorldSnapshot> {
+    let (Some(first), Some(last)) = (snapshots.first(), snapshots.last()) else {
+        return Vec::new();
+    };
+    if interval.is_zero() {
+        return Vec::new();
+    }
+    let mut resampled = Vec::new();
+    // 時刻を挟む 2 つのスナップショットのうち、前のものの位置。時刻は単調に増えるため先頭から進めるだけで済みます。
+    let mut segment = 0;
+    let mut time = first.elapsed;
+    while time <= last.elapsed {
+        while segment + 1 < snapshots.len() && snapshots[segment + 1].elapsed <= time {
+            segment += 1;
+        }
+        resampled.push(match snapshots.get(segment + 1) {
+            Some(next) => interpolate(&snapshots[segment], next, time),
+            None => WorldSnapshot {
+                elapsed: time,
+                collisions: Vec::new(),
+                ..snapshots[segment].clone()
+            },
+        });
+        time += interval;
+    }
+    resampled
+}
+
+/// `before` と `after` の間の時刻 `time` の状態を、線形補間で求めます。
+///
+/// # 引数
+/// - `before`: 前のスナップショット（`before.elapsed <= time`）
+/// - `after`: 後のスナップショット（`time < after.elapsed`）
+/// - `time`: 求める時刻
+fn interpolate(before: &WorldSnapshot, after: &WorldSnapshot, time: Duration) -> WorldSnapshot {
+    let span = after.elapsed.saturating_sub(before.elapsed).as_secs_f32();
+    let t = if span > 0.0 {
+        time.saturating_sub(before.elapsed).as_secs_f32() / span
+    } else {
+        0.0
+    };
+    let lerp = |a: f32, b: f32| a + (b - a) * t;
+    let balls = before
+        .balls
+        .iter()
+        .map(|b| {
+            // スナップショットの balls は BallId の昇順に並んでいます。
+            match after.balls.binary_search_by_key(&b.id, |a| a.id) {
+                Ok(index) => {
+                    let a = &after.balls[index];
+                    BallSnapshot {
+                        x: lerp(b.x, a.x),
+                        y: lerp(b.y, a.y),
+                        vx: lerp(b.vx, a.vx),
+                        vy: lerp(b.vy, a.vy),
+                        scale: lerp(b.scale, a.scale),
+                        ..*b
+                    }
+                }
+                Err(_) => *b,
+            }
+        })
+        .collect();
+    WorldSnapshot {
+        step: before.step,
+        elapsed: time,
+        balls,
+        collisions: Vec::new(),
+    }
+}
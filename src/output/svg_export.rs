@@ -0,0 +1,167 @@
+// src/output/svg_export.rs
+//
+// このファイルでは、World の現在の状態を SVG 形式の文字列として書き出す関数を定義します。
+
+use crate::components::{
+    Ball, BallId, BallKind, Color, CushionSegment, Peg, Pocket, Position, Render, Table,
+};
+use crate::config::TableShape;
+use crate::output::Overlay;
+use specs::prelude::*;
+use std::fmt::Write;
+
+/// テーブルの面の色です。
+const CLOTH_COLOR: &str = "#0b6623";
+/// ポケットの色です。
+const POCKET_COLOR: &str = "#000000";
+/// ペグの色です。
+const PEG_COLOR: &str = "#c0c0c0";
+/// 線分のクッションの色です。
+const CUSHION_SEGMENT_COLOR: &str = "#5c3a1e";
+/// 線分のクッションを描く線の太さ（cm）です。
+const CUSHION_SEGMENT_WIDTH: f32 = 1.0;
+/// エイムラインとゴーストボールの色です。
+const OVERLAY_COLOR: &str = "#ffffff";
+
+/// World の現在の状態（テーブル、ポケット、ペグ、線分のクッション、ボール）を SVG 形式の文字列に変換します。
+///
+/// 内部座標は左下の隅を原点とし y 軸が上向きのため、SVG の座標（y 軸が下向き）に反転して描きます。
+/// `overlay` を指定した場合は、ボールの上にエイムラインとゴーストボールを重ねて描きます。
+///
+/// # 引数
+/// - `world`: 書き出す World
+/// - `overlay`: ボールの上に重ねて描く補助表示（None の場合は描かない）
+///
+/// # 戻り値
+/// SVG 文書全体を表す文字列
+pub fn render_svg(world: &World, overlay: Option<&Overlay>) -> String {
+    let table = world.read_storage::<Table>();
+    let pos = world.read_storage::<Position>();
+    let ball = world.read_storage::<Ball>();
+    let pocket = world.read_storage::<Pocket>();
+    let peg = world.read_storage::<Peg>();
+    let segment = world.read_storage::<CushionSegment>();
+    let id = world.read_storage::<BallId>();
+    let kind = world.read_storage::<BallKind>();
+    let color = world.read_storage::<Color>();
+    let render = world.read_storage::<Render>();
+
+    let table = (&table).join().next().copied();
+    let (width, height) = table.map_or((0.0, 0.0), |t| (t.width, t.height));
+    // 内部座標の y を SVG の座標に変換します。
+    let flip = |y: f32| height - y;
+
+    // String への書き込みは失敗しないため、write! の結果は無視します。
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+    );
+    if let Some(table) = table {
+        match table.shape {
+            TableShape::Rectangle => {
+                let _ = writeln!(
+                    svg,
+                    r#"  <rect x="0" y="0" width="{width}" height="{height}" fill="{CLOTH_COLOR}"/>"#
+                );
+            }
+            TableShape::Circle { radius } => {
+                let _ = writeln!(
+                    svg,
+                    r#"  <circle cx="{}" cy="{}" r="{radius}" fill="{CLOTH_COLOR}"/>"#,
+                    width / 2.0,
+                    height / 2.0
+                );
+            }
+        }
+    }
+    for (p, pocket) in (&pos, &pocket).join() {
+        let _ = writeln!(
+            svg,
+            r#"  <circle cx="{}" cy="{}" r="{}" fill="{POCKET_COLOR}"/>"#,
+            p.x,
+            flip(p.y),
+            pocket.radius
+        );
+    }
+    for (p, peg) in (&pos, &peg).join() {
+        let _ = writeln!(
+            svg,
+            r#"  <circle cx="{}" cy="{}" r="{}" fill="{PEG_COLOR}"/>"#,
+            p.x,
+            flip(p.y),
+            peg.radius
+        );
+    }
+    for segment in (&segment).join() {
+        let _ = writeln!(
+            svg,
+            r#"  <line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{CUSHION_SEGMENT_COLOR}" stroke-width="{CUSHION_SEGMENT_WIDTH}" stroke-linecap="round"/>"#,
+            segment.start.x,
+            flip(segment.start.y),
+            segment.end.x,
+            flip(segment.end.y)
+        );
+    }
+    // ボールは BallId の昇順に描き、重なった場合は BallId の大きいボールが上になるようにします。
+    let mut balls: Vec<_> = (
+        &pos,
+        &ball,
+        &id,
+        kind.maybe(),
+        color.maybe(),
+        render.maybe(),
+    )
+        .join()
+        .collect();
+    balls.sort_by_key(|(_, _, id, _, _, _)| **id);
+    let mut cue = None;
+    for (p, b, id, k, c, r) in balls {
+        let k = k.copied().unwrap_or(BallKind::Object);
+        let c = c.copied().unwrap_or_else(|| Color::default_for(k, *id));
+        let scale = r.map_or(1.0, |r| r.scale);
+        if k == BallKind::Cue {
+            cue = Some((*p, b.radius));
+        }
+        let _ = writeln!(
+            svg,
+            r##"  <circle cx="{}" cy="{}" r="{}" fill="#{:02x}{:02x}{:02x}"/>"##,
+            p.x,
+            flip(p.y),
+            b.radius * scale,
+            c.r,
+            c.g,
+            c.b
+        );
+    }
+    // 補助表示は手球がテーブル上にある場合のみ描きます。
+    if let (Some(overlay), Some((cue_pos, cue_radius))) = (overlay, cue) {
+        // 接触が予測されない場合は、テーブルの対角線の長さだけ延ばし、表示範囲で切り取らせます。
+        let (end_x, end_y) = overlay.contact.unwrap_or_else(|| {
+            let reach = (width * width + height * height).sqrt();
+            (
+                cue_pos.x + reach * overlay.aim.cos(),
+                cue_pos.y + reach * overlay.aim.sin(),
+            )
+        });
+        let _ = writeln!(
+            svg,
+            r#"  <line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{OVERLAY_COLOR}" stroke-width="0.3"/>"#,
+            cue_pos.x,
+            flip(cue_pos.y),
+            end_x,
+            flip(end_y)
+        );
+        if let Some((ghost_x, ghost_y)) = overlay.contact {
+            let _ = writeln!(
+                svg,
+                r#"  <circle cx="{}" cy="{}" r="{}" fill="none" stroke="{OVERLAY_COLOR}" stroke-width="0.3" stroke-dasharray="1 1"/>"#,
+                ghost_x,
+                flip(ghost_y),
+                cue_radius
+            );
+        }
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
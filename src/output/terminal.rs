@@ -0,0 +1,65 @@
+// src/output/terminal.rs
+//
+// このファイルでは、テーブルとボールの配置を端末に表示するための ASCII の文字列として書き出す関数を定義します。
+
+use crate::components::BallId;
+
+/// 1 つの文字のマスに複数のボールが入った場合に表示する文字です。
+const OVERLAP_CHAR: char = '*';
+/// ボールのないマスに表示する文字です。
+const EMPTY_CHAR: char = ' ';
+
+/// テーブルとボールの配置を、横 `columns` 文字 × 縦 `rows` 文字の ASCII のグリッドに変換します。
+///
+/// テーブルの範囲を文字のマスに等分し、各ボールをその中心が入るマスに BallId の 1 の位の数字で表示します。
+/// 同じマスに複数のボールが入った場合は `*` を表示します。
+/// 内部座標は y 軸が上向きのため、上の行ほど y が大きくなるよう反転して描き、グリッドの周りを
+/// `+`、`-`、`|` の枠で囲みます。テーブルの外にあるボールは、最も近い端のマスに表示します。
+///
+/// # 引数
+/// - `table_size`: テーブルの横幅と高さ（cm）
+/// - `balls`: 表示するボールの BallId と中心の位置 (x, y)（cm）
+/// - `columns`: グリッドの横の文字数（0 の場合は 1 として扱います）
+/// - `rows`: グリッドの縦の文字数（0 の場合は 1 として扱います）
+///
+/// # 戻り値
+/// 枠を含めた各行を改行で区切った文字列（最後の行も改行で終わります）
+pub fn render_ascii(
+    table_size: (f32, f32),
+    balls: &[(BallId, f32, f32)],
+    columns: usize,
+    rows: usize,
+) -> String {
+    let columns = columns.max(1);
+    let rows = rows.max(1);
+    let (width, height) = table_size;
+    let mut grid = vec![vec![EMPTY_CHAR; columns]; rows];
+    // 座標を 0 以上 count 未満のマスの番号に変換します。テーブルの寸法が 0 の場合や NaN の場合は 0 とします。
+    let cell = |value: f32, size: f32, count: usize| {
+        let index = (value / size * count as f32).floor();
+        if index.is_nan() || index < 0.0 {
+            0
+        } else {
+            (index as usize).min(count - 1)
+        }
+    };
+    for &(id, x, y) in balls {
+        let column = cell(x, width, columns);
+        let row = rows - 1 - cell(y, height, rows);
+        let c = &mut grid[row][column];
+        *c = if *c == EMPTY_CHAR {
+            char::from_digit(id.0 % 10, 10).unwrap_or(OVERLAP_CHAR)
+        } else {
+            OVERLAP_CHAR
+        };
+    }
+    let border = format!("+{}+\n", "-".repeat(columns));
+    let mut text = border.clone();
+    for line in grid {
+        text.push('|');
+        text.extend(line);
+        text.push_str("|\n");
+    }
+    text.push_str(&border);
+    text
+}
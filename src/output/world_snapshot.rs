@@ -0,0 +1,42 @@
+// src/output/world_snapshot.rs
+//
+// このファイルでは、ある時点の World の状態を表すスナップショットの構造体を定義します。
+
+use crate::components::{BallId, BallKind};
+use crate::events::BallCollision;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// ある時点の各ボールの状態を表す構造体です。
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BallSnapshot {
+    /// ボールの識別番号
+    pub id: BallId,
+    /// ボールの種類（手球か的球か）
+    pub kind: BallKind,
+    /// 位置 x（cm）
+    pub x: f32,
+    /// 位置 y（cm）
+    pub y: f32,
+    /// 速度 x（cm/s）
+    pub vx: f32,
+    /// 速度 y（cm/s）
+    pub vy: f32,
+    /// 描画時の半径の倍率（ポケットへの落下中は 1.0 から 0 に向かって小さくなります）
+    pub scale: f32,
+}
+
+/// あるステップ終了時点の World の状態を表す構造体です。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorldSnapshot {
+    /// ステップ番号（0 始まり）
+    pub step: usize,
+    /// シミュレーション上の経過時間
+    pub elapsed: Duration,
+    /// 各ボールの状態（BallId の昇順）
+    pub balls: Vec<BallSnapshot>,
+    /// このステップで解決されたボール同士の衝突（解決順）。
+    /// 出力先が別のストリームとして書き出すため、スナップショットの JSON には含めません。
+    #[serde(skip)]
+    pub collisions: Vec<BallCollision>,
+}
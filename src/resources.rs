@@ -0,0 +1,46 @@
+pub mod pocket_rules;
+pub use pocket_rules::PocketRules;
+pub mod friction;
+pub use friction::Friction;
+pub mod sim_clock;
+pub use sim_clock::SimClock;
+pub mod collision_settings;
+pub use collision_settings::CollisionSettings;
+pub mod wall_impacts;
+pub use wall_impacts::{Wall, WallImpacts};
+pub mod cushion_wear;
+pub use cushion_wear::CushionWear;
+pub mod table_defects;
+pub use table_defects::TableDefects;
+pub mod ball_registry;
+pub use ball_registry::BallRegistry;
+pub mod stats;
+pub use stats::Stats;
+pub mod finite_guard;
+pub use finite_guard::FiniteGuard;
+pub mod restitution_override;
+pub use restitution_override::RestitutionOverride;
+pub mod collision_events;
+pub use collision_events::CollisionEvents;
+pub mod slip_friction;
+pub use slip_friction::SlipFriction;
+pub mod sleep_settings;
+pub use sleep_settings::SleepSettings;
+pub mod constraints;
+pub use constraints::{Constraint, Constraints};
+pub mod sim_rng;
+pub use sim_rng::SimRng;
+pub mod collision_rules;
+pub use collision_rules::CollisionRules;
+pub mod gravity;
+pub use gravity::Gravity;
+pub mod contact_manifold;
+pub use contact_manifold::ContactManifold;
+pub mod low_speed_damping;
+pub use low_speed_damping::LowSpeedDamping;
+pub mod pocket_events;
+pub use pocket_events::PocketEvents;
+pub mod speed_limit;
+pub use speed_limit::SpeedLimit;
+pub mod friction_regions;
+pub use friction_regions::{FrictionRegion, FrictionRegions};
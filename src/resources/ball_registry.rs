@@ -0,0 +1,39 @@
+// src/resources/ball_registry.rs
+//
+// このファイルでは、BallId からエンティティを引くための対応表（リソース）を定義します。
+
+use crate::components::BallId;
+use specs::Entity;
+use std::collections::HashMap;
+
+/// BallId とエンティティの対応表を保持するリソースです。
+///
+/// ボールの生成時に登録し、ポケットなどでボールが削除されたときに取り除きます。
+#[derive(Debug, Default)]
+pub struct BallRegistry {
+    entities: HashMap<BallId, Entity>,
+}
+
+impl BallRegistry {
+    /// `capacity` 個のボールを登録できる領域をあらかじめ確保した対応表を作成します。
+    pub fn with_capacity(capacity: usize) -> BallRegistry {
+        BallRegistry {
+            entities: HashMap::with_capacity(capacity),
+        }
+    }
+
+    /// BallId とエンティティの対応を登録します。
+    pub fn insert(&mut self, id: BallId, entity: Entity) {
+        self.entities.insert(id, entity);
+    }
+
+    /// BallId の登録を取り除きます。
+    pub fn remove(&mut self, id: BallId) {
+        self.entities.remove(&id);
+    }
+
+    /// BallId に対応するエンティティを返します。登録されていない場合は None を返します。
+    pub fn get(&self, id: BallId) -> Option<Entity> {
+        self.entities.get(&id).copied()
+    }
+}
@@ -0,0 +1,23 @@
+// src/resources/collision_events.rs
+//
+// このファイルでは、各ステップで解決されたボール同士の衝突を記録するリソースを定義します。
+
+use crate::events::BallCollision;
+
+/// 現在のステップで解決されたボール同士の衝突の一覧を保持するリソースです。
+///
+/// CollisionSystem または EventDrivenSystem が、ステップの処理の先頭で空にしてから記録します。
+#[derive(Debug, Default, Clone)]
+pub struct CollisionEvents {
+    /// 解決された順に並んだ衝突の記録
+    pub pairs: Vec<BallCollision>,
+}
+
+impl CollisionEvents {
+    /// `capacity` 件の衝突を記録できる領域をあらかじめ確保した一覧を作成します。
+    pub fn with_capacity(capacity: usize) -> CollisionEvents {
+        CollisionEvents {
+            pairs: Vec::with_capacity(capacity),
+        }
+    }
+}
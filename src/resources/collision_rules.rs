@@ -0,0 +1,32 @@
+// src/resources/collision_rules.rs
+//
+// このファイルでは、ボールの種類の組み合わせごとに衝突させるかどうかを決めるリソースを定義します。
+
+use crate::components::BallKind;
+
+/// ボールの種類 (BallKind) の組み合わせごとに、ボール同士の衝突を判定するかどうかを保持するリソースです。
+///
+/// 規則に含まれない組み合わせは衝突します。同じ組み合わせに複数の規則がある場合は、後のものが優先されます。
+/// この規則は CollisionSystem、PhysicsSystem のばねモデル、EventDrivenSystem のいずれでも参照されます。
+#[derive(Debug, Default, Clone)]
+pub struct CollisionRules {
+    /// 種類の組み合わせと、その組み合わせで衝突させるかどうか（順序は問いません）
+    pub rules: Vec<((BallKind, BallKind), bool)>,
+}
+
+impl CollisionRules {
+    /// 2 つの種類のボール同士の衝突を判定するかどうかを返します。
+    ///
+    /// # 引数
+    /// * `a`, `b` - 2 つのボールの種類
+    ///
+    /// # 戻り値
+    /// 衝突を判定する場合は true
+    pub fn allows(&self, a: BallKind, b: BallKind) -> bool {
+        self.rules
+            .iter()
+            .rev()
+            .find(|((x, y), _)| (*x == a && *y == b) || (*x == b && *y == a))
+            .is_none_or(|(_, enabled)| *enabled)
+    }
+}
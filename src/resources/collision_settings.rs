@@ -0,0 +1,60 @@
+// src/resources/collision_settings.rs
+//
+// このファイルでは、衝突処理の挙動を調整するためのリソースを定義します。
+
+use crate::config::{CollisionPriority, CollisionSolver, ContactModel, RestitutionBlend};
+
+/// 衝突処理の調整用パラメータを保持するリソースです。
+#[derive(Debug, Clone, Copy)]
+pub struct CollisionSettings {
+    /// ボールの中心間距離がこの値（cm）未満の場合、ほぼ同一点にあるとみなします。
+    /// このとき法線は +x 方向に固定し、インパルスは与えずに位置の補正のみで引き離します。
+    pub epsilon: f32,
+    /// イベント駆動モードで、1 つのボールがシミュレーション時間 1 秒あたりに
+    /// 関与できる衝突イベント数の上限です。これを超えたボールは静止しているものとして凍結し、
+    /// 角に挟まったボールが時間の進まない衝突を無限に繰り返すのを防ぎます。
+    pub max_events_per_second: f32,
+    /// 壁で跳ね返るときに、壁に沿った（接線方向の）速度に掛ける係数です。
+    /// 1.0 で接線方向の速度は変化せず、小さいほどクッションとの摩擦で失われる速度が大きくなります。
+    pub tangential_damping: f32,
+    /// 同じステップで壁とボールの両方に接したときに、どちらとの衝突を先に処理するかを表します。
+    pub priority: CollisionPriority,
+    /// 反発係数の異なるボール同士の衝突に用いる反発係数の決め方です。
+    pub restitution_blend: RestitutionBlend,
+    /// インパルスを与えた後に残ったボール同士のめり込みを、位置の補正で解消する反復の上限回数です。
+    /// 回数が多いほど密集した場面でのめり込みが小さくなり、計算量は増えます。
+    /// 既定値は 0 で補正を行わず、これまでに記録した実行結果と同じ結果になります。
+    pub solver_iterations: usize,
+    /// ボール同士の衝突のインパルスを処理する方法です。Colored の場合も、結果は Serial と同じになります。
+    pub solver: CollisionSolver,
+    /// ボール同士の接点に働く摩擦係数です。0 より大きい場合、衝突のたびに接点の滑りと逆向きの
+    /// 接線方向の力積（法線方向の力積 × この係数が上限）を与え、速度と鉛直軸まわりの回転をやり取りします。
+    pub ball_friction: f32,
+    /// ボールとクッションの接点に働く摩擦係数です。0 より大きい場合、壁で跳ね返るたびに接点の滑りと逆向きの
+    /// 力積（法線方向の力積 × この係数が上限）を与え、壁に沿った速度と回転をやり取りします。
+    /// 既定値は 0 で、摩擦は働きません（壁に沿った速度の変化は `tangential_damping` のみで決まります）。
+    pub cushion_friction: f32,
+    /// ボール同士の接触の扱い方です。Spring の場合、CollisionSystem はボール同士のインパルスと
+    /// めり込みの補正を行わず、PhysicsSystem がばねの力で押し戻します。
+    pub contact_model: ContactModel,
+    /// contact_model が Spring の場合に、PhysicsSystem が 1 ステップを分割して計算する回数です。
+    pub contact_substeps: usize,
+}
+
+impl Default for CollisionSettings {
+    fn default() -> Self {
+        CollisionSettings {
+            epsilon: 1.0e-3,
+            max_events_per_second: 1000.0,
+            tangential_damping: 1.0,
+            priority: CollisionPriority::default(),
+            restitution_blend: RestitutionBlend::default(),
+            solver_iterations: 0,
+            solver: CollisionSolver::default(),
+            ball_friction: 0.0,
+            cushion_friction: 0.0,
+            contact_model: ContactModel::default(),
+            contact_substeps: 10,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+// src/resources/constraints.rs
+//
+// このファイルでは、2 つのボールを伸び縮みするひもでつなぐ距離の拘束と、その一覧を表すリソースを定義します。
+
+use specs::Entity;
+
+/// 2 つのボールの中心間距離を `rest_length` に保とうとする、ばねによる拘束です。
+///
+/// 中心間距離が `rest_length` からずれると、ずれの大きさに `stiffness` を掛けた力が
+/// 2 つのボールを結ぶ向きに働き、互いを引き寄せ（または押し離し）ます。
+#[derive(Debug, Clone, Copy)]
+pub struct Constraint {
+    /// つながれたボールの一方
+    pub a: Entity,
+    /// つながれたボールのもう一方
+    pub b: Entity,
+    /// 力が働かない中心間距離（cm）
+    pub rest_length: f32,
+    /// ばね定数（距離のずれ 1 cm あたりの力。質量の単位 × cm/s² を単位とします）
+    pub stiffness: f32,
+}
+
+/// ConstraintSystem が毎ステップ解決する、距離の拘束の一覧を保持するリソースです。
+/// 既定値は空で、拘束は働きません。
+#[derive(Debug, Default, Clone)]
+pub struct Constraints {
+    pub list: Vec<Constraint>,
+}
@@ -0,0 +1,14 @@
+// src/resources/contact_manifold.rs
+//
+// このファイルでは、各ステップの終了時点で接触しているボールのペアを記録するリソースを定義します。
+
+use crate::events::Contact;
+
+/// 現在のステップの終了時点で接触しているボールのペアの一覧を保持するリソースです。
+///
+/// CollisionSystem が、衝突処理の後に空にしてから記録します。イベント駆動モードでは記録されません。
+#[derive(Debug, Default, Clone)]
+pub struct ContactManifold {
+    /// (BallId A, BallId B) の昇順に並んだ接触情報
+    pub contacts: Vec<Contact>,
+}
@@ -0,0 +1,34 @@
+// src/resources/cushion_wear.rs
+//
+// このファイルでは、衝突回数に応じてクッションの反発係数を変化させるモデルのリソースを定義します。
+
+/// クッションが温まる（使い込まれる）ことによる反発係数の変化を表すリソースです。
+///
+/// 有効な場合、各壁の実効的な反発係数は
+/// `ボールの反発係数 + rate × その壁の衝突回数` を `[min, max]` の範囲に収めた値になります。
+/// 既定では無効で、ボールの反発係数がそのまま使われます。
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CushionWear {
+    /// モデルを有効にするかどうか
+    pub enabled: bool,
+    /// 衝突 1 回あたりの反発係数の変化量
+    pub rate: f32,
+    /// 実効的な反発係数の下限
+    pub min: f32,
+    /// 実効的な反発係数の上限
+    pub max: f32,
+}
+
+impl CushionWear {
+    /// 壁の衝突回数から、実効的な反発係数を計算します。
+    ///
+    /// # 引数
+    /// - `base`: ボールの反発係数
+    /// - `impacts`: その壁のこれまでの衝突回数
+    pub fn effective_restitution(&self, base: f32, impacts: u32) -> f32 {
+        if !self.enabled {
+            return base;
+        }
+        (base + self.rate * impacts as f32).clamp(self.min, self.max)
+    }
+}
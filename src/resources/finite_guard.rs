@@ -0,0 +1,14 @@
+// src/resources/finite_guard.rs
+//
+// このファイルでは、位置・速度の NaN や無限大を検出したときの扱いを保持するリソースを定義します。
+
+use crate::config::NonFinitePolicy;
+
+/// NaN や無限大の検出方針と、実行を中断すべきかどうかを保持するリソースです。
+#[derive(Debug, Default)]
+pub struct FiniteGuard {
+    /// 検出したときの扱い
+    pub policy: NonFinitePolicy,
+    /// Abort の方針で不正な値を検出した場合に true になります。
+    pub aborted: bool,
+}
@@ -0,0 +1,18 @@
+// src/resources/friction.rs
+//
+// このファイルでは、ラシャ（台のクロス）による摩擦を表すリソースを定義します。
+
+use serde::{Deserialize, Serialize};
+
+/// ボールに働く摩擦による減速度（cm/s^2）を軸ごとに保持するリソースです。
+///
+/// x と y に同じ値を設定すると通常の（等方的な）摩擦になり、
+/// 異なる値を設定するとラシャの毛並みによる異方性を表現できます。
+/// 既定値は (0, 0) で、摩擦は働きません。
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct Friction {
+    /// x 軸方向の減速度（cm/s^2）
+    pub x: f32,
+    /// y 軸方向の減速度（cm/s^2）
+    pub y: f32,
+}
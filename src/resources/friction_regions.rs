@@ -0,0 +1,41 @@
+// src/resources/friction_regions.rs
+//
+// このファイルでは、ラシャの一部だけ摩擦が異なる円形の領域（傷んだ箇所など）を表すリソースを定義します。
+
+/// 摩擦が異なる円形の領域 1 つを表す構造体です。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrictionRegion {
+    /// 中心の x 座標（隅を原点とした内部座標、cm）
+    pub x: f32,
+    /// 中心の y 座標（隅を原点とした内部座標、cm）
+    pub y: f32,
+    /// 半径（cm）
+    pub radius: f32,
+    /// 領域内で摩擦に掛ける倍率
+    pub multiplier: f32,
+}
+
+/// 摩擦が異なる領域の一覧を保持するリソースです。
+///
+/// ボールの中心がいずれかの領域の内側にある間、そのボールに働く摩擦（Friction と SlipFriction）に
+/// 領域の倍率を掛けます。複数の領域に含まれる場合は、それぞれの倍率の積を掛けます。
+/// どの領域にも含まれないボールには、通常の摩擦がそのまま働きます。
+#[derive(Debug, Default, Clone)]
+pub struct FrictionRegions {
+    /// 領域の一覧
+    pub regions: Vec<FrictionRegion>,
+}
+
+impl FrictionRegions {
+    /// 指定した位置で摩擦に掛ける倍率を返します。どの領域にも含まれない場合は 1.0 です。
+    ///
+    /// # 引数
+    /// - `x`, `y`: 隅を原点とした内部座標（cm）
+    pub fn multiplier_at(&self, x: f32, y: f32) -> f32 {
+        self.regions
+            .iter()
+            .filter(|r| (x - r.x).powi(2) + (y - r.y).powi(2) < r.radius * r.radius)
+            .map(|r| r.multiplier)
+            .product()
+    }
+}
@@ -0,0 +1,17 @@
+// src/resources/gravity.rs
+//
+// このファイルでは、すべてのボールに一様に働く重力（台の傾きなど）を表すリソースを定義します。
+
+use serde::{Deserialize, Serialize};
+
+/// すべてのボールに働く一様な加速度（cm/s^2）を軸ごとに保持するリソースです。
+///
+/// 傾けた盤面を転がるパチンコのような場面を表すために使います。
+/// 既定値は (0, 0) で、重力は働きません。
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct Gravity {
+    /// x 軸方向の加速度（cm/s^2）
+    pub x: f32,
+    /// y 軸方向の加速度（cm/s^2）
+    pub y: f32,
+}
@@ -0,0 +1,15 @@
+// src/resources/low_speed_damping.rs
+//
+// このファイルでは、低速で転がるボールにだけ働く追加の減速を表すリソースを定義します。
+
+/// 速さが `threshold` 未満のボールにだけ、通常の摩擦に加えて働く減速度を保持するリソースです。
+///
+/// 止まる直前のボールがゆっくり転がり続ける時間を短くし、転がりから静止への移り変わりを表します。
+/// `threshold` 以上の速さでは何もしません。既定値は (0, 0) で、追加の減速は働きません。
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LowSpeedDamping {
+    /// 追加の減速が働き始める速さ（cm/s）
+    pub threshold: f32,
+    /// 追加の減速度（cm/s^2）
+    pub deceleration: f32,
+}
@@ -0,0 +1,13 @@
+// src/resources/pocket_events.rs
+//
+// このファイルでは、直前のステップでボールがポケットに入った記録を保持するリソースを定義します。
+
+use crate::events::PocketEvent;
+
+/// 直前のステップでポケットに入ったボールの記録を保持するリソースです。
+/// PocketSystem が毎ステップ内容を入れ替え、BallId の昇順に並べます。
+#[derive(Debug, Default, Clone)]
+pub struct PocketEvents {
+    /// ポケットに入った順（同じステップでは BallId の昇順）に並んだ記録
+    pub events: Vec<PocketEvent>,
+}
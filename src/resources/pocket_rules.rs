@@ -0,0 +1,14 @@
+// src/resources/pocket_rules.rs
+//
+// このファイルでは、ポケットされたボールの扱いを決めるリソースを定義します。
+
+/// ポケットされた的球の扱い（削除するか、フットスポットに戻すか）を保持するリソースです。
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PocketRules {
+    /// true の場合、的球を削除せずフットスポットに戻します。
+    pub respot: bool,
+    /// 的球を戻すフットスポットの位置（隅を原点とした内部座標）
+    pub foot_spot: (f32, f32),
+    /// 削除するボールをポケットへ落とす演出にかけるステップ数（0 の場合はすぐに削除します）
+    pub drop_steps: u32,
+}
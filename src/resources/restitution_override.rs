@@ -0,0 +1,31 @@
+// src/resources/restitution_override.rs
+//
+// このファイルでは、すべての衝突の反発係数を一括で上書きするためのリソースを定義します。
+
+use serde::{Deserialize, Serialize};
+
+/// 反発係数の一括上書きを表すリソースです。
+///
+/// `Force(1.0)` で完全弾性衝突、`Force(0.0)` で完全非弾性衝突となり、
+/// ボールごとの反発係数やクッションの変化によらず、ボール同士・壁のすべての衝突に適用されます。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum RestitutionOverride {
+    /// 上書きせず、ボールごとの反発係数を使います（既定値）。
+    #[default]
+    None,
+    /// すべての衝突の反発係数を指定した値に固定します。
+    Force(f32),
+}
+
+impl RestitutionOverride {
+    /// 上書きを適用した反発係数を返します。
+    ///
+    /// # 引数
+    /// - `restitution`: 本来の反発係数
+    pub fn apply(self, restitution: f32) -> f32 {
+        match self {
+            RestitutionOverride::None => restitution,
+            RestitutionOverride::Force(forced) => forced,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+// src/resources/sim_clock.rs
+//
+// このファイルでは、シミュレーション開始からの経過時間を保持するリソースを定義します。
+
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// シミュレーション上の経過時間と、開始したステップ数を保持するリソースです。
+/// ClockSystem により、各ステップの先頭で dt ずつ進められます。
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct SimClock {
+    /// シミュレーション開始からの経過時間
+    pub elapsed: Duration,
+    /// 開始したステップの数。各ステップの実行中は、そのステップの番号（0 始まり）に 1 を足した値になります。
+    #[serde(default)]
+    pub steps: usize,
+}
+
+impl SimClock {
+    /// 実行中のステップの番号（0 始まり）を返します。ClockSystem より後に実行されるシステムから使います。
+    pub fn current_step(&self) -> usize {
+        self.steps.saturating_sub(1)
+    }
+}
@@ -0,0 +1,44 @@
+// src/resources/sim_rng.rs
+//
+// このファイルでは、シミュレーション全体で共有する、シード付きの疑似乱数生成器を表すリソースを定義します。
+
+use serde::{Deserialize, Serialize};
+
+/// シミュレーション全体で共有する疑似乱数生成器です。
+///
+/// 同じシードからは常に同じ乱数列を生成するため、乱数を使う処理（ラックの揺らぎなど）も
+/// 設定ファイルの `seed` が同じであれば毎回同じ結果になります。
+/// アルゴリズムには、状態が 64 ビットの整数 1 つだけで済む SplitMix64 を用います。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimRng {
+    state: u64,
+}
+
+impl SimRng {
+    /// 指定したシードで初期化した乱数生成器を作成します。
+    pub fn new(seed: u64) -> SimRng {
+        SimRng { state: seed }
+    }
+
+    /// 次の 64 ビットの乱数を返します。
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// 区間 (0, 1] の一様乱数を返します。
+    pub fn next_unit(&mut self) -> f32 {
+        // 上位 24 ビットを使い、0 を含まないよう 1 を足してから割ります。
+        ((self.next_u64() >> 40) as f32 + 1.0) / (1u32 << 24) as f32
+    }
+
+    /// 平均 0、標準偏差 1 の正規分布に従う乱数の組を返します（Box-Muller 法）。
+    pub fn next_gaussian_pair(&mut self) -> (f32, f32) {
+        let r = (-2.0 * self.next_unit().ln()).sqrt();
+        let theta = std::f32::consts::TAU * self.next_unit();
+        (r * theta.cos(), r * theta.sin())
+    }
+}
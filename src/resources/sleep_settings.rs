@@ -0,0 +1,15 @@
+// src/resources/sleep_settings.rs
+//
+// このファイルでは、静止したボールを休止させる条件を表すリソースを定義します。
+
+/// ボールを休止（Sleeping）させる条件を保持するリソースです。
+///
+/// 速さが `threshold` 以下のステップが `steps` 回続いたボールを休止させます。
+/// 既定値は `steps` = 0 で、ボールは休止しません。
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SleepSettings {
+    /// 静止とみなす速さの上限（cm/s）
+    pub threshold: f32,
+    /// 休止までに必要な、静止が連続したステップ数（0 の場合は休止しない）
+    pub steps: u32,
+}
@@ -0,0 +1,19 @@
+// src/resources/slip_friction.rs
+//
+// このファイルでは、ボールが台の上を滑るときに働く滑り摩擦を表すリソースを定義します。
+
+/// 滑り摩擦の係数を保持するリソースです。
+///
+/// ボールの接点が台に対して滑っている間、接点の滑りと逆向きに `mu × m × g` の摩擦力が働き、
+/// 並進と回転の両方を変化させて、やがてボールを転がり状態にします。
+/// 既定値は 0 で、滑り摩擦は働かず、回転は並進と独立に保たれます。
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SlipFriction {
+    /// 滑り摩擦係数（無次元）
+    pub mu: f32,
+}
+
+impl SlipFriction {
+    /// 重力加速度（cm/s^2）
+    pub const GRAVITY: f32 = 981.0;
+}
@@ -0,0 +1,20 @@
+// src/resources/speed_limit.rs
+//
+// このファイルでは、衝突処理の後にすべてのボールに適用する速さの上限を表すリソースを定義します。
+
+/// 衝突処理で一時的に生じた極端な速さによって、ボールが他のボールや壁をすり抜けるのを防ぐための
+/// 速さの上限を保持するリソースです。SpeedLimitSystem が毎ステップ、上限を超えたボールの速度を
+/// 向きを保ったまま上限まで縮めます。既定値は無限大で、速さは制限されません。
+#[derive(Debug, Clone, Copy)]
+pub struct SpeedLimit {
+    /// 速さの上限（cm/s）
+    pub max_speed: f32,
+}
+
+impl Default for SpeedLimit {
+    fn default() -> Self {
+        SpeedLimit {
+            max_speed: f32::INFINITY,
+        }
+    }
+}
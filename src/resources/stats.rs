@@ -0,0 +1,24 @@
+// src/resources/stats.rs
+//
+// このファイルでは、シミュレーションの統計情報を保持するリソースを定義します。
+
+use crate::components::BallId;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// シミュレーションの統計情報を保持するリソースです。StatsSystem が毎ステップ更新します。
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Stats {
+    /// 集計したステップ数
+    pub steps: usize,
+    /// 各ボールの累積移動距離（cm）。BallId の昇順で並びます。
+    pub distance: BTreeMap<BallId, f32>,
+    /// 直前に集計したステップでの各ボールの位置 (x, y)。移動距離の計算に使います。
+    pub last_positions: BTreeMap<BallId, (f32, f32)>,
+    /// ボール同士の衝突回数の合計
+    #[serde(default)]
+    pub ball_collisions: usize,
+    /// ポケットに入ったボールの数。PocketSystem が更新します。
+    #[serde(default)]
+    pub pocketed: usize,
+}
@@ -0,0 +1,63 @@
+// src/resources/table_defects.rs
+//
+// このファイルでは、テーブルの個体差（クッションの反発係数とラシャの摩擦のばらつき）を表すリソースを定義します。
+
+use crate::config::{DefectDistribution, TableDefectsConfig};
+use crate::resources::{SimRng, Wall};
+
+/// テーブルの個体差を表すリソースです。
+///
+/// クッションの反発係数とラシャの摩擦に掛ける倍率を保持します。
+/// 既定ではすべての倍率が 1.0 で、個体差はありません。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TableDefects {
+    /// 壁ごと（Wall::index() の順）のクッションの反発係数に掛ける倍率
+    pub cushion_restitution: [f32; 4],
+    /// ラシャの摩擦（転がり摩擦と滑り摩擦）に掛ける倍率
+    pub cloth_friction: f32,
+}
+
+impl Default for TableDefects {
+    fn default() -> Self {
+        TableDefects {
+            cushion_restitution: [1.0; 4],
+            cloth_friction: 1.0,
+        }
+    }
+}
+
+impl TableDefects {
+    /// 個体差の乱数列を、共有の SimRng の乱数列と分けるために種に混ぜる値です。
+    const STREAM: u64 = 0x7461_626c_655f_6466;
+
+    /// 設定された分布から、乱数の種 `seed` を使って倍率を引きます。
+    ///
+    /// 乱数列は seed から SimRng とは別に作るため、個体差を有効にしてもラックの揺らぎなど
+    /// 他の乱数を使う処理の結果は変わりません。標準偏差が 0 の場合は、常に平均の値になります。
+    /// 引いた倍率が負になる場合は 0 に抑えます。
+    ///
+    /// # 引数
+    /// - `config`: 個体差の分布の設定
+    /// - `seed`: シミュレーションの乱数の種
+    pub fn draw(config: &TableDefectsConfig, seed: u64) -> TableDefects {
+        let mut rng = SimRng::new(seed ^ Self::STREAM);
+        let sample = |dist: &DefectDistribution, z: f32| {
+            (dist.mean.unwrap_or(1.0) + dist.std_dev * z).max(0.0)
+        };
+        let (z0, z1) = rng.next_gaussian_pair();
+        let (z2, z3) = rng.next_gaussian_pair();
+        let (z4, _) = rng.next_gaussian_pair();
+        TableDefects {
+            cushion_restitution: [z0, z1, z2, z3].map(|z| sample(&config.cushion_restitution, z)),
+            cloth_friction: sample(&config.cloth_friction, z4),
+        }
+    }
+
+    /// 指定した壁について、ボールの反発係数に倍率を掛けたクッションの反発係数を返します。
+    ///
+    /// 倍率によって反発係数が 1 を超える場合は 1 に抑えます
+    /// （allow_energized でもとの反発係数が 1 を超えている場合は、もとの値まで許します）。
+    pub fn cushion_restitution(&self, base: f32, wall: Wall) -> f32 {
+        (base * self.cushion_restitution[wall.index()]).min(base.max(1.0))
+    }
+}
@@ -0,0 +1,87 @@
+// src/resources/wall_impacts.rs
+//
+// このファイルでは、テーブルの各壁（クッション）を表す列挙型と、
+// 壁ごとの衝突回数を記録するリソースを定義します。
+
+use serde::{Deserialize, Serialize};
+
+/// テーブルの 4 つの壁（クッション）を表す列挙型です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Wall {
+    /// 左側の壁（x = 0）
+    Left,
+    /// 右側の壁（x = width）
+    Right,
+    /// 下側の壁（y = 0）
+    Bottom,
+    /// 上側の壁（y = height）
+    Top,
+}
+
+impl Wall {
+    /// すべての壁の一覧です。
+    pub const ALL: [Wall; 4] = [Wall::Left, Wall::Right, Wall::Bottom, Wall::Top];
+
+    /// 配列の添字として使う番号を返します。
+    pub fn index(self) -> usize {
+        match self {
+            Wall::Left => 0,
+            Wall::Right => 1,
+            Wall::Bottom => 2,
+            Wall::Top => 3,
+        }
+    }
+
+    /// 外向きの法線 `normal` に最も近い向きの壁を返します。
+    ///
+    /// 円形のテーブルのクッションでの衝突を、衝突回数や反発係数の個体差の記録のために
+    /// 4 つの壁のいずれかに振り分けるときに使います。x 成分と y 成分の大きさが等しい場合は左右の壁とします。
+    pub fn from_normal(normal: (f32, f32)) -> Wall {
+        let (nx, ny) = normal;
+        if nx.abs() >= ny.abs() {
+            if nx < 0.0 {
+                Wall::Left
+            } else {
+                Wall::Right
+            }
+        } else if ny < 0.0 {
+            Wall::Bottom
+        } else {
+            Wall::Top
+        }
+    }
+
+    /// テーブルの内側から見た、壁の外向きの法線（長さ 1）を返します。
+    pub fn normal(self) -> (f32, f32) {
+        match self {
+            Wall::Left => (-1.0, 0.0),
+            Wall::Right => (1.0, 0.0),
+            Wall::Bottom => (0.0, -1.0),
+            Wall::Top => (0.0, 1.0),
+        }
+    }
+}
+
+/// 壁ごとのボールの衝突回数を記録するリソースです。
+/// CollisionSystem が壁との衝突を処理するたびに加算します。
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct WallImpacts {
+    counts: [u32; 4],
+}
+
+impl WallImpacts {
+    /// 指定した壁の衝突回数を返します。
+    pub fn count(&self, wall: Wall) -> u32 {
+        self.counts[wall.index()]
+    }
+
+    /// すべての壁の衝突回数の合計を返します。
+    pub fn total(&self) -> u32 {
+        self.counts.iter().sum()
+    }
+
+    /// 指定した壁の衝突回数を 1 増やします。
+    pub fn record(&mut self, wall: Wall) {
+        self.counts[wall.index()] += 1;
+    }
+}
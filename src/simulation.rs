@@ -0,0 +1,1186 @@
+// src/simulation.rs
+//
+// このファイルでは、ECS の World とシステムディスパッチャをまとめて保持し、
+// シミュレーションを 1 ステップずつ進める Simulation を定義します。
+
+use crate::components::{
+    Ball, BallId, BallKind, CollisionMask, Pocketed, Pocketing, Position, Render, Spin, Table,
+    Velocity,
+};
+use crate::config::{Config, ConfigPatch, LogFilter, SteppingMode, CM_PER_M};
+use crate::events::{BallCollision, CollisionEvent, Contact};
+use crate::math::{next_event, MovingBall};
+use crate::output::{
+    render_raster, render_svg, BallSnapshot, OutputSink, Overlay, RasterFrame, WorldSnapshot,
+};
+use crate::resources::{
+    BallRegistry, CollisionEvents, FiniteGuard, PocketEvents, SimClock, Stats, WallImpacts,
+};
+use crate::{components, entities, resources, TimeDelta};
+use specs::prelude::*;
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::time::{Duration, Instant};
+
+pub mod ball_state;
+pub use ball_state::BallState;
+pub mod build_dispatcher;
+pub use build_dispatcher::{build_dispatcher, build_settle_dispatcher};
+pub mod checkpoint;
+pub use checkpoint::{BallCheckpoint, Checkpoint};
+mod cue_template;
+use cue_template::CueTemplate;
+pub mod determinism_check;
+pub use determinism_check::{check_determinism, DeterminismMismatch};
+pub mod frames;
+pub use frames::Frames;
+pub mod invariants;
+pub use invariants::InvariantTolerances;
+pub mod run_metrics;
+pub use run_metrics::RunMetrics;
+pub mod replay;
+pub use replay::{load_recording, verify_replay, ReplayDivergence};
+pub mod shot_outcome;
+pub use shot_outcome::ShotOutcome;
+pub mod shot_spin;
+pub use shot_spin::ShotSpin;
+pub mod state_hash;
+pub use state_hash::STATE_HASH_RESOLUTION;
+pub mod step_report;
+pub use step_report::StepReport;
+pub mod substepping;
+pub use substepping::{substep_count, Substepping};
+pub mod worker_pool;
+pub use worker_pool::WorkerPool;
+
+/// 設定で省略された場合の、ボールが静止しているとみなす速さの上限（cm/s）です。
+const DEFAULT_REST_THRESHOLD: f32 = 0.1;
+
+/// Simulation::moved_balls で、ボールが動いたとみなす位置の変化の下限（cm）です。
+/// 浮動小数点の丸めや、静止しているボールのわずかな位置の補正を動きとして数えないための値です。
+pub const MOVED_BALL_TOLERANCE: f32 = 0.01;
+
+/// 設定で省略された場合の、run_shots() で各ショットの後にボールが静止するのを待つ最大のステップ数です。
+const DEFAULT_SHOT_MAX_STEPS: usize = 10_000;
+
+/// settle() の間に、ボール同士のめり込みを位置の補正で解消する反復の下限回数です。
+/// `solver_iterations` を指定していない設定でも、重なって置かれたボールを引き離せるようにします。
+const SETTLE_SOLVER_ITERATIONS: usize = 8;
+
+/// Simulation::on_collision で登録する、ボール同士の衝突を受け取るコールバックです。
+pub type CollisionCallback = Box<dyn FnMut(&BallCollision)>;
+
+/// シミュレーション全体（World、ディスパッチャ、出力先）を保持する構造体です。
+pub struct Simulation {
+    /// ECS の World
+    world: World,
+    /// 各システムを実行するディスパッチャ
+    dispatcher: Dispatcher<'static, 'static>,
+    /// settle() で運動と衝突処理だけを実行するディスパッチャ
+    settle_dispatcher: Dispatcher<'static, 'static>,
+    /// ステップの自動分割を行う場合に dispatcher の代わりに使うディスパッチャ（None の場合は分割しない）
+    substepping: Option<Substepping>,
+    /// 各ステップの結果を受け取る出力先の一覧
+    sinks: Vec<Box<dyn OutputSink>>,
+    /// 実行済みのステップ数
+    step: usize,
+    /// デバッグビルドで各ステップ後に検査する不変条件の許容誤差（None の場合は検査しない）
+    invariants: Option<InvariantTolerances>,
+    /// ボールが静止しているとみなす速さの上限（cm/s）
+    rest_threshold: f32,
+    /// ボール同士の衝突が解決されるたびに呼び出すコールバックの一覧
+    collision_callbacks: Vec<CollisionCallback>,
+    /// これまでのステップで失われた運動エネルギーの合計
+    energy_dissipated: f32,
+    /// 生成した時点の運動エネルギーの合計
+    initial_energy: f32,
+    /// これまでのステップの実行に要した実時間の合計
+    wall_time: Duration,
+    /// SVG への書き出し時にボールの上に重ねて描く補助表示（None の場合は描かない）
+    overlay: Option<Overlay>,
+    /// 乱数生成器の初期化に使った種
+    seed: u64,
+    /// 直前のステップの終了時点で接触していたボールのペア
+    contacts: Vec<Contact>,
+    /// 生成に使った設定を、乱数の種を確定させて YAML 形式に書き出したもの
+    effective_config: String,
+    /// run_shots() で順に撞くショットの (向き（ラジアン）, 速さ（cm/s）, 撞点の種類) の一覧
+    shots: Vec<(f32, f32, ShotSpin)>,
+    /// run_shots() で各ショットの後にボールが静止するのを待つ最大のステップ数
+    shot_max_steps: usize,
+    /// ポケットに入った手球を置き直すための、手球の諸元（手球がない設定では None）
+    cue_template: Option<CueTemplate>,
+    /// すべてのディスパッチャで共有するスレッドプール
+    pool: WorkerPool,
+    /// 時間の進め方（apply_config_patch() で、イベント駆動モードと組み合わせられない差分を拒否するために使います）
+    stepping: SteppingMode,
+}
+
+impl Simulation {
+    /// 設定情報から World を構築し、シミュレーションを生成します。
+    ///
+    /// # 引数
+    /// - `config`: 設定情報
+    ///
+    /// # 戻り値
+    /// システムを実行するスレッドプールを作成できない場合は Error を返します。
+    pub fn new(config: &Config) -> Result<Simulation, Box<dyn Error>> {
+        // --- 1. ECS の World を生成します ---
+        let mut world = World::new();
+
+        // --- 2. 各コンポーネントを World に登録します ---
+        components::register_all_components(&mut world);
+
+        // --- 3. シミュレーションの時間刻み dt をリソースとして World に登録します ---
+        world.insert(TimeDelta {
+            dt: Duration::from_secs_f32(config.dt),
+        });
+        // シミュレーション上の経過時間をリソースとして登録します。
+        world.insert(resources::SimClock::default());
+        // 乱数を使う処理で共有する乱数の種です。
+        let seed = config.seed.value();
+        // テーブルの個体差を乱数の種から引き、再現できるよう引いた値をログに出力します。
+        let defects = match &config.table_defects {
+            Some(defects) => {
+                let drawn = resources::TableDefects::draw(defects, seed);
+                tracing::info!(
+                    "Table defects (seed {}): cushion restitution x{:?}, cloth friction x{}",
+                    seed,
+                    drawn.cushion_restitution,
+                    drawn.cloth_friction
+                );
+                drawn
+            }
+            None => resources::TableDefects::default(),
+        };
+        world.insert(defects);
+        // 摩擦による減速度をリソースとして登録します。m/s^2 から cm/s^2 に変換し、
+        // テーブルの個体差によるラシャの摩擦の倍率を掛けます。
+        let (friction_x, friction_y) = config.friction_xy.unwrap_or((0.0, 0.0));
+        world.insert(resources::Friction {
+            x: friction_x * CM_PER_M * defects.cloth_friction,
+            y: friction_y * CM_PER_M * defects.cloth_friction,
+        });
+        // 摩擦が異なる領域をリソースとして登録します。中心は隅を原点とした内部座標に変換します。
+        world.insert(resources::FrictionRegions {
+            regions: config
+                .friction_regions
+                .iter()
+                .map(|region| {
+                    let (x, y) =
+                        config
+                            .coordinate_origin
+                            .to_corner(region.x, region.y, &config.table);
+                    resources::FrictionRegion {
+                        x,
+                        y,
+                        radius: region.radius,
+                        multiplier: region.multiplier,
+                    }
+                })
+                .collect(),
+        });
+        // 低速のボールにだけ働く追加の減速をリソースとして登録します。
+        // 速さは設定の単位から cm/s に、減速度は m/s^2 から cm/s^2 に変換します。
+        world.insert(match &config.low_speed_damping {
+            Some(damping) => resources::LowSpeedDamping {
+                threshold: config.units.velocity.to_cm_per_s(damping.threshold),
+                deceleration: damping.deceleration * CM_PER_M,
+            },
+            None => resources::LowSpeedDamping::default(),
+        });
+        // 衝突処理の後に適用する速さの上限をリソースとして登録します。設定の単位から cm/s に変換します。
+        world.insert(match config.max_speed {
+            Some(max_speed) => resources::SpeedLimit {
+                max_speed: config.units.velocity.to_cm_per_s(max_speed),
+            },
+            None => resources::SpeedLimit::default(),
+        });
+        // 一様な重力をリソースとして登録します。m/s^2 から cm/s^2 に変換します。
+        let (gravity_x, gravity_y) = config.gravity_xy.unwrap_or((0.0, 0.0));
+        world.insert(resources::Gravity {
+            x: gravity_x * CM_PER_M,
+            y: gravity_y * CM_PER_M,
+        });
+        // 滑り摩擦の係数をリソースとして登録します。テーブルの個体差によるラシャの摩擦の倍率を掛けます。
+        world.insert(resources::SlipFriction {
+            mu: config.slip_friction.unwrap_or(0.0) * defects.cloth_friction,
+        });
+        // 衝突処理の調整用パラメータをリソースとして登録します。
+        let mut collision_settings = resources::CollisionSettings::default();
+        if let Some(epsilon) = config.collision_epsilon {
+            collision_settings.epsilon = epsilon;
+        }
+        if let Some(friction) = config.ball_friction {
+            collision_settings.ball_friction = friction;
+        }
+        if let Some(friction) = config.cushion_friction {
+            collision_settings.cushion_friction = friction;
+        }
+        if let Some(iterations) = config.solver_iterations {
+            collision_settings.solver_iterations = iterations;
+        }
+        if let Some(max_events) = config.max_events_per_second {
+            collision_settings.max_events_per_second = max_events;
+        }
+        if let Some(damping) = config.cushion_tangential_damping {
+            collision_settings.tangential_damping = damping;
+        }
+        collision_settings.priority = config.collision_priority;
+        collision_settings.restitution_blend = config.restitution_blend;
+        collision_settings.solver = config.collision_solver;
+        collision_settings.contact_model = config.contact_model;
+        if let Some(substeps) = config.contact_substeps {
+            collision_settings.contact_substeps = substeps;
+        }
+        world.insert(collision_settings);
+        // 各ステップで解決されたボール同士の衝突の記録を登録します。
+        // ボールの数の見込みが指定されている場合は、記録に使う領域をあらかじめ確保します。
+        // 見込みが上限を超える場合は、上限の分だけ確保します。
+        let capacity = config.max_balls.unwrap_or(0).min(config.ball_limit());
+        world.insert(CollisionEvents::with_capacity(capacity));
+        world.insert(resources::PocketEvents::default());
+        // 各ステップの終了時点で接触しているボールのペアの記録を登録します。
+        world.insert(resources::ContactManifold::default());
+        // 壁ごとの衝突回数と、それに応じたクッションの反発係数の変化をリソースとして登録します。
+        world.insert(resources::WallImpacts::default());
+        // 反発係数の一括上書きを登録します。
+        world.insert(match config.restitution_override {
+            Some(e) => resources::RestitutionOverride::Force(e),
+            None => resources::RestitutionOverride::None,
+        });
+        world.insert(match &config.cushion_wear {
+            Some(wear) => resources::CushionWear {
+                enabled: true,
+                rate: wear.rate,
+                min: wear.min,
+                max: wear.max,
+            },
+            None => resources::CushionWear::default(),
+        });
+        // NaN や無限大を検出したときの扱いを登録します。
+        world.insert(FiniteGuard {
+            policy: config.non_finite_policy,
+            aborted: false,
+        });
+        // ログと出力先に書き出すボールの絞り込み条件を登録します。
+        world.insert(config.log_filter);
+        // ボールの種類の組み合わせごとの衝突の有無を登録します。
+        // cue_only_collisions の場合は、的球同士を無効にする規則を最後に加えて、ほかの規則より優先させます。
+        let mut rules: Vec<_> = config
+            .collision_rules
+            .iter()
+            .map(|rule| (rule.kinds, rule.enabled))
+            .collect();
+        if config.cue_only_collisions {
+            rules.push(((BallKind::Object, BallKind::Object), false));
+        }
+        world.insert(resources::CollisionRules { rules });
+        // 静止とみなす速さの上限は、設定の単位から内部の単位（cm/s）に変換して使います。
+        let rest_threshold = config
+            .rest_threshold_cm_per_s()
+            .unwrap_or(DEFAULT_REST_THRESHOLD);
+        // 静止したボールを休止させる条件を登録します。
+        world.insert(resources::SleepSettings {
+            threshold: rest_threshold,
+            steps: config.sleep_steps,
+        });
+        // 統計情報を登録します。
+        world.insert(resources::Stats::default());
+        // BallId とエンティティの対応表を登録します（ボールの生成時に追加されます）。
+        world.insert(BallRegistry::with_capacity(capacity));
+        // ポケットされた的球の扱いをリソースとして登録します。
+        world.insert(resources::PocketRules {
+            respot: config.pockets.as_ref().is_some_and(|p| p.respot),
+            foot_spot: config.table.foot_spot(config.coordinate_origin),
+            drop_steps: config.pockets.as_ref().map_or(0, |p| p.drop_steps),
+        });
+
+        // 乱数を使う処理で共有する、シード付きの乱数生成器を登録します。
+        world.insert(resources::SimRng::new(seed));
+
+        // --- 4. エンティティ生成関数を用いて、各エンティティ（テーブル、ボール）を作成します ---
+        // テーブル（ビリヤード台）と、設定に記載されたすべてのボールのエンティティを作成
+        entities::create_entities(&mut world, config);
+        // ポケットのエンティティを作成
+        entities::create_pockets(&mut world, config);
+        // ペグのエンティティを作成
+        entities::create_peg_grid(&mut world, config);
+        // 線分のクッションのエンティティを作成
+        entities::create_cushion_segments(&mut world, config);
+        // 距離の拘束を、BallId から引いたエンティティの組として登録します。
+        let constraints = {
+            let registry = world.read_resource::<BallRegistry>();
+            config
+                .constraints
+                .iter()
+                .filter_map(
+                    |c| match (registry.get(BallId(c.a)), registry.get(BallId(c.b))) {
+                        (Some(a), Some(b)) => Some(resources::Constraint {
+                            a,
+                            b,
+                            rest_length: c.rest_length,
+                            stiffness: c.stiffness,
+                        }),
+                        _ => {
+                            tracing::warn!("Constraint {}-{}: ball not found, ignoring", c.a, c.b);
+                            None
+                        }
+                    },
+                )
+                .collect()
+        };
+        world.insert(resources::Constraints { list: constraints });
+
+        // --- 5. システムディスパッチャの構築 ---
+        // 設定の systems セクションに応じて、実行するシステムを選びます。
+        // すべてのディスパッチャで共有するスレッドプールを、設定のスレッド数で 1 回だけ作ります。
+        let pool = WorkerPool::new(config.threads)?;
+        let dispatcher = build_dispatcher(config, &pool);
+
+        // ポケットに入った手球を run_shots() で置き直せるよう、生成した手球の諸元を写し取ります。
+        let cue_template = CueTemplate::capture(&world, config);
+        let mut simulation = Simulation {
+            world,
+            dispatcher,
+            settle_dispatcher: build_settle_dispatcher(config, &pool),
+            substepping: config
+                .adaptive_substeps
+                .as_ref()
+                .map(|adaptive| Substepping::new(config, adaptive, &pool)),
+            sinks: Vec::new(),
+            step: 0,
+            rest_threshold,
+            collision_callbacks: Vec::new(),
+            energy_dissipated: 0.0,
+            initial_energy: 0.0,
+            wall_time: Duration::ZERO,
+            overlay: None,
+            seed,
+            contacts: Vec::new(),
+            effective_config: Self::resolve_config_yaml(config, seed),
+            shots: config
+                .shots
+                .iter()
+                .map(|shot| {
+                    (
+                        shot.angle_deg.to_radians(),
+                        config.units.velocity.to_cm_per_s(shot.speed),
+                        shot.spin,
+                    )
+                })
+                .collect(),
+            shot_max_steps: config.shot_max_steps.unwrap_or(DEFAULT_SHOT_MAX_STEPS),
+            cue_template,
+            pool,
+            stepping: config.stepping,
+            invariants: config
+                .debug_invariants
+                .as_ref()
+                .map(|c| InvariantTolerances {
+                    penetration: c.penetration_tolerance,
+                    momentum: c.momentum_tolerance,
+                }),
+        };
+        // 進み具合（progress）の基準として、生成した時点の運動エネルギーを記録します。
+        simulation.initial_energy = simulation.kinetic_energy();
+        Ok(simulation)
+    }
+
+    /// ボール同士の衝突が解決されるたびに呼び出すコールバックを登録します。
+    ///
+    /// コールバックは、各ステップのシステムの実行がすべて終わった後に、そのステップで解決された
+    /// 衝突ごとに解決された順で呼び出されます。World の借用はすでに解放されているため、
+    /// 効果音の再生など、時間のかかる処理を行ってもシステムの実行を妨げません。
+    /// 衝突の記録にはインパルスの大きさが含まれるため、音量の調整などに使えます。
+    pub fn on_collision(&mut self, callback: CollisionCallback) {
+        self.collision_callbacks.push(callback);
+    }
+
+    /// 出力先（シンク）を登録します。複数登録した場合は、登録順にすべてへ出力されます。
+    pub fn add_sink(&mut self, sink: Box<dyn OutputSink>) {
+        self.sinks.push(sink);
+    }
+
+    /// World への参照を返します。
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    /// 実行済みのステップ数を返します。
+    pub fn steps(&self) -> usize {
+        self.step
+    }
+
+    /// すべてのボールの速さが静止とみなす上限（`rest_threshold`）以下かどうかを返します。
+    /// テーブル上にボールが 1 つもない場合は true を返します。
+    pub fn is_at_rest(&self) -> bool {
+        let vel = self.world.read_storage::<Velocity>();
+        let ball = self.world.read_storage::<Ball>();
+        (&vel, &ball)
+            .join()
+            .all(|(v, _)| (v.x * v.x + v.y * v.y).sqrt() <= self.rest_threshold)
+    }
+
+    /// シミュレーションを所有し、1 ステップごとのスナップショットを返すイテレータに変換します。
+    ///
+    /// イテレータは、すべてのボールが静止するか `max_steps` ステップに達すると終了します。
+    /// 登録済みの出力先にも、通常の実行と同じく各ステップの結果が通知されます。
+    ///
+    /// # 引数
+    /// - `max_steps`: 進める最大のステップ数
+    pub fn frames(self, max_steps: usize) -> Frames {
+        Frames::new(self, max_steps)
+    }
+
+    /// 位置・速度の不正な値（NaN や無限大）を検出し、実行が中断されたかどうかを返します。
+    ///
+    /// `non_finite_policy` が Abort の場合のみ true になり得ます。
+    pub fn is_aborted(&self) -> bool {
+        self.world.read_resource::<FiniteGuard>().aborted
+    }
+
+    /// 手球を撞き、指定した向きと速さの初速度と、撞点に応じた回転を与えます。
+    ///
+    /// 撞点が中心から上下にずれると進行方向まわりの回転（押し・引き）が、
+    /// 左右にずれると鉛直軸まわりの回転（ひねり）が `5 × speed × ずれ / (2 × radius^2)` の大きさで生じます。
+    /// 回転が的球に当たった後の手球の動きに影響するのは、滑り摩擦（`slip_friction`）が
+    /// 設定されている場合です。
+    ///
+    /// # 引数
+    /// - `aim`: 撞く向き（+x 軸から反時計回りの角度、ラジアン）
+    /// - `speed`: 初速度の大きさ（cm/s）
+    /// - `spin_kind`: 撞点の種類
+    ///
+    /// # 戻り値
+    /// 手球（BallKind::Cue のボール）がテーブル上にない場合や、複数ある場合は Error を返します。
+    pub fn shot(
+        &mut self,
+        aim: f32,
+        speed: f32,
+        spin_kind: ShotSpin,
+    ) -> Result<(), Box<dyn Error>> {
+        let entity = find_cue_ball(&self.world)?;
+        let radius = self
+            .world
+            .read_storage::<Ball>()
+            .get(entity)
+            .map(|b| b.radius)
+            .ok_or("Cue ball has no Ball component")?;
+
+        let (dir_x, dir_y) = (aim.cos(), aim.sin());
+        let (vertical, horizontal) = spin_kind.tip_offset();
+        // 撞点のずれ（半径に対する比）から、撞いた直後の角速度の大きさを求めます。
+        let roll = 2.5 * speed * vertical / radius;
+        let side = 2.5 * speed * horizontal / radius;
+        self.world.write_storage::<Velocity>().insert(
+            entity,
+            Velocity {
+                x: speed * dir_x,
+                y: speed * dir_y,
+            },
+        )?;
+        // 順回転の軸は進行方向に垂直な水平軸で、転がりと同じ向き（rolling_spin と同じ規則）です。
+        self.world.write_storage::<Spin>().insert(
+            entity,
+            Spin {
+                x: -dir_y * roll,
+                y: dir_x * roll,
+                z: side,
+            },
+        )?;
+        Ok(())
+    }
+
+    /// 実行中のシミュレーションに設定の差分を適用します。エンティティは作り直しません。
+    ///
+    /// 変更は次のステップから反映され、すでに動いているボールにもそのまま適用されます。
+    /// 摩擦には、生成時に引いたテーブルの個体差によるラシャの摩擦の倍率を掛けます。
+    ///
+    /// # 引数
+    /// - `patch`: 適用する設定の差分
+    ///
+    /// # 戻り値
+    /// 差分の値が無効な場合や、イベント駆動モードで 0 以外の摩擦や重力を指定した場合は、
+    /// 何も変更せずに Error を返します。
+    pub fn apply_config_patch(&mut self, patch: &ConfigPatch) -> Result<(), Box<dyn Error>> {
+        patch.validate()?;
+        // イベント駆動モードは摩擦のない等速直線運動を前提とするため、設定ファイルと同じく速度を変える値は拒否します。
+        if self.stepping == SteppingMode::EventDriven {
+            for (name, value) in [
+                ("friction_xy", patch.friction_xy),
+                ("gravity_xy", patch.gravity_xy),
+            ] {
+                if value.is_some_and(|(x, y)| x != 0.0 || y != 0.0) {
+                    return Err(format!(
+                        "{name} is not supported with stepping: EventDriven (it assumes frictionless motion)"
+                    )
+                    .into());
+                }
+            }
+        }
+        if let Some(dt) = patch.dt {
+            self.world.write_resource::<TimeDelta>().dt = Duration::from_secs_f32(dt);
+        }
+        if let Some((x, y)) = patch.friction_xy {
+            let cloth = self
+                .world
+                .read_resource::<resources::TableDefects>()
+                .cloth_friction;
+            *self.world.write_resource::<resources::Friction>() = resources::Friction {
+                x: x * CM_PER_M * cloth,
+                y: y * CM_PER_M * cloth,
+            };
+        }
+        if let Some(e) = patch.restitution_override {
+            *self
+                .world
+                .write_resource::<resources::RestitutionOverride>() =
+                resources::RestitutionOverride::Force(e);
+        }
+        if let Some((x, y)) = patch.gravity_xy {
+            *self.world.write_resource::<resources::Gravity>() = resources::Gravity {
+                x: x * CM_PER_M,
+                y: y * CM_PER_M,
+            };
+        }
+        Ok(())
+    }
+
+    /// テーブルの寸法を実行中に変更します。
+    ///
+    /// ボールの位置はこの時点では変更しません。新しい境界の外側に出たボールは、
+    /// 次のステップの衝突処理（FixedStep の CollisionSystem、EventDriven の EventDrivenSystem）で壁の内側へ押し戻されます。
+    /// ポケットの位置は変更しません。
+    ///
+    /// # 引数
+    /// - `width`: 新しい横幅（cm）
+    /// - `height`: 新しい高さ（cm）
+    ///
+    /// # 戻り値
+    /// 寸法が正の有限の値でない場合は、何も変更せずに Error を返します。
+    pub fn resize_table(&mut self, width: f32, height: f32) -> Result<(), Box<dyn Error>> {
+        for (name, value) in [("width", width), ("height", height)] {
+            if !(value.is_finite() && value > 0.0) {
+                return Err(format!("table {name} must be finite and positive: {value}").into());
+            }
+        }
+        let mut tables = self.world.write_storage::<Table>();
+        for table in (&mut tables).join() {
+            table.width = width;
+            table.height = height;
+        }
+        Ok(())
+    }
+
+    /// 現在の World の状態（各ボールの位置・速度）を WorldSnapshot として取得します。
+    pub fn snapshot(&self) -> WorldSnapshot {
+        let pos = self.world.read_storage::<Position>();
+        let vel = self.world.read_storage::<Velocity>();
+        let ball = self.world.read_storage::<Ball>();
+        let kind = self.world.read_storage::<BallKind>();
+        let id = self.world.read_storage::<BallId>();
+        let render = self.world.read_storage::<Render>();
+        let clock = self.world.read_resource::<SimClock>();
+        let events = self.world.read_resource::<CollisionEvents>();
+        // ポケットへの落下中のボールは Velocity を持たないため、速度 0 として扱います。
+        let mut balls: Vec<BallSnapshot> =
+            (&pos, vel.maybe(), &ball, &id, kind.maybe(), render.maybe())
+                .join()
+                .map(|(p, v, _, id, k, r)| {
+                    let v = v.copied().unwrap_or(Velocity { x: 0.0, y: 0.0 });
+                    BallSnapshot {
+                        id: *id,
+                        kind: k.copied().unwrap_or(BallKind::Object),
+                        x: p.x,
+                        y: p.y,
+                        vx: v.x,
+                        vy: v.y,
+                        scale: r.copied().unwrap_or_default().scale,
+                    }
+                })
+                .collect();
+        // 出力の順序がエンティティの格納順に依存しないよう、BallId 順に並べます。
+        balls.sort_by_key(|b| b.id);
+        WorldSnapshot {
+            step: self.step,
+            elapsed: clock.elapsed,
+            balls,
+            collisions: events.pairs.clone(),
+        }
+    }
+
+    /// 指定したコンポーネントを持つすべてのボールについて、BallId とコンポーネントの値の組を
+    /// BallId の昇順で返します。
+    ///
+    /// WorldSnapshot を作らずに 1 種類のコンポーネントだけを調べたいツール向けの、軽量な取得方法です。
+    /// コンポーネントを持たないボール（ポケットへの落下中で Velocity を持たないボールなど）は含みません。
+    pub fn query<T: Component + Copy>(&self) -> Vec<(BallId, T)> {
+        let storage = self.world.read_storage::<T>();
+        let ball = self.world.read_storage::<Ball>();
+        let id = self.world.read_storage::<BallId>();
+        let mut values: Vec<(BallId, T)> = (&storage, &ball, &id)
+            .join()
+            .map(|(value, _, id)| (*id, *value))
+            .collect();
+        values.sort_by_key(|(id, _)| *id);
+        values
+    }
+
+    /// すべてのボールの位置を、BallId の昇順で返します。
+    pub fn query_positions(&self) -> Vec<(BallId, Position)> {
+        self.query::<Position>()
+    }
+
+    /// Velocity を持つすべてのボールの速度を、BallId の昇順で返します。
+    pub fn query_velocities(&self) -> Vec<(BallId, Velocity)> {
+        self.query::<Velocity>()
+    }
+
+    /// Spin を持つすべてのボールの回転を、BallId の昇順で返します。
+    pub fn query_spins(&self) -> Vec<(BallId, Spin)> {
+        self.query::<Spin>()
+    }
+
+    /// World を読み取るだけの関数 `f` をステップの間に実行し、その戻り値を返します。
+    ///
+    /// 組み込みの集計値にない量（ボールの重心など）を利用者が自由に計算するための手段です。
+    /// `f` には World の共有参照だけを渡すため、read_storage や read_resource で読み取ることはできますが、
+    /// エンティティやコンポーネントを書き換えてシミュレーションの状態を変えることはありません。
+    /// ステップごとの値を集める場合は、step() の後に毎回呼び出してください。
+    ///
+    /// # 引数
+    /// - `f`: World を受け取って値を計算する関数
+    ///
+    /// # 戻り値
+    /// `f` の戻り値
+    pub fn observe<T, F: FnMut(&World) -> T>(&self, mut f: F) -> T {
+        f(&self.world)
+    }
+
+    /// 基準のスナップショットの時点から、位置が `MOVED_BALL_TOLERANCE` を超えて変わったボールの一覧を返します。
+    ///
+    /// 撞く前に snapshot() で基準を取っておき、ボールが止まった後に呼び出すことで、
+    /// 「最初に正しい的球に当てたか」などのファウルの判定に使えます。
+    /// 基準の時点にあったボールのうち、その後ポケットに入るなどしてテーブルから取り除かれたボールも
+    /// 動いたものとして含めます。基準の時点になかったボールは含めません。
+    ///
+    /// # 引数
+    /// - `since`: 基準とするスナップショット
+    ///
+    /// # 戻り値
+    /// 動いたボールの BallId（昇順）
+    pub fn moved_balls(&self, since: &WorldSnapshot) -> Vec<BallId> {
+        let positions = self.query_positions();
+        since
+            .balls
+            .iter()
+            .filter(|before| {
+                positions
+                    .iter()
+                    .find(|(id, _)| *id == before.id)
+                    .is_none_or(|(_, after)| {
+                        let (dx, dy) = (after.x - before.x, after.y - before.y);
+                        (dx * dx + dy * dy).sqrt() > MOVED_BALL_TOLERANCE
+                    })
+            })
+            .map(|before| before.id)
+            .collect()
+    }
+
+    /// 指定した BallId のボールの現在の状態を返します。
+    ///
+    /// BallRegistry の対応表からエンティティを引くため、全ボールを走査しません。
+    /// ボールがポケットされて削除されている場合は None を返します。
+    pub fn ball_state(&self, id: BallId) -> Option<BallState> {
+        let entity = self.world.read_resource::<BallRegistry>().get(id)?;
+        if !self.world.is_alive(entity) {
+            return None;
+        }
+        let pos = self.world.read_storage::<Position>();
+        let vel = self.world.read_storage::<Velocity>();
+        let kind = self.world.read_storage::<BallKind>();
+        Some(BallState {
+            id,
+            kind: kind.get(entity).copied().unwrap_or(BallKind::Object),
+            position: *pos.get(entity)?,
+            velocity: vel
+                .get(entity)
+                .copied()
+                .unwrap_or(Velocity { x: 0.0, y: 0.0 }),
+        })
+    }
+
+    /// 現在の状態をチェックポイントとして JSON 形式でファイルに保存します。
+    ///
+    /// # 引数
+    /// - `path`: 保存先のファイルのパス
+    pub fn save_checkpoint(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        let checkpoint = Checkpoint::capture(&self.world, self.step);
+        let writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(writer, &checkpoint)?;
+        Ok(())
+    }
+
+    /// ファイルに保存したチェックポイントを読み込み、その時点の状態から再開できるようにします。
+    ///
+    /// 保存時と同じ設定から生成した Simulation に対して呼び出す必要があります。
+    /// 浮動小数点数は JSON 上で正確に往復するため、再開後の結果は保存前から
+    /// そのまま実行を続けた場合とビット単位で一致します。
+    ///
+    /// # 引数
+    /// - `path`: チェックポイントのファイルのパス
+    pub fn load_checkpoint(&mut self, path: &str) -> Result<(), Box<dyn Error>> {
+        let reader = BufReader::new(File::open(path)?);
+        let checkpoint: Checkpoint = serde_json::from_reader(reader)?;
+        checkpoint.restore(&mut self.world)?;
+        self.step = checkpoint.step;
+        Ok(())
+    }
+
+    /// 現在の状態から、次に衝突（ボール同士またはボールと壁）が起きるまでの時間を求めます。
+    ///
+    /// 摩擦のない等速直線運動を仮定し、すべてのボールのペアと壁について
+    /// 衝突までの時間を計算して、最も早いものを返します。
+    ///
+    /// # 戻り値
+    /// 衝突までの時間（秒）と衝突イベントを返します。衝突が起きない場合は None を返します。
+    pub fn time_to_next_event(&self) -> Option<(f32, CollisionEvent)> {
+        let pos = self.world.read_storage::<Position>();
+        let vel = self.world.read_storage::<Velocity>();
+        let ball = self.world.read_storage::<Ball>();
+        let id = self.world.read_storage::<BallId>();
+        let mask = self.world.read_storage::<CollisionMask>();
+        let kind = self.world.read_storage::<BallKind>();
+        let table_storage = self.world.read_storage::<Table>();
+        let rules = self.world.read_resource::<resources::CollisionRules>();
+
+        // BallId 順に並べておき、同時刻の衝突では若い番号を優先します。
+        let mut balls: Vec<MovingBall> = (&pos, &vel, &ball, &id, mask.maybe(), kind.maybe())
+            .join()
+            .map(|(p, v, b, id, m, k)| MovingBall {
+                id: *id,
+                pos: *p,
+                vel: *v,
+                radius: b.radius,
+                mask: m.copied().unwrap_or_default(),
+                kind: k.copied().unwrap_or(BallKind::Object),
+            })
+            .collect();
+        balls.sort_by_key(|b| b.id);
+        next_event(&balls, (&table_storage).join().next(), &rules)
+    }
+
+    /// 出力先に渡すスナップショットを作成します。
+    /// LogFilter に一致するボール（と、それらが関わる衝突）のみを含めます。
+    fn filtered_snapshot(&self) -> WorldSnapshot {
+        let filter = *self.world.read_resource::<LogFilter>();
+        let mut snapshot = self.snapshot();
+        snapshot.balls.retain(|b| filter.matches(b.id, b.kind));
+        let ids: Vec<BallId> = snapshot.balls.iter().map(|b| b.id).collect();
+        snapshot
+            .collisions
+            .retain(|c| ids.contains(&c.id_a) || ids.contains(&c.id_b));
+        snapshot
+    }
+
+    /// 全ボールの運動エネルギーの合計（質量の単位 × cm²/s²）を返します。
+    fn kinetic_energy(&self) -> f32 {
+        let vel = self.world.read_storage::<Velocity>();
+        let ball = self.world.read_storage::<Ball>();
+        (&vel, &ball)
+            .join()
+            .map(|(v, b)| 0.5 * b.mass * (v.x * v.x + v.y * v.y))
+            .sum()
+    }
+
+    /// 全ボールの BallId・位置・速度から計算した、決定的なハッシュ値を返します。
+    ///
+    /// 位置と速度は STATE_HASH_RESOLUTION 単位に丸めてからハッシュするため、同じ設定を同じステップ数だけ
+    /// 進めた結果は、わずかな浮動小数点の誤差があっても同じ値になります。物理演算の回帰の検出に使えます。
+    pub fn state_hash(&self) -> u64 {
+        state_hash::state_hash(&self.world)
+    }
+
+    /// これまでの実行全体の集計値を取得します。
+    ///
+    /// 失われた運動エネルギーと実時間は、この Simulation でステップを実行した分のみを集計します
+    /// （チェックポイントからは復元されません）。
+    pub fn metrics(&self) -> RunMetrics {
+        let stats = self.world.read_resource::<Stats>();
+        RunMetrics {
+            steps: self.step,
+            wall_collisions: self.world.read_resource::<WallImpacts>().total(),
+            ball_collisions: stats.ball_collisions,
+            balls_pocketed: stats.pocketed,
+            distance: stats.distance.iter().map(|(id, d)| (id.0, *d)).collect(),
+            energy_dissipated: self.energy_dissipated,
+            wall_clock_seconds: self.wall_time.as_secs_f64(),
+            seed: self.seed,
+        }
+    }
+
+    /// ショットの進み具合の目安として、生成した時点の運動エネルギーのうち、これまでに失われた割合を返します。
+    ///
+    /// 開始時点では 0.0 で、ボールが止まるにつれて 1.0 に近づきます。重力などでエネルギーが増えた場合も
+    /// `[0.0, 1.0]` の範囲に収めます。最初からすべてのボールが止まっている場合は 1.0 を返します。
+    /// 失われた運動エネルギーは metrics() と同じく、この Simulation でステップを実行した分のみを集計します。
+    pub fn progress(&self) -> f32 {
+        if self.initial_energy <= 0.0 {
+            return 1.0;
+        }
+        (self.energy_dissipated / self.initial_energy).clamp(0.0, 1.0)
+    }
+
+    /// 直前のステップの終了時点で接触していた（めり込んでいた）ボールのペアの一覧を返します。
+    ///
+    /// 一覧はステップの実行中に計算したものを保持しており、呼び出すたびに計算し直すことはありません。
+    /// 次のステップを実行すると置き換えられます。ステップを実行する前やイベント駆動モードでは空です。
+    pub fn current_contacts(&self) -> &[Contact] {
+        &self.contacts
+    }
+
+    /// システムを実行するスレッドプールのスレッドの数を返します。`parallel` フィーチャーが無効な場合は 1 を返します。
+    pub fn worker_threads(&self) -> usize {
+        self.pool.threads()
+    }
+
+    /// 乱数生成器の初期化に使った種を返します。この種を設定の `seed` に指定すると、同じ実行を再現できます。
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// SVG への書き出し時にボールの上に重ねて描く補助表示を設定します。None を渡すと描かなくなります。
+    pub fn set_overlay(&mut self, overlay: Option<Overlay>) {
+        self.overlay = overlay;
+    }
+
+    /// 現在の状態を SVG 形式の文字列として書き出します。補助表示が設定されている場合は重ねて描きます。
+    pub fn render_svg(&self) -> String {
+        render_svg(&self.world, self.overlay.as_ref())
+    }
+
+    /// 現在の状態を、1 cm あたり `pixels_per_cm` ピクセルの RGBA の画像として描きます。
+    /// 動画を作る場合は、ステップごとに呼び出してコマとして使います。
+    pub fn render_raster(&self, pixels_per_cm: f32) -> RasterFrame {
+        render_raster(&self.world, pixels_per_cm)
+    }
+
+    /// これまでの実行全体の集計値（RunMetrics）を JSON 文字列として取得します。
+    pub fn metrics_json(&self) -> String {
+        // RunMetrics は数値と数値をキーとするマップのみで構成されるため、変換は失敗しません。
+        serde_json::to_string_pretty(&self.metrics()).expect("Failed to serialize RunMetrics")
+    }
+
+    /// 生成に使った設定を、省略した項目の既定値と確定した乱数の種を含めて YAML 形式の文字列で取得します。
+    ///
+    /// 書き出した設定を読み込んで生成し直すと、同じ実行を再現できます。値の単位は `units` の指定のまま
+    /// 書き出し、`units` 自体も明示します。apply_config_patch() で後から適用した差分は含みません。
+    pub fn effective_config_yaml(&self) -> String {
+        self.effective_config.clone()
+    }
+
+    /// 設定を YAML 形式に書き出し、`seed` を実際に使った種に置き換えます。
+    fn resolve_config_yaml(config: &Config, seed: u64) -> String {
+        // Config は数値、文字列、列挙型とそれらの列や構造体のみで構成されるため、変換は失敗しません。
+        // f32 の値を最短の表記で書き出すため、いったん文字列に書き出してから読み直して種を置き換えます。
+        let yaml = serde_yaml::to_string(config).expect("Failed to serialize Config");
+        let mut value: serde_yaml::Value =
+            serde_yaml::from_str(&yaml).expect("Failed to serialize Config");
+        if let serde_yaml::Value::Mapping(mapping) = &mut value {
+            mapping.insert("seed".into(), seed.into());
+        }
+        serde_yaml::to_string(&value).expect("Failed to serialize Config")
+    }
+
+    /// シミュレーションを 1 ステップ進め、登録済みの出力先へ結果を通知します。
+    ///
+    /// デバッグビルドで `debug_invariants` が設定されている場合は、ステップの後に
+    /// 物理的な不変条件を検査し、違反があれば panic します。リリースビルドでは検査しません。
+    ///
+    /// # 戻り値
+    /// ボールが動いたかどうか、衝突の数、削除されたボールの数をまとめた StepReport
+    pub fn step(&mut self) -> StepReport {
+        tracing::info!("--- Time step {} ---", self.step);
+        let invariants = self.invariants.filter(|_| cfg!(debug_assertions));
+        let baseline = invariants.map(|_| invariants::StepBaseline::capture(&self.world));
+        let energy_before = self.kinetic_energy();
+        let positions_before = self.ball_positions();
+        let started = Instant::now();
+        // 各システムを順次実行します。ステップの自動分割を行う場合は、分割数も求めます。
+        let substeps = match &mut self.substepping {
+            Some(substepping) => substepping.dispatch(&mut self.world),
+            None => {
+                self.dispatcher.dispatch(&self.world);
+                1
+            }
+        };
+        // エンティティの生成／削除などの更新処理を実行します。
+        self.world.maintain();
+        self.wall_time += started.elapsed();
+        self.energy_dissipated += energy_before - self.kinetic_energy();
+        if let (Some(tolerances), Some(baseline)) = (invariants, baseline) {
+            invariants::check_invariants(&self.world, &baseline, &tolerances);
+        }
+        let report = self.step_report(&positions_before, substeps);
+        // 接触情報を World から写し取り、次のステップまで借用なしで参照できるようにします。
+        self.contacts.clear();
+        self.contacts.extend_from_slice(
+            &self
+                .world
+                .read_resource::<resources::ContactManifold>()
+                .contacts,
+        );
+        // 衝突の記録を複製してから World の借用を解放し、登録済みのコールバックを呼び出します。
+        if !self.collision_callbacks.is_empty() {
+            let collisions = self.world.read_resource::<CollisionEvents>().pairs.clone();
+            for collision in &collisions {
+                for callback in &mut self.collision_callbacks {
+                    callback(collision);
+                }
+            }
+        }
+
+        // 出力先が登録されている場合のみスナップショットを作成します。
+        if !self.sinks.is_empty() {
+            let snapshot = self.filtered_snapshot();
+            for sink in &mut self.sinks {
+                sink.on_step(self.step, &snapshot);
+            }
+        }
+        self.step += 1;
+        report
+    }
+
+    /// 計測の前にボールを落ち着かせるため、すべてのボールが静止するまで運動と衝突処理だけを繰り返します。
+    ///
+    /// 静止したまま重なっているボールも衝突処理で引き離すため、少なくとも 1 ステップは進め、
+    /// この間はめり込みの位置の補正を少なくとも `SETTLE_SOLVER_ITERATIONS` 回行います。
+    /// この間はログを出力せず、ステップ数、経過時間、統計情報、出力先、衝突のコールバックにも反映しません。
+    /// ポケットの判定も行いません。終了後は衝突の記録を空にして壁への衝突回数を元に戻し、進み具合（progress）と
+    /// 失われたエネルギーの基準を落ち着いた後の状態に取り直すため、以降の計測はこの状態から始まります。
+    ///
+    /// # 引数
+    /// - `max_steps`: 進める最大のステップ数
+    ///
+    /// # 戻り値
+    /// `max_steps` ステップ以内にすべてのボールが静止した場合は true、そうでない場合は false
+    pub fn settle(&mut self, max_steps: usize) -> bool {
+        // 壁への衝突回数（クッションの摩耗の基準）に落ち着くまでの衝突を含めないよう、元の値を控えておきます。
+        let impacts = *self.world.read_resource::<WallImpacts>();
+        // 静止したまま重なっているボールを引き離せるよう、この間だけ位置の補正を有効にします。
+        let iterations = {
+            let mut settings = self.world.write_resource::<resources::CollisionSettings>();
+            let iterations = settings.solver_iterations;
+            settings.solver_iterations = iterations.max(SETTLE_SOLVER_ITERATIONS);
+            iterations
+        };
+        let mut at_rest = false;
+        tracing::subscriber::with_default(tracing::subscriber::NoSubscriber::default(), || {
+            for _ in 0..max_steps {
+                self.settle_dispatcher.dispatch(&self.world);
+                self.world.maintain();
+                if self.is_at_rest() || self.is_aborted() {
+                    at_rest = self.is_at_rest();
+                    break;
+                }
+            }
+        });
+        self.world
+            .write_resource::<resources::CollisionSettings>()
+            .solver_iterations = iterations;
+        self.world.write_resource::<CollisionEvents>().pairs.clear();
+        *self.world.write_resource::<WallImpacts>() = impacts;
+        // 統計情報の移動距離に落ち着くまでの移動を含めないよう、基準の位置を現在の位置に更新します。
+        {
+            let pos = self.world.read_storage::<Position>();
+            let ids = self.world.read_storage::<BallId>();
+            let mut stats = self.world.write_resource::<Stats>();
+            for (p, id) in (&pos, &ids).join() {
+                stats.last_positions.insert(*id, (p.x, p.y));
+            }
+        }
+        self.contacts.clear();
+        self.contacts.extend_from_slice(
+            &self
+                .world
+                .read_resource::<resources::ContactManifold>()
+                .contacts,
+        );
+        self.energy_dissipated = 0.0;
+        self.initial_energy = self.kinetic_energy();
+        at_rest
+    }
+
+    /// 各ボールのエンティティと位置の一覧を返します。
+    fn ball_positions(&self) -> Vec<(Entity, Position)> {
+        let entities = self.world.entities();
+        let pos = self.world.read_storage::<Position>();
+        let ball = self.world.read_storage::<Ball>();
+        (&entities, &pos, &ball)
+            .join()
+            .map(|(ent, p, _)| (ent, *p))
+            .collect()
+    }
+
+    /// ステップの前の各ボールの位置と、現在の World の状態を比べて StepReport を作成します。
+    ///
+    /// # 引数
+    /// - `positions_before`: ステップの前に ball_positions() で取得した一覧
+    /// - `substeps`: このステップの運動と衝突処理の分割数
+    fn step_report(&self, positions_before: &[(Entity, Position)], substeps: usize) -> StepReport {
+        let pos = self.world.read_storage::<Position>();
+        let ball = self.world.read_storage::<Ball>();
+        // 削除されたボールは位置が取得できないため、動いたボールには数えません。
+        let any_movement = positions_before.iter().any(|(ent, before)| {
+            pos.get(*ent)
+                .is_some_and(|after| after.x != before.x || after.y != before.y)
+        });
+        let remaining = (&pos, &ball).join().count();
+        StepReport {
+            any_movement,
+            collisions: self.world.read_resource::<CollisionEvents>().pairs.len(),
+            balls_removed: positions_before.len().saturating_sub(remaining),
+            substeps,
+        }
+    }
+
+    /// 指定したステップ数だけシミュレーションを進め、最後に各出力先の終了処理を呼び出します。
+    ///
+    /// 不正な値の検出により実行が中断された場合は、その時点で打ち切ります。
+    pub fn run(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+            if self.is_aborted() {
+                tracing::error!("Simulation aborted at step {}", self.step);
+                break;
+            }
+        }
+        for sink in &mut self.sinks {
+            sink.finish();
+        }
+    }
+
+    /// 設定の `shots` を順に撞き、各ショットの結果を返します。最後に各出力先の終了処理を呼び出します。
+    ///
+    /// 最初のショットの前に動いているボールがあれば、静止するまで進めます。各ショットでは、手球が
+    /// ポケットに入っていればボールインハンドとして `ball_in_hand` の位置に置き直してから撞き、
+    /// すべてのボールが静止するか `shot_max_steps` ステップに達するまで進めます。
+    /// 不正な値の検出により実行が中断された場合や、手球を置き直せない（手球のない設定の）場合は、
+    /// その時点で残りのショットを打ち切ります。
+    ///
+    /// # 戻り値
+    /// 撞いたショットごとの結果の一覧
+    pub fn run_shots(&mut self) -> Vec<ShotOutcome> {
+        let mut outcomes = Vec::new();
+        self.run_until_rest(&mut Vec::new());
+        for (aim, speed, spin) in self.shots.clone() {
+            if self.is_aborted() {
+                tracing::error!("Simulation aborted at step {}", self.step);
+                break;
+            }
+            let cue_replaced = self.place_cue_ball_in_hand();
+            if let Err(e) = self.shot(aim, speed, spin) {
+                tracing::warn!("Stopping shots: {}", e);
+                break;
+            }
+            let mut pocketed = Vec::new();
+            let (steps, settled) = self.run_until_rest(&mut pocketed);
+            outcomes.push(ShotOutcome {
+                cue_replaced,
+                steps,
+                settled,
+                pocketed,
+                snapshot: self.snapshot(),
+            });
+        }
+        for sink in &mut self.sinks {
+            sink.finish();
+        }
+        outcomes
+    }
+
+    /// すべてのボールが静止してポケットへの落下の演出も終わるか、`shot_max_steps` ステップに達するか、
+    /// 実行が中断されるまで進めます。
+    ///
+    /// # 引数
+    /// - `pocketed`: 進めた間にポケットに入ったボールを追加する一覧
+    ///
+    /// # 戻り値
+    /// (進めたステップ数, すべてのボールが静止したかどうか)
+    fn run_until_rest(&mut self, pocketed: &mut Vec<BallId>) -> (usize, bool) {
+        let settled = |simulation: &Simulation| {
+            simulation.is_at_rest() && simulation.world.read_storage::<Pocketing>().is_empty()
+        };
+        for steps in 0..self.shot_max_steps {
+            if settled(self) {
+                return (steps, true);
+            }
+            self.step();
+            pocketed.extend(
+                self.world
+                    .read_resource::<PocketEvents>()
+                    .events
+                    .iter()
+                    .map(|e| e.id),
+            );
+            if self.is_aborted() {
+                return (steps + 1, false);
+            }
+        }
+        (self.shot_max_steps, settled(self))
+    }
+
+    /// 手球がテーブル上にない（ポケットに入った）場合に、ボールインハンドとして手球を置き直します。
+    ///
+    /// ポケットへ落ちている途中の手球は、その場で取り除いてから置き直します。
+    ///
+    /// # 戻り値
+    /// 手球を置き直した場合は true
+    fn place_cue_ball_in_hand(&mut self) -> bool {
+        let Some(template) = self.cue_template else {
+            return false;
+        };
+        let current = self.world.read_resource::<BallRegistry>().get(template.id);
+        if let Some(entity) = current.filter(|ent| self.world.is_alive(*ent)) {
+            if !self.world.read_storage::<Pocketed>().contains(entity) {
+                return false;
+            }
+            // 落下の演出の途中の手球を取り除きます。
+            let _ = self.world.delete_entity(entity);
+        }
+        self.world
+            .write_resource::<BallRegistry>()
+            .remove(template.id);
+        let entity = template.place(&mut self.world);
+        self.world.maintain();
+        if let Some(p) = self.world.read_storage::<Position>().get(entity) {
+            tracing::info!("Cue ball in hand placed at ({:.2}, {:.2})", p.x, p.y);
+        }
+        true
+    }
+}
+
+/// テーブル上にある手球（BallKind::Cue のボール）のエンティティを返します。
+///
+/// BallId は設定の `entities` の並び順で決まるため、手球は BallId ではなく BallKind で探します。
+/// ポケットに入ったボール（Pocketed）は含めません。
+///
+/// # 戻り値
+/// 手球がちょうど 1 つある場合はそのエンティティ、ない場合や複数ある場合は Error を返します。
+fn find_cue_ball(world: &World) -> Result<Entity, Box<dyn Error>> {
+    let entities = world.entities();
+    let kind = world.read_storage::<BallKind>();
+    let ball = world.read_storage::<Ball>();
+    let pocketed = world.read_storage::<Pocketed>();
+    let mut cues = (&entities, &kind, &ball, !&pocketed)
+        .join()
+        .filter(|(_, kind, _, _)| **kind == BallKind::Cue)
+        .map(|(entity, _, _, _)| entity);
+    let cue = cues.next().ok_or("Cue ball is not on the table")?;
+    if cues.next().is_some() {
+        return Err("More than one cue ball is on the table".into());
+    }
+    Ok(cue)
+}
@@ -0,0 +1,18 @@
+// src/simulation/ball_state.rs
+//
+// このファイルでは、1 つのボールの現在の状態を表す構造体を定義します。
+
+use crate::components::{BallId, BallKind, Position, Velocity};
+
+/// Simulation::ball_state で取得する、1 つのボールの現在の状態です。
+#[derive(Debug, Clone, Copy)]
+pub struct BallState {
+    /// ボールの識別番号
+    pub id: BallId,
+    /// ボールの種類（手球か的球か）
+    pub kind: BallKind,
+    /// 位置（cm）
+    pub position: Position,
+    /// 速度（cm/s）
+    pub velocity: Velocity,
+}
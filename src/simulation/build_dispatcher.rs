@@ -0,0 +1,188 @@
+// src/simulation/build_dispatcher.rs
+//
+// このファイルでは、設定に応じて実行するシステムを選び、ディスパッチャを構築する関数を定義します。
+
+use super::WorkerPool;
+use crate::config::{Config, SteppingMode};
+use crate::systems;
+use specs::prelude::*;
+
+/// 設定に応じてシステムを並べ、ディスパッチャを構築する関数です。
+///
+/// システムの実行順序は、Clock → Physics → Constraint → Pocket → Collision → SpeedLimit → Sleep → FiniteGuard → Trail → Print → TerminalRender → EnergyLog → Stats とします。
+/// ポケットの判定は、壁との衝突処理で位置が補正される前に行います。
+/// イベント駆動モードでは、Physics と Collision の代わりに EventDriven を実行します
+/// （`systems.physics` が false の場合は実行せず、`systems.collision` が false の場合は衝突を処理せずにボールを進めます）。SpeedLimit は `max_speed` が指定されている場合のみ、
+/// どちらのモードでも衝突処理の直後に実行します。
+/// `systems` の設定で無効にしたシステムは、パイプラインから取り除かれます。
+/// Trail は `trail_capacity` が 1 以上の場合のみ、Sleep は `sleep_steps` が 1 以上の場合のみ、
+/// TerminalRender は `terminal_render` が指定されている場合のみ、EnergyLog は `systems.log_energy` が true の場合のみ、
+/// Constraint は固定ステップのモードで `constraints` が 1 つ以上ある場合のみ実行します。
+/// 各システムは直前のシステムに依存させて直列に実行するため、`parallel` フィーチャーの有無で
+/// 実行順序や結果が変わることはありません。
+///
+/// # 引数
+/// - `config`: 設定情報
+/// - `pool`: システムを実行するスレッドプール
+pub fn build_dispatcher(config: &Config, pool: &WorkerPool) -> Dispatcher<'static, 'static> {
+    let mut builder = pool
+        .builder()
+        .with(systems::ClockSystem, "clock_system", &[]);
+    // 直前に追加したシステムの名前。各システムはこれに依存させ、順番に実行されるようにします。
+    let mut last = Some("clock_system");
+    // 運動と衝突処理のシステムは、build_settle_dispatcher などと共通の順序で追加します。
+    add_motion_systems(&mut builder, config, config.systems.pockets, &mut last);
+    add_post_systems(&mut builder, config, &mut last);
+    builder.build()
+}
+
+/// 静止するまでボールを落ち着かせる `Simulation::settle` 用に、ボールの運動と衝突処理だけを行う
+/// ディスパッチャを構築する関数です。
+///
+/// build_dispatcher と同じ順序で運動と衝突のシステムを並べ、最後に FiniteGuard を実行します。
+/// 時刻を進める Clock、ボールを取り除く Pocket、記録や出力を行う Sleep・Trail・Print・TerminalRender・EnergyLog・Stats は実行しません。
+///
+/// # 引数
+/// - `config`: 設定情報
+/// - `pool`: システムを実行するスレッドプール
+pub fn build_settle_dispatcher(config: &Config, pool: &WorkerPool) -> Dispatcher<'static, 'static> {
+    let mut builder = pool.builder();
+    let mut last = None;
+    add_motion_systems(&mut builder, config, false, &mut last);
+    chain_system(
+        &mut builder,
+        systems::FiniteGuardSystem,
+        "finite_guard_system",
+        &mut last,
+    );
+    builder.build()
+}
+
+/// 運動と衝突処理の後に実行するシステム（Sleep → FiniteGuard → Trail → Print → TerminalRender → EnergyLog → Stats）を、
+/// 設定で有効なものだけ追加します。
+///
+/// # 引数
+/// - `builder`: システムを追加する DispatcherBuilder
+/// - `config`: 設定情報
+/// - `last`: 直前に追加したシステムの名前（まだない場合は None）。追加するたびに更新します。
+pub(super) fn add_post_systems(
+    builder: &mut DispatcherBuilder<'static, 'static>,
+    config: &Config,
+    last: &mut Option<&'static str>,
+) {
+    let enabled = &config.systems;
+    if config.sleep_steps > 0 {
+        chain_system(builder, systems::SleepSystem, "sleep_system", last);
+    }
+    // 位置・速度の NaN や無限大の検査は、設定によらず常に実行します。
+    chain_system(
+        builder,
+        systems::FiniteGuardSystem,
+        "finite_guard_system",
+        last,
+    );
+    if config.trail_capacity > 0 {
+        chain_system(builder, systems::TrailSystem, "trail_system", last);
+    }
+    if enabled.logging {
+        chain_system(builder, systems::LoggingSystem, "print_system", last);
+    }
+    if let Some(render) = &config.terminal_render {
+        chain_system(
+            builder,
+            systems::TerminalRenderSystem::new(render),
+            "terminal_render_system",
+            last,
+        );
+    }
+    if enabled.log_energy {
+        chain_system(builder, systems::EnergyLogSystem, "energy_log_system", last);
+    }
+    if enabled.stats {
+        chain_system(builder, systems::StatsSystem, "stats_system", last);
+    }
+}
+
+/// ボールを動かすシステムと衝突処理のシステムを、刻み方の設定に応じた順序で追加します。
+///
+/// # 引数
+/// - `builder`: システムを追加する DispatcherBuilder
+/// - `config`: 設定情報
+/// - `pockets`: PocketSystem を追加するかどうか
+/// - `last`: 直前に追加したシステムの名前（まだない場合は None）。追加するたびに更新します。
+pub(super) fn add_motion_systems(
+    builder: &mut DispatcherBuilder<'static, 'static>,
+    config: &Config,
+    pockets: bool,
+    last: &mut Option<&'static str>,
+) {
+    let enabled = &config.systems;
+    match config.stepping {
+        SteppingMode::FixedStep => {
+            if enabled.physics {
+                chain_system(builder, systems::PhysicsSystem, "physics_system", last);
+            }
+            if !config.constraints.is_empty() {
+                chain_system(
+                    builder,
+                    systems::ConstraintSystem,
+                    "constraint_system",
+                    last,
+                );
+            }
+            if pockets {
+                chain_system(builder, systems::PocketSystem, "pocket_system", last);
+            }
+            if enabled.collision {
+                chain_system(
+                    builder,
+                    systems::CollisionSystem::default(),
+                    "collision_system",
+                    last,
+                );
+            }
+            if config.max_speed.is_some() {
+                chain_system(
+                    builder,
+                    systems::SpeedLimitSystem,
+                    "speed_limit_system",
+                    last,
+                );
+            }
+        }
+        SteppingMode::EventDriven => {
+            if enabled.physics {
+                chain_system(
+                    builder,
+                    systems::EventDrivenSystem::new(enabled.collision),
+                    "event_driven_system",
+                    last,
+                );
+            }
+            if config.max_speed.is_some() {
+                chain_system(
+                    builder,
+                    systems::SpeedLimitSystem,
+                    "speed_limit_system",
+                    last,
+                );
+            }
+            if pockets {
+                chain_system(builder, systems::PocketSystem, "pocket_system", last);
+            }
+        }
+    }
+}
+
+/// 直前に追加したシステムに依存させてシステムを追加し、`last` を追加したシステムの名前に更新します。
+pub(super) fn chain_system<T>(
+    builder: &mut DispatcherBuilder<'static, 'static>,
+    system: T,
+    name: &'static str,
+    last: &mut Option<&'static str>,
+) where
+    T: for<'a> System<'a> + Send + 'static,
+{
+    builder.add(system, name, last.as_slice());
+    *last = Some(name);
+}
@@ -0,0 +1,291 @@
+// src/simulation/checkpoint.rs
+//
+// このファイルでは、シミュレーションの途中状態を保存・復元するためのチェックポイントを定義します。
+
+use crate::components::{
+    BallId, BallKind, Pocketed, Pocketing, Position, Render, Sleeping, Spin, StillSteps, Table,
+    Trail, Velocity,
+};
+use crate::resources::{
+    BallRegistry, FiniteGuard, Friction, Gravity, RestitutionOverride, SimClock, SimRng, Stats,
+    WallImpacts,
+};
+use crate::TimeDelta;
+use serde::{Deserialize, Serialize};
+use specs::prelude::*;
+use std::error::Error;
+use std::time::Duration;
+
+/// チェックポイントに保存する、1 つのボールの状態です。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BallCheckpoint {
+    /// ボールの識別番号
+    pub id: BallId,
+    /// ボールの種類（手球か的球か）
+    pub kind: BallKind,
+    /// 位置 x（cm）
+    pub x: f32,
+    /// 位置 y（cm）
+    pub y: f32,
+    /// 速度 x（cm/s、Velocity を持たないボールは 0）
+    pub vx: f32,
+    /// 速度 y（cm/s、Velocity を持たないボールは 0）
+    pub vy: f32,
+    /// 回転 (x, y, z)（rad/s、Spin を持たないボールは None）
+    #[serde(default)]
+    pub spin: Option<(f32, f32, f32)>,
+    /// 軌跡（Trail を持たないボールは空）
+    #[serde(default)]
+    pub trail: Vec<(f32, f32)>,
+    /// 静止が連続したステップ数
+    #[serde(default)]
+    pub still_steps: u32,
+    /// 休止中かどうか
+    #[serde(default)]
+    pub sleeping: bool,
+    /// ポケットへの落下の演出の状態（落下中でないボールは None）。落下中のボールは Velocity を持ちません。
+    #[serde(default)]
+    pub pocketing: Option<Pocketing>,
+    /// 最後に入ったポケットの記録（Pocketed を持たないボールは None）
+    #[serde(default)]
+    pub pocketed: Option<Pocketed>,
+    /// 描画時の半径の倍率（Render を持たないボールは None）
+    #[serde(default)]
+    pub render_scale: Option<f32>,
+}
+
+/// ある時点のシミュレーションの状態を表すチェックポイントです。
+///
+/// 半径や質量、衝突レイヤーなど実行中に変化しない値は設定ファイルから再構築されるため保存せず、
+/// ステップごとに変化する状態（ボールの位置・速度・回転・軌跡・休止状態・ポケットへの落下の状態・描画の倍率、
+/// 経過時間、ステップ数、壁の衝突回数、統計情報、乱数生成器の状態、不正な値による中断の有無）と、
+/// resize_table() で変わるテーブルの寸法、apply_config_patch() で変わる時間刻み・摩擦・重力・反発係数の上書きを保存します。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// 実行済みのステップ数
+    pub step: usize,
+    /// シミュレーション上の経過時間
+    pub clock: SimClock,
+    /// 壁ごとの衝突回数
+    pub wall_impacts: WallImpacts,
+    /// 統計情報
+    pub stats: Stats,
+    /// 乱数生成器の状態
+    #[serde(default)]
+    pub rng: SimRng,
+    /// 不正な値を検出して実行を中断したかどうか
+    #[serde(default)]
+    pub aborted: bool,
+    /// テーブルの横幅と高さ（cm、テーブルがない場合は None）
+    #[serde(default)]
+    pub table: Option<(f32, f32)>,
+    /// 時間刻み（None の場合は、復元先の設定の値のまま）
+    #[serde(default)]
+    pub dt: Option<Duration>,
+    /// 摩擦による減速度（None の場合は、復元先の設定の値のまま）
+    #[serde(default)]
+    pub friction: Option<Friction>,
+    /// 重力による加速度（None の場合は、復元先の設定の値のまま）
+    #[serde(default)]
+    pub gravity: Option<Gravity>,
+    /// 反発係数の一括上書き（None の場合は、復元先の設定の値のまま）
+    #[serde(default)]
+    pub restitution_override: Option<RestitutionOverride>,
+    /// 残っている各ボールの状態（BallId の昇順）
+    pub balls: Vec<BallCheckpoint>,
+}
+
+impl Checkpoint {
+    /// World の現在の状態からチェックポイントを作成します。
+    ///
+    /// # 引数
+    /// - `world`: 保存する World
+    /// - `step`: 実行済みのステップ数
+    pub fn capture(world: &World, step: usize) -> Checkpoint {
+        let pos = world.read_storage::<Position>();
+        let vel = world.read_storage::<Velocity>();
+        let kind = world.read_storage::<BallKind>();
+        let id = world.read_storage::<BallId>();
+        let spin = world.read_storage::<Spin>();
+        let trail = world.read_storage::<Trail>();
+        let still = world.read_storage::<StillSteps>();
+        let sleeping = world.read_storage::<Sleeping>();
+        let pocketing = world.read_storage::<Pocketing>();
+        let pocketed = world.read_storage::<Pocketed>();
+        let render = world.read_storage::<Render>();
+        // ポケットへの落下中のボールは Velocity を持たないため、Velocity は持たない場合も含めて集めます。
+        let mut balls: Vec<BallCheckpoint> = (
+            &pos,
+            vel.maybe(),
+            &id,
+            kind.maybe(),
+            spin.maybe(),
+            trail.maybe(),
+            still.maybe(),
+            sleeping.maybe(),
+            pocketing.maybe(),
+            pocketed.maybe(),
+            render.maybe(),
+        )
+            .join()
+            .map(
+                |(p, v, id, k, s, t, still, sleeping, pocketing, pocketed, render)| {
+                    BallCheckpoint {
+                        id: *id,
+                        kind: k.copied().unwrap_or(BallKind::Object),
+                        x: p.x,
+                        y: p.y,
+                        vx: v.map_or(0.0, |v| v.x),
+                        vy: v.map_or(0.0, |v| v.y),
+                        spin: s.map(|s| (s.x, s.y, s.z)),
+                        trail: t
+                            .map(|t| t.points.iter().copied().collect())
+                            .unwrap_or_default(),
+                        still_steps: still.map_or(0, |s| s.count),
+                        sleeping: sleeping.is_some(),
+                        pocketing: pocketing.copied(),
+                        pocketed: pocketed.copied(),
+                        render_scale: render.map(|r| r.scale),
+                    }
+                },
+            )
+            .collect();
+        balls.sort_by_key(|b| b.id);
+        Checkpoint {
+            step,
+            clock: *world.read_resource::<SimClock>(),
+            wall_impacts: *world.read_resource::<WallImpacts>(),
+            stats: (*world.read_resource::<Stats>()).clone(),
+            rng: *world.read_resource::<SimRng>(),
+            aborted: world.read_resource::<FiniteGuard>().aborted,
+            table: (&world.read_storage::<Table>())
+                .join()
+                .next()
+                .map(|t| (t.width, t.height)),
+            dt: Some(world.read_resource::<TimeDelta>().dt),
+            friction: Some(*world.read_resource::<Friction>()),
+            gravity: Some(*world.read_resource::<Gravity>()),
+            restitution_override: Some(*world.read_resource::<RestitutionOverride>()),
+            balls,
+        }
+    }
+
+    /// チェックポイントの状態を World に反映します。
+    ///
+    /// World は、チェックポイントを保存したときと同じ設定から生成されたものである必要があります。
+    /// 同じ設定からはエンティティが同じ順序で生成されるため、エンティティの ID と BallId の
+    /// 対応も保存時と一致します。チェックポイントに含まれないボール（保存時点までにポケットされたもの）は
+    /// World から削除します。
+    ///
+    /// # 戻り値
+    /// チェックポイントのボールが World に存在しない場合は Error を返します。
+    pub fn restore(&self, world: &mut World) -> Result<(), Box<dyn Error>> {
+        {
+            let entities = world.entities();
+            let mut registry = world.write_resource::<BallRegistry>();
+            let mut pos = world.write_storage::<Position>();
+            let mut vel = world.write_storage::<Velocity>();
+            let mut spin = world.write_storage::<Spin>();
+            let mut trail = world.write_storage::<Trail>();
+            let mut still = world.write_storage::<StillSteps>();
+            let mut sleeping = world.write_storage::<Sleeping>();
+            let mut pocketing = world.write_storage::<Pocketing>();
+            let mut pocketed = world.write_storage::<Pocketed>();
+            let mut render = world.write_storage::<Render>();
+            let ids = world.read_storage::<BallId>();
+
+            // チェックポイントに含まれないボールを削除します。
+            let removed: Vec<(Entity, BallId)> = (&entities, &ids)
+                .join()
+                .filter(|(_, id)| !self.balls.iter().any(|b| b.id == **id))
+                .map(|(ent, id)| (ent, *id))
+                .collect();
+            for (ent, id) in removed {
+                entities.delete(ent)?;
+                registry.remove(id);
+            }
+
+            for b in &self.balls {
+                let entity = registry
+                    .get(b.id)
+                    .filter(|ent| entities.is_alive(*ent))
+                    .ok_or_else(|| format!("Ball {} does not exist in this simulation", b.id.0))?;
+                pos.insert(entity, Position { x: b.x, y: b.y })?;
+                // 落下中のボールは、保存時と同じく Velocity を持たない状態に戻します。
+                match b.pocketing {
+                    Some(p) => {
+                        pocketing.insert(entity, p)?;
+                        vel.remove(entity);
+                    }
+                    None => {
+                        pocketing.remove(entity);
+                        vel.insert(entity, Velocity { x: b.vx, y: b.vy })?;
+                    }
+                }
+                match b.pocketed {
+                    Some(p) => {
+                        pocketed.insert(entity, p)?;
+                    }
+                    None => {
+                        pocketed.remove(entity);
+                    }
+                }
+                match b.render_scale {
+                    Some(scale) => {
+                        render.insert(entity, Render { scale })?;
+                    }
+                    None => {
+                        render.remove(entity);
+                    }
+                }
+                match b.spin {
+                    Some((x, y, z)) => {
+                        spin.insert(entity, Spin { x, y, z })?;
+                    }
+                    None => {
+                        spin.remove(entity);
+                    }
+                }
+                if let Some(t) = trail.get_mut(entity) {
+                    t.points = b.trail.iter().copied().collect();
+                }
+                still.insert(
+                    entity,
+                    StillSteps {
+                        count: b.still_steps,
+                    },
+                )?;
+                if b.sleeping {
+                    sleeping.insert(entity, Sleeping)?;
+                } else {
+                    sleeping.remove(entity);
+                }
+            }
+        }
+        *world.write_resource::<SimClock>() = self.clock;
+        *world.write_resource::<WallImpacts>() = self.wall_impacts;
+        *world.write_resource::<Stats>() = self.stats.clone();
+        *world.write_resource::<SimRng>() = self.rng;
+        world.write_resource::<FiniteGuard>().aborted = self.aborted;
+        if let Some(dt) = self.dt {
+            world.write_resource::<TimeDelta>().dt = dt;
+        }
+        if let Some(friction) = self.friction {
+            *world.write_resource::<Friction>() = friction;
+        }
+        if let Some(gravity) = self.gravity {
+            *world.write_resource::<Gravity>() = gravity;
+        }
+        if let Some(e) = self.restitution_override {
+            *world.write_resource::<RestitutionOverride>() = e;
+        }
+        if let Some((width, height)) = self.table {
+            for table in (&mut world.write_storage::<Table>()).join() {
+                table.width = width;
+                table.height = height;
+            }
+        }
+        world.maintain();
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+// src/simulation/cue_template.rs
+//
+// このファイルでは、ポケットに入った手球をテーブルに置き直す（ボールインハンド）ために、
+// 手球の諸元を保持する CueTemplate を定義します。
+
+use crate::components::{
+    Ball, BallId, BallKind, CollisionMask, Color, Pocketing, Position, Spin, Table, Trail, Velocity,
+};
+use crate::config::{BallInHand, Config};
+use crate::entities::find_free_spot;
+use crate::resources::BallRegistry;
+use specs::prelude::*;
+
+/// 生成した時点の手球の諸元と、置き直す位置を保持する構造体です。
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CueTemplate {
+    /// 手球の BallId
+    pub(crate) id: BallId,
+    /// 置き直す位置（隅を原点とした内部座標、cm）
+    spot: (f32, f32),
+    /// 半径、質量、反発係数
+    ball: Ball,
+    /// 描画に用いる色
+    color: Color,
+    /// 衝突レイヤー（持たない場合は None）
+    mask: Option<CollisionMask>,
+    /// 軌跡として保持する位置の数（0 の場合は軌跡を記録しない）
+    trail_capacity: usize,
+}
+
+impl CueTemplate {
+    /// World にある手球（BallKind::Cue のボール）の諸元を写し取ります。
+    ///
+    /// # 引数
+    /// - `world`: 手球を生成した直後の World
+    /// - `config`: 設定情報（置き直す位置の決め方と軌跡の容量）
+    ///
+    /// # 戻り値
+    /// 手球がない場合や複数ある場合は None を返します。
+    pub(crate) fn capture(world: &World, config: &Config) -> Option<CueTemplate> {
+        let entity = super::find_cue_ball(world).ok()?;
+        let position = *world.read_storage::<Position>().get(entity)?;
+        let spot = match config.ball_in_hand {
+            BallInHand::HeadSpot => config.table.head_spot(config.coordinate_origin),
+            BallInHand::InitialPosition => (position.x, position.y),
+        };
+        Some(CueTemplate {
+            id: *world.read_storage::<BallId>().get(entity)?,
+            spot,
+            ball: *world.read_storage::<Ball>().get(entity)?,
+            color: *world.read_storage::<Color>().get(entity)?,
+            mask: world.read_storage::<CollisionMask>().get(entity).copied(),
+            trail_capacity: config.trail_capacity,
+        })
+    }
+
+    /// 静止した手球を、置き直す位置（他のボールと重なる場合は find_free_spot で探した近くの空いた位置）に
+    /// 生成し、BallRegistry に登録します。
+    ///
+    /// # 引数
+    /// - `world`: 手球がテーブル上にない World
+    ///
+    /// # 戻り値
+    /// 生成した手球のエンティティ
+    pub(crate) fn place(&self, world: &mut World) -> Entity {
+        let (x, y) = {
+            let pos = world.read_storage::<Position>();
+            let ball = world.read_storage::<Ball>();
+            let pocketing = world.read_storage::<Pocketing>();
+            // ポケットへ落ちている途中のボールは、置き直す位置の障害物に含めません。
+            let others: Vec<(f32, f32, f32)> = (&pos, &ball, !&pocketing)
+                .join()
+                .map(|(p, b, _)| (p.x, p.y, b.radius))
+                .collect();
+            let table = world.read_storage::<Table>();
+            match (&table).join().next() {
+                Some(table) => find_free_spot(self.spot, self.ball.radius, &others, table),
+                None => self.spot,
+            }
+        };
+        let mut builder = world
+            .create_entity()
+            .with(Position { x, y })
+            .with(Velocity { x: 0.0, y: 0.0 })
+            .with(self.ball)
+            .with(BallKind::Cue)
+            .with(self.id)
+            .with(self.color)
+            .with(Spin::default());
+        if let Some(mask) = self.mask {
+            builder = builder.with(mask);
+        }
+        if self.trail_capacity > 0 {
+            builder = builder.with(Trail::new(self.trail_capacity));
+        }
+        let entity = builder.build();
+        world
+            .write_resource::<BallRegistry>()
+            .insert(self.id, entity);
+        entity
+    }
+}
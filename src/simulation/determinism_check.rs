@@ -0,0 +1,67 @@
+// src/simulation/determinism_check.rs
+//
+// このファイルでは、同じ設定から 2 回実行した結果が一致するかを確かめる起動時の自己検査を定義します。
+
+use super::Simulation;
+use crate::config::Config;
+use std::error::Error;
+use std::fmt;
+
+/// 同じ設定から 2 回実行した結果が一致しなかったことを表すエラーです。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeterminismMismatch {
+    /// 状態のハッシュ値が最初に一致しなかったステップ番号（0 始まり）
+    pub step: usize,
+    /// 1 回目の実行での状態のハッシュ値
+    pub first: u64,
+    /// 2 回目の実行での状態のハッシュ値
+    pub second: u64,
+}
+
+impl fmt::Display for DeterminismMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "simulation is not deterministic: state hash differs at step {} ({:016x} != {:016x})",
+            self.step, self.first, self.second
+        )
+    }
+}
+
+impl Error for DeterminismMismatch {}
+
+/// 設定から 2 つのシミュレーションを生成して `steps` ステップずつ進め、各ステップ後の
+/// 状態のハッシュ値（Simulation::state_hash）が一致するかを確かめます。
+///
+/// 乱数の種を固定していない機能や、HashMap の走査順に依存する処理などによる非決定性を、
+/// 本番の実行の前に検出するために使います。出力先は登録しないため、ファイルへの書き出しは行いません。
+///
+/// # 引数
+/// - `config`: 設定情報
+/// - `steps`: 進めるステップ数
+///
+/// # 戻り値
+/// すべてのステップで一致した場合は最後のハッシュ値、そうでない場合は最初に一致しなかった箇所を返します。
+/// シミュレーションを生成できない場合は、外側の Error を返します。
+pub fn check_determinism(
+    config: &Config,
+    steps: usize,
+) -> Result<Result<u64, DeterminismMismatch>, Box<dyn Error>> {
+    let mut first = Simulation::new(config)?;
+    let mut second = Simulation::new(config)?;
+    let mut hash = first.state_hash();
+    for step in 0..steps {
+        first.step();
+        second.step();
+        let (a, b) = (first.state_hash(), second.state_hash());
+        if a != b {
+            return Ok(Err(DeterminismMismatch {
+                step,
+                first: a,
+                second: b,
+            }));
+        }
+        hash = a;
+    }
+    Ok(Ok(hash))
+}
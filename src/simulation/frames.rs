@@ -0,0 +1,54 @@
+// src/simulation/frames.rs
+//
+// このファイルでは、シミュレーションを 1 ステップずつ進めながらスナップショットを返すイテレータを定義します。
+
+use super::Simulation;
+use crate::output::WorldSnapshot;
+
+/// Simulation::frames が返すイテレータです。
+///
+/// 1 回の next() でシミュレーションを 1 ステップ進め、そのステップ終了時点のスナップショットを返します。
+/// すべてのボールが静止したステップのスナップショットを返した後、または
+/// 指定したステップ数に達した後は None を返します。
+pub struct Frames {
+    /// 進めるシミュレーション（イテレータが所有します）
+    simulation: Simulation,
+    /// 残りのステップ数
+    remaining: usize,
+    /// すべてのボールが静止したかどうか
+    at_rest: bool,
+}
+
+impl Frames {
+    /// シミュレーションと最大ステップ数から Frames を生成します。
+    pub(crate) fn new(simulation: Simulation, max_steps: usize) -> Frames {
+        Frames {
+            simulation,
+            remaining: max_steps,
+            at_rest: false,
+        }
+    }
+
+    /// イテレータを消費し、内部のシミュレーションを返します。
+    pub fn into_simulation(self) -> Simulation {
+        self.simulation
+    }
+}
+
+impl Iterator for Frames {
+    type Item = WorldSnapshot;
+
+    fn next(&mut self) -> Option<WorldSnapshot> {
+        if self.at_rest || self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let index = self.simulation.steps();
+        self.simulation.step();
+        // 出力先に渡すスナップショットと同じく、実行したステップの番号（0 始まり）を付けます。
+        let mut snapshot = self.simulation.snapshot();
+        snapshot.step = index;
+        self.at_rest = self.simulation.is_at_rest();
+        Some(snapshot)
+    }
+}
@@ -0,0 +1,145 @@
+// src/simulation/invariants.rs
+//
+// このファイルでは、開発時に各ステップの後で物理的な不変条件を検査する関数を定義します。
+
+use crate::components::{Ball, BallId, Pocketing, Position, Table, Velocity};
+use crate::resources::{Friction, SlipFriction, WallImpacts};
+use specs::prelude::*;
+
+/// 不変条件の検査で許容する誤差です。
+#[derive(Debug, Clone, Copy)]
+pub struct InvariantTolerances {
+    /// ボール同士のめり込み、およびボールの壁からのはみ出しの許容量（cm）
+    pub penetration: f32,
+    /// 外力が働かないステップでの、全運動量の変化の許容割合（ステップ開始時の運動量の大きさの総和に対する比）
+    pub momentum: f32,
+}
+
+/// ステップ開始時点の状態です。運動量の保存の検査に使います。
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StepBaseline {
+    /// 全ボールの運動量の総和
+    momentum: (f32, f32),
+    /// 全ボールの運動量の大きさの総和
+    momentum_scale: f32,
+    /// ボールの数
+    balls: usize,
+    /// 壁の衝突回数の合計
+    wall_impacts: u32,
+}
+
+impl StepBaseline {
+    /// World の現在の状態を記録します。
+    pub(crate) fn capture(world: &World) -> StepBaseline {
+        let (momentum, momentum_scale, balls) = total_momentum(world);
+        StepBaseline {
+            momentum,
+            momentum_scale,
+            balls,
+            wall_impacts: world.read_resource::<WallImpacts>().total(),
+        }
+    }
+}
+
+/// 全ボールの運動量の総和、運動量の大きさの総和、ボールの数を返します。
+fn total_momentum(world: &World) -> ((f32, f32), f32, usize) {
+    let vel = world.read_storage::<Velocity>();
+    let ball = world.read_storage::<Ball>();
+    let mut momentum = (0.0, 0.0);
+    let mut scale = 0.0;
+    let mut count = 0;
+    for (v, b) in (&vel, &ball).join() {
+        momentum.0 += b.mass * v.x;
+        momentum.1 += b.mass * v.y;
+        scale += b.mass * (v.x * v.x + v.y * v.y).sqrt();
+        count += 1;
+    }
+    (momentum, scale, count)
+}
+
+/// ステップ後の World が物理的な不変条件を満たしているかを検査し、違反があれば panic します。
+///
+/// 検査する条件は次のとおりです。
+/// - ボール同士が許容量を超えてめり込んでいないこと
+/// - ボールが許容量を超えてテーブルの外にはみ出していないこと
+/// - 壁との衝突・摩擦・ボールの削除がなかったステップでは、ボール同士のインパルスは
+///   作用・反作用で打ち消し合うため、全運動量が変化していないこと
+///
+/// # 引数
+/// - `world`: ステップ実行後の World
+/// - `baseline`: ステップ開始時点の状態
+/// - `tolerances`: 許容する誤差
+pub(crate) fn check_invariants(
+    world: &World,
+    baseline: &StepBaseline,
+    tolerances: &InvariantTolerances,
+) {
+    let pos = world.read_storage::<Position>();
+    let ball = world.read_storage::<Ball>();
+    let id = world.read_storage::<BallId>();
+    let table_storage = world.read_storage::<Table>();
+    let pocketing = world.read_storage::<Pocketing>();
+
+    // ポケットへの落下中のボールは、テーブル上のボールとしては扱いません。
+    let mut balls: Vec<(BallId, Position, f32)> = (&pos, &ball, &id, !&pocketing)
+        .join()
+        .map(|(p, b, id, _)| (*id, *p, b.radius))
+        .collect();
+    balls.sort_by_key(|(id, _, _)| *id);
+
+    // ボール同士のめり込み
+    for i in 0..balls.len() {
+        for j in (i + 1)..balls.len() {
+            let (id_a, pa, ra) = balls[i];
+            let (id_b, pb, rb) = balls[j];
+            let distance = ((pb.x - pa.x).powi(2) + (pb.y - pa.y).powi(2)).sqrt();
+            let depth = ra + rb - distance;
+            if depth > tolerances.penetration {
+                panic!(
+                    "Invariant violated: balls {} and {} interpenetrate by {:.4} cm (tolerance {} cm)",
+                    id_a.0, id_b.0, depth, tolerances.penetration
+                );
+            }
+        }
+    }
+
+    // テーブルからのはみ出し
+    if let Some(table) = (&table_storage).join().next() {
+        for (id, p, r) in &balls {
+            let outside = [
+                r - p.x,
+                p.x + r - table.width,
+                r - p.y,
+                p.y + r - table.height,
+            ]
+            .into_iter()
+            .fold(0.0_f32, f32::max);
+            if outside > tolerances.penetration {
+                panic!(
+                    "Invariant violated: ball {} is outside the table by {:.4} cm at ({}, {})",
+                    id.0, outside, p.x, p.y
+                );
+            }
+        }
+    }
+
+    // 外力が働かなかったステップでの運動量の保存
+    let friction = world.read_resource::<Friction>();
+    let slip = world.read_resource::<SlipFriction>();
+    let no_external = friction.x == 0.0
+        && friction.y == 0.0
+        && slip.mu == 0.0
+        && world.read_resource::<WallImpacts>().total() == baseline.wall_impacts;
+    let (momentum, _, count) = total_momentum(world);
+    if no_external && count == baseline.balls {
+        let dx = momentum.0 - baseline.momentum.0;
+        let dy = momentum.1 - baseline.momentum.1;
+        let change = (dx * dx + dy * dy).sqrt();
+        if change > tolerances.momentum * baseline.momentum_scale.max(f32::EPSILON) {
+            panic!(
+                "Invariant violated: total momentum changed by ({}, {}) without external impulses",
+                dx, dy
+            );
+        }
+    }
+}
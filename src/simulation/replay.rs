@@ -0,0 +1,134 @@
+// src/simulation/replay.rs
+//
+// このファイルでは、記録済みの実行結果（JSON Lines のスナップショット）を読み込み、
+// 同じ設定で再実行した結果と一致するかを検証する関数を定義します。
+
+use super::Simulation;
+use crate::config::Config;
+use crate::output::{BallSnapshot, WorldSnapshot};
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+/// 再実行の結果が記録と一致しなかった最初の箇所を表すエラーです。
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayDivergence {
+    /// 一致しなかったステップ番号
+    pub step: usize,
+    /// 一致しなかった内容の説明
+    pub detail: String,
+}
+
+impl fmt::Display for ReplayDivergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "replay diverged at step {}: {}", self.step, self.detail)
+    }
+}
+
+impl Error for ReplayDivergence {}
+
+/// JsonSink が書き出した JSON Lines のファイルから、記録されたスナップショットの一覧を読み込みます。
+///
+/// # 引数
+/// - `path`: 記録のファイルのパス
+///
+/// # 戻り値
+/// 読み込みに成功した場合はスナップショットの一覧（記録順）、失敗した場合は Error を返します。
+pub fn load_recording(path: &str) -> Result<Vec<WorldSnapshot>, Box<dyn Error>> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut snapshots = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        snapshots.push(serde_json::from_str(&line)?);
+    }
+    Ok(snapshots)
+}
+
+/// 設定からシミュレーションを生成して記録と同じステップ数だけ実行し、各ステップの結果が
+/// 記録と許容誤差の範囲で一致するかを検証します。
+///
+/// 比較するのは、出力先に渡されるものと同じ（LogFilter で絞り込んだ）スナップショットの、
+/// 各ボールの BallId・位置・速度です。記録は JsonSink で書き出したものを想定します。
+///
+/// # 引数
+/// - `config`: 記録したときと同じ設定
+/// - `recording`: 記録されたスナップショットの一覧（load_recording() で読み込んだもの）
+/// - `tolerance`: 位置（cm）と速度（cm/s）の各成分の許容誤差
+///
+/// # 戻り値
+/// すべてのステップが一致した場合は Ok、そうでない場合は最初に一致しなかった箇所を返します。
+/// シミュレーションを生成できない場合は、外側の Error を返します。
+pub fn verify_replay(
+    config: &Config,
+    recording: &[WorldSnapshot],
+    tolerance: f32,
+) -> Result<Result<(), ReplayDivergence>, Box<dyn Error>> {
+    let mut simulation = Simulation::new(config)?;
+    for expected in recording {
+        let step = simulation.steps();
+        simulation.step();
+        let actual = simulation.filtered_snapshot();
+        if let Err(divergence) = compare_snapshot(step, expected, &actual, tolerance) {
+            return Ok(Err(divergence));
+        }
+    }
+    Ok(Ok(()))
+}
+
+/// 1 ステップ分のスナップショットを比較し、一致しない場合はその内容を返します。
+fn compare_snapshot(
+    step: usize,
+    expected: &WorldSnapshot,
+    actual: &WorldSnapshot,
+    tolerance: f32,
+) -> Result<(), ReplayDivergence> {
+    let diverged = |detail: String| ReplayDivergence { step, detail };
+    if expected.step != step {
+        return Err(diverged(format!(
+            "recorded step number is {}",
+            expected.step
+        )));
+    }
+    if expected.balls.len() != actual.balls.len() {
+        return Err(diverged(format!(
+            "recorded {} balls, simulated {}",
+            expected.balls.len(),
+            actual.balls.len()
+        )));
+    }
+    for (e, a) in expected.balls.iter().zip(&actual.balls) {
+        if e.id != a.id {
+            return Err(diverged(format!(
+                "recorded ball {}, simulated ball {}",
+                e.id.0, a.id.0
+            )));
+        }
+        if let Some((name, recorded, simulated)) = first_mismatch(e, a, tolerance) {
+            return Err(diverged(format!(
+                "ball {} {}: recorded {}, simulated {}",
+                e.id.0, name, recorded, simulated
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// 2 つのボールの状態を成分ごとに比較し、許容誤差を超えた最初の成分の名前と値を返します。
+fn first_mismatch(
+    expected: &BallSnapshot,
+    actual: &BallSnapshot,
+    tolerance: f32,
+) -> Option<(&'static str, f32, f32)> {
+    [
+        ("x", expected.x, actual.x),
+        ("y", expected.y, actual.y),
+        ("vx", expected.vx, actual.vx),
+        ("vy", expected.vy, actual.vy),
+    ]
+    .into_iter()
+    .find(|(_, e, a)| (e - a).abs() > tolerance || e.is_nan() != a.is_nan())
+}
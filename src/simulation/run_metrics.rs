@@ -0,0 +1,31 @@
+// src/simulation/run_metrics.rs
+//
+// このファイルでは、1 回の実行全体の集計値をまとめた構造体を定義します。
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Simulation::metrics で取得する、実行全体の集計値です。
+///
+/// 実験の記録やダッシュボードで扱いやすいよう、Simulation::metrics_json で JSON として書き出せます。
+/// 集計値はいずれも合計や個数で、ボールの数で割った値を含まないため、ボールが 1 つもない場合も
+/// 0 や空のマップになります。
+#[derive(Debug, Clone, Serialize)]
+pub struct RunMetrics {
+    /// 実行済みのステップ数
+    pub steps: usize,
+    /// 壁との衝突回数の合計
+    pub wall_collisions: u32,
+    /// ボール同士の衝突回数の合計
+    pub ball_collisions: usize,
+    /// ポケットに入ったボールの数（フットスポットに戻されたものを含みます）
+    pub balls_pocketed: usize,
+    /// 各ボールの累積移動距離（cm）。キーは BallId の番号です。
+    pub distance: BTreeMap<u32, f32>,
+    /// 摩擦や非弾性衝突、ボールの削除によって失われた運動エネルギーの合計（質量の単位 × cm²/s²）
+    pub energy_dissipated: f32,
+    /// ステップの実行に要した実時間の合計（秒）
+    pub wall_clock_seconds: f64,
+    /// 乱数生成器の初期化に使った種
+    pub seed: u64,
+}
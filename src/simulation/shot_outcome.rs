@@ -0,0 +1,21 @@
+// src/simulation/shot_outcome.rs
+//
+// このファイルでは、Simulation::run_shots で撞いた 1 回のショットの結果を表す構造体を定義します。
+
+use crate::components::BallId;
+use crate::output::WorldSnapshot;
+
+/// Simulation::run_shots が返す、1 回のショットの結果です。
+#[derive(Debug, Clone)]
+pub struct ShotOutcome {
+    /// ショットの前に、ポケットに入っていた手球を置き直した場合は true
+    pub cue_replaced: bool,
+    /// 撞いてから静止する（または待つ上限に達する）までに進めたステップ数
+    pub steps: usize,
+    /// すべてのボールが静止した場合は true、`shot_max_steps` に達したか実行が中断された場合は false
+    pub settled: bool,
+    /// このショットの間にポケットに入ったボールの一覧（入った順）
+    pub pocketed: Vec<BallId>,
+    /// ショットの終了時点のスナップショット
+    pub snapshot: WorldSnapshot,
+}
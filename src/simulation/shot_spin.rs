@@ -0,0 +1,43 @@
+// src/simulation/shot_spin.rs
+//
+// このファイルでは、Simulation::shot で手球に与える回転の種類を表す列挙型を定義します。
+
+use serde::{Deserialize, Serialize};
+
+/// 手球を撞くときの撞点（キューが当たる位置）と、それによって生じる回転の種類です。
+///
+/// 設定ファイルの `shots` では `spin: Follow` のように記述します（省略時は Stun）。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ShotSpin {
+    /// 押し球：中心より上を撞き、進行方向に転がる順回転を与えます。
+    /// 的球に当たった後も手球は前に進みます。
+    Follow,
+    /// 引き球：中心より下を撞き、逆回転を与えます。
+    /// 的球に当たった後、手球は手前に戻ります。
+    Draw,
+    /// 止め球：中心を撞き、回転を与えません（既定値）。
+    /// 正面から的球に当たると、手球はその場に止まります。
+    #[default]
+    Stun,
+    /// 左ひねり：中心より左を撞き、鉛直軸まわりの回転（上から見て時計回り）を与えます。
+    Left,
+    /// 右ひねり：中心より右を撞き、鉛直軸まわりの回転（上から見て反時計回り）を与えます。
+    Right,
+}
+
+impl ShotSpin {
+    /// 撞点の中心からのずれを、ボールの半径に対する比で返します。
+    ///
+    /// # 戻り値
+    /// (上下方向のずれ（上が正）, 左右方向のずれ（右が正）) を返します。
+    /// 上下に半径の 2/5 ずらして撞くと、手球は撞いた直後から滑らずに転がります。
+    pub fn tip_offset(self) -> (f32, f32) {
+        match self {
+            ShotSpin::Follow => (0.4, 0.0),
+            ShotSpin::Draw => (-0.4, 0.0),
+            ShotSpin::Stun => (0.0, 0.0),
+            ShotSpin::Left => (0.0, -0.4),
+            ShotSpin::Right => (0.0, 0.4),
+        }
+    }
+}
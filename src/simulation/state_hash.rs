@@ -0,0 +1,56 @@
+// src/simulation/state_hash.rs
+//
+// このファイルでは、回帰の検出に使う、ボールの状態のハッシュ値を計算する関数を定義します。
+
+use crate::components::{BallId, Position, Velocity};
+use specs::prelude::*;
+
+/// ハッシュ値の計算で位置（cm）と速度（cm/s）を丸める単位です。
+/// この値より小さい浮動小数点の誤差は、ハッシュ値に影響しません（丸めの境界をまたぐ場合を除きます）。
+pub const STATE_HASH_RESOLUTION: f32 = 1.0e-3;
+
+/// FNV-1a の初期値
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+/// FNV-1a の乗数
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 全ボールの BallId・位置・速度から、決定的なハッシュ値を計算します。
+///
+/// ボールは BallId の昇順に並べ、位置と速度は STATE_HASH_RESOLUTION 単位に四捨五入した整数として扱います。
+/// ハッシュ関数には、Rust のバージョンや実行環境によらず同じ値になる FNV-1a を用います。
+/// 速度を持たないボール（ポケットへ落下中のもの）は、速度 0 として扱います。
+///
+/// # 引数
+/// - `world`: ハッシュ値を計算する World
+pub(crate) fn state_hash(world: &World) -> u64 {
+    let pos = world.read_storage::<Position>();
+    let vel = world.read_storage::<Velocity>();
+    let id = world.read_storage::<BallId>();
+    let mut balls: Vec<(BallId, [i64; 4])> = (&id, &pos, vel.maybe())
+        .join()
+        .map(|(id, p, v)| {
+            let v = v.copied().unwrap_or(Velocity { x: 0.0, y: 0.0 });
+            (*id, [p.x, p.y, v.x, v.y].map(quantize))
+        })
+        .collect();
+    balls.sort_by_key(|(id, _)| *id);
+
+    let mut hash = FNV_OFFSET_BASIS;
+    for (id, values) in balls {
+        let bytes = u64::from(id.0)
+            .to_le_bytes()
+            .into_iter()
+            .chain(values.into_iter().flat_map(i64::to_le_bytes));
+        for byte in bytes {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+    hash
+}
+
+/// 値を STATE_HASH_RESOLUTION 単位に四捨五入した整数に変換します。
+/// NaN は 0 に、範囲外の値は i64 の上限・下限に丸められます。
+fn quantize(value: f32) -> i64 {
+    (f64::from(value) / f64::from(STATE_HASH_RESOLUTION)).round() as i64
+}
@@ -0,0 +1,18 @@
+// src/simulation/step_report.rs
+//
+// このファイルでは、1 ステップの間に起きたことをまとめた構造体を定義します。
+
+/// Simulation::step が返す、そのステップで起きたことの要約です。
+///
+/// 外部のループから駆動する場合に、何も動かなかったステップの再描画を省くといった判断に使えます。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StepReport {
+    /// いずれかのボールの位置が変化した場合は true
+    pub any_movement: bool,
+    /// このステップで解決したボール同士の衝突の数
+    pub collisions: usize,
+    /// このステップでテーブル上から削除されたボールの数
+    pub balls_removed: usize,
+    /// このステップの運動と衝突処理の分割数（ステップの自動分割を行わない場合は 1）
+    pub substeps: usize,
+}
@@ -0,0 +1,146 @@
+// src/simulation/substepping.rs
+//
+// このファイルでは、速いボールのすり抜けを防ぐため、1 ステップの運動と衝突処理を
+// 自動で決めた回数に分割して実行する Substepping を定義します。
+
+use super::build_dispatcher::{add_motion_systems, add_post_systems, chain_system};
+use super::WorkerPool;
+use crate::components::{Ball, Sleeping, Velocity};
+use crate::config::{AdaptiveSubstepsConfig, Config};
+use crate::resources::{CollisionEvents, PocketEvents};
+use crate::{systems, TimeDelta};
+use specs::prelude::*;
+
+/// 設定で省略された場合の、1 回の分割でボールが進んでよい距離の、最も小さいボールの半径に対する割合です。
+const DEFAULT_MAX_STEP_FRACTION: f32 = 0.5;
+/// 設定で省略された場合の、1 ステップの分割数の上限です。
+const DEFAULT_MAX_SUBSTEPS: usize = 64;
+
+/// 1 ステップの分割数を求める純粋関数です。
+///
+/// 分割数は `ceil(max_speed × dt / (max_step_fraction × min_radius))` を 1 以上 `max_substeps` 以下に
+/// 収めた値です。速さや距離が有限でない場合は、分割しても意味がないため 1 を返します
+/// （不正な値は FiniteGuardSystem が扱います）。
+///
+/// # 引数
+/// - `max_speed`: 最も速いボールの速さ（cm/s）
+/// - `dt`: 1 ステップの時間刻み（秒）
+/// - `min_radius`: 最も小さいボールの半径（cm）
+/// - `max_step_fraction`: 1 回の分割でボールが進んでよい距離の、`min_radius` に対する割合
+/// - `max_substeps`: 分割数の上限
+pub fn substep_count(
+    max_speed: f32,
+    dt: f32,
+    min_radius: f32,
+    max_step_fraction: f32,
+    max_substeps: usize,
+) -> usize {
+    let distance = max_speed * dt;
+    let limit = max_step_fraction * min_radius;
+    if !(distance.is_finite() && limit.is_finite() && limit > 0.0) || distance <= limit {
+        return 1;
+    }
+    ((distance / limit).ceil() as usize).clamp(1, max_substeps.max(1))
+}
+
+/// ステップの自動分割（`adaptive_substeps`）が設定されている場合に、Simulation::step が
+/// 通常のディスパッチャの代わりに使う、3 つに分けたディスパッチャと分割の設定です。
+///
+/// 各ステップでは、Clock を 1 回実行した後、運動と衝突処理のシステムを分割数だけ `dt / 分割数` の
+/// 時間刻みで繰り返し、最後に Sleep 以降のシステムを 1 回実行します。
+/// 分割ごとに記録されるボール同士の衝突とポケットに入った記録は、ステップ全体の記録としてまとめ直します。
+/// ポケットへの落下の演出は分割ごとに 1 ステップ分進みます。
+pub struct Substepping {
+    /// ClockSystem だけを実行するディスパッチャ
+    clock: Dispatcher<'static, 'static>,
+    /// 運動と衝突処理のシステムを実行するディスパッチャ
+    motion: Dispatcher<'static, 'static>,
+    /// Sleep 以降のシステムを実行するディスパッチャ
+    post: Dispatcher<'static, 'static>,
+    /// 1 回の分割でボールが進んでよい距離の、最も小さいボールの半径に対する割合
+    max_step_fraction: f32,
+    /// 1 ステップの分割数の上限
+    max_substeps: usize,
+}
+
+impl Substepping {
+    /// 設定に応じて 3 つのディスパッチャを構築します。
+    ///
+    /// # 引数
+    /// - `config`: 設定情報
+    /// - `adaptive`: ステップの自動分割の設定
+    /// - `pool`: システムを実行するスレッドプール
+    pub fn new(
+        config: &Config,
+        adaptive: &AdaptiveSubstepsConfig,
+        pool: &WorkerPool,
+    ) -> Substepping {
+        let mut clock = pool.builder();
+        chain_system(&mut clock, systems::ClockSystem, "clock_system", &mut None);
+        let mut motion = pool.builder();
+        add_motion_systems(&mut motion, config, config.systems.pockets, &mut None);
+        let mut post = pool.builder();
+        add_post_systems(&mut post, config, &mut None);
+        Substepping {
+            clock: clock.build(),
+            motion: motion.build(),
+            post: post.build(),
+            max_step_fraction: adaptive
+                .max_step_fraction
+                .unwrap_or(DEFAULT_MAX_STEP_FRACTION),
+            max_substeps: adaptive.max_substeps.unwrap_or(DEFAULT_MAX_SUBSTEPS),
+        }
+    }
+
+    /// 1 ステップ分のシステムを実行します。
+    ///
+    /// # 引数
+    /// - `world`: ECS の World
+    ///
+    /// # 戻り値
+    /// このステップの分割数
+    pub fn dispatch(&mut self, world: &mut World) -> usize {
+        self.clock.dispatch(world);
+        let dt = world.read_resource::<TimeDelta>().dt;
+        let substeps = self.substeps_for(world, dt.as_secs_f32());
+        world.write_resource::<TimeDelta>().dt = dt / substeps as u32;
+        let mut collisions = Vec::new();
+        let mut pocketed = Vec::new();
+        for substep in 0..substeps {
+            self.motion.dispatch(world);
+            // 最後の分割で削除したエンティティは、通常のステップと同じく Simulation::step で反映します。
+            if substep + 1 < substeps {
+                world.maintain();
+            }
+            collisions.append(&mut world.write_resource::<CollisionEvents>().pairs);
+            pocketed.append(&mut world.write_resource::<PocketEvents>().events);
+        }
+        world.write_resource::<CollisionEvents>().pairs = collisions;
+        world.write_resource::<PocketEvents>().events = pocketed;
+        world.write_resource::<TimeDelta>().dt = dt;
+        self.post.dispatch(world);
+        substeps
+    }
+
+    /// 現在のボールの速さと半径から、このステップの分割数を求めます。休止中のボールの速さは数えません。
+    fn substeps_for(&self, world: &World, dt: f32) -> usize {
+        let vel = world.read_storage::<Velocity>();
+        let ball = world.read_storage::<Ball>();
+        let sleeping = world.read_storage::<Sleeping>();
+        let max_speed = (&vel, &ball, !&sleeping)
+            .join()
+            .map(|(v, _, _)| (v.x * v.x + v.y * v.y).sqrt())
+            .fold(0.0, f32::max);
+        let min_radius = (&ball)
+            .join()
+            .map(|b| b.radius)
+            .fold(f32::INFINITY, f32::min);
+        substep_count(
+            max_speed,
+            dt,
+            min_radius,
+            self.max_step_fraction,
+            self.max_substeps,
+        )
+    }
+}
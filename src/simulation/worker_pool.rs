@@ -0,0 +1,73 @@
+// src/simulation/worker_pool.rs
+//
+// このファイルでは、Simulation のすべてのディスパッチャで共有するスレッドプールを保持する WorkerPool を定義します。
+
+use specs::prelude::*;
+#[cfg(feature = "parallel")]
+use specs::rayon::{ThreadPool, ThreadPoolBuilder};
+use std::error::Error;
+#[cfg(feature = "parallel")]
+use std::sync::Arc;
+
+/// Simulation のすべてのディスパッチャで共有するスレッドプールです。
+///
+/// `parallel` フィーチャーが有効な場合は、設定の `threads` の数のスレッドを持つ rayon のスレッドプールを
+/// Simulation の生成時に 1 回だけ作り、各ディスパッチャのシステムと、システムの中の並列処理はすべてこのプールで実行します。
+/// 無効な場合はスレッドプールを持たず、すべての処理を呼び出し元のスレッドで実行します。
+#[derive(Clone)]
+pub struct WorkerPool {
+    #[cfg(feature = "parallel")]
+    pool: Arc<ThreadPool>,
+}
+
+impl WorkerPool {
+    /// 指定した数のスレッドを持つスレッドプールを作成します。
+    ///
+    /// # 引数
+    /// - `threads`: スレッドの数。0 の場合は rayon の既定値（CPU のコア数、または環境変数 `RAYON_NUM_THREADS` の値）を使います。
+    ///   `parallel` フィーチャーが無効な場合は使いません。
+    ///
+    /// # 戻り値
+    /// スレッドを起動できないなど、スレッドプールを作成できない場合は Error を返します。
+    pub fn new(threads: usize) -> Result<WorkerPool, Box<dyn Error>> {
+        #[cfg(feature = "parallel")]
+        {
+            let pool = ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .map_err(|e| format!("Failed to build the worker thread pool: {e}"))?;
+            Ok(WorkerPool {
+                pool: Arc::new(pool),
+            })
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            let _ = threads;
+            Ok(WorkerPool {})
+        }
+    }
+
+    /// このスレッドプールを使う、システムを追加する前の DispatcherBuilder を返します。
+    pub fn builder<'a, 'b>(&self) -> DispatcherBuilder<'a, 'b> {
+        #[cfg(feature = "parallel")]
+        {
+            DispatcherBuilder::new().with_pool(Arc::clone(&self.pool))
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            DispatcherBuilder::new()
+        }
+    }
+
+    /// システムを実行するスレッドの数を返します。`parallel` フィーチャーが無効な場合は 1 を返します。
+    pub fn threads(&self) -> usize {
+        #[cfg(feature = "parallel")]
+        {
+            self.pool.current_num_threads()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            1
+        }
+    }
+}
@@ -4,3 +4,25 @@ pub mod physics_system;
 pub use physics_system::PhysicsSystem;
 pub mod logging_system;
 pub use logging_system::LoggingSystem;
+pub mod energy_log_system;
+pub use energy_log_system::EnergyLogSystem;
+pub mod pocket_system;
+pub use pocket_system::PocketSystem;
+pub mod clock_system;
+pub use clock_system::ClockSystem;
+pub mod event_driven_system;
+pub use event_driven_system::EventDrivenSystem;
+pub mod stats_system;
+pub use stats_system::StatsSystem;
+pub mod trail_system;
+pub use trail_system::TrailSystem;
+pub mod finite_guard_system;
+pub use finite_guard_system::FiniteGuardSystem;
+pub mod sleep_system;
+pub use sleep_system::SleepSystem;
+pub mod constraint_system;
+pub use constraint_system::ConstraintSystem;
+pub mod speed_limit_system;
+pub use speed_limit_system::SpeedLimitSystem;
+pub mod terminal_render_system;
+pub use terminal_render_system::TerminalRenderSystem;
@@ -0,0 +1,20 @@
+// src/systems/clock_system.rs
+//
+// このファイルでは、シミュレーション上の経過時間を進める ClockSystem を実装します。
+
+use crate::resources::SimClock;
+use crate::TimeDelta;
+use specs::prelude::*;
+
+/// ClockSystem は、各ステップの先頭で SimClock を dt だけ進め、開始したステップ数を 1 増やします。
+/// 他のシステムは、このステップの終了時点の経過時間と、このステップの番号として SimClock を参照できます。
+pub struct ClockSystem;
+
+impl<'a> System<'a> for ClockSystem {
+    type SystemData = (Write<'a, SimClock>, Read<'a, TimeDelta>);
+
+    fn run(&mut self, (mut clock, time): Self::SystemData) {
+        clock.elapsed += time.dt;
+        clock.steps += 1;
+    }
+}
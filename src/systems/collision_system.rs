@@ -3,15 +3,87 @@
 // このファイルでは、テーブル境界との衝突処理と、
 // ボール同士の衝突判定および反発処理を３つのフェーズに分割して実装します。
 
-use crate::components::{Ball, Position, Table, Velocity};
+use crate::components::{
+    Ball, BallId, BallKind, CollisionMask, CushionSegment, Peg, Position, Sleeping, Spin, Table,
+    Velocity,
+};
+use crate::config::{CollisionPhase, CollisionSolver, ContactModel, TableShape};
+use crate::core_math::{self, Body};
+use crate::events::{BallCollision, Contact};
+use crate::math::color_contacts;
+use crate::resources::{
+    CollisionEvents, CollisionRules, CollisionSettings, ContactManifold, CushionWear,
+    RestitutionOverride, TableDefects, Wall, WallImpacts,
+};
 use specs::prelude::*;
+#[cfg(feature = "parallel")]
+use specs::rayon::prelude::*;
 use specs::Entity;
 
+/// インパルスを与えたボールのペアの記録です。
+/// (エンティティ A, エンティティ B, インパルスの大きさ, A から B の向きの法線, 実効的な反発係数,
+/// 衝突の直前に法線方向に近づく速さ) の順に並びます。
+type ResolvedPair = (Entity, Entity, f32, (f32, f32), f32, f32);
+
+/// ボール同士の衝突判定に使う、1 つのボールの情報です。
+/// (Entity, pos_x, pos_y, vel_x, vel_y, mass, restitution, radius) の順に並びます。
+type BallInfo = (Entity, f32, f32, f32, f32, f32, f32, f32);
+
+/// process_ball_collisions_colored() に渡す関数に求める境界です。`parallel` フィーチャーが有効な場合は
+/// スレッドをまたいで呼び出すため Sync を求め、無効な場合は何も求めません。
+#[cfg(feature = "parallel")]
+trait MaybeSync: Sync {}
+#[cfg(feature = "parallel")]
+impl<T: Sync> MaybeSync for T {}
+#[cfg(not(feature = "parallel"))]
+trait MaybeSync {}
+#[cfg(not(feature = "parallel"))]
+impl<T> MaybeSync for T {}
+
+/// process_ball_collisions_colored() で、1 つのペアについて判定した処理の内容です。
+#[derive(Debug, Clone, Copy)]
+enum PairOutcome {
+    /// 中心がほぼ同一点にあるため、A を -x 方向、B を +x 方向にこの量だけ動かします。
+    Separate(f32),
+    /// A にインパルス (impulse_x, impulse_y) を、B にその逆向きのインパルスを与えます。
+    /// 最後の値は、このペアの衝突に用いた実効的な反発係数です。
+    Impulse(f32, f32, f32),
+}
+
+/// process_table_collisions() に渡す、クッション（テーブルの壁）での跳ね返り方を決める値の組です。
+struct Cushion<F: Fn(f32, Wall, u32) -> f32> {
+    /// ボールの反発係数、壁、その壁のこれまでの衝突回数から、実効的な反発係数を求める関数
+    restitution_of: F,
+    /// 跳ね返るときに壁に沿った速度成分に掛ける係数（1.0 で変化なし）
+    tangential_damping: f32,
+    /// 接点に働く摩擦係数（0 で摩擦なし）
+    friction: f32,
+}
+
 /// CollisionSystem は、各シミュレーションステップにおいて、
-/// 1. テーブル境界との衝突処理、
+/// 1. テーブル境界、固定されたペグ（Peg）および線分のクッション（CushionSegment）との衝突処理、
 /// 2. ボール同士の衝突判定および反発処理（ペアごと、i < j）
-/// を順次実施します。
-pub struct CollisionSystem;
+///    を順次実施します。
+///
+/// 1 と 2 の順序は CollisionSettings の `priority` で決まり、既定値（WallFirst）では上記の順に、
+/// BallFirst ではボール同士の衝突を先に処理します。
+/// 接触の扱い方（`contact_model`）が Spring の場合、2 は行わず、接触情報の記録のみを行います。
+/// CollisionSettings の `solver` が Colored の場合、2 のインパルスの処理を、同じボールを含まない
+/// バッチに分けて並列に行います（結果は Serial と同じです）。めり込みの補正と接点の摩擦は順に処理します。
+///
+/// ボール同士の衝突判定に使う作業用の領域はシステム自身が保持し、毎ステップ空にして再利用するため、
+/// ボールの数が変わらない間はステップごとのメモリの確保が発生しません。
+#[derive(Debug, Default)]
+pub struct CollisionSystem {
+    /// 各ボールの情報（ステップごとに集め直します）
+    ball_info: Vec<BallInfo>,
+    /// 各ペグの位置と諸元（ステップごとに集め直します）
+    pegs: Vec<(Position, Peg)>,
+    /// 各線分のクッション（ステップごとに集め直します）
+    segments: Vec<CushionSegment>,
+    /// インパルスを与えたペアの記録（ステップごとに空にします）
+    resolved: Vec<ResolvedPair>,
+}
 
 impl<'a> System<'a> for CollisionSystem {
     type SystemData = (
@@ -20,15 +92,183 @@ impl<'a> System<'a> for CollisionSystem {
         WriteStorage<'a, Velocity>,
         ReadStorage<'a, Ball>,
         ReadStorage<'a, Table>,
+        ReadStorage<'a, CollisionMask>,
+        Read<'a, CollisionSettings>,
+        Write<'a, WallImpacts>,
+        Read<'a, CushionWear>,
+        Read<'a, RestitutionOverride>,
+        ReadStorage<'a, BallId>,
+        Write<'a, CollisionEvents>,
+        ReadStorage<'a, Sleeping>,
+        ReadStorage<'a, BallKind>,
+        Read<'a, CollisionRules>,
+        ReadStorage<'a, Peg>,
+        Write<'a, ContactManifold>,
+        WriteStorage<'a, Spin>,
+        Read<'a, TableDefects>,
+        ReadStorage<'a, CushionSegment>,
     );
 
-    fn run(&mut self, (entities, mut pos, mut vel, ball, table_storage): Self::SystemData) {
-        // フェーズ1: テーブル（ビリヤード台）の境界との衝突判定と反射処理
-        if let Some(table) = (&table_storage).join().next() {
-            Self::process_table_collisions(&mut pos, &mut vel, &ball, table);
+    fn run(&mut self, data: Self::SystemData) {
+        let (
+            entities,
+            mut pos,
+            mut vel,
+            ball,
+            table_storage,
+            mask,
+            settings,
+            mut impacts,
+            wear,
+            restitution_override,
+            ids,
+            mut events,
+            sleeping,
+            kind,
+            rules,
+            peg,
+            mut manifold,
+            mut spin,
+            defects,
+            segment,
+        ) = data;
+        let table = (&table_storage).join().next();
+        // テーブルの個体差を反映した壁ごとの反発係数を、壁の衝突回数に応じて変化させ、
+        // 一括の上書きを適用したものを使います。
+        let restitution_of = |base: f32, wall: Wall, count: u32| {
+            let base = defects.cushion_restitution(base, wall);
+            restitution_override.apply(wear.effective_restitution(base, count))
+        };
+        // 衝突レイヤーが重ならないペア、種類の組み合わせの規則で無効なペア、
+        // どちらも休止中のペアは判定しません。BallKind を持たないボールは的球として扱います。
+        let kind_of = |e: Entity| kind.get(e).copied().unwrap_or(BallKind::Object);
+        let should_test = |a: Entity, b: Entity| {
+            Self::masks_overlap(mask.get(a), mask.get(b))
+                && rules.allows(kind_of(a), kind_of(b))
+                && !(sleeping.contains(a) && sleeping.contains(b))
+        };
+        // 2 つのボールの反発係数を設定に従って組み合わせ、一括の上書きを適用したものを使います。
+        let pair_restitution =
+            |a: f32, b: f32| restitution_override.apply(settings.restitution_blend.combine(a, b));
+        self.resolved.clear();
+        // 壁とボールの両方に接したボールの結果が毎回同じになるよう、設定された優先順位の順に処理します。
+        for phase in settings.priority.phases() {
+            match phase {
+                // フェーズ1: テーブル（ビリヤード台）の境界との衝突判定と反射処理
+                CollisionPhase::Walls => {
+                    if let Some(table) = table {
+                        Self::process_table_collisions(
+                            &mut pos,
+                            &mut vel,
+                            &mut spin,
+                            &ball,
+                            table,
+                            &mut impacts,
+                            Cushion {
+                                restitution_of,
+                                tangential_damping: settings.tangential_damping,
+                                friction: settings.cushion_friction,
+                            },
+                        );
+                    }
+                    // ペグは動かない障害物のため、壁と同じフェーズで処理します。
+                    self.pegs.clear();
+                    self.pegs
+                        .extend((&pos, &peg).join().map(|(p, peg)| (*p, *peg)));
+                    if !self.pegs.is_empty() {
+                        Self::process_peg_collisions(
+                            &mut pos,
+                            &mut vel,
+                            &ball,
+                            &self.pegs,
+                            settings.epsilon,
+                            pair_restitution,
+                        );
+                    }
+                    // 線分のクッションもペグと同じく動かない障害物のため、同じフェーズで処理します。
+                    self.segments.clear();
+                    self.segments.extend((&segment).join().copied());
+                    if !self.segments.is_empty() {
+                        Self::process_segment_collisions(
+                            &mut pos,
+                            &mut vel,
+                            &ball,
+                            &self.segments,
+                            settings.epsilon,
+                            pair_restitution,
+                        );
+                    }
+                }
+                // フェーズ2および3: ボール同士の衝突判定および反発処理をペアごとに実施
+                CollisionPhase::Balls => {
+                    // 各ボールの情報を作業用の領域に集め直します。
+                    self.ball_info.clear();
+                    self.ball_info
+                        .extend((&entities, &pos, &vel, &ball).join().map(|(ent, p, v, b)| {
+                            (ent, p.x, p.y, v.x, v.y, b.mass, b.restitution, b.radius)
+                        }));
+                    // ばねの接触では、ボール同士の重なりは PhysicsSystem がばねの力で押し戻します。
+                    if let ContactModel::Spring { .. } = settings.contact_model {
+                        continue;
+                    }
+                    match settings.solver {
+                        CollisionSolver::Serial => Self::process_ball_collisions(
+                            &self.ball_info,
+                            &mut pos,
+                            &mut vel,
+                            should_test,
+                            settings.epsilon,
+                            pair_restitution,
+                            &mut self.resolved,
+                        ),
+                        CollisionSolver::Colored => Self::process_ball_collisions_colored(
+                            &self.ball_info,
+                            &mut pos,
+                            &mut vel,
+                            should_test,
+                            settings.epsilon,
+                            pair_restitution,
+                            &mut self.resolved,
+                        ),
+                    }
+                    if settings.ball_friction > 0.0 {
+                        Self::apply_ball_friction(
+                            &self.resolved,
+                            &mut vel,
+                            &mut spin,
+                            &ball,
+                            settings.ball_friction,
+                        );
+                    }
+                    Self::relax_overlaps(
+                        &self.ball_info,
+                        &mut pos,
+                        should_test,
+                        settings.epsilon,
+                        settings.solver_iterations,
+                    );
+                }
+            }
+        }
+        // 解決したペアを、BallId を付けて CollisionEvents に記録します。
+        events.pairs.clear();
+        for &(a, b, impulse, (normal_x, normal_y), effective_restitution, impact_speed) in
+            &self.resolved
+        {
+            if let (Some(id_a), Some(id_b)) = (ids.get(a), ids.get(b)) {
+                events.pairs.push(BallCollision {
+                    id_a: *id_a,
+                    id_b: *id_b,
+                    impulse,
+                    normal_x,
+                    normal_y,
+                    effective_restitution,
+                    impact_speed,
+                });
+            }
         }
-        // フェーズ2および3: ボール同士の衝突判定および反発処理をペアごとに実施
-        Self::process_ball_collisions(&entities, &mut pos, &mut vel, &ball);
+        // 衝突処理の後もめり込んでいるペアを、接触情報として記録します。
+        Self::collect_contacts(&self.ball_info, &pos, &ids, should_test, &mut manifold);
     }
 }
 
@@ -36,81 +276,383 @@ impl CollisionSystem {
     /// 【フェーズ1】
     /// 各ボールについて、テーブル境界との衝突判定と反射処理を行います。
     /// この関数は、各ボールの状態を引数として受け取り、handle_table_collision() という純粋関数を呼び出して結果を反映します。
+    /// 各壁の反発係数は、ボールの反発係数と壁、その壁のこれまでの衝突回数から `cushion.restitution_of` で求めます
+    /// （TableDefects による個体差、CushionWear による変化と RestitutionOverride による上書きを適用したもの）。
+    /// `cushion.friction` が 0 より大きい場合は、跳ね返った壁ごとに apply_cushion_friction() で
+    /// 接点の摩擦による速度と回転の変化を加えます。
     fn process_table_collisions(
         pos: &mut WriteStorage<Position>,
         vel: &mut WriteStorage<Velocity>,
+        spin: &mut WriteStorage<Spin>,
         ball: &ReadStorage<Ball>,
         table: &Table,
+        impacts: &mut WallImpacts,
+        cushion: Cushion<impl Fn(f32, Wall, u32) -> f32>,
     ) {
-        for (p, v, b) in (pos, vel, ball).join() {
+        for (p, v, mut s, b) in (pos, vel, spin.maybe(), ball).join() {
+            // 壁ごとの実効的な反発係数を求めます。
+            let restitution = Wall::ALL
+                .map(|wall| (cushion.restitution_of)(b.restitution, wall, impacts.count(wall)));
             // 純粋関数 handle_table_collision() で新しい位置と速度を計算
-            let (new_pos, new_vel) = Self::handle_table_collision(*p, *v, b, table);
+            let (new_pos, new_vel, hits) = Self::handle_table_collision(
+                *p,
+                *v,
+                b,
+                table,
+                &restitution,
+                cushion.tangential_damping,
+            );
+            let before = *v;
             *p = new_pos;
             *v = new_vel;
+            // 衝突した壁の衝突回数を記録します。
+            for wall in Wall::ALL.into_iter().filter(|wall| hits[wall.index()]) {
+                impacts.record(wall);
+                if cushion.friction > 0.0 {
+                    // 接点の外向きの法線（円形のテーブルでは中心からボールへ向かう向き）
+                    let normal = match table.shape {
+                        TableShape::Circle { .. } => {
+                            let (dx, dy) = (p.x - table.width / 2.0, p.y - table.height / 2.0);
+                            let distance = (dx * dx + dy * dy).sqrt().max(f32::MIN_POSITIVE);
+                            (dx / distance, dy / distance)
+                        }
+                        TableShape::Rectangle => wall.normal(),
+                    };
+                    // 法線方向の力積（質量あたり）は、跳ね返る前後の法線方向の速度の変化です。
+                    let approach = (before.x * normal.0 + before.y * normal.1).max(0.0);
+                    let normal_impulse = (1.0 + restitution[wall.index()]) * approach;
+                    let (new_vel, new_spin) = Self::apply_cushion_friction(
+                        *v,
+                        s.as_deref().copied().unwrap_or_default(),
+                        b.radius,
+                        normal,
+                        normal_impulse,
+                        cushion.friction,
+                    );
+                    *v = new_vel;
+                    if let Some(s) = s.as_deref_mut() {
+                        *s = new_spin;
+                    }
+                }
+            }
+        }
+    }
+
+    /// クッションとの接点の摩擦による速度と回転の変化を計算する純粋関数です。
+    ///
+    /// 考え方は apply_ball_friction() と同じで、クッションは動かない（質量が無限大の）相手として扱います。
+    /// 接点の滑り速度は、接点（ボールの中心から外向きの法線 `normal` の向きに半径の位置）での
+    /// ボールの表面の速度です。力積は成分ごとに滑りを止めるのに必要な量を上限とし、さらに合計の大きさが
+    /// 法線方向の力積 × `friction` を超えないよう同じ比率で縮めます。壁に沿って進むボールには
+    /// 進む向きに応じた横回転が生じ（レールによるひねり）、もとの横回転は壁に沿った速度に変わります。
+    /// 鉛直方向の力積はテーブルが受け止めるものとして、回転のみを変化させます。
+    ///
+    /// # 引数
+    /// - `normal_impulse`: 法線方向の力積を質量で割った値（cm/s）
+    ///
+    /// # 戻り値
+    /// 新しい速度と回転
+    fn apply_cushion_friction(
+        vel: Velocity,
+        spin: Spin,
+        radius: f32,
+        normal: (f32, f32),
+        normal_impulse: f32,
+        friction: f32,
+    ) -> (Velocity, Spin) {
+        let (nx, ny) = normal;
+        // 接線の向き（法線を反時計回りに 90 度回したもの）と、その向きの接点の滑り速度
+        let (tx, ty) = (-ny, nx);
+        let slip = vel.x * tx + vel.y * ty + spin.z * radius;
+        // 鉛直方向（上向き）の接点の滑り速度
+        let slip_vertical = radius * (spin.x * ny - spin.y * nx);
+        // 滑りを止める力積（質量あたり）は、水平方向は並進と回転の両方で滑りが減るため 1/3.5、
+        // 鉛直方向は回転だけで滑りが減るため 1/2.5 を掛けた値になります。
+        let mut j = slip / 3.5;
+        let mut j_vertical = slip_vertical / 2.5;
+        let limit = friction * normal_impulse;
+        let magnitude = (j * j + j_vertical * j_vertical).sqrt();
+        if magnitude > limit {
+            let scale = limit / magnitude;
+            j *= scale;
+            j_vertical *= scale;
+        }
+        let k = 2.5 / radius;
+        (
+            Velocity {
+                x: vel.x - j * tx,
+                y: vel.y - j * ty,
+            },
+            Spin {
+                x: spin.x - k * j_vertical * ny,
+                y: spin.y + k * j_vertical * nx,
+                z: spin.z - k * j,
+            },
+        )
+    }
+
+    /// 【フェーズ1】
+    /// 各ボールについて、固定されたペグとの衝突処理を行います。
+    /// 各ペアの反発係数は、ボールとペグの反発係数から `pair_restitution` で求めます。
+    fn process_peg_collisions(
+        pos: &mut WriteStorage<Position>,
+        vel: &mut WriteStorage<Velocity>,
+        ball: &ReadStorage<Ball>,
+        pegs: &[(Position, Peg)],
+        epsilon: f32,
+        pair_restitution: impl Fn(f32, f32) -> f32,
+    ) {
+        for (p, v, b) in (pos, vel, ball).join() {
+            for (peg_pos, peg) in pegs {
+                let restitution = pair_restitution(b.restitution, peg.restitution);
+                // 純粋関数 handle_peg_collision() で新しい位置と速度を計算
+                let (new_pos, new_vel) =
+                    Self::handle_peg_collision(*p, *v, b, *peg_pos, peg, restitution, epsilon);
+                *p = new_pos;
+                *v = new_vel;
+            }
         }
     }
 
+    /// 固定されたペグとの衝突処理を行う純粋関数
+    /// ペグの質量は無限大とみなし、ボールだけを法線方向に `restitution` で跳ね返します。
+    ///
+    /// ボールがペグにめり込んでいる場合は、中心を結ぶ向きにペグの外側まで押し戻します。
+    /// 中心間距離が `epsilon` 未満の場合、法線は +x 方向に固定します。
+    /// 速度はペグへ近づいている場合のみ反射させ、すでに離れつつある速度は反転させません。
+    fn handle_peg_collision(
+        pos: Position,
+        vel: Velocity,
+        ball: &Ball,
+        peg_pos: Position,
+        peg: &Peg,
+        restitution: f32,
+        epsilon: f32,
+    ) -> (Position, Velocity) {
+        let (dx, dy) = (pos.x - peg_pos.x, pos.y - peg_pos.y);
+        let distance = (dx * dx + dy * dy).sqrt();
+        let min_distance = ball.radius + peg.radius;
+        if distance >= min_distance {
+            return (pos, vel);
+        }
+        // ペグの中心からボールの中心へ向かう法線
+        let (nx, ny) = if distance < epsilon {
+            (1.0, 0.0)
+        } else {
+            (dx / distance, dy / distance)
+        };
+        let new_pos = Position {
+            x: peg_pos.x + nx * min_distance,
+            y: peg_pos.y + ny * min_distance,
+        };
+        let normal_speed = vel.x * nx + vel.y * ny;
+        if normal_speed >= 0.0 {
+            return (new_pos, vel);
+        }
+        let change = (1.0 + restitution) * normal_speed;
+        let new_vel = Velocity {
+            x: vel.x - change * nx,
+            y: vel.y - change * ny,
+        };
+        (new_pos, new_vel)
+    }
+
+    /// 【フェーズ1】
+    /// 各ボールについて、線分のクッションとの衝突処理を行います。
+    /// 各ペアの反発係数は、ボールとクッションの反発係数から `pair_restitution` で求めます。
+    fn process_segment_collisions(
+        pos: &mut WriteStorage<Position>,
+        vel: &mut WriteStorage<Velocity>,
+        ball: &ReadStorage<Ball>,
+        segments: &[CushionSegment],
+        epsilon: f32,
+        pair_restitution: impl Fn(f32, f32) -> f32,
+    ) {
+        for (p, v, b) in (pos, vel, ball).join() {
+            for segment in segments {
+                let restitution = pair_restitution(b.restitution, segment.restitution);
+                // 純粋関数 handle_segment_collision() で新しい位置と速度を計算
+                let (new_pos, new_vel) =
+                    Self::handle_segment_collision(*p, *v, b, segment, restitution, epsilon);
+                *p = new_pos;
+                *v = new_vel;
+            }
+        }
+    }
+
+    /// 線分のクッションとの衝突処理を行う純粋関数
+    /// クッションの質量は無限大とみなし、ボールだけを法線方向に `restitution` で跳ね返します。
+    ///
+    /// 接触の判定は core_math::segment_contact() に委ね、ボールの中心から線分までの距離が半径未満の場合に
+    /// 衝突とみなします。めり込んでいる場合は、中心が線分からちょうど半径の距離になるまで法線方向に押し戻します。
+    /// 速度はクッションへ近づいている場合のみ反射させ、すでに離れつつある速度は反転させません。
+    fn handle_segment_collision(
+        pos: Position,
+        vel: Velocity,
+        ball: &Ball,
+        segment: &CushionSegment,
+        restitution: f32,
+        epsilon: f32,
+    ) -> (Position, Velocity) {
+        let Some((nx, ny, depth)) = core_math::segment_contact(
+            pos.x,
+            pos.y,
+            ball.radius,
+            (segment.start.x, segment.start.y),
+            (segment.end.x, segment.end.y),
+            epsilon,
+        ) else {
+            return (pos, vel);
+        };
+        let new_pos = Position {
+            x: pos.x + nx * depth,
+            y: pos.y + ny * depth,
+        };
+        let normal_speed = vel.x * nx + vel.y * ny;
+        if normal_speed >= 0.0 {
+            return (new_pos, vel);
+        }
+        let change = (1.0 + restitution) * normal_speed;
+        let new_vel = Velocity {
+            x: vel.x - change * nx,
+            y: vel.y - change * ny,
+        };
+        (new_pos, new_vel)
+    }
+
     /// テーブルとの衝突処理を行う純粋関数
     /// 入力値（位置、速度、ボールの諸元、テーブル情報）から、衝突判定を行い、
-    /// 必要に応じて反射処理後の新しい状態を返します。
+    /// 必要に応じて反射処理後の新しい状態と、Wall::index() の順に並べた各壁と衝突したかどうかを返します。
+    /// 衝突した壁は固定長の配列で返すため、ステップごとのメモリの確保は発生しません。
+    ///
+    /// 位置は設定の `coordinate_origin` によらず隅を原点とした座標に正規化されているため、
+    /// 壁は常に 0 と width/height の位置にあるものとして判定します。
+    /// `restitution` は Wall::index() の順に並べた、壁ごとの反発係数です。
+    /// `tangential_damping` は、跳ね返るときに壁に沿った速度成分に掛ける係数です（1.0 で変化なし）。
+    ///
+    /// テーブルの縮小などでボールが最初から壁の外側にある場合も、位置を壁の内側へ押し戻します。
+    /// 速度は壁の外側へ向かっている場合のみ反射させ、すでに内側へ戻りつつある速度は反転させません。
+    /// テーブルの形状が円形（TableShape::Circle）の場合は、handle_circle_collision() で処理します。
     fn handle_table_collision(
         pos: Position,
         vel: Velocity,
         ball: &Ball,
         table: &Table,
-    ) -> (Position, Velocity) {
-        let mut new_pos = pos;
-        let mut new_vel = vel;
-
-        // 左側の壁との衝突
-        if new_pos.x - ball.radius < 0.0 {
-            new_pos.x = ball.radius;
-            new_vel.x = -new_vel.x * ball.restitution;
+        restitution: &[f32; 4],
+        tangential_damping: f32,
+    ) -> (Position, Velocity, [bool; 4]) {
+        if let TableShape::Circle { radius } = table.shape {
+            let center = (table.width / 2.0, table.height / 2.0);
+            return Self::handle_circle_collision(
+                pos,
+                vel,
+                ball,
+                center,
+                radius,
+                restitution,
+                tangential_damping,
+            );
         }
-        // 右側の壁との衝突
-        if new_pos.x + ball.radius > table.width {
-            new_pos.x = table.width - ball.radius;
-            new_vel.x = -new_vel.x * ball.restitution;
-        }
-        // 下側の壁との衝突
-        if new_pos.y - ball.radius < 0.0 {
-            new_pos.y = ball.radius;
-            new_vel.y = -new_vel.y * ball.restitution;
+        let ((x, y), (vx, vy), hit) = core_math::reflect_in_rect(
+            (pos.x, pos.y),
+            (vel.x, vel.y),
+            ball.radius,
+            (table.width, table.height),
+            restitution,
+            tangential_damping,
+        );
+        (Position { x, y }, Velocity { x: vx, y: vy }, hit)
+    }
+
+    /// 円形のクッション（中心 `center`、半径 `radius`）との衝突を処理する純粋関数です。
+    ///
+    /// ボールが円の外にはみ出した場合は円の内側に戻し、外向きに進んでいれば、
+    /// 中心から外向きの法線に沿った速度成分を反転させて反発係数を掛け、接線方向の成分に
+    /// `tangential_damping` を掛けます。衝突回数と反発係数には、接点の向き（法線）に最も近い
+    /// 長方形の壁のものを用います。
+    fn handle_circle_collision(
+        pos: Position,
+        vel: Velocity,
+        ball: &Ball,
+        center: (f32, f32),
+        radius: f32,
+        restitution: &[f32; 4],
+        tangential_damping: f32,
+    ) -> (Position, Velocity, [bool; 4]) {
+        let dx = pos.x - center.0;
+        let dy = pos.y - center.1;
+        let distance = (dx * dx + dy * dy).sqrt();
+        let limit = radius - ball.radius;
+        if distance <= limit || distance == 0.0 {
+            return (pos, vel, [false; 4]);
         }
-        // 上側の壁との衝突
-        if new_pos.y + ball.radius > table.height {
-            new_pos.y = table.height - ball.radius;
-            new_vel.y = -new_vel.y * ball.restitution;
+        // 中心から外向きの法線
+        let nx = dx / distance;
+        let ny = dy / distance;
+        let new_pos = Position {
+            x: center.0 + nx * limit,
+            y: center.1 + ny * limit,
+        };
+        let outward = vel.x * nx + vel.y * ny;
+        if outward <= 0.0 {
+            return (new_pos, vel, [false; 4]);
         }
-
-        (new_pos, new_vel)
+        let wall = Wall::from_normal((nx, ny));
+        // 法線方向と接線方向に分解し、それぞれに反発係数と減衰の係数を掛けます。
+        let tangent = (vel.x - outward * nx, vel.y - outward * ny);
+        let normal = -outward * restitution[wall.index()];
+        let new_vel = Velocity {
+            x: tangent.0 * tangential_damping + normal * nx,
+            y: tangent.1 * tangential_damping + normal * ny,
+        };
+        let mut hit = [false; 4];
+        hit[wall.index()] = true;
+        (new_pos, new_vel, hit)
     }
 
     /// 【フェーズ2 & 3】
     /// ボール同士の衝突判定および反発処理を、すべてのボールについてペアごと（i < j）に実施します。
-    /// 各ボールの情報を収集し、compute_ball_collision_impulse() という純粋関数で各ペアの衝突判定とインパルス計算を行い、
+    /// 事前に集めた各ボールの情報 `ball_info` をもとに、compute_ball_collision_impulse() という純粋関数で各ペアの衝突判定とインパルス計算を行い、
     /// 結果として得られた衝突インパルスを各ボールの速度に反映します。
+    /// `should_test` が false を返すペア（CollisionMask が重ならないペア、CollisionRules で無効なペア、
+    /// どちらも休止中のペア）は
+    /// 衝突判定を行いません。
+    /// 中心間距離が `epsilon` 未満のペアは、separate_coincident() による位置の補正のみを行います。
+    /// 各ペアの反発係数は、2 つのボールの反発係数から `pair_restitution` で求めます
+    /// （RestitutionBlend による組み合わせと RestitutionOverride による上書きを適用したもの）。
+    ///
+    /// インパルスを与えたペアごとの ResolvedPair を、`resolved` に追加します。
     fn process_ball_collisions(
-        entities: &Entities,
+        ball_info: &[BallInfo],
         pos: &mut WriteStorage<Position>,
         vel: &mut WriteStorage<Velocity>,
-        ball: &ReadStorage<Ball>,
+        should_test: impl Fn(Entity, Entity) -> bool,
+        epsilon: f32,
+        pair_restitution: impl Fn(f32, f32) -> f32,
+        resolved: &mut Vec<ResolvedPair>,
     ) {
-        // 以下のブロック内で、pos と vel の不変借用を行い、ball_info を収集する
-        let ball_info: Vec<_> = {
-            let pos_ref = &*pos;
-            let vel_ref = &*vel;
-            (&*entities, pos_ref, vel_ref, ball)
-                .join()
-                .map(|(ent, p, v, b)| (ent, p.x, p.y, v.x, v.y, b.mass, b.restitution, b.radius))
-                .collect()
-        };
-
         // i < j となるように、全ペアについて衝突判定を実施
         for i in 0..ball_info.len() {
             for j in (i + 1)..ball_info.len() {
+                // 衝突レイヤーが重ならないペアはすり抜けるため、判定をスキップします。
+                if !should_test(ball_info[i].0, ball_info[j].0) {
+                    continue;
+                }
+                // 中心がほぼ同一点にある場合は、インパルスを与えずに位置の補正のみで引き離します。
+                if let Some(shift_x) =
+                    Self::separate_coincident(&ball_info[i], &ball_info[j], epsilon)
+                {
+                    if let Some(pa) = pos.get_mut(ball_info[i].0) {
+                        pa.x -= shift_x;
+                    }
+                    if let Some(pb) = pos.get_mut(ball_info[j].0) {
+                        pb.x += shift_x;
+                    }
+                    continue;
+                }
+                let e = pair_restitution(ball_info[i].6, ball_info[j].6);
                 if let Some((impulse_x, impulse_y)) =
-                    Self::compute_ball_collision_impulse(&ball_info[i], &ball_info[j])
+                    Self::compute_ball_collision_impulse(&ball_info[i], &ball_info[j], e, epsilon)
                 {
                     let (entity_a, _, _, _, _, mass_a, _, _) = ball_info[i];
                     let (entity_b, _, _, _, _, mass_b, _, _) = ball_info[j];
@@ -124,55 +666,385 @@ impl CollisionSystem {
                         vb.x -= impulse_x / mass_b;
                         vb.y -= impulse_y / mass_b;
                     }
+                    resolved.extend(Self::resolved_pair(
+                        &ball_info[i],
+                        &ball_info[j],
+                        (impulse_x, impulse_y),
+                        e,
+                    ));
                 }
             }
         }
     }
 
-    /// 【フェーズ2：個々のペアごとの衝突判定】
-    /// ボール A とボール B の情報から、衝突が発生している場合のインパルス（反発）を計算する純粋関数です。
+    /// 【フェーズ2 & 3（バッチに分けた処理）】
+    /// process_ball_collisions() と同じ処理を、衝突しているペアを同じボールを含まないバッチに分けて行います。
     ///
-    /// 入力タプルの内容は次のとおりです:
-    /// - (Entity, pos_x, pos_y, vel_x, vel_y, mass, restitution, radius)
+    /// 1. 各ペアの衝突判定とインパルスの計算を、ボールごとの行に分けて並列に行い、`(i, j)` の昇順に並べます。
+    ///    インパルスは事前に集めた `ball_info` の状態から求めるため、処理の順序によりません。
+    /// 2. 衝突しているペアを color_contacts() でバッチに分けます。
+    /// 3. バッチを前から順に、各バッチの中のペアを並列に処理して、位置と速度の変化を加えます。
     ///
-    /// 衝突している場合、(impulse_x, impulse_y) を返します。
-    /// 衝突していない場合は None を返します。
-    fn compute_ball_collision_impulse(
-        a: &(Entity, f32, f32, f32, f32, f32, f32, f32),
-        b: &(Entity, f32, f32, f32, f32, f32, f32, f32),
-    ) -> Option<(f32, f32)> {
-        // a: (entity, pos_x, pos_y, vel_x, vel_y, mass, restitution, radius)
-        // b: (entity, pos_x, pos_y, vel_x, vel_y, mass, restitution, radius)
-        let dx = b.1 - a.1;
-        let dy = b.2 - a.2;
-        let dist_sq = dx * dx + dy * dy;
-        let radius_sum = a.7 + b.7; // 各ボールの半径の和
+    /// color_contacts() は各ボールを含むペアを `(i, j)` の昇順のままバッチに割り当てるため、
+    /// 各ボールの位置と速度には process_ball_collisions() と同じ順序で同じ変化が加わり、
+    /// 浮動小数点の丸めを含めて結果が完全に一致します。`resolved` への記録の順序も同じです。
+    /// `parallel` フィーチャーが無効な場合は、並列に行う部分も順に処理します。
+    fn process_ball_collisions_colored(
+        ball_info: &[BallInfo],
+        pos: &mut WriteStorage<Position>,
+        vel: &mut WriteStorage<Velocity>,
+        should_test: impl Fn(Entity, Entity) -> bool + MaybeSync,
+        epsilon: f32,
+        pair_restitution: impl Fn(f32, f32) -> f32 + MaybeSync,
+        resolved: &mut Vec<ResolvedPair>,
+    ) {
+        // ボール i と、i より後のボールとのペアを判定します。
+        let detect_row = |i: usize| -> Vec<(usize, usize, PairOutcome)> {
+            ((i + 1)..ball_info.len())
+                .filter(|&j| should_test(ball_info[i].0, ball_info[j].0))
+                .filter_map(|j| {
+                    if let Some(shift_x) =
+                        Self::separate_coincident(&ball_info[i], &ball_info[j], epsilon)
+                    {
+                        return Some((i, j, PairOutcome::Separate(shift_x)));
+                    }
+                    let e = pair_restitution(ball_info[i].6, ball_info[j].6);
+                    Self::compute_ball_collision_impulse(&ball_info[i], &ball_info[j], e, epsilon)
+                        .map(|(impulse_x, impulse_y)| {
+                            (i, j, PairOutcome::Impulse(impulse_x, impulse_y, e))
+                        })
+                })
+                .collect()
+        };
+        #[cfg(feature = "parallel")]
+        let rows: Vec<_> = (0..ball_info.len())
+            .into_par_iter()
+            .map(detect_row)
+            .collect();
+        #[cfg(not(feature = "parallel"))]
+        let rows: Vec<_> = (0..ball_info.len()).map(detect_row).collect();
+        let outcomes: Vec<(usize, usize, PairOutcome)> = rows.into_iter().flatten().collect();
+
+        // 各ボールの (x 座標, x 方向の速度, y 方向の速度) を作業用の値に写し、バッチごとに更新します。
+        let mut states: Vec<(f32, f32, f32)> = ball_info
+            .iter()
+            .map(|&(_, x, _, vx, vy, _, _, _)| (x, vx, vy))
+            .collect();
+        let pairs: Vec<(usize, usize)> = outcomes.iter().map(|&(i, j, _)| (i, j)).collect();
+        for batch in color_contacts(&pairs, ball_info.len()) {
+            // 同じバッチのペアは同じボールを含まないため、更新後の値を互いに干渉せずに求められます。
+            let apply = |&index: &usize| {
+                let (i, j, outcome) = outcomes[index];
+                let (mut a, mut b) = (states[i], states[j]);
+                match outcome {
+                    PairOutcome::Separate(shift_x) => {
+                        a.0 -= shift_x;
+                        b.0 += shift_x;
+                    }
+                    PairOutcome::Impulse(impulse_x, impulse_y, _) => {
+                        let (mass_a, mass_b) = (ball_info[i].5, ball_info[j].5);
+                        a.1 += impulse_x / mass_a;
+                        a.2 += impulse_y / mass_a;
+                        b.1 -= impulse_x / mass_b;
+                        b.2 -= impulse_y / mass_b;
+                    }
+                }
+                (i, a, j, b)
+            };
+            #[cfg(feature = "parallel")]
+            let updates: Vec<_> = batch.par_iter().map(apply).collect();
+            #[cfg(not(feature = "parallel"))]
+            let updates: Vec<_> = batch.iter().map(apply).collect();
+            for (i, a, j, b) in updates {
+                states[i] = a;
+                states[j] = b;
+            }
+        }
 
-        // 衝突していなければ、または完全に重なっている場合は何も返さない
-        if dist_sq >= radius_sum * radius_sum || dist_sq == 0.0 {
+        for (info, &(x, vx, vy)) in ball_info.iter().zip(&states) {
+            if let Some(p) = pos.get_mut(info.0) {
+                p.x = x;
+            }
+            if let Some(v) = vel.get_mut(info.0) {
+                v.x = vx;
+                v.y = vy;
+            }
+        }
+        for &(i, j, outcome) in &outcomes {
+            if let PairOutcome::Impulse(impulse_x, impulse_y, e) = outcome {
+                resolved.extend(Self::resolved_pair(
+                    &ball_info[i],
+                    &ball_info[j],
+                    (impulse_x, impulse_y),
+                    e,
+                ));
+            }
+        }
+    }
+
+    /// A にインパルス `impulse` を与えたペアの ResolvedPair を求めます。インパルスが 0 の場合は None を返します。
+    ///
+    /// # 引数
+    /// - `a`, `b`: 衝突の直前の 2 つのボールの情報
+    /// - `impulse`: A に与えたインパルス (impulse_x, impulse_y)
+    /// - `restitution`: このペアの衝突に用いた実効的な反発係数
+    fn resolved_pair(
+        a: &BallInfo,
+        b: &BallInfo,
+        (impulse_x, impulse_y): (f32, f32),
+        restitution: f32,
+    ) -> Option<ResolvedPair> {
+        // インパルスは法線と逆向きなので、法線はその向きを反転して求めます。
+        let impulse_mag = (impulse_x * impulse_x + impulse_y * impulse_y).sqrt();
+        if impulse_mag <= 0.0 {
             return None;
         }
+        let normal = (-impulse_x / impulse_mag, -impulse_y / impulse_mag);
+        let approach = (a.3 - b.3) * normal.0 + (a.4 - b.4) * normal.1;
+        Some((a.0, b.0, impulse_mag, normal, restitution, approach.abs()))
+    }
 
-        let distance = dist_sq.sqrt();
-        let nx = dx / distance;
-        let ny = dy / distance;
+    /// 【フェーズ3の後処理】
+    /// 法線方向のインパルスを与えた各ペアに、接点の摩擦による接線方向の力積を与えます。
+    ///
+    /// 接点の滑り速度は、接点での 2 つのボールの表面の速度の差（並進の速度 + 回転 × 半径）です。
+    /// 接点は中心の高さにあるため、接線面の水平方向の成分は速度の差の接線成分に鉛直軸まわりの回転
+    /// （Spin の z）を加えたもの、鉛直方向の成分は水平軸まわりの回転（Spin の x, y）によるものになります。
+    /// 力積は成分ごとに滑りを止めるのに必要な量を上限とし、さらに合計の大きさが
+    /// 法線方向の力積 × `friction`（クーロン摩擦）を超えないよう同じ比率で縮めます。
+    /// これにより、回転のないボールをずらして当てると的球が中心を結ぶ線からわずかにそれて進み（スロー）、
+    /// 横回転を持つボールはその回転に応じて的球を押し出し、押し球や引き球ではスローが小さくなります。
+    /// 鉛直方向の力積はテーブルが受け止めるものとして、回転のみを変化させます。
+    /// 回転は均質な球（慣性モーメント 2/5 m r^2）として更新し、Spin を持たないボールは
+    /// 回転なしとして扱って速度のみを更新します。
+    fn apply_ball_friction(
+        resolved: &[ResolvedPair],
+        vel: &mut WriteStorage<Velocity>,
+        spin: &mut WriteStorage<Spin>,
+        ball: &ReadStorage<Ball>,
+        friction: f32,
+    ) {
+        for &(a, b, normal_impulse, (nx, ny), _, _) in resolved {
+            let (Some(ball_a), Some(ball_b)) = (ball.get(a), ball.get(b)) else {
+                continue;
+            };
+            let (Some(va), Some(vb)) = (vel.get(a).copied(), vel.get(b).copied()) else {
+                continue;
+            };
+            let spin_a = spin.get(a).copied().unwrap_or_default();
+            let spin_b = spin.get(b).copied().unwrap_or_default();
+            // 接線の向き（法線を反時計回りに 90 度回したもの）と、その向きの接点の滑り速度
+            let (tx, ty) = (-ny, nx);
+            let slip = (va.x - vb.x) * tx
+                + (va.y - vb.y) * ty
+                + spin_a.z * ball_a.radius
+                + spin_b.z * ball_b.radius;
+            // 鉛直方向（上向き）の接点の滑り速度
+            let slip_vertical = ball_a.radius * (spin_a.x * ny - spin_a.y * nx)
+                + ball_b.radius * (spin_b.x * ny - spin_b.y * nx);
+            if slip == 0.0 && slip_vertical == 0.0 {
+                continue;
+            }
+            // 水平方向は並進と回転の両方で滑りが減るため、滑りを止める力積は 1/m の和の 3.5 倍で割った値、
+            // 鉛直方向は回転だけで滑りが減るため、2.5 倍で割った値になります。
+            let inverse_mass = 1.0 / ball_a.mass + 1.0 / ball_b.mass;
+            let mut j = slip / (3.5 * inverse_mass);
+            let mut j_vertical = slip_vertical / (2.5 * inverse_mass);
+            let limit = friction * normal_impulse;
+            let magnitude = (j * j + j_vertical * j_vertical).sqrt();
+            if magnitude > limit {
+                let scale = limit / magnitude;
+                j *= scale;
+                j_vertical *= scale;
+            }
+            // A には滑りと逆向き、B には滑りの向きに力積を与えます。
+            if let Some(v) = vel.get_mut(a) {
+                v.x -= j * tx / ball_a.mass;
+                v.y -= j * ty / ball_a.mass;
+            }
+            if let Some(v) = vel.get_mut(b) {
+                v.x += j * tx / ball_b.mass;
+                v.y += j * ty / ball_b.mass;
+            }
+            // 接点に働く力のトルクは、どちらのボールでも、水平方向の力積による鉛直軸まわりの -j × radius と、
+            // 鉛直方向の力積による水平軸まわりの j_vertical × radius × (-ny, nx) です。
+            if let Some(s) = spin.get_mut(a) {
+                let k = 2.5 / (ball_a.mass * ball_a.radius);
+                s.x -= k * j_vertical * ny;
+                s.y += k * j_vertical * nx;
+                s.z -= k * j;
+            }
+            if let Some(s) = spin.get_mut(b) {
+                let k = 2.5 / (ball_b.mass * ball_b.radius);
+                s.x -= k * j_vertical * ny;
+                s.y += k * j_vertical * nx;
+                s.z -= k * j;
+            }
+        }
+    }
 
-        // 相対速度（a の速度 - b の速度）
-        let rvx = a.3 - b.3;
-        let rvy = a.4 - b.4;
-        let vel_along_normal = rvx * nx + rvy * ny;
+    /// 【フェーズ3の後処理】
+    /// インパルスを与えた後に残ったボール同士のめり込みを、位置の補正で繰り返し解消します。
+    ///
+    /// 1 回の反復では、めり込んでいるすべてのペア（i < j）を中心を結ぶ向きに引き離し、
+    /// 移動量は質量の逆数の比で 2 つのボールに配分します。あるペアを引き離すと別のペアが
+    /// めり込むことがあるため、めり込みがなくなるか `iterations` 回に達するまで繰り返します。
+    /// 速度は変更しません。`should_test` が false を返すペアと、中心間距離が `epsilon` 未満の
+    /// ペア（separate_coincident() で補正済み）は対象外です。
+    fn relax_overlaps(
+        ball_info: &[BallInfo],
+        pos: &mut WriteStorage<Position>,
+        should_test: impl Fn(Entity, Entity) -> bool,
+        epsilon: f32,
+        iterations: usize,
+    ) {
+        for _ in 0..iterations {
+            let mut any_overlap = false;
+            for i in 0..ball_info.len() {
+                for j in (i + 1)..ball_info.len() {
+                    let (entity_a, _, _, _, _, mass_a, _, radius_a) = ball_info[i];
+                    let (entity_b, _, _, _, _, mass_b, _, radius_b) = ball_info[j];
+                    if !should_test(entity_a, entity_b) {
+                        continue;
+                    }
+                    let (Some(pa), Some(pb)) = (pos.get(entity_a), pos.get(entity_b)) else {
+                        continue;
+                    };
+                    let (dx, dy) = (pb.x - pa.x, pb.y - pa.y);
+                    let distance = (dx * dx + dy * dy).sqrt();
+                    let overlap = radius_a + radius_b - distance;
+                    // 位置が NaN のボールは相手を巻き込まないよう動かさず、FiniteGuardSystem の検査に任せます。
+                    if overlap <= 0.0 || distance < epsilon || overlap.is_nan() {
+                        continue;
+                    }
+                    any_overlap = true;
+                    let (nx, ny) = (dx / distance, dy / distance);
+                    // 軽いボールほど大きく動かします。
+                    let share_a = mass_b / (mass_a + mass_b);
+                    let share_b = 1.0 - share_a;
+                    if let Some(pa) = pos.get_mut(entity_a) {
+                        pa.x -= nx * overlap * share_a;
+                        pa.y -= ny * overlap * share_a;
+                    }
+                    if let Some(pb) = pos.get_mut(entity_b) {
+                        pb.x += nx * overlap * share_b;
+                        pb.y += ny * overlap * share_b;
+                    }
+                }
+            }
+            if !any_overlap {
+                break;
+            }
+        }
+    }
 
-        // すでに分離している場合は何もしない
-        if vel_along_normal > 0.0 {
-            return None;
+    /// 衝突処理を終えた時点の位置から、互いにめり込んでいるボールのペアを `manifold` に記録し直します。
+    ///
+    /// 各ペアは BallId の小さい方を A とし、(A, B) の昇順に並べます。
+    /// `should_test` が false を返すペア（すり抜けるペアなど）は記録しません。
+    fn collect_contacts(
+        ball_info: &[BallInfo],
+        pos: &WriteStorage<Position>,
+        ids: &ReadStorage<BallId>,
+        should_test: impl Fn(Entity, Entity) -> bool,
+        manifold: &mut ContactManifold,
+    ) {
+        manifold.contacts.clear();
+        for i in 0..ball_info.len() {
+            for j in (i + 1)..ball_info.len() {
+                let (entity_i, _, _, _, _, _, _, radius_i) = ball_info[i];
+                let (entity_j, _, _, _, _, _, _, radius_j) = ball_info[j];
+                if !should_test(entity_i, entity_j) {
+                    continue;
+                }
+                let (Some(id_i), Some(id_j)) = (ids.get(entity_i), ids.get(entity_j)) else {
+                    continue;
+                };
+                let (Some(pos_i), Some(pos_j)) = (pos.get(entity_i), pos.get(entity_j)) else {
+                    continue;
+                };
+                // BallId の小さい方を A とします。
+                let ((id_a, pa), (id_b, pb)) = if id_i < id_j {
+                    ((*id_i, pos_i), (*id_j, pos_j))
+                } else {
+                    ((*id_j, pos_j), (*id_i, pos_i))
+                };
+                let (dx, dy) = (pb.x - pa.x, pb.y - pa.y);
+                let distance = (dx * dx + dy * dy).sqrt();
+                let penetration = radius_i + radius_j - distance;
+                if penetration <= 0.0 {
+                    continue;
+                }
+                // 中心がほぼ同一点の場合は、separate_coincident() と同じく +x 方向を法線とします。
+                let (normal_x, normal_y) = if distance > 0.0 {
+                    (dx / distance, dy / distance)
+                } else {
+                    (1.0, 0.0)
+                };
+                manifold.contacts.push(Contact {
+                    id_a,
+                    id_b,
+                    normal_x,
+                    normal_y,
+                    penetration,
+                });
+            }
         }
+        manifold.contacts.sort_by_key(|c| (c.id_a, c.id_b));
+    }
 
-        // 反発係数は両者のうち小さい方を採用
-        let e = a.6.min(b.6);
+    /// 2 つのボールの衝突レイヤーが重なっているか（衝突対象か）を判定する純粋関数です。
+    /// CollisionMask を持たないボールは、すべてのビットが立ったマスクとして扱います。
+    fn masks_overlap(a: Option<&CollisionMask>, b: Option<&CollisionMask>) -> bool {
+        let a = a.copied().unwrap_or_default();
+        let b = b.copied().unwrap_or_default();
+        a.overlaps(b)
+    }
 
-        // インパルスの大きさを計算
-        let impulse_mag = -(1.0 + e) * vel_along_normal / (1.0 / a.5 + 1.0 / b.5);
+    /// 中心間距離が `epsilon` 未満（ほぼ同一点）のペアについて、
+    /// 位置の補正量を計算する純粋関数です。
+    ///
+    /// 法線が定まらないため +x 方向を法線として扱い、重なりの半分ずつ
+    /// A を -x 方向、B を +x 方向に動かす移動量を返します。
+    /// 該当しない場合は None を返します。
+    fn separate_coincident(a: &BallInfo, b: &BallInfo, epsilon: f32) -> Option<f32> {
+        let dx = b.1 - a.1;
+        let dy = b.2 - a.2;
+        let dist_sq = dx * dx + dy * dy;
+        // 位置が NaN のボールは相手を巻き込まないよう補正せず、FiniteGuardSystem の検査に任せます。
+        if dist_sq >= epsilon * epsilon || dist_sq.is_nan() {
+            return None;
+        }
+        // 重なり量（半径の和 - 中心間距離）の半分ずつ動かします。
+        let overlap = a.7 + b.7 - dist_sq.sqrt();
+        Some(overlap / 2.0)
+    }
 
-        Some((impulse_mag * nx, impulse_mag * ny))
+    /// 【フェーズ2：個々のペアごとの衝突判定】
+    /// ボール A とボール B の情報から、衝突が発生している場合のインパルス（反発）を計算する純粋関数です。
+    ///
+    /// 入力タプルの内容は次のとおりです:
+    /// - (Entity, pos_x, pos_y, vel_x, vel_y, mass, restitution, radius)
+    ///
+    /// `restitution` は、このペアの衝突に用いる実効的な反発係数です。
+    ///
+    /// 衝突している場合、(impulse_x, impulse_y) を返します。
+    /// 衝突していない場合、または中心間距離が `epsilon` 未満で法線が定まらない場合は None を返します。
+    fn compute_ball_collision_impulse(
+        a: &BallInfo,
+        b: &BallInfo,
+        restitution: f32,
+        epsilon: f32,
+    ) -> Option<(f32, f32)> {
+        // 計算は specs に依存しない core_math::ball_collision_impulse() に委ねます。
+        let body = |info: &BallInfo| Body {
+            x: info.1,
+            y: info.2,
+            vx: info.3,
+            vy: info.4,
+            mass: info.5,
+            radius: info.7,
+        };
+        core_math::ball_collision_impulse(&body(a), &body(b), restitution, epsilon)
     }
 }
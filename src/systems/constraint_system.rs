@@ -0,0 +1,72 @@
+// src/systems/constraint_system.rs
+//
+// このファイルでは、距離の拘束（Constraint）でつながれたボールにばねの力を与える ConstraintSystem を実装します。
+
+use crate::components::{Ball, Position, Velocity};
+use crate::resources::Constraints;
+use crate::TimeDelta;
+use specs::prelude::*;
+
+/// ConstraintSystem は、Constraints に登録された各拘束について、つながれた 2 つのボールに
+/// 中心間距離のずれに比例したばねの力を与え、速度を更新します。
+/// どちらかのボールが削除されている（ポケットされた）拘束は無視します。
+pub struct ConstraintSystem;
+
+impl<'a> System<'a> for ConstraintSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadStorage<'a, Position>,
+        WriteStorage<'a, Velocity>,
+        ReadStorage<'a, Ball>,
+        Read<'a, Constraints>,
+        Read<'a, TimeDelta>,
+    );
+
+    fn run(&mut self, (entities, pos, mut vel, ball, constraints, time): Self::SystemData) {
+        let dt = time.dt.as_secs_f32();
+        for c in &constraints.list {
+            if !entities.is_alive(c.a) || !entities.is_alive(c.b) {
+                continue;
+            }
+            let (Some(pa), Some(pb), Some(ba), Some(bb)) =
+                (pos.get(c.a), pos.get(c.b), ball.get(c.a), ball.get(c.b))
+            else {
+                continue;
+            };
+            let Some((fx, fy)) = Self::spring_force(*pa, *pb, c.rest_length, c.stiffness) else {
+                continue;
+            };
+            // A には力を、B にはその反作用を与えます。
+            if let Some(va) = vel.get_mut(c.a) {
+                va.x += fx / ba.mass * dt;
+                va.y += fy / ba.mass * dt;
+            }
+            if let Some(vb) = vel.get_mut(c.b) {
+                vb.x -= fx / bb.mass * dt;
+                vb.y -= fy / bb.mass * dt;
+            }
+        }
+    }
+}
+
+impl ConstraintSystem {
+    /// ボール A がばねから受ける力 (fx, fy) を求める純粋関数です。
+    ///
+    /// 中心間距離が `rest_length` より長ければ B に向かう向き、短ければ B から離れる向きの力になります。
+    /// 2 つの中心が重なっていて向きが定まらない場合は None を返します。
+    fn spring_force(
+        a: Position,
+        b: Position,
+        rest_length: f32,
+        stiffness: f32,
+    ) -> Option<(f32, f32)> {
+        let dx = b.x - a.x;
+        let dy = b.y - a.y;
+        let distance = (dx * dx + dy * dy).sqrt();
+        if distance == 0.0 {
+            return None;
+        }
+        let magnitude = stiffness * (distance - rest_length);
+        Some((magnitude * dx / distance, magnitude * dy / distance))
+    }
+}
@@ -0,0 +1,31 @@
+// src/systems/energy_log_system.rs
+//
+// このファイルでは、全ボールの運動エネルギーの合計を 1 ステップにつき 1 行だけログ出力する
+// EnergyLogSystem を実装します。
+
+use crate::components::{Ball, Velocity};
+use crate::resources::SimClock;
+use specs::prelude::*;
+use tracing::info;
+
+/// EnergyLogSystem は、全ボールの運動エネルギーの合計（質量の単位 × cm²/s²）を、
+/// シミュレーション上の経過時間とともに `kinetic_energy` フィールドとしてログ出力します。
+/// ボールごとの位置を出力する LoggingSystem とは独立しており、長い実行で静止に向かう様子を
+/// 少ない出力量で確かめるのに使います。回転のエネルギーは含みません。
+pub struct EnergyLogSystem;
+
+impl<'a> System<'a> for EnergyLogSystem {
+    type SystemData = (
+        ReadStorage<'a, Velocity>,
+        ReadStorage<'a, Ball>,
+        Read<'a, SimClock>,
+    );
+
+    fn run(&mut self, (vel, ball, clock): Self::SystemData) {
+        let kinetic_energy: f32 = (&vel, &ball)
+            .join()
+            .map(|(v, b)| 0.5 * b.mass * (v.x * v.x + v.y * v.y))
+            .sum();
+        info!(kinetic_energy, "t={:.2}s", clock.elapsed.as_secs_f32());
+    }
+}
@@ -0,0 +1,395 @@
+// src/systems/event_driven_system.rs
+//
+// このファイルでは、固定の時間刻みで積分する代わりに、次の衝突時刻まで正確に進めて
+// 衝突を解決することを繰り返す、イベント駆動型のシミュレーション（EventDrivenSystem）を実装します。
+
+use crate::components::{Ball, BallId, BallKind, CollisionMask, Position, Table, Velocity};
+use crate::config::TableShape;
+use crate::events::{BallCollision, CollisionEvent};
+use crate::math::{next_event, MovingBall};
+use crate::resources::{
+    CollisionEvents, CollisionRules, CollisionSettings, CushionWear, RestitutionOverride,
+    TableDefects, Wall, WallImpacts,
+};
+use crate::TimeDelta;
+use specs::prelude::*;
+use std::collections::{HashMap, HashSet};
+use tracing::warn;
+
+/// EventDrivenSystem は、1 ステップ（dt）の間に起きる衝突を時刻順に厳密に処理します。
+///
+/// 摩擦のない等速直線運動を前提としており、衝突と衝突の間は位置を解析的に進めるため、
+/// すり抜け（トンネリング）や積分誤差が生じません。
+/// PhysicsSystem と CollisionSystem の代わりに使います（摩擦は考慮しません）。
+/// 円形のテーブル（TableShape::Circle）では、円形のクッションとの衝突時刻を求めて接点の法線について反射させます。
+///
+/// 1 ステップ内で 1 つのボールが関与した衝突の数が
+/// `CollisionSettings::max_events_per_second × dt` を超えた場合、そのボールを凍結（速度 0）し、
+/// 非弾性崩壊（時間の進まない衝突の無限の繰り返し）を防ぎます。
+/// 凍結したボールはそのステップの残りの間、動かない障害物（質量が無限大）として扱い、
+/// ぶつかったボールだけを跳ね返すため、凍結が周りのボールへ連鎖することはありません。
+///
+/// 衝突処理を無効にした場合（`systems.collision` が false）は、固定ステップの場合と同じく
+/// 壁やほかのボールとの衝突を判定せず、すべてのボールを dt だけ等速直線運動させます。
+pub struct EventDrivenSystem {
+    /// 壁やボール同士の衝突を処理するかどうか
+    collisions: bool,
+}
+
+impl EventDrivenSystem {
+    /// EventDrivenSystem を作成します。
+    ///
+    /// # 引数
+    /// - `collisions`: 壁やボール同士の衝突を処理するかどうか（設定の `systems.collision`）
+    pub fn new(collisions: bool) -> EventDrivenSystem {
+        EventDrivenSystem { collisions }
+    }
+}
+
+impl<'a> System<'a> for EventDrivenSystem {
+    type SystemData = (
+        Entities<'a>,
+        WriteStorage<'a, Position>,
+        WriteStorage<'a, Velocity>,
+        ReadStorage<'a, Ball>,
+        ReadStorage<'a, BallId>,
+        ReadStorage<'a, CollisionMask>,
+        ReadStorage<'a, BallKind>,
+        ReadStorage<'a, Table>,
+        Read<'a, TimeDelta>,
+        Write<'a, WallImpacts>,
+        Read<'a, CushionWear>,
+        Read<'a, CollisionSettings>,
+        Read<'a, RestitutionOverride>,
+        Write<'a, CollisionEvents>,
+        Read<'a, TableDefects>,
+        Read<'a, CollisionRules>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (
+            entities,
+            mut pos,
+            mut vel,
+            ball,
+            id,
+            mask,
+            kind,
+            table_storage,
+            time,
+            mut impacts,
+            wear,
+            settings,
+            restitution_override,
+            mut events,
+            defects,
+            rules,
+        ) = data;
+        events.pairs.clear();
+        // 2 つのボールの反発係数を設定に従って組み合わせ、一括の上書きを適用したものを使います。
+        let pair_restitution =
+            |a: f32, b: f32| restitution_override.apply(settings.restitution_blend.combine(a, b));
+        let table = (&table_storage).join().next();
+        let dt = time.dt.as_secs_f32();
+        if !self.collisions {
+            Self::advance(&mut pos, &vel, dt);
+            return;
+        }
+        // テーブルの寸法の変更などで境界の外側にあるボールは、CollisionSystem と同じく先に内側へ押し戻します。
+        // 外へ向かう速度は、直後の時刻 0 の壁との衝突で反射されます。
+        if let Some(table) = table {
+            Self::push_inside(&mut pos, &ball, table);
+        }
+        let mut remaining = dt;
+        // このステップで各ボールが関与した衝突の数と、その上限
+        let mut event_counts: HashMap<BallId, usize> = HashMap::new();
+        let max_events = (settings.max_events_per_second * dt).ceil().max(1.0) as usize;
+        // このステップで凍結したボール
+        let mut frozen: HashSet<BallId> = HashSet::new();
+
+        loop {
+            // 現在の状態を BallId 順に収集します。
+            let mut balls: Vec<(Entity, MovingBall)> = (
+                &entities,
+                &pos,
+                &vel,
+                &ball,
+                &id,
+                mask.maybe(),
+                kind.maybe(),
+            )
+                .join()
+                .map(|(ent, p, v, b, id, m, k)| {
+                    let moving = MovingBall {
+                        id: *id,
+                        pos: *p,
+                        vel: *v,
+                        radius: b.radius,
+                        mask: m.copied().unwrap_or_default(),
+                        kind: k.copied().unwrap_or(BallKind::Object),
+                    };
+                    (ent, moving)
+                })
+                .collect();
+            balls.sort_by_key(|(_, b)| b.id);
+            let moving: Vec<MovingBall> = balls.iter().map(|(_, b)| *b).collect();
+
+            match next_event(&moving, table, &rules) {
+                // このステップ内に衝突が起きる場合は、衝突時刻まで進めて衝突を解決します。
+                Some((t, event)) if t <= remaining => {
+                    Self::advance(&mut pos, &vel, t);
+                    let entity_of = |ball_id: BallId| {
+                        balls
+                            .iter()
+                            .find(|(_, b)| b.id == ball_id)
+                            .map(|(ent, _)| *ent)
+                    };
+                    remaining -= t;
+                    // 衝突の上限を超えたボールは凍結します。凍結したボールを含む衝突も、
+                    // 凍結したボールを動かない障害物として解決し、同じ衝突が繰り返し現れないようにします。
+                    for ball_id in Self::participants(event) {
+                        if frozen.contains(&ball_id) {
+                            continue;
+                        }
+                        let count = event_counts.entry(ball_id).or_insert(0);
+                        *count += 1;
+                        if *count > max_events {
+                            if let Some(v) = entity_of(ball_id).and_then(|ent| vel.get_mut(ent)) {
+                                *v = Velocity { x: 0.0, y: 0.0 };
+                            }
+                            warn!(
+                                "Ball {} exceeded {} collision events in one step; freezing it",
+                                ball_id.0, max_events
+                            );
+                            frozen.insert(ball_id);
+                        }
+                    }
+                    match event {
+                        CollisionEvent::Wall {
+                            ball: ball_id,
+                            wall,
+                            normal,
+                            ..
+                        } => {
+                            // 凍結したボールは止まっているため、壁との衝突を解決する必要はありません。
+                            if frozen.contains(&ball_id) {
+                                continue;
+                            }
+                            if let Some(ent) = entity_of(ball_id) {
+                                // 反発係数は CollisionSystem と同じく、テーブルの個体差、クッションの変化、
+                                // 一括の上書きの順に適用します。
+                                let base = ball.get(ent).map_or(1.0, |b| b.restitution);
+                                let base = defects.cushion_restitution(base, wall);
+                                let e = restitution_override
+                                    .apply(wear.effective_restitution(base, impacts.count(wall)));
+                                let circle = table
+                                    .is_some_and(|t| matches!(t.shape, TableShape::Circle { .. }));
+                                if let Some(v) = vel.get_mut(ent) {
+                                    *v = if circle {
+                                        Self::reflect_off_circle(
+                                            *v,
+                                            normal,
+                                            e,
+                                            settings.tangential_damping,
+                                        )
+                                    } else {
+                                        Self::reflect_off_wall(
+                                            *v,
+                                            wall,
+                                            e,
+                                            settings.tangential_damping,
+                                        )
+                                    };
+                                }
+                                impacts.record(wall);
+                            }
+                        }
+                        CollisionEvent::Ball { a, b, .. } => {
+                            if let (Some(ea), Some(eb)) = (entity_of(a), entity_of(b)) {
+                                if let Some((
+                                    impulse,
+                                    (normal_x, normal_y),
+                                    effective_restitution,
+                                    impact_speed,
+                                )) = Self::resolve_ball_pair(
+                                    ea,
+                                    eb,
+                                    &pos,
+                                    &mut vel,
+                                    &ball,
+                                    (frozen.contains(&a), frozen.contains(&b)),
+                                    pair_restitution,
+                                ) {
+                                    events.pairs.push(BallCollision {
+                                        id_a: a,
+                                        id_b: b,
+                                        impulse,
+                                        normal_x,
+                                        normal_y,
+                                        effective_restitution,
+                                        impact_speed,
+                                    });
+                                }
+                            }
+                        }
+                    }
+                }
+                // 衝突が起きない場合は、残りの時間だけ進めて終了します。
+                _ => {
+                    Self::advance(&mut pos, &vel, remaining);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+impl EventDrivenSystem {
+    /// 衝突イベントに関与したボールの一覧を返します。
+    fn participants(event: CollisionEvent) -> Vec<BallId> {
+        match event {
+            CollisionEvent::Wall { ball, .. } => vec![ball],
+            CollisionEvent::Ball { a, b, .. } => vec![a, b],
+        }
+    }
+
+    /// すべてのボールを、現在の速度で時間 t だけ等速直線運動させます。
+    fn advance(pos: &mut WriteStorage<Position>, vel: &WriteStorage<Velocity>, t: f32) {
+        for (p, v) in (pos, vel).join() {
+            p.x += v.x * t;
+            p.y += v.y * t;
+        }
+    }
+
+    /// テーブルの境界の外側にはみ出したボールを、クッションの内側に接する位置まで押し戻します。
+    /// 円形のテーブルでは、中心からボールへ向かう方向に沿って戻します。
+    fn push_inside(pos: &mut WriteStorage<Position>, ball: &ReadStorage<Ball>, table: &Table) {
+        for (p, b) in (pos, ball).join() {
+            match table.shape {
+                TableShape::Rectangle => {
+                    p.x = p.x.min(table.width - b.radius).max(b.radius);
+                    p.y = p.y.min(table.height - b.radius).max(b.radius);
+                }
+                TableShape::Circle { radius: rim } => {
+                    let (cx, cy) = (table.width / 2.0, table.height / 2.0);
+                    let (dx, dy) = (p.x - cx, p.y - cy);
+                    let distance = (dx * dx + dy * dy).sqrt();
+                    let limit = rim - b.radius;
+                    if distance > limit && distance > 0.0 {
+                        p.x = cx + dx / distance * limit;
+                        p.y = cy + dy / distance * limit;
+                    }
+                }
+            }
+        }
+    }
+
+    /// 壁に接触したボールの速度を、壁の法線方向について反射させる純粋関数です。
+    /// 反射後の法線方向の速度は、必ず壁から離れる向き（テーブルの内側）になります。
+    /// 壁に沿った速度成分には `tangential_damping` を掛けます。
+    fn reflect_off_wall(
+        vel: Velocity,
+        wall: Wall,
+        restitution: f32,
+        tangential_damping: f32,
+    ) -> Velocity {
+        match wall {
+            Wall::Left => Velocity {
+                x: vel.x.abs() * restitution,
+                y: vel.y * tangential_damping,
+            },
+            Wall::Right => Velocity {
+                x: -vel.x.abs() * restitution,
+                y: vel.y * tangential_damping,
+            },
+            Wall::Bottom => Velocity {
+                x: vel.x * tangential_damping,
+                y: vel.y.abs() * restitution,
+            },
+            Wall::Top => Velocity {
+                x: vel.x * tangential_damping,
+                y: -vel.y.abs() * restitution,
+            },
+        }
+    }
+
+    /// 円形のクッションに接触したボールの速度を、接点の外向きの法線 `normal` について反射させる純粋関数です。
+    /// CollisionSystem の円形のテーブルの処理と同じく、法線方向の成分に `restitution` を、
+    /// 接線方向の成分に `tangential_damping` を掛けます。すでに内側へ向かっている速度は変えません。
+    fn reflect_off_circle(
+        vel: Velocity,
+        normal: (f32, f32),
+        restitution: f32,
+        tangential_damping: f32,
+    ) -> Velocity {
+        let (nx, ny) = normal;
+        let outward = vel.x * nx + vel.y * ny;
+        if outward <= 0.0 {
+            return vel;
+        }
+        let tangent = (vel.x - outward * nx, vel.y - outward * ny);
+        let normal_speed = -outward * restitution;
+        Velocity {
+            x: tangent.0 * tangential_damping + normal_speed * nx,
+            y: tangent.1 * tangential_damping + normal_speed * ny,
+        }
+    }
+
+    /// 接触している 2 つのボールの間で、法線方向のインパルスをやり取りさせます。
+    /// 反発係数は、2 つのボールの反発係数から `pair_restitution` で求めます（CollisionSystem と同じ規則）。
+    /// `frozen` は (A, B) がそれぞれ凍結しているかどうかで、凍結したボールは質量が無限大であるものとして動かしません。
+    ///
+    /// インパルスを与えた場合は、その大きさ、A から B の向きの法線、用いた反発係数、衝突の直前に近づく速さを返します。
+    fn resolve_ball_pair(
+        a: Entity,
+        b: Entity,
+        pos: &WriteStorage<Position>,
+        vel: &mut WriteStorage<Velocity>,
+        ball: &ReadStorage<Ball>,
+        frozen: (bool, bool),
+        pair_restitution: impl Fn(f32, f32) -> f32,
+    ) -> Option<(f32, (f32, f32), f32, f32)> {
+        let (Some(pa), Some(pb), Some(ba), Some(bb)) =
+            (pos.get(a), pos.get(b), ball.get(a), ball.get(b))
+        else {
+            return None;
+        };
+        let (Some(va), Some(vb)) = (vel.get(a).copied(), vel.get(b).copied()) else {
+            return None;
+        };
+        let dx = pb.x - pa.x;
+        let dy = pb.y - pa.y;
+        let distance = (dx * dx + dy * dy).sqrt();
+        if distance == 0.0 {
+            return None;
+        }
+        // A から B の向きの法線
+        let nx = dx / distance;
+        let ny = dy / distance;
+        // 法線方向の接近速度（正なら近づいている）
+        let approach = (va.x - vb.x) * nx + (va.y - vb.y) * ny;
+        if approach <= 0.0 {
+            return None;
+        }
+        // 凍結したボールの質量の逆数は 0 とします。
+        let inverse_mass = |m: f32, frozen: bool| if frozen { 0.0 } else { 1.0 / m };
+        let (inv_a, inv_b) = (
+            inverse_mass(ba.mass, frozen.0),
+            inverse_mass(bb.mass, frozen.1),
+        );
+        if inv_a + inv_b == 0.0 {
+            return None;
+        }
+        let e = pair_restitution(ba.restitution, bb.restitution);
+        let impulse = (1.0 + e) * approach / (inv_a + inv_b);
+        if let Some(v) = vel.get_mut(a).filter(|_| !frozen.0) {
+            v.x -= impulse * nx / ba.mass;
+            v.y -= impulse * ny / ba.mass;
+        }
+        if let Some(v) = vel.get_mut(b).filter(|_| !frozen.1) {
+            v.x += impulse * nx / bb.mass;
+            v.y += impulse * ny / bb.mass;
+        }
+        Some((impulse, (nx, ny), e, approach))
+    }
+}
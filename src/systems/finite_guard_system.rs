@@ -0,0 +1,57 @@
+// src/systems/finite_guard_system.rs
+//
+// このファイルでは、位置・速度に NaN や無限大が含まれていないかを検査する FiniteGuardSystem を実装します。
+
+use crate::components::{BallId, Position, Table, Velocity};
+use crate::config::NonFinitePolicy;
+use crate::resources::FiniteGuard;
+use specs::prelude::*;
+use tracing::error;
+
+/// FiniteGuardSystem は、各ボールの位置・速度に NaN や無限大が含まれていないかを検査します。
+///
+/// 不正な値が一度現れると以降のすべてのステップへ波及するため、検出した時点でエラーを記録し、
+/// `FiniteGuard::policy` に従って値を安全なものに戻すか、実行の中断を要求します。
+pub struct FiniteGuardSystem;
+
+impl<'a> System<'a> for FiniteGuardSystem {
+    type SystemData = (
+        WriteStorage<'a, Position>,
+        WriteStorage<'a, Velocity>,
+        ReadStorage<'a, BallId>,
+        ReadStorage<'a, Table>,
+        Write<'a, FiniteGuard>,
+    );
+
+    fn run(&mut self, (mut pos, mut vel, id, table_storage, mut guard): Self::SystemData) {
+        // 位置を戻す先として、テーブルの中央を用います。
+        let center = (&table_storage)
+            .join()
+            .next()
+            .map(|t| (t.width / 2.0, t.height / 2.0))
+            .unwrap_or((0.0, 0.0));
+
+        for (p, v, id) in (&mut pos, &mut vel, &id).join() {
+            let position_ok = p.x.is_finite() && p.y.is_finite();
+            let velocity_ok = v.x.is_finite() && v.y.is_finite();
+            if position_ok && velocity_ok {
+                continue;
+            }
+            error!(
+                "Ball {} has non-finite state: position ({}, {}), velocity ({}, {})",
+                id.0, p.x, p.y, v.x, v.y
+            );
+            match guard.policy {
+                NonFinitePolicy::Reset => {
+                    if !position_ok {
+                        p.x = center.0;
+                        p.y = center.1;
+                    }
+                    v.x = 0.0;
+                    v.y = 0.0;
+                }
+                NonFinitePolicy::Abort => guard.aborted = true,
+            }
+        }
+    }
+}
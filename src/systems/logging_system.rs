@@ -3,20 +3,37 @@
 // このファイルでは、各エンティティ（ボール）の現在の位置を
 // tracing クレートを用いたログ出力により表示する PrintSystem を実装します。
 
-use crate::components::{Ball, Position};
+use crate::components::{Ball, BallId, BallKind, Position};
+use crate::config::LogFilter;
+use crate::resources::SimClock;
 use specs::prelude::*;
 use tracing::info;
 
-/// PrintSystem は、各ボールの位置情報をログ出力します。
+/// PrintSystem は、各ボールの位置情報をシミュレーション上の経過時間とともにログ出力します。
+/// LogFilter に一致しないボールは出力しません。
 pub struct LoggingSystem;
 
 impl<'a> System<'a> for LoggingSystem {
-    type SystemData = (ReadStorage<'a, Position>, ReadStorage<'a, Ball>);
+    type SystemData = (
+        ReadStorage<'a, Position>,
+        ReadStorage<'a, Ball>,
+        ReadStorage<'a, BallId>,
+        ReadStorage<'a, BallKind>,
+        Read<'a, SimClock>,
+        Read<'a, LogFilter>,
+    );
 
-    fn run(&mut self, (pos, ball): Self::SystemData) {
+    fn run(&mut self, (pos, ball, id, kind, clock, filter): Self::SystemData) {
+        let t = clock.elapsed.as_secs_f32();
         // Position と Ball コンポーネントを持つすべてのエンティティについて位置をログ出力します。
-        for (pos, _ball) in (&pos, &ball).join() {
-            info!("Ball position: ({:.2}, {:.2})", pos.x, pos.y);
+        for (pos, _ball, id, kind) in (&pos, &ball, &id, kind.maybe()).join() {
+            if !filter.matches(*id, kind.copied().unwrap_or(BallKind::Object)) {
+                continue;
+            }
+            info!(
+                "t={:.2}s Ball {} position: ({:.2}, {:.2})",
+                t, id.0, pos.x, pos.y
+            );
         }
     }
 }
@@ -2,26 +2,258 @@
 //
 // このファイルでは、各エンティティの速度情報をもとに位置を更新する物理シミュレーション（PhysicsSystem）を実装します。
 
-use crate::components::{Position, Velocity};
+use crate::components::{Ball, BallKind, CollisionMask, Position, Sleeping, Spin, Velocity};
+use crate::config::ContactModel;
+use crate::core_math::{self, Body};
+use crate::resources::{
+    CollisionRules, CollisionSettings, Friction, FrictionRegions, Gravity, LowSpeedDamping,
+    SlipFriction,
+};
 use crate::TimeDelta;
 use specs::prelude::*;
 
-/// PhysicsSystem は、各エンティティの位置を速度に基づいて更新します。
+/// PhysicsSystem は、各エンティティの速度に重力（Gravity）による加速を加え、摩擦により減速させたうえで、
+/// 位置を速度に基づいて更新します。
+///
+/// 滑り摩擦（SlipFriction）が設定されている場合、回転（Spin）を持つボールは、
+/// 接点が滑っている間は滑り摩擦によって並進と回転を同時に変化させ、
+/// 転がり状態に達した後は転がり摩擦（Friction）で減速しながら回転を並進に合わせます。
+/// 摩擦が異なる領域（FrictionRegions）の内側にあるボールには、転がり摩擦と滑り摩擦の両方に
+/// その領域の倍率を掛けます。
+/// 低速時の追加の減速（LowSpeedDamping）が設定されている場合は、摩擦を適用した後の速さが
+/// しきい値未満のボールをさらに減速させます。
+/// 休止中（Sleeping）のボールは更新しません。
+///
+/// 接触の扱い方（`CollisionSettings::contact_model`）が Spring の場合は、摩擦を適用した後に
+/// 1 ステップを `contact_substeps` 回に分割し、分割した時間ごとに重なったボール同士のばねの力で
+/// 速度を変えてから位置を進めます。休止中のボールは動かない障害物として力を受けません。
 pub struct PhysicsSystem;
 
+/// ばねの接触の計算に使う、1 つのボールのエンティティ、状態、休止中でないかどうか、
+/// 衝突レイヤー、種類の組です。
+type SpringBody = (Entity, Body, bool, CollisionMask, BallKind);
+
 impl<'a> System<'a> for PhysicsSystem {
     type SystemData = (
         WriteStorage<'a, Position>,
-        ReadStorage<'a, Velocity>,
+        WriteStorage<'a, Velocity>,
+        WriteStorage<'a, Spin>,
+        ReadStorage<'a, Ball>,
         Read<'a, TimeDelta>,
+        Read<'a, Friction>,
+        Read<'a, SlipFriction>,
+        ReadStorage<'a, Sleeping>,
+        Read<'a, Gravity>,
+        Read<'a, LowSpeedDamping>,
+        Read<'a, FrictionRegions>,
+        Entities<'a>,
+        Read<'a, CollisionSettings>,
+        ReadStorage<'a, CollisionMask>,
+        ReadStorage<'a, BallKind>,
+        Read<'a, CollisionRules>,
     );
 
-    fn run(&mut self, (mut pos, vel, time): Self::SystemData) {
+    fn run(&mut self, data: Self::SystemData) {
+        let (
+            mut pos,
+            mut vel,
+            mut spin,
+            ball,
+            time,
+            base_friction,
+            slip,
+            sleeping,
+            gravity,
+            low_speed,
+            regions,
+            entities,
+            settings,
+            mask,
+            kind,
+            rules,
+        ) = data;
+        // ばねの接触を使う場合、ボールの位置は摩擦の適用後に分割した時間ごとに進めます。
+        let spring = match settings.contact_model {
+            ContactModel::Spring { stiffness, damping } => Some((stiffness, damping)),
+            ContactModel::Impulse => None,
+        };
         let dt = time.dt.as_secs_f32();
-        // オイラー法により、すべての対象エンティティの位置を更新します。
-        for (pos, vel) in (&mut pos, &vel).join() {
-            pos.x += vel.x * dt;
-            pos.y += vel.y * dt;
+        let mu_g = slip.mu * SlipFriction::GRAVITY;
+        // 摩擦で速度を更新してから、オイラー法によりすべての対象エンティティの位置を更新します。
+        for (pos, vel, spin, ball, ()) in (
+            &mut pos,
+            &mut vel,
+            (&mut spin).maybe(),
+            ball.maybe(),
+            !&sleeping,
+        )
+            .join()
+        {
+            // 重力による加速は、摩擦による減速の前に加えます。
+            vel.x += gravity.x * dt;
+            vel.y += gravity.y * dt;
+            // ボールの中心がある位置の倍率を、転がり摩擦と滑り摩擦に掛けます。
+            let multiplier = regions.multiplier_at(pos.x, pos.y);
+            let friction = Friction {
+                x: base_friction.x * multiplier,
+                y: base_friction.y * multiplier,
+            };
+            let mu_g = mu_g * multiplier;
+            match (spin, ball) {
+                (Some(spin), Some(ball)) if mu_g > 0.0 => {
+                    // 滑っている間は滑り摩擦、転がり始めた後の残りの時間は転がり摩擦を適用します。
+                    let (new_vel, new_spin, slip_time) =
+                        Self::apply_slip_friction(*vel, *spin, ball.radius, mu_g, dt);
+                    *vel = Self::apply_friction(new_vel, &friction, dt - slip_time);
+                    *spin = if slip_time < dt {
+                        Self::rolling_spin(*vel, new_spin, ball.radius)
+                    } else {
+                        new_spin
+                    };
+                }
+                _ => *vel = Self::apply_friction(*vel, &friction, dt),
+            }
+            *vel = Self::apply_low_speed_damping(*vel, &low_speed, dt);
+            if spring.is_none() || ball.is_none() {
+                pos.x += vel.x * dt;
+                pos.y += vel.y * dt;
+            }
+        }
+
+        if let Some((stiffness, damping)) = spring {
+            // BallKind を持たないボールは的球として扱います。
+            let mut bodies: Vec<SpringBody> = (&entities, &pos, &vel, &ball)
+                .join()
+                .map(|(ent, p, v, b)| {
+                    let body = Body {
+                        x: p.x,
+                        y: p.y,
+                        vx: v.x,
+                        vy: v.y,
+                        mass: b.mass,
+                        radius: b.radius,
+                    };
+                    let mask = mask.get(ent).copied().unwrap_or_default();
+                    let kind = kind.get(ent).copied().unwrap_or(BallKind::Object);
+                    (ent, body, !sleeping.contains(ent), mask, kind)
+                })
+                .collect();
+            let substeps = settings.contact_substeps.max(1);
+            let h = dt / substeps as f32;
+            for _ in 0..substeps {
+                Self::spring_substep(&mut bodies, stiffness, damping, &settings, &rules, h);
+            }
+            for (ent, body, awake, _, _) in &bodies {
+                if !awake {
+                    continue;
+                }
+                if let (Some(p), Some(v)) = (pos.get_mut(*ent), vel.get_mut(*ent)) {
+                    *p = Position {
+                        x: body.x,
+                        y: body.y,
+                    };
+                    *v = Velocity {
+                        x: body.vx,
+                        y: body.vy,
+                    };
+                }
+            }
+        }
+    }
+}
+
+impl PhysicsSystem {
+    /// 分割した時間 `h` の間、重なったボール同士にばねの力を働かせて速度を変え、位置を進めます。
+    ///
+    /// 力は分割した時間の始めの位置と速度から求め、半陰的オイラー法（速度を更新してから位置を更新）で積分します。
+    /// 衝突レイヤーが重ならないペアと、種類の組み合わせの規則で無効なペアには力を働かせません。
+    fn spring_substep(
+        bodies: &mut [SpringBody],
+        stiffness: f32,
+        damping: f32,
+        settings: &CollisionSettings,
+        rules: &CollisionRules,
+        h: f32,
+    ) {
+        let mut forces = vec![(0.0f32, 0.0f32); bodies.len()];
+        for i in 0..bodies.len() {
+            for j in (i + 1)..bodies.len() {
+                let (_, a, _, mask_a, kind_a) = bodies[i];
+                let (_, b, _, mask_b, kind_b) = bodies[j];
+                if !mask_a.overlaps(mask_b) || !rules.allows(kind_a, kind_b) {
+                    continue;
+                }
+                if let Some((fx, fy)) =
+                    core_math::spring_contact_force(&a, &b, stiffness, damping, settings.epsilon)
+                {
+                    forces[i].0 -= fx;
+                    forces[i].1 -= fy;
+                    forces[j].0 += fx;
+                    forces[j].1 += fy;
+                }
+            }
+        }
+        for ((_, body, awake, _, _), (fx, fy)) in bodies.iter_mut().zip(forces) {
+            if !*awake {
+                continue;
+            }
+            body.vx += fx / body.mass * h;
+            body.vy += fy / body.mass * h;
+            body.x += body.vx * h;
+            body.y += body.vy * h;
         }
     }
+
+    /// 摩擦による減速を適用した新しい速度を返します。計算は core_math::apply_friction() に委ねます。
+    fn apply_friction(vel: Velocity, friction: &Friction, dt: f32) -> Velocity {
+        let (x, y) = core_math::apply_friction((vel.x, vel.y), (friction.x, friction.y), dt);
+        Velocity { x, y }
+    }
+
+    /// 速さが `damping.threshold` 未満の場合に減速を加えた新しい速度を返します。
+    /// 計算は core_math::apply_low_speed_damping() に委ねます。
+    fn apply_low_speed_damping(vel: Velocity, damping: &LowSpeedDamping, dt: f32) -> Velocity {
+        let (x, y) = core_math::apply_low_speed_damping(
+            (vel.x, vel.y),
+            damping.threshold,
+            damping.deceleration,
+            dt,
+        );
+        Velocity { x, y }
+    }
+
+    /// 滑り摩擦による並進と回転の変化を計算します。計算は core_math::apply_slip_friction() に委ねます。
+    ///
+    /// # 戻り値
+    /// 新しい速度と回転、および滑っていた時間（dt 以下、最初から転がっている場合は 0）を返します。
+    fn apply_slip_friction(
+        vel: Velocity,
+        spin: Spin,
+        radius: f32,
+        mu_g: f32,
+        dt: f32,
+    ) -> (Velocity, Spin, f32) {
+        let ((vx, vy), (sx, sy, sz), t) = core_math::apply_slip_friction(
+            (vel.x, vel.y),
+            (spin.x, spin.y, spin.z),
+            radius,
+            mu_g,
+            dt,
+        );
+        (
+            Velocity { x: vx, y: vy },
+            Spin {
+                x: sx,
+                y: sy,
+                z: sz,
+            },
+            t,
+        )
+    }
+
+    /// 速度に対して滑らずに転がる回転を返します。計算は core_math::rolling_spin() に委ねます。
+    fn rolling_spin(vel: Velocity, spin: Spin, radius: f32) -> Spin {
+        let (x, y, z) = core_math::rolling_spin((vel.x, vel.y), (spin.x, spin.y, spin.z), radius);
+        Spin { x, y, z }
+    }
 }
@@ -0,0 +1,238 @@
+// src/systems/pocket_system.rs
+//
+// このファイルでは、ボールがポケットに入ったかを判定し、
+// ポケットされたボールを削除（またはフットスポットに戻す）PocketSystem を実装します。
+
+use crate::components::{
+    Ball, BallId, BallKind, Pocket, Pocketed, Pocketing, Position, Render, Table, Velocity,
+};
+use crate::entities::find_free_spot;
+use crate::events::PocketEvent;
+use crate::resources::{BallRegistry, PocketEvents, PocketRules, SimClock, Stats};
+use specs::prelude::*;
+use tracing::info;
+
+/// PocketSystem は、中心がポケットの内側に入ったボールを処理します。
+/// - 入ったポケットの番号（設定の順番）とステップの番号を、PocketEvents に記録し、
+///   ボールに Pocketed として付けてから、以下の処理を行います。
+///   複数のポケットの内側にある場合は中心が最も近いポケットに、距離が等しい場合は番号の小さいポケットに
+///   入ったものとします。
+/// - 通常はボールのエンティティを削除します。
+/// - `PocketRules::respot` が true の場合、的球はフットスポット（または最寄りの空き位置）に戻し、
+///   速度を 0 にします。手球は常に削除します。
+/// - `PocketRules::drop_steps` が 1 以上の場合、削除するボールには Pocketing を付け、
+///   そのステップ数をかけてポケットの中心へ引き寄せながら縮めてから削除します。
+pub struct PocketSystem;
+
+impl<'a> System<'a> for PocketSystem {
+    type SystemData = (
+        Entities<'a>,
+        WriteStorage<'a, Position>,
+        WriteStorage<'a, Velocity>,
+        ReadStorage<'a, Ball>,
+        ReadStorage<'a, BallKind>,
+        ReadStorage<'a, BallId>,
+        ReadStorage<'a, Pocket>,
+        ReadStorage<'a, Table>,
+        Read<'a, PocketRules>,
+        Write<'a, BallRegistry>,
+        WriteStorage<'a, Pocketing>,
+        WriteStorage<'a, Render>,
+        Write<'a, Stats>,
+        WriteStorage<'a, Pocketed>,
+        Write<'a, PocketEvents>,
+        Read<'a, SimClock>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (
+            entities,
+            mut pos,
+            mut vel,
+            ball,
+            kind,
+            id,
+            pocket,
+            table_storage,
+            rules,
+            mut registry,
+            mut pocketing,
+            mut render,
+            mut stats,
+            mut marked,
+            mut pocket_events,
+            clock,
+        ) = data;
+        pocket_events.events.clear();
+        // 落下中のボールを進め、落ちきったものを削除します。
+        Self::advance_drops(
+            &entities,
+            &mut pos,
+            &id,
+            &mut pocketing,
+            &mut render,
+            &mut registry,
+        );
+
+        // 各ポケットの (x, y, radius, index) を、ポケットの番号の順に収集します。
+        let mut pockets: Vec<(f32, f32, f32, usize)> = (&pos, &pocket)
+            .join()
+            .map(|(p, k)| (p.x, p.y, k.radius, k.index))
+            .collect();
+        pockets.sort_by_key(|&(_, _, _, index)| index);
+        if pockets.is_empty() {
+            return;
+        }
+
+        // ポケットに入ったボールと、そのポケットの番号と中心を収集します（落下中のボールは除きます）。
+        // 同じステップで複数のボールが入った場合もログと削除の順序が毎回同じになるよう、
+        // ストレージの走査順ではなく BallId の順に処理します（BallId を持たないボールは最後）。
+        let mut pocketed: Vec<(Entity, usize, (f32, f32))> = (&entities, &pos, &ball, !&pocketing)
+            .join()
+            .filter_map(|(ent, p, _, _)| {
+                Self::containing_pocket(p, &pockets).map(|(index, center)| (ent, index, center))
+            })
+            .collect();
+        pocketed.sort_by_key(|&(entity, _, _)| Self::ball_order(&id, entity));
+
+        let table = (&table_storage).join().next();
+        stats.pocketed += pocketed.len();
+        let step = clock.current_step();
+        for (entity, index, center) in pocketed {
+            let ball_id = id.get(entity).copied();
+            let label = ball_id.map_or(0, |id| id.0);
+            if let Some(ball_id) = ball_id {
+                pocket_events.events.push(PocketEvent {
+                    id: ball_id,
+                    pocket: index,
+                    step,
+                });
+            }
+            let _ = marked.insert(
+                entity,
+                Pocketed {
+                    pocket: index,
+                    step,
+                },
+            );
+            let is_object = kind.get(entity) == Some(&BallKind::Object);
+            match (rules.respot && is_object, table, ball.get(entity)) {
+                (true, Some(table), Some(b)) => {
+                    // 自分以外のボールの (x, y, radius) を収集し、重ならない位置を探します。
+                    let others: Vec<(f32, f32, f32)> = (&entities, &pos, &ball)
+                        .join()
+                        .filter(|(ent, _, _)| *ent != entity)
+                        .map(|(_, p, b)| (p.x, p.y, b.radius))
+                        .collect();
+                    let (x, y) = find_free_spot(rules.foot_spot, b.radius, &others, table);
+                    if let Some(p) = pos.get_mut(entity) {
+                        *p = Position { x, y };
+                    }
+                    if let Some(v) = vel.get_mut(entity) {
+                        *v = Velocity { x: 0.0, y: 0.0 };
+                    }
+                    info!(
+                        "Ball {} respotted from pocket {}: ({:.2}, {:.2})",
+                        label, index, x, y
+                    );
+                }
+                _ if rules.drop_steps > 0 => {
+                    // 速度を取り除いて移動と衝突の対象から外し、落下の演出を始めます。
+                    vel.remove(entity);
+                    let _ = pocketing.insert(
+                        entity,
+                        Pocketing {
+                            target: center,
+                            remaining: rules.drop_steps,
+                            duration: rules.drop_steps,
+                        },
+                    );
+                    let _ = render.insert(entity, Render::default());
+                    info!("Ball {} dropping into pocket {}", label, index);
+                }
+                _ => {
+                    // エンティティの削除は world.maintain() の時点で反映されます。
+                    if let Some(p) = pos.get(entity) {
+                        info!(
+                            "Ball {} pocketed into pocket {}: ({:.2}, {:.2})",
+                            label, index, p.x, p.y
+                        );
+                    }
+                    // BallId とエンティティの対応表からも取り除きます。
+                    if let Some(ball_id) = ball_id {
+                        registry.remove(ball_id);
+                    }
+                    let _ = entities.delete(entity);
+                }
+            }
+        }
+    }
+}
+
+impl PocketSystem {
+    /// 同じステップで処理するボールを並べるためのキーを返します。
+    /// BallId の昇順に並び、BallId を持たないボールは最後になります。
+    fn ball_order(id: &ReadStorage<BallId>, entity: Entity) -> (bool, Option<BallId>) {
+        let ball_id = id.get(entity).copied();
+        (ball_id.is_none(), ball_id)
+    }
+
+    /// ボールの中心を内側に含むポケットのうち、中心が最も近いものを探し、その番号と中心を返す純粋関数です。
+    /// 距離が等しいポケットが複数ある場合は、`pockets` の並び順によらず番号の小さいポケットを選びます。
+    /// どのポケットにも入っていない場合は None を返します。
+    fn containing_pocket(
+        pos: &Position,
+        pockets: &[(f32, f32, f32, usize)],
+    ) -> Option<(usize, (f32, f32))> {
+        pockets
+            .iter()
+            .filter_map(|&(px, py, radius, index)| {
+                let dx = pos.x - px;
+                let dy = pos.y - py;
+                let dist_sq = dx * dx + dy * dy;
+                (dist_sq <= radius * radius).then_some((dist_sq, index, (px, py)))
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)))
+            .map(|(_, index, center)| (index, center))
+    }
+
+    /// 落下中の各ボールを 1 ステップ分ポケットの中心へ近づけて縮め、
+    /// 残りのステップ数が 0 になったものを BallId の順に削除します。
+    fn advance_drops(
+        entities: &Entities,
+        pos: &mut WriteStorage<Position>,
+        id: &ReadStorage<BallId>,
+        pocketing: &mut WriteStorage<Pocketing>,
+        render: &mut WriteStorage<Render>,
+        registry: &mut BallRegistry,
+    ) {
+        let mut finished = Vec::new();
+        for (entity, p, drop, r) in (entities, &mut *pos, &mut *pocketing, render).join() {
+            // 残りのステップ数で割った分だけ中心へ近づけ、最後のステップで中心に一致させます。
+            let fraction = 1.0 / drop.remaining as f32;
+            p.x += (drop.target.0 - p.x) * fraction;
+            p.y += (drop.target.1 - p.y) * fraction;
+            drop.remaining -= 1;
+            r.scale = drop.remaining as f32 / drop.duration as f32;
+            if drop.remaining == 0 {
+                finished.push(entity);
+            }
+        }
+        finished.sort_by_key(|&entity| Self::ball_order(id, entity));
+        for entity in finished {
+            let ball_id = id.get(entity).copied();
+            if let Some(p) = pos.get(entity) {
+                info!(
+                    "Ball {} pocketed: ({:.2}, {:.2})",
+                    ball_id.map_or(0, |id| id.0),
+                    p.x,
+                    p.y
+                );
+            }
+            if let Some(ball_id) = ball_id {
+                registry.remove(ball_id);
+            }
+            let _ = entities.delete(entity);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+// src/systems/sleep_system.rs
+//
+// このファイルでは、静止が続いたボールを休止させ、ぶつかられたボールを起こす SleepSystem を実装します。
+
+use crate::components::{Ball, Position, Sleeping, StillSteps, Velocity};
+use crate::resources::SleepSettings;
+use specs::prelude::*;
+
+/// 休止中のボールに「接している」とみなす、表面どうしの隙間の上限（cm）です。
+const CONTACT_MARGIN: f32 = 0.1;
+
+/// SleepSystem は、速さが静止とみなす上限以下のステップが続いたボールに Sleeping を付け、
+/// 速度を 0 にします。休止中のボールの速さが上限を超えた（ぶつかられた）場合は Sleeping を取り除き、
+/// そのボールに接している休止中のボールも起こします。
+pub struct SleepSystem;
+
+impl<'a> System<'a> for SleepSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadStorage<'a, Position>,
+        WriteStorage<'a, Velocity>,
+        ReadStorage<'a, Ball>,
+        WriteStorage<'a, StillSteps>,
+        WriteStorage<'a, Sleeping>,
+        Read<'a, SleepSettings>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (entities, pos, mut vel, ball, mut still, mut sleeping, settings) = data;
+        if settings.steps == 0 {
+            return;
+        }
+        // 起こしたボールの位置と半径（接しているボールを起こすために使います）
+        let mut woken = Vec::new();
+        for (ent, p, v, b) in (&entities, &pos, &mut vel, &ball).join() {
+            let Ok(entry) = still.entry(ent) else {
+                continue;
+            };
+            let counter = entry.or_insert_with(StillSteps::default);
+            if (v.x * v.x + v.y * v.y).sqrt() <= settings.threshold {
+                counter.count = counter.count.saturating_add(1);
+                if counter.count >= settings.steps && !sleeping.contains(ent) {
+                    *v = Velocity { x: 0.0, y: 0.0 };
+                    let _ = sleeping.insert(ent, Sleeping);
+                }
+            } else {
+                counter.count = 0;
+                if sleeping.remove(ent).is_some() {
+                    woken.push((*p, b.radius));
+                }
+            }
+        }
+        // 起こしたボールに接している休止中のボールも起こします。
+        if woken.is_empty() {
+            return;
+        }
+        let contacts: Vec<Entity> = (&entities, &pos, &ball, &sleeping)
+            .join()
+            .filter(|(_, p, b, _)| Self::touches_any(**p, b.radius, &woken))
+            .map(|(ent, _, _, _)| ent)
+            .collect();
+        for ent in contacts {
+            sleeping.remove(ent);
+            if let Some(counter) = still.get_mut(ent) {
+                counter.count = 0;
+            }
+        }
+    }
+}
+
+impl SleepSystem {
+    /// 位置 `p`、半径 `radius` のボールが、`others` のいずれかのボールに接しているかを判定する純粋関数です。
+    ///
+    /// 表面どうしの隙間が CONTACT_MARGIN 以下であれば接しているとみなします。
+    fn touches_any(p: Position, radius: f32, others: &[(Position, f32)]) -> bool {
+        others.iter().any(|(q, r)| {
+            let dx = q.x - p.x;
+            let dy = q.y - p.y;
+            (dx * dx + dy * dy).sqrt() <= radius + r + CONTACT_MARGIN
+        })
+    }
+}
@@ -0,0 +1,33 @@
+// src/systems/speed_limit_system.rs
+//
+// このファイルでは、衝突処理の後に各ボールの速さを上限までに抑える SpeedLimitSystem を実装します。
+
+use crate::components::{Ball, Velocity};
+use crate::resources::SpeedLimit;
+use specs::prelude::*;
+
+/// SpeedLimitSystem は、速さが `SpeedLimit::max_speed` を超えたボールの速度を、
+/// 向きを保ったまま上限の速さに縮めます。
+///
+/// 個々の衝突のインパルスではなく、衝突処理がすべて終わった後の速度に対する最後の安全策です。
+/// NaN や無限大を含む速度は変更せず、後に続く FiniteGuardSystem に任せます。
+pub struct SpeedLimitSystem;
+
+impl<'a> System<'a> for SpeedLimitSystem {
+    type SystemData = (
+        WriteStorage<'a, Velocity>,
+        ReadStorage<'a, Ball>,
+        Read<'a, SpeedLimit>,
+    );
+
+    fn run(&mut self, (mut vel, ball, limit): Self::SystemData) {
+        for (v, _) in (&mut vel, &ball).join() {
+            let speed = (v.x * v.x + v.y * v.y).sqrt();
+            if speed.is_finite() && speed > limit.max_speed {
+                let scale = limit.max_speed / speed;
+                v.x *= scale;
+                v.y *= scale;
+            }
+        }
+    }
+}
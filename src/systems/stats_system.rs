@@ -0,0 +1,35 @@
+// src/systems/stats_system.rs
+//
+// このファイルでは、ステップ数や各ボールの移動距離を集計する StatsSystem を実装します。
+
+use crate::components::{BallId, Position};
+use crate::resources::{CollisionEvents, Stats};
+use specs::prelude::*;
+
+/// StatsSystem は、ステップごとに Stats リソースを更新します。
+/// 各ボールの移動距離は、Stats に記録した前のステップの位置との差から求めます。
+/// ボール同士の衝突回数には、そのステップの CollisionEvents の件数を加えます。
+pub struct StatsSystem;
+
+impl<'a> System<'a> for StatsSystem {
+    type SystemData = (
+        ReadStorage<'a, Position>,
+        ReadStorage<'a, BallId>,
+        Write<'a, Stats>,
+        Read<'a, CollisionEvents>,
+    );
+
+    fn run(&mut self, (pos, id, mut stats, events): Self::SystemData) {
+        stats.steps += 1;
+        stats.ball_collisions += events.pairs.len();
+        for (p, id) in (&pos, &id).join() {
+            let moved = stats.last_positions.get(id).map_or(0.0, |&(x, y)| {
+                let dx = p.x - x;
+                let dy = p.y - y;
+                (dx * dx + dy * dy).sqrt()
+            });
+            *stats.distance.entry(*id).or_insert(0.0) += moved;
+            stats.last_positions.insert(*id, (p.x, p.y));
+        }
+    }
+}
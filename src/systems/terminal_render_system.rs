@@ -0,0 +1,72 @@
+// src/systems/terminal_render_system.rs
+//
+// このファイルでは、テーブルとボールの配置を ASCII のグリッドとして標準出力に表示する
+// TerminalRenderSystem を実装します。
+
+use crate::components::{Ball, BallId, Position, Table};
+use crate::config::TerminalRenderConfig;
+use crate::output::render_ascii;
+use crate::resources::SimClock;
+use specs::prelude::*;
+
+/// 設定で省略された場合の、グリッドの横の文字数です。
+const DEFAULT_COLUMNS: usize = 40;
+/// 設定で省略された場合の、グリッドの縦の文字数です。
+const DEFAULT_ROWS: usize = 20;
+/// 設定で省略された場合の、表示するステップの間隔です。
+const DEFAULT_EVERY: usize = 1;
+
+/// TerminalRenderSystem は、`every` ステップごと（最初のステップを含みます）に、テーブルとボールの配置を
+/// render_ascii で ASCII のグリッドに変換し、経過時間の行とともに標準出力に表示します。
+/// ファイルを書き出さずに、端末で動きを確かめるための表示です。
+pub struct TerminalRenderSystem {
+    // グリッドの横の文字数
+    columns: usize,
+    // グリッドの縦の文字数
+    rows: usize,
+    // 表示するステップの間隔
+    every: usize,
+}
+
+impl TerminalRenderSystem {
+    /// 設定から TerminalRenderSystem を作成します。
+    ///
+    /// # 引数
+    /// - `config`: 端末への表示の設定
+    pub fn new(config: &TerminalRenderConfig) -> Self {
+        TerminalRenderSystem {
+            columns: config.columns.unwrap_or(DEFAULT_COLUMNS),
+            rows: config.rows.unwrap_or(DEFAULT_ROWS),
+            every: config.every.unwrap_or(DEFAULT_EVERY),
+        }
+    }
+}
+
+impl<'a> System<'a> for TerminalRenderSystem {
+    type SystemData = (
+        ReadStorage<'a, Table>,
+        ReadStorage<'a, Position>,
+        ReadStorage<'a, Ball>,
+        ReadStorage<'a, BallId>,
+        Read<'a, SimClock>,
+    );
+
+    fn run(&mut self, (table, pos, ball, id, clock): Self::SystemData) {
+        if clock.current_step() % self.every != 0 {
+            return;
+        }
+        let table_size = (&table)
+            .join()
+            .next()
+            .map_or((0.0, 0.0), |t| (t.width, t.height));
+        let balls: Vec<(BallId, f32, f32)> = (&pos, &ball, &id)
+            .join()
+            .map(|(p, _, id)| (*id, p.x, p.y))
+            .collect();
+        println!("t={:.2}s", clock.elapsed.as_secs_f32());
+        print!(
+            "{}",
+            render_ascii(table_size, &balls, self.columns, self.rows)
+        );
+    }
+}
@@ -0,0 +1,20 @@
+// src/systems/trail_system.rs
+//
+// このファイルでは、各ボールの現在位置を軌跡（Trail）に記録する TrailSystem を実装します。
+
+use crate::components::{Position, Trail};
+use specs::prelude::*;
+
+/// TrailSystem は、Trail コンポーネントを持つ各エンティティの現在位置を軌跡に追加します。
+/// 容量を超えた古い位置は取り除かれます。
+pub struct TrailSystem;
+
+impl<'a> System<'a> for TrailSystem {
+    type SystemData = (ReadStorage<'a, Position>, WriteStorage<'a, Trail>);
+
+    fn run(&mut self, (pos, mut trail): Self::SystemData) {
+        for (p, trail) in (&pos, &mut trail).join() {
+            trail.push((p.x, p.y));
+        }
+    }
+}
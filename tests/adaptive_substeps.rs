@@ -0,0 +1,95 @@
+// tests/adaptive_substeps.rs
+//
+// adaptive_substeps を指定すると、各ステップの分割数が最も速いボールと最も小さいボールの半径から
+// `ceil(速さ × dt / (max_step_fraction × 半径))` で決まり、速いボールも他のボールをすり抜けないこと、
+// イベント駆動モードとは組み合わせられないことを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::config::{load_config_from_reader, Format};
+use my_specs_game::simulation::substep_count;
+use my_specs_game::Simulation;
+
+/// `adaptive` の分割の設定と `entities` のボールで Simulation を生成します。
+fn simulation(adaptive: &str, entities: &str) -> Simulation {
+    let yaml = standard_yaml(
+        1.0,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+adaptive_substeps: {adaptive}
+entities:
+{entities}"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn fast_ball_yields_the_formula_substep_count() {
+    // 5000 cm/s × 0.01 秒 = 50 cm を、0.5 × 2.85 cm = 1.425 cm ずつに分けると 35.09 回なので 36 回です。
+    let mut sim = simulation("{}", "  - {kind: Cue, x: 63.5, y: 40.0, vy: 5000.0}\n");
+    assert_eq!(sim.step().substeps, 36);
+    assert_eq!(substep_count(5000.0, 0.01, 2.85, 0.5, 64), 36);
+    // 割合を 0.25 にすると 0.7125 cm ずつになり、70.18 の切り上げで 71 回が必要ですが、既定の上限の 64 回に抑えられます。
+    let mut sim = simulation(
+        "{max_step_fraction: 0.25}",
+        "  - {kind: Cue, x: 63.5, y: 40.0, vy: 5000.0}\n",
+    );
+    assert_eq!(sim.step().substeps, 64);
+    let mut sim = simulation(
+        "{max_step_fraction: 0.25, max_substeps: 100}",
+        "  - {kind: Cue, x: 63.5, y: 40.0, vy: 5000.0}\n",
+    );
+    assert_eq!(sim.step().substeps, 71);
+}
+
+#[test]
+fn smallest_radius_and_fastest_ball_decide_the_count() {
+    // 遅い大きなボールではなく、半径 1 cm の小さなボールの半径と、2000 cm/s の最も速いボールで決まります。
+    // 2000 × 0.01 / (0.5 × 1.0) = 40 回です。
+    let mut sim = simulation(
+        "{}",
+        "  - {kind: Cue, x: 63.5, y: 40.0, vy: 2000.0}
+  - {kind: Object, x: 20.0, y: 200.0, vx: 10.0, radius: 1.0}
+",
+    );
+    assert_eq!(sim.step().substeps, 40);
+    // 1 ステップで半径の半分も進まない遅いボールだけなら、分割しません。
+    let mut sim = simulation("{}", "  - {kind: Cue, x: 63.5, y: 40.0, vy: 100.0}\n");
+    assert_eq!(sim.step().substeps, 1);
+}
+
+#[test]
+fn fast_ball_does_not_tunnel_through_another_ball() {
+    // 1 ステップで 50 cm 進む手球が、30 cm 先の的球に当たって速度を受け渡します。
+    let mut sim = simulation(
+        "{}",
+        "  - {kind: Cue, x: 63.5, y: 40.0, vy: 5000.0}
+  - {kind: Object, x: 63.5, y: 70.0}
+",
+    );
+    let report = sim.step();
+    assert_eq!(report.collisions, 1);
+    let velocities = sim.query_velocities();
+    assert!(velocities[0].1.y.abs() < 1.0, "{velocities:?}");
+    assert!((velocities[1].1.y - 5000.0).abs() < 1.0, "{velocities:?}");
+}
+
+#[test]
+fn adaptive_substeps_are_rejected_with_event_driven_stepping() {
+    let yaml = standard_yaml(
+        1.0,
+        "dt: 0.01
+adaptive_substeps: {}
+stepping: EventDriven
+",
+    );
+    let err = load_config_from_reader(yaml.as_bytes(), Format::Yaml).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "adaptive_substeps is not supported with stepping: EventDriven"
+    );
+}
@@ -0,0 +1,52 @@
+// tests/animate_example.rs
+//
+// examples/animate.rs を 3 コマだけの実行で動かし、空でないアニメーション GIF が書き出されることを確かめる動作確認のテストです。
+// テストの中で例のプログラムを cargo run でビルドして実行するため、時間がかかります。
+
+mod common;
+
+use common::standard_yaml;
+use std::process::Command;
+
+#[test]
+fn animate_example_writes_a_non_empty_gif() {
+    let target_dir = concat!(env!("CARGO_TARGET_TMPDIR"), "/animate_example");
+    std::fs::create_dir_all(target_dir).unwrap();
+    let config = format!("{target_dir}/config.yaml");
+    let gif = format!("{target_dir}/break.gif");
+    std::fs::write(
+        &config,
+        standard_yaml(0.95, "dt: 0.01
+units: {velocity: CmPerS}
+scenario: break
+break_speed: 800.0
+systems: {physics: true, collision: true, pockets: true, logging: false, log_energy: false, stats: true}
+"),
+    )
+    .unwrap();
+    let _ = std::fs::remove_file(&gif);
+    let output = Command::new(env!("CARGO"))
+        .args(["run", "--offline", "--quiet", "--manifest-path"])
+        .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"))
+        .args(["--example", "animate", "--"])
+        .args([config.as_str(), gif.as_str(), "3"])
+        .env("CARGO_TARGET_DIR", target_dir)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(std::fs::metadata(&gif).unwrap().len() > 0);
+    // 127 × 254 cm の台を 1 cm あたり 2 ピクセルで描いた、254 × 508 ピクセルのコマが 3 枚入っています。
+    let mut decoder = gif::DecodeOptions::new()
+        .read_info(std::fs::File::open(&gif).unwrap())
+        .unwrap();
+    assert_eq!((decoder.width(), decoder.height()), (254, 508));
+    let mut frames = 0;
+    while decoder.read_next_frame().unwrap().is_some() {
+        frames += 1;
+    }
+    assert_eq!(frames, 3);
+}
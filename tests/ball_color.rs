@@ -0,0 +1,38 @@
+// tests/ball_color.rs
+//
+// 設定の color が Color コンポーネントに反映され、指定のないボールには既定のパレットの色が割り当てられることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::{BallId, Color};
+use my_specs_game::Simulation;
+
+#[test]
+fn configured_hex_color_and_palette_fallback() {
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+cue_ball: {x: 63.5, y: 40.0, vx: 0.0, vy: 0.0}
+object_balls:
+  positions:
+    - {x: 40.0, y: 150.0, color: \"#ff0000\"}
+    - {x: 60.0, y: 150.0}
+    - {x: 80.0, y: 150.0, color: \"not a color\"}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let sim = Simulation::new(&config).unwrap();
+    let colors = sim.query::<Color>();
+    assert_eq!(
+        colors,
+        [
+            (BallId(0), Color::rgb(0xff, 0xff, 0xff)),
+            (BallId(1), Color::rgb(0xff, 0x00, 0x00)),
+            (BallId(2), Color::palette(BallId(2))),
+            // 解釈できない色も、BallId に応じた既定の色になります。
+            (BallId(3), Color::palette(BallId(3))),
+        ]
+    );
+    assert_eq!(Color::palette(BallId(2)), Color::rgb(0x1f, 0x4e, 0xb4));
+}
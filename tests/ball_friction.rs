@@ -0,0 +1,74 @@
+// tests/ball_friction.rs
+//
+// ball_friction を指定した厚みの半分の当たり（ハーフボール）で、接点の摩擦によって的球が中心線からそれ（スロー）、
+// 押し球の回転によって手球の進路が曲がることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::simulation::ShotSpin;
+use my_specs_game::Simulation;
+use std::cell::Cell;
+use std::f32::consts::FRAC_PI_2;
+use std::rc::Rc;
+
+/// 手球の真正面から半径だけ横にずらした的球へ押し球で撞き、`extra` を加えた設定で 800 ステップ実行します。
+/// 的球が中心線（衝突の法線）から横にそれた距離と、衝突の直後と最後の手球の進む向きの差（ラジアン）を返します。
+fn half_ball_hit(extra: &str) -> (f32, f32) {
+    let yaml = standard_yaml(
+        1.0,
+        &format!(
+            "dt: 0.001
+units: {{velocity: CmPerS}}
+friction_xy: [0.05, 0.05]
+slip_friction: 0.2
+entities:
+  - {{kind: Cue, x: 63.5, y: 40.0}}
+  - {{kind: Object, x: 66.35, y: 100.0}}
+{extra}"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    let normal = Rc::new(Cell::new(None));
+    let recorded = Rc::clone(&normal);
+    sim.on_collision(Box::new(move |collision| {
+        if recorded.get().is_none() {
+            recorded.set(Some((collision.normal_x, collision.normal_y)));
+        }
+    }));
+    sim.shot(FRAC_PI_2, 150.0, ShotSpin::Follow).unwrap();
+    let heading = |sim: &Simulation| {
+        let v = sim.query_velocities()[0].1;
+        v.y.atan2(v.x)
+    };
+    let mut after_contact = None;
+    for _ in 0..800 {
+        sim.step();
+        if after_contact.is_none() && normal.get().is_some() {
+            after_contact = Some(heading(&sim));
+        }
+    }
+    let (nx, ny) = normal
+        .get()
+        .expect("the cue ball should hit the object ball");
+    let object = sim.query_positions()[1].1;
+    let (dx, dy) = (object.x - 66.35, object.y - 100.0);
+    let throw = (dx * ny - dy * nx).abs();
+    (throw, (heading(&sim) - after_contact.unwrap()).abs())
+}
+
+#[test]
+fn contact_friction_throws_the_object_ball_off_the_line_of_centres() {
+    let (straight, _) = half_ball_hit("");
+    assert!(straight < 0.05, "{straight}");
+    let (thrown, _) = half_ball_hit("ball_friction: 0.2");
+    assert!(thrown > 1.0, "{thrown}");
+}
+
+#[test]
+fn follow_spin_curves_the_cue_ball_after_the_hit() {
+    let (_, turn) = half_ball_hit("ball_friction: 0.2");
+    // 押し球の回転が残った手球は、衝突の直後の向きから前へ向かって曲がっていきます。
+    assert!(turn > 0.1, "{turn}");
+}
@@ -0,0 +1,44 @@
+// tests/ball_ids.rs
+//
+// BallId が設定に並べた順に割り当てられることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::BallId;
+use my_specs_game::Simulation;
+
+/// 手球と、x 座標を 1 つずつ変えた 12 個の的球を `object_balls` に並べた Simulation を生成します。
+fn simulation() -> Simulation {
+    let positions: Vec<String> = (0..12)
+        .map(|i| format!("{{x: {}.0, y: {}.0}}", 10 + 8 * i, 150 + (i % 3) * 10))
+        .collect();
+    let yaml = standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.01
+cue_ball: {{x: 63.5, y: 40.0, vx: 0.0, vy: 0.0}}
+object_balls: {{positions: [{}]}}
+",
+            positions.join(", ")
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn object_ball_ids_follow_the_config_order() {
+    for _ in 0..5 {
+        let sim = simulation();
+        let positions = sim.query_positions();
+        assert_eq!(positions.len(), 13);
+        assert_eq!(positions[0].0, BallId(0));
+        for (i, (id, p)) in positions.iter().skip(1).enumerate() {
+            // i 番目の位置の的球は BallId(i + 1) です。
+            assert_eq!(*id, BallId(i as u32 + 1));
+            assert_eq!(p.x, (10 + 8 * i) as f32);
+            assert_eq!(p.y, (150 + (i % 3) * 10) as f32);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// tests/ball_limit.rs
+//
+// 設定から生成するエンティティの総数の上限（max_balls と max_balls_strict）を確かめるテストです。
+
+use my_specs_game::config::{load_config_from_reader, Format};
+
+/// 64 個の的球からなる `object_balls` と、`extra` を加えた設定を読み込めるかどうかを返します。
+fn loads(extra: &str) -> bool {
+    let positions: Vec<String> = (0..64)
+        .map(|i| format!("{{x: {}.0, y: 100.0}}", 10 + i))
+        .collect();
+    let yaml = format!(
+        "dt: 0.01
+table: {{width: 127.0, height: 254.0}}
+ball: {{radius: 0.4, mass: 0.17, restitution: 1.0}}
+cue_ball: {{x: 60.0, y: 30.0, vx: 0.0, vy: 0.0}}
+object_balls: {{positions: [{}]}}
+{extra}",
+        positions.join(", ")
+    );
+    load_config_from_reader(yaml.as_bytes(), Format::Yaml).is_ok()
+}
+
+#[test]
+fn a_count_within_max_balls_loads() {
+    assert!(loads(""));
+    assert!(loads("max_balls: 65\nmax_balls_strict: true"));
+}
+
+#[test]
+fn balls_over_max_balls_are_rejected() {
+    assert!(!loads("max_balls: 64\nmax_balls_strict: true"));
+}
+
+#[test]
+fn pegs_pockets_and_segments_count_towards_the_limit() {
+    let strict = "max_balls: 70\nmax_balls_strict: true";
+    assert!(loads(strict));
+    assert!(!loads(&format!(
+        "{strict}\npegs: {{x: 20.0, y: 200.0, rows: 2, columns: 3, spacing_x: 5.0, spacing_y: 5.0, radius: 0.5}}"
+    )));
+    assert!(!loads(&format!(
+        "{strict}\npockets: {{radius: 6.0, positions: [{{x: 0.0, y: 0.0}}, {{x: 127.0, y: 0.0}}, {{x: 0.0, y: 254.0}}, {{x: 127.0, y: 254.0}}, {{x: 0.0, y: 127.0}}, {{x: 127.0, y: 127.0}}]}}"
+    )));
+    assert!(!loads(&format!(
+        "{strict}\ncushion_segments: [{}]",
+        ["{x1: 5.0, y1: 5.0, x2: 20.0, y2: 5.0}"; 6].join(", ")
+    )));
+}
+
+#[test]
+fn a_huge_peg_grid_is_rejected_without_overflowing() {
+    assert!(!loads(
+        "pegs: {x: 20.0, y: 200.0, rows: 18446744073709551615, columns: 3, spacing_x: 5.0, spacing_y: 5.0, radius: 0.5}"
+    ));
+}
@@ -0,0 +1,58 @@
+// tests/ball_state.rs
+//
+// Simulation::ball_state() が BallId から 1 つのボールの状態を返し、ポケットされたボールには None を返すことを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::{BallId, BallKind};
+use my_specs_game::Simulation;
+
+/// 共通の台とボールの設定に `extra` を加えた設定で Simulation を生成します。
+fn simulation(extra: &str) -> Simulation {
+    let yaml = standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+{extra}"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn ball_state_is_looked_up_by_id() {
+    let sim = simulation(
+        "entities:
+  - {kind: Cue, x: 63.5, y: 40.0}
+  - {kind: Object, x: 42.0, y: 160.0, vx: 10.0, vy: -5.0}
+",
+    );
+    let cue = sim.ball_state(BallId(0)).unwrap();
+    assert_eq!(cue.kind, BallKind::Cue);
+    assert_eq!((cue.position.x, cue.position.y), (63.5, 40.0));
+    let object = sim.ball_state(BallId(1)).unwrap();
+    assert_eq!(object.id, BallId(1));
+    assert_eq!(object.kind, BallKind::Object);
+    assert_eq!((object.position.x, object.position.y), (42.0, 160.0));
+    assert_eq!((object.velocity.x, object.velocity.y), (10.0, -5.0));
+    assert!(sim.ball_state(BallId(2)).is_none());
+}
+
+#[test]
+fn pocketed_ball_state_is_none() {
+    let mut sim = simulation(
+        "pockets: {radius: 6.0, positions: [{x: 0.0, y: 60.0}]}
+entities:
+  - {kind: Cue, x: 63.5, y: 200.0}
+  - {kind: Object, x: 30.0, y: 60.0, vx: -300.0}
+",
+    );
+    assert!(sim.ball_state(BallId(1)).is_some());
+    sim.run(50);
+    assert!(sim.ball_state(BallId(1)).is_none());
+    let cue = sim.ball_state(BallId(0)).unwrap();
+    assert_eq!((cue.position.x, cue.position.y), (63.5, 200.0));
+}
@@ -0,0 +1,63 @@
+// tests/bisection_time_of_impact.rs
+//
+// 二分探索による接触時刻 bisection_time_of_impact() が、解析的に求めた円との接触時刻と一致することを確かめるテストです。
+
+use my_specs_game::components::{Position, Velocity};
+use my_specs_game::math::{ball_time_of_impact, bisection_time_of_impact};
+
+const RADIUS: f32 = 2.85;
+const TOLERANCE: f32 = 1e-6;
+
+/// 中心 `center`、半径 `peg` の円の符号付き距離関数を返します。
+fn circle(center: Position, peg: f32) -> impl Fn(Position) -> f32 {
+    move |p: Position| ((p.x - center.x).powi(2) + (p.y - center.y).powi(2)).sqrt() - peg
+}
+
+#[test]
+fn bisection_matches_the_analytic_circle_contact_time() {
+    let center = Position { x: 50.0, y: 50.0 };
+    let peg = 1.5;
+    let dt = 0.1;
+    // 正面から、斜めから、かすめるように、の 3 通りで円に近づきます。
+    for (start, vel) in [
+        (Position { x: 20.0, y: 50.0 }, Velocity { x: 400.0, y: 0.0 }),
+        (
+            Position { x: 30.0, y: 30.0 },
+            Velocity { x: 250.0, y: 240.0 },
+        ),
+        (Position { x: 20.0, y: 54.0 }, Velocity { x: 500.0, y: 0.0 }),
+    ] {
+        let end = Position {
+            x: start.x + vel.x * dt,
+            y: start.y + vel.y * dt,
+        };
+        let analytic =
+            ball_time_of_impact(start, vel, RADIUS, center, Velocity { x: 0.0, y: 0.0 }, peg)
+                .unwrap();
+        let bisection =
+            bisection_time_of_impact(start, end, RADIUS, dt, circle(center, peg), TOLERANCE)
+                .unwrap();
+        // 二分探索は接触時刻以前の側の値を返します。
+        assert!(
+            bisection <= analytic + 1e-6 && analytic - bisection < 2.0 * TOLERANCE,
+            "{start:?}: bisection {bisection} != analytic {analytic}"
+        );
+    }
+}
+
+#[test]
+fn bisection_reports_missed_and_initial_contacts() {
+    let center = Position { x: 50.0, y: 50.0 };
+    let peg = 1.5;
+    let start = Position { x: 20.0, y: 60.0 };
+    let end = Position { x: 80.0, y: 60.0 };
+    assert_eq!(
+        bisection_time_of_impact(start, end, RADIUS, 0.1, circle(center, peg), TOLERANCE),
+        None
+    );
+    let touching = Position { x: 47.0, y: 50.0 };
+    assert_eq!(
+        bisection_time_of_impact(touching, end, RADIUS, 0.1, circle(center, peg), TOLERANCE),
+        Some(0.0)
+    );
+}
@@ -0,0 +1,54 @@
+// tests/break_scenario.rs
+//
+// scenario: break が、手球をヘッドスポットに置いてフットスポットへ向けて打ち出し、15 個の的球を
+// フットスポットを先頭とする三角形に並べることを確かめるテストです。
+
+mod common;
+
+use common::config_from_yaml;
+use my_specs_game::components::BallKind;
+use my_specs_game::Simulation;
+
+const RADIUS: f32 = 2.85;
+
+#[test]
+fn break_scenario_racks_fifteen_balls_in_a_triangle() {
+    let yaml = format!(
+        "dt: 0.002
+table: {{width: 127.0, height: 254.0}}
+ball: {{radius: {RADIUS}, mass: 0.17, restitution: 0.95}}
+units: {{velocity: CmPerS}}
+scenario: break
+break_speed: 800.0
+"
+    );
+    let config = config_from_yaml(&yaml);
+    let sim = Simulation::new(&config).unwrap();
+    let kinds = sim.query::<BallKind>();
+    let positions = sim.query_positions();
+    let velocities = sim.query_velocities();
+    assert_eq!(positions.len(), 16);
+    // 手球はヘッドスポット（奥行きの 1/4）に置き、フットスポットの向き（+y）へ打ち出します。
+    assert_eq!(kinds[0].1, BallKind::Cue);
+    assert_eq!((positions[0].1.x, positions[0].1.y), (63.5, 63.5));
+    assert_eq!((velocities[0].1.x, velocities[0].1.y), (0.0, 800.0));
+    // 的球は静止しており、k 行目（0 始まり）に k + 1 個ずつ、中心線について左右対称に並びます。
+    let row_spacing = RADIUS * 3f32.sqrt();
+    let mut index = 1;
+    for row in 0..5 {
+        let y = 190.5 + row as f32 * row_spacing;
+        let mut xs = Vec::new();
+        for _ in 0..=row {
+            assert_eq!(kinds[index].1, BallKind::Object);
+            let (p, v) = (positions[index].1, velocities[index].1);
+            assert!((p.y - y).abs() < 1e-3, "ball {index}: {p:?}");
+            assert_eq!((v.x, v.y), (0.0, 0.0));
+            xs.push(p.x);
+            index += 1;
+        }
+        for (i, x) in xs.iter().enumerate() {
+            let expected = 63.5 + (row as f32 / 2.0 - i as f32) * 2.0 * RADIUS;
+            assert!((x - expected).abs() < 1e-3, "row {row}: {xs:?}");
+        }
+    }
+}
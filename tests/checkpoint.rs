@@ -0,0 +1,126 @@
+// tests/checkpoint.rs
+//
+// チェックポイントの保存と読み込みが、実行の途中の状態を正確に往復させることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::{Pocketing, Table};
+use my_specs_game::config::{Config, ConfigPatch};
+use my_specs_game::Simulation;
+use specs::prelude::*;
+
+/// 的球をポケットへ向けて転がし、数ステップかけて落ちる演出を行う設定です。
+fn drop_config() -> Config {
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: CmPerS}
+pockets: {radius: 6.0, drop_steps: 10, positions: [{x: 127.0, y: 60.0}]}
+entities:
+  - {kind: Cue, x: 30.0, y: 200.0}
+  - {kind: Object, x: 100.0, y: 60.0, vx: 200.0}
+",
+    );
+    config_from_yaml(&yaml)
+}
+
+fn pocketing_count(sim: &Simulation) -> usize {
+    sim.observe(|world| world.read_storage::<Pocketing>().join().count())
+}
+
+fn table_size(sim: &Simulation) -> (f32, f32) {
+    sim.observe(|world| {
+        let table = world.read_storage::<Table>();
+        let table = (&table).join().next().unwrap();
+        (table.width, table.height)
+    })
+}
+
+#[test]
+fn checkpoint_taken_mid_drop_resumes_identically() {
+    let config = drop_config();
+    let mut original = Simulation::new(&config).unwrap();
+    while pocketing_count(&original) == 0 {
+        original.step();
+        assert!(original.steps() < 100, "the ball never started dropping");
+    }
+    // 落下の演出の途中で数ステップ進め、テーブルの寸法も変えてから保存します。
+    original.step();
+    original.step();
+    original.resize_table(120.0, 250.0).unwrap();
+    let path =
+        std::env::temp_dir().join(format!("checkpoint_mid_drop_{}.json", std::process::id()));
+    let path = path.to_str().unwrap();
+    original.save_checkpoint(path).unwrap();
+
+    let mut restored = Simulation::new(&config).unwrap();
+    restored.load_checkpoint(path).unwrap();
+    std::fs::remove_file(path).unwrap();
+
+    assert_eq!(pocketing_count(&restored), 1);
+    assert_eq!(table_size(&restored), (120.0, 250.0));
+    assert_eq!(restored.steps(), original.steps());
+    assert_eq!(restored.state_hash(), original.state_hash());
+
+    for _ in 0..20 {
+        original.step();
+        restored.step();
+        let (a, b) = (original.snapshot(), restored.snapshot());
+        assert_eq!(a.step, b.step);
+        assert_eq!(a.balls.len(), b.balls.len());
+        for (a, b) in a.balls.iter().zip(&b.balls) {
+            assert_eq!(
+                (a.id, a.x, a.y, a.vx, a.vy, a.scale),
+                (b.id, b.x, b.y, b.vx, b.vy, b.scale)
+            );
+        }
+    }
+    assert_eq!(pocketing_count(&restored), 0);
+    assert_eq!(restored.state_hash(), original.state_hash());
+}
+
+#[test]
+fn checkpoint_keeps_parameters_changed_by_a_config_patch() {
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: CmPerS}
+friction_xy: [0.2, 0.2]
+entities:
+  - {kind: Cue, x: 63.5, y: 40.0, vy: 150.0}
+  - {kind: Object, x: 63.5, y: 190.5}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut original = Simulation::new(&config).unwrap();
+    original.run(5);
+    original
+        .apply_config_patch(&ConfigPatch {
+            dt: Some(0.005),
+            friction_xy: Some((0.5, 0.1)),
+            restitution_override: Some(0.5),
+            gravity_xy: Some((0.0, 0.2)),
+        })
+        .unwrap();
+    original.run(5);
+    let path = std::env::temp_dir().join(format!("checkpoint_patched_{}.json", std::process::id()));
+    let path = path.to_str().unwrap();
+    original.save_checkpoint(path).unwrap();
+
+    // 復元先は差分を適用していない設定から生成しますが、差分で変えた値もチェックポイントから戻ります。
+    let mut restored = Simulation::new(&config).unwrap();
+    restored.load_checkpoint(path).unwrap();
+    std::fs::remove_file(path).unwrap();
+    assert_eq!(restored.state_hash(), original.state_hash());
+
+    // 手球が的球とクッションに当たるまで進め、時間刻み・摩擦・重力・反発係数のすべてが一致することを確かめます。
+    for _ in 0..400 {
+        original.step();
+        restored.step();
+        assert_eq!(restored.state_hash(), original.state_hash());
+    }
+    let (a, b) = (original.snapshot(), restored.snapshot());
+    assert_eq!(a.elapsed, b.elapsed);
+    assert!(original.metrics().ball_collisions > 0);
+}
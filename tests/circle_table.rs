@@ -0,0 +1,71 @@
+// tests/circle_table.rs
+//
+// 円形のテーブルで、半径方向に外へ向かうボールが、クッションの反発係数を掛けて半径に沿って真っすぐ跳ね返ること、
+// 正の有限の値でない半径は読み込み時に拒否されることを確かめるテストです。
+
+mod common;
+
+use common::config_from_yaml;
+use my_specs_game::config::{load_config_from_reader, Format};
+use my_specs_game::Simulation;
+
+const RADIUS: f32 = 2.85;
+
+#[test]
+fn radial_shot_reflects_straight_back_along_the_radius() {
+    for stepping in ["FixedStep", "EventDriven"] {
+        // 中心 (30, 30) から (3, 4) の向きに 50 cm/s で転がします。
+        let yaml = format!(
+            "dt: 0.001
+stepping: {stepping}
+table: {{width: 60.0, height: 60.0, shape: !Circle {{ radius: 30.0 }}}}
+ball: {{radius: {RADIUS}, mass: 0.17, restitution: 0.8}}
+units: {{velocity: CmPerS}}
+friction_xy: [0.0, 0.0]
+entities:
+  - {{kind: Cue, x: 30.0, y: 30.0, vx: 30.0, vy: 40.0}}
+"
+        );
+        let config = config_from_yaml(&yaml);
+        let mut sim = Simulation::new(&config).unwrap();
+        sim.run(1000);
+        // 跳ね返った後も、ボールは同じ半径の上にあり、速度は逆向きで 0.8 倍になります。
+        let p = sim.query_positions()[0].1;
+        let (dx, dy) = (p.x - 30.0, p.y - 30.0);
+        assert!(
+            (dx * 4.0 - dy * 3.0).abs() < 1e-2,
+            "{stepping}: {p:?} left the radius"
+        );
+        let v = sim.query_velocities()[0].1;
+        assert!(
+            (v.x + 24.0).abs() < 1e-2 && (v.y + 32.0).abs() < 1e-2,
+            "{stepping}: {v:?}"
+        );
+        // 1 秒で 50 cm のうち (30 - RADIUS) cm 進んで跳ね返り、残りの時間は 40 cm/s で戻ります。
+        let rim = 30.0 - RADIUS;
+        let expected = rim - (1.0 - rim / 50.0) * 40.0;
+        let distance = (dx * dx + dy * dy).sqrt();
+        assert!(
+            (distance - expected).abs() < 0.1,
+            "{stepping}: {distance} != {expected}"
+        );
+    }
+}
+
+#[test]
+fn non_positive_circle_radius_is_rejected() {
+    for radius in ["0.0", "-30.0", ".nan", ".inf"] {
+        let yaml = format!(
+            "dt: 0.001
+table: {{width: 60.0, height: 60.0, shape: !Circle {{ radius: {radius} }}}}
+ball: {{radius: {RADIUS}, mass: 0.17, restitution: 0.8}}
+"
+        );
+        let err = load_config_from_reader(yaml.as_bytes(), Format::Yaml).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("table.shape: Circle radius must be finite and positive"),
+            "{radius}: {err}"
+        );
+    }
+}
@@ -0,0 +1,43 @@
+// tests/clamp_initial_positions.rs
+//
+// clamp_initial_positions が true の場合はテーブルからはみ出した初期位置がクッションの内側に収められ、
+// false（既定値）の場合は読み込み時にエラーになることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::config::{load_config_from_reader, Format};
+use my_specs_game::Simulation;
+
+/// 手球と的球がそれぞれテーブルからはみ出した設定に `extra` を加えた YAML を返します。
+fn yaml(extra: &str) -> String {
+    standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+cue_ball: {{x: -5.0, y: 40.0, vx: 0.0, vy: 0.0}}
+object_balls:
+  positions:
+    - {{x: 126.0, y: 260.0}}
+{extra}"
+        ),
+    )
+}
+
+#[test]
+fn out_of_bounds_positions_are_clamped_when_enabled() {
+    let config = config_from_yaml(&yaml("clamp_initial_positions: true"));
+    let positions = Simulation::new(&config).unwrap().query_positions();
+    let (cue, object) = (positions[0].1, positions[1].1);
+    assert_eq!((cue.x, cue.y), (2.85, 40.0));
+    assert_eq!((object.x, object.y), (127.0 - 2.85, 254.0 - 2.85));
+}
+
+#[test]
+fn out_of_bounds_positions_are_rejected_by_default() {
+    for extra in ["", "clamp_initial_positions: false"] {
+        let error = load_config_from_reader(yaml(extra).as_bytes(), Format::Yaml).unwrap_err();
+        assert!(error.to_string().contains("outside the table"), "{error}");
+    }
+}
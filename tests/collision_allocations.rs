@@ -0,0 +1,97 @@
+// tests/collision_allocations.rs
+//
+// CollisionSystem が作業用の領域を再利用し、ボールの数が変わらない間はステップごとにメモリを確保しないことを、
+// 確保の回数を数えるアロケータで確かめるテストです。
+
+mod common;
+
+use common::config_from_yaml;
+use my_specs_game::systems::{CollisionSystem, PhysicsSystem};
+use my_specs_game::Simulation;
+use specs::prelude::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+thread_local! {
+    /// このスレッドでメモリを確保した回数
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// 確保（と再確保）の回数をスレッドごとに数えながら、System に処理を委ねるアロケータです。
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// このスレッドでこれまでにメモリを確保した回数を返します。
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+/// `table` のテーブルで、ボール同士と壁との衝突を繰り返し起こしながら CollisionSystem を実行し、
+/// 作業用の領域を確保し終えた後のステップでメモリを確保した回数を返します。
+fn steady_state_allocations(table: &str) -> usize {
+    let yaml = format!(
+        "dt: 0.001
+table: {table}
+ball: {{radius: 2.85, mass: 0.17, restitution: 1.0}}
+units: {{velocity: CmPerS}}
+entities:
+  - {{kind: Cue, x: 10.0, y: 14.0, vx: 90.0, vy: 70.0}}
+  - {{kind: Object, x: 20.0, y: 20.0, vx: -40.0, vy: 30.0}}
+  - {{kind: Object, x: 27.0, y: 14.0, vx: 20.0, vy: -60.0}}
+  - {{kind: Object, x: 14.0, y: 27.0, vx: 50.0, vy: 10.0}}
+"
+    );
+    let config = config_from_yaml(&yaml);
+    let sim = Simulation::new(&config).unwrap();
+    let world = sim.world();
+    let mut physics = PhysicsSystem;
+    let mut collision = CollisionSystem::default();
+    let mut step = |count: &mut usize| {
+        physics.run_now(world);
+        let before = allocations();
+        collision.run_now(world);
+        *count += allocations() - before;
+    };
+    // 最初のステップでは作業用の領域を確保するため、しばらく実行してから数えます。
+    let mut warm_up = 0;
+    for _ in 0..2000 {
+        step(&mut warm_up);
+    }
+    assert!(warm_up > 0);
+    let mut steady = 0;
+    for _ in 0..2000 {
+        step(&mut steady);
+    }
+    steady
+}
+
+#[test]
+fn collision_system_does_not_allocate_in_steady_state() {
+    assert_eq!(steady_state_allocations("{width: 40.0, height: 40.0}"), 0);
+}
+
+#[test]
+fn circle_table_collisions_do_not_allocate_in_steady_state() {
+    assert_eq!(
+        steady_state_allocations("{width: 40.0, height: 40.0, shape: !Circle { radius: 20.0 }}"),
+        0
+    );
+}
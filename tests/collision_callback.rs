@@ -0,0 +1,53 @@
+// tests/collision_callback.rs
+//
+// Simulation::on_collision() で登録したコールバックが、解決されたボール同士の衝突ごとに 1 回ずつ、
+// インパルスの大きさを含む記録とともに呼び出されることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// 手球を、縦に接して並んだ 2 つの的球に向けて撞く `stepping` のモードの Simulation を生成します。
+fn cluster_shot(stepping: &str) -> Simulation {
+    let yaml = standard_yaml(
+        1.0,
+        &format!(
+            "dt: 0.001
+units: {{velocity: CmPerS}}
+stepping: {stepping}
+entities:
+  - {{kind: Cue, x: 63.5, y: 40.0, vy: 200.0}}
+  - {{kind: Object, x: 63.5, y: 100.0}}
+  - {{kind: Object, x: 63.5, y: 105.8}}
+"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn callback_fires_once_per_resolved_collision() {
+    for stepping in ["FixedStep", "EventDriven"] {
+        let mut sim = cluster_shot(stepping);
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let recorded = Rc::clone(&calls);
+        sim.on_collision(Box::new(move |collision| {
+            recorded
+                .borrow_mut()
+                .push((collision.id_a.0, collision.id_b.0, collision.impulse));
+        }));
+        sim.run(400);
+        let calls = calls.borrow();
+        assert_eq!(calls.len(), sim.metrics().ball_collisions, "{stepping}");
+        // 手球の運動量 0.17 kg × 200 cm/s が、1 つ目の的球を経て 2 つ目の的球へ受け渡されます。
+        let pairs: Vec<(u32, u32)> = calls.iter().map(|&(a, b, _)| (a, b)).collect();
+        assert_eq!(pairs, [(0, 1), (1, 2)], "{stepping}");
+        for &(_, _, impulse) in calls.iter() {
+            assert!((impulse - 34.0).abs() < 1e-2, "{stepping}: {impulse}");
+        }
+    }
+}
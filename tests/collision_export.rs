@@ -0,0 +1,97 @@
+// tests/collision_export.rs
+//
+// CSV と JSON の出力に付随する衝突の一覧に、解決されたボール同士の衝突のペアが書き出されることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::output::{CsvSink, JsonSink};
+use my_specs_game::Simulation;
+
+/// 手球を、縦に接して並んだ 2 つの的球に向けて撞く Simulation を生成します。
+fn cluster_shot() -> Simulation {
+    let yaml = standard_yaml(
+        1.0,
+        "dt: 0.001
+units: {velocity: CmPerS}
+entities:
+  - {kind: Cue, x: 63.5, y: 40.0, vy: 200.0}
+  - {kind: Object, x: 63.5, y: 100.0}
+  - {kind: Object, x: 63.5, y: 105.8}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+/// テスト用の出力ファイルのパスを返します。
+fn output_path(name: &str) -> String {
+    format!("{}/collision_export_{name}", env!("CARGO_TARGET_TMPDIR"))
+}
+
+#[test]
+fn csv_collision_stream_lists_the_resolved_pairs() {
+    let (states, collisions) = (output_path("states.csv"), output_path("collisions.csv"));
+    let sink = CsvSink::create(&states)
+        .unwrap()
+        .with_collisions_file(&collisions)
+        .unwrap();
+    let mut sim = cluster_shot();
+    sim.add_sink(Box::new(sink));
+    sim.run(400);
+    let text = std::fs::read_to_string(&collisions).unwrap();
+    let mut lines = text.lines();
+    assert_eq!(
+        lines.next(),
+        Some("step,id_a,id_b,impulse,normal_x,normal_y,effective_restitution")
+    );
+    let pairs: Vec<(usize, u32, u32, f32, f32, f32)> = lines
+        .map(|line| {
+            let columns: Vec<&str> = line.split(',').collect();
+            (
+                columns[0].parse().unwrap(),
+                columns[1].parse().unwrap(),
+                columns[2].parse().unwrap(),
+                columns[3].parse().unwrap(),
+                columns[4].parse().unwrap(),
+                columns[5].parse().unwrap(),
+            )
+        })
+        .collect();
+    // 手球が 1 つ目の的球に当たり、その速度が 2 つ目の的球へ受け渡されます。
+    // 各衝突のインパルスは 0.17 kg × 200 cm/s で、法線は +y 方向です。
+    let first = pairs.iter().position(|p| (p.1, p.2) == (0, 1)).unwrap();
+    let second = pairs.iter().position(|p| (p.1, p.2) == (1, 2)).unwrap();
+    assert!(pairs[first].0 <= pairs[second].0, "{pairs:?}");
+    assert!(pairs.iter().all(|p| (p.1, p.2) != (0, 2)), "{pairs:?}");
+    for p in &pairs {
+        assert!((p.3 - 34.0).abs() < 1e-2, "{p:?}");
+        assert!(p.4.abs() < 1e-3 && (p.5 - 1.0).abs() < 1e-3, "{p:?}");
+    }
+}
+
+#[test]
+fn json_collision_stream_lists_the_resolved_pairs() {
+    let (states, collisions) = (output_path("states.json"), output_path("collisions.json"));
+    let sink = JsonSink::create(&states)
+        .unwrap()
+        .with_collisions_file(&collisions)
+        .unwrap();
+    let mut sim = cluster_shot();
+    sim.add_sink(Box::new(sink));
+    sim.run(400);
+    let text = std::fs::read_to_string(&collisions).unwrap();
+    let records: Vec<serde_json::Value> = text
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    let pairs: Vec<(u64, u64)> = records
+        .iter()
+        .map(|r| (r["id_a"].as_u64().unwrap(), r["id_b"].as_u64().unwrap()))
+        .collect();
+    assert_eq!(pairs, [(0, 1), (1, 2)]);
+    assert!(records[0]["step"].as_u64() <= records[1]["step"].as_u64());
+    for r in &records {
+        assert!((r["impulse"].as_f64().unwrap() - 34.0).abs() < 1e-2, "{r}");
+    }
+}
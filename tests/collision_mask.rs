@@ -0,0 +1,63 @@
+// tests/collision_mask.rs
+//
+// 衝突レイヤー（CollisionMask）が重ならないボール同士は衝突せず、壁とは衝突することを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+
+/// 衝突レイヤー `mask_a` と `mask_b` の 2 つのボールを x 軸に沿って向かい合わせに転がす Simulation を生成します。
+fn head_on(mask_a: u32, mask_b: u32) -> Simulation {
+    let yaml = standard_yaml(
+        1.0,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+entities:
+  - {{kind: Cue, x: 40.0, y: 60.0, vx: 50.0, collision_mask: {mask_a}}}
+  - {{kind: Object, x: 60.0, y: 60.0, vx: -50.0, collision_mask: {mask_b}}}
+"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+/// 各ボールの x 座標と x 方向の速度を返します。
+fn state(sim: &Simulation) -> Vec<(f32, f32)> {
+    sim.query_positions()
+        .into_iter()
+        .zip(sim.query_velocities())
+        .map(|((_, p), (_, v))| (p.x, v.x))
+        .collect()
+}
+
+#[test]
+fn balls_on_separate_layers_pass_through_each_other() {
+    let mut sim = head_on(0b01, 0b10);
+    for _ in 0..40 {
+        sim.step();
+    }
+    // インパルスを受けずにすれ違います。
+    let balls = state(&sim);
+    assert!(balls[0].0 > balls[1].0, "{balls:?}");
+    assert_eq!((balls[0].1, balls[1].1), (50.0, -50.0));
+    // 壁とは衝突して跳ね返ります。
+    for _ in 0..160 {
+        sim.step();
+    }
+    let balls = state(&sim);
+    assert!(balls[0].1 < 0.0 && balls[1].1 > 0.0, "{balls:?}");
+}
+
+#[test]
+fn overlapping_layers_still_collide() {
+    let mut sim = head_on(0b11, 0b10);
+    for _ in 0..40 {
+        sim.step();
+    }
+    let balls = state(&sim);
+    assert!(balls[0].0 < balls[1].0, "{balls:?}");
+    assert!(balls[0].1 < 0.0 && balls[1].1 > 0.0, "{balls:?}");
+}
@@ -0,0 +1,62 @@
+// tests/collision_priority.rs
+//
+// 同じステップで壁とボールの両方に接したボールの衝突が、collision_priority の順序で処理されることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+
+/// 左のクッションへ斜めに向かう手球 A と、その右上で静止した的球 B を置き、`priority` で 1 ステップ進めた後の
+/// A と B の速度を返します。A はこのステップで壁にめり込みます。`priority` が空の場合は設定を省略します。
+fn one_step(priority: &str) -> ((f32, f32), (f32, f32)) {
+    let priority = if priority.is_empty() {
+        String::new()
+    } else {
+        format!("collision_priority: {priority}")
+    };
+    let yaml = standard_yaml(
+        1.0,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+friction_xy: [0.0, 0.0]
+{priority}
+entities:
+  - {{kind: Cue, x: 2.9, y: 50.0, vx: -100.0, vy: 100.0}}
+  - {{kind: Object, x: 6.86, y: 53.96}}
+"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    sim.step();
+    let velocities = sim.query_velocities();
+    let (a, b) = (velocities[0].1, velocities[1].1);
+    ((a.x, a.y), (b.x, b.y))
+}
+
+#[test]
+fn wall_first_reflects_before_testing_the_ball_contact() {
+    // 壁で (100, 100) に跳ね返った A は B に近づく向きになり、速度の多くを B に受け渡します。
+    let (a, b) = one_step("WallFirst");
+    assert!(b.0 > 50.0 && b.1 > 50.0, "B: {b:?}");
+    assert!(a.0.hypot(a.1) < 50.0, "A: {a:?}");
+}
+
+#[test]
+fn ball_first_tests_the_ball_contact_with_the_incoming_velocity() {
+    // 壁に当たる前の (-100, 100) は B に対して接線方向に近く B に近づいていないため、B は静止したままで、
+    // A は壁で跳ね返るだけです。
+    let (a, b) = one_step("BallFirst");
+    assert_eq!(b, (0.0, 0.0));
+    assert!(
+        (a.0 - 100.0).abs() < 1e-3 && (a.1 - 100.0).abs() < 1e-3,
+        "A: {a:?}"
+    );
+}
+
+#[test]
+fn wall_first_is_the_default() {
+    assert_eq!(one_step(""), one_step("WallFirst"));
+}
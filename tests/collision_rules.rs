@@ -0,0 +1,73 @@
+// tests/collision_rules.rs
+//
+// collision_rules で手球と的球の組み合わせの衝突を無効にすると、その組み合わせのボールはすり抜けて重なり、
+// 的球同士は引き続き衝突することを確かめるテストです。
+
+mod common;
+
+use common::config_from_yaml;
+use my_specs_game::Simulation;
+
+const RADIUS: f32 = 2.85;
+
+/// 手球が静止した的球へ、2 つの的球が互いへ向かって進む設定に `extra` を加え、1000 ステップ（1 秒）実行します。
+/// 手球と的球 1 の中心間距離の最小値、的球 2 と的球 3 の中心間距離の最小値、最後の各ボールの速度 x 成分を返します。
+fn run(extra: &str) -> (f32, f32, Vec<f32>) {
+    let yaml = format!(
+        "dt: 0.001
+table: {{width: 127.0, height: 254.0}}
+ball: {{radius: {RADIUS}, mass: 0.17, restitution: 1.0}}
+units: {{velocity: CmPerS}}
+entities:
+  - {{kind: Cue, x: 40.0, y: 60.0, vx: 20.0}}
+  - {{kind: Object, x: 60.0, y: 60.0}}
+  - {{kind: Object, x: 40.0, y: 150.0, vx: 20.0}}
+  - {{kind: Object, x: 60.0, y: 150.0, vx: -20.0}}
+{extra}"
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    let distance = |sim: &Simulation, a: usize, b: usize| {
+        let positions = sim.query_positions();
+        let (p, q) = (positions[a].1, positions[b].1);
+        ((p.x - q.x).powi(2) + (p.y - q.y).powi(2)).sqrt()
+    };
+    let (mut cue_object, mut object_object) = (f32::MAX, f32::MAX);
+    for _ in 0..1000 {
+        sim.step();
+        cue_object = cue_object.min(distance(&sim, 0, 1));
+        object_object = object_object.min(distance(&sim, 2, 3));
+    }
+    let velocities = sim
+        .query_velocities()
+        .into_iter()
+        .map(|(_, v)| v.x)
+        .collect();
+    (cue_object, object_object, velocities)
+}
+
+#[test]
+fn disabled_kind_pair_passes_through_while_others_collide() {
+    let (cue_object, object_object, velocities) =
+        run("collision_rules:\n  - {kinds: [Object, Cue], enabled: false}");
+    // 手球は的球と重なりながら通り抜け、的球は動きません。
+    assert!(cue_object < 0.1, "{cue_object}");
+    assert_eq!(velocities[1], 0.0);
+    assert!((velocities[0] - 20.0).abs() < 1e-3, "{velocities:?}");
+    // 的球同士は接したところで跳ね返ります。
+    assert!(object_object > 2.0 * RADIUS - 0.1, "{object_object}");
+    assert!(
+        (velocities[2] + 20.0).abs() < 1e-3 && (velocities[3] - 20.0).abs() < 1e-3,
+        "{velocities:?}"
+    );
+}
+
+#[test]
+fn all_kind_pairs_collide_by_default() {
+    let (cue_object, _, velocities) = run("");
+    assert!(cue_object > 2.0 * RADIUS - 0.1, "{cue_object}");
+    assert!(
+        velocities[0].abs() < 1e-3 && (velocities[1] - 20.0).abs() < 1e-3,
+        "{velocities:?}"
+    );
+}
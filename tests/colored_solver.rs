@@ -0,0 +1,72 @@
+// tests/colored_solver.rs
+//
+// collision_solver: Colored で衝突をバッチに分けて並列に解決した結果が、密集した配置でも
+// Serial で 1 つずつ解決した結果とビット単位で一致することを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+
+/// 10 × 10 の格子に、ほぼ接するように並べた 100 個のボールの entities を返します。
+/// 各ボールには BallId に応じて向きと速さの異なる初速度を与えます。
+fn dense_cluster() -> String {
+    let mut entities = String::new();
+    for i in 0..100 {
+        let (column, row) = (i % 10, i / 10);
+        let x = 35.0 + column as f32 * 5.8;
+        let y = 100.0 + row as f32 * 5.8;
+        let vx = ((i * 37) % 41) as f32 * 10.0 - 200.0;
+        let vy = ((i * 53) % 43) as f32 * 10.0 - 210.0;
+        let kind = if i == 0 { "Cue" } else { "Object" };
+        entities.push_str(&format!(
+            "  - {{kind: {kind}, x: {x}, y: {y}, vx: {vx}, vy: {vy}}}\n"
+        ));
+    }
+    entities
+}
+
+/// `solver` の方法と `extra` の設定で密集した配置を 150 ステップ進め、各ボールの位置と速度のビット列と、
+/// ボール同士の衝突回数を返します。
+fn final_state(solver: &str, extra: &str) -> (Vec<[u32; 4]>, usize) {
+    let yaml = standard_yaml(
+        0.95,
+        &format!(
+            "dt: 0.001
+units: {{velocity: CmPerS}}
+friction_xy: [0.2, 0.2]
+collision_solver: {solver}
+threads: 4
+{extra}
+entities:
+{}",
+            dense_cluster()
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    sim.run(150);
+    let velocities = sim.query_velocities();
+    let state = sim
+        .query_positions()
+        .into_iter()
+        .zip(velocities)
+        .map(|((_, p), (_, v))| [p.x.to_bits(), p.y.to_bits(), v.x.to_bits(), v.y.to_bits()])
+        .collect();
+    (state, sim.metrics().ball_collisions)
+}
+
+#[test]
+fn colored_solver_matches_the_serial_solver_bit_for_bit() {
+    for extra in [
+        "",
+        "solver_iterations: 4",
+        "ball_friction: 0.2\nsolver_iterations: 2",
+    ] {
+        let (serial, serial_collisions) = final_state("Serial", extra);
+        let (colored, colored_collisions) = final_state("Colored", extra);
+        assert!(serial_collisions > 100, "{extra:?} {serial_collisions}");
+        assert_eq!(colored_collisions, serial_collisions, "{extra:?}");
+        assert_eq!(colored, serial, "{extra:?}");
+    }
+}
@@ -0,0 +1,53 @@
+// tests/common/mod.rs
+//
+// このファイルでは、結合テストの各ファイルで共通に使う、設定の読み込みとログの記録の補助を定義します。
+// テストのファイルごとに使う補助が異なるため、使わない補助があっても警告にしません。
+#![allow(dead_code)]
+
+use my_specs_game::config::{load_config_from_reader, Config, Format};
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+/// 多くのテストで使う、127 × 254 cm の台と、半径 2.85 cm・質量 0.17 kg のボールの設定に、
+/// 反発係数 `restitution` と残りの設定 `rest` を加えた YAML を返します。
+///
+/// # 引数
+/// - `restitution`: ボールの反発係数
+/// - `rest`: 台とボール以外の設定（YAML）
+pub fn standard_yaml(restitution: f32, rest: &str) -> String {
+    format!(
+        "table: {{width: 127.0, height: 254.0}}
+ball: {{radius: 2.85, mass: 0.17, restitution: {restitution:?}}}
+{rest}"
+    )
+}
+
+/// YAML 形式の設定を読み込み、検証した Config を返します。読み込みに失敗した場合はパニックします。
+///
+/// # 引数
+/// - `yaml`: 設定の YAML
+pub fn config_from_yaml(yaml: &str) -> Config {
+    load_config_from_reader(yaml.as_bytes(), Format::Yaml).unwrap()
+}
+
+/// ログの出力を貯めておく書き込み先です。複製した LogBuffer は同じ領域に書き込みます。
+#[derive(Clone, Default)]
+pub struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl LogBuffer {
+    /// これまでに書き込まれたログを文字列として返します。
+    pub fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl Write for LogBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+// tests/component_queries.rs
+//
+// query_positions() などのコンポーネントごとの取得結果が、同じステップの WorldSnapshot と件数・値ともに一致することを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::BallKind;
+use my_specs_game::output::MemorySink;
+use my_specs_game::Simulation;
+
+#[test]
+fn component_queries_match_the_full_snapshot() {
+    let yaml = standard_yaml(
+        0.95,
+        "dt: 0.002
+units: {velocity: CmPerS}
+scenario: break
+break_speed: 800.0
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    let sink = MemorySink::new();
+    let snapshots = sink.snapshots();
+    sim.add_sink(Box::new(sink));
+    for _ in 0..100 {
+        sim.step();
+        let snapshots = snapshots.borrow();
+        let snapshot = snapshots.last().unwrap();
+        let (positions, velocities, kinds) = (
+            sim.query_positions(),
+            sim.query_velocities(),
+            sim.query::<BallKind>(),
+        );
+        assert_eq!(positions.len(), snapshot.balls.len());
+        assert_eq!(velocities.len(), snapshot.balls.len());
+        assert_eq!(kinds.len(), snapshot.balls.len());
+        for (i, ball) in snapshot.balls.iter().enumerate() {
+            assert_eq!(positions[i].0, ball.id);
+            assert_eq!((positions[i].1.x, positions[i].1.y), (ball.x, ball.y));
+            assert_eq!(velocities[i].0, ball.id);
+            assert_eq!((velocities[i].1.x, velocities[i].1.y), (ball.vx, ball.vy));
+            assert_eq!(kinds[i], (ball.id, ball.kind));
+        }
+    }
+    assert!(sim.metrics().ball_collisions > 0);
+}
@@ -0,0 +1,129 @@
+// tests/config_builder.rs
+//
+// ConfigBuilder で組み立てた Config が、指定した値を持ち、設定ファイルを読み込む場合と同じ検証を受けることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::config::ConfigBuilder;
+use my_specs_game::Simulation;
+
+/// テストで共通に使う、台とボールと時間刻みを指定したビルダーを返します。
+fn builder() -> ConfigBuilder {
+    ConfigBuilder::new()
+        .dt(0.01)
+        .table(127.0, 254.0)
+        .ball(2.85, 0.17, 0.9)
+}
+
+#[test]
+fn builder_sets_the_given_fields() {
+    let config = builder()
+        .cue(63.5, 40.0, 0.5, 2.0)
+        .add_object_ball(63.5, 190.5)
+        .add_object_ball(60.6, 195.4)
+        .build()
+        .unwrap();
+    assert_eq!(config.dt, 0.01);
+    assert_eq!((config.table.width, config.table.height), (127.0, 254.0));
+    assert_eq!(
+        (
+            config.ball.radius,
+            config.ball.mass,
+            config.ball.restitution
+        ),
+        (2.85, 0.17, 0.9)
+    );
+    let cue = config.cue_ball.as_ref().unwrap();
+    assert_eq!(
+        (cue.x, cue.y, cue.vx, cue.vy),
+        (63.5, 40.0, Some(0.5), Some(2.0))
+    );
+    let positions: Vec<(f32, f32)> = config
+        .object_balls
+        .positions
+        .iter()
+        .map(|p| (p.x, p.y))
+        .collect();
+    assert_eq!(positions, [(63.5, 190.5), (60.6, 195.4)]);
+}
+
+#[test]
+fn built_config_matches_the_equivalent_yaml() {
+    let built = builder()
+        .cue(63.5, 40.0, 0.0, 3.0)
+        .add_object_ball(63.5, 190.5)
+        .build()
+        .unwrap();
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+cue_ball: {x: 63.5, y: 40.0, vx: 0.0, vy: 3.0}
+object_balls: {positions: [{x: 63.5, y: 190.5}]}
+",
+    );
+    let loaded = config_from_yaml(&yaml);
+    let mut from_builder = Simulation::new(&built).unwrap();
+    let mut from_yaml = Simulation::new(&loaded).unwrap();
+    from_builder.run(200);
+    from_yaml.run(200);
+    assert_eq!(from_builder.state_hash(), from_yaml.state_hash());
+}
+
+#[test]
+fn build_runs_validation() {
+    let err = ConfigBuilder::new()
+        .dt(0.01)
+        .table(127.0, 254.0)
+        .ball(2.85, 0.17, 1.2)
+        .build()
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("ball.restitution is 1.2, greater than 1"),
+        "{err}"
+    );
+    // 必須の値（ここでは dt）を指定しない場合も Error になります。
+    assert!(ConfigBuilder::new()
+        .table(127.0, 254.0)
+        .ball(2.85, 0.17, 0.9)
+        .build()
+        .is_err());
+    // 台の外側の的球は、設定ファイルと同じく拒否されます。
+    let err = builder().add_object_ball(200.0, 40.0).build().unwrap_err();
+    assert!(err.to_string().contains("outside the table"), "{err}");
+}
+
+#[test]
+fn build_rejects_a_non_positive_dt() {
+    for dt in [-0.01, 0.0] {
+        let err = ConfigBuilder::new()
+            .dt(dt)
+            .table(127.0, 254.0)
+            .ball(2.85, 0.17, 0.9)
+            .build()
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("dt must be finite and positive"),
+            "{dt}: {err}"
+        );
+    }
+}
+
+#[test]
+fn build_rejects_a_non_positive_mass() {
+    for mass in [-0.17, 0.0] {
+        let err = ConfigBuilder::new()
+            .dt(0.01)
+            .table(127.0, 254.0)
+            .ball(2.85, mass, 0.9)
+            .build()
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("ball.mass must be finite and positive"),
+            "{mass}: {err}"
+        );
+    }
+}
@@ -0,0 +1,48 @@
+// tests/config_loading.rs
+//
+// 設定をファイル以外の読み込み元から読み込む load_config_from_reader() と、拡張子から書式を推定する load_config() を確かめるテストです。
+
+use my_specs_game::config::{load_config, load_config_from_reader, Format};
+
+const YAML: &str = "dt: 0.01
+table: {width: 127.0, height: 254.0}
+ball: {radius: 2.85, mass: 0.17, restitution: 0.9}
+entities:
+  - {kind: Cue, x: 20.0, y: 60.0, vx: 1.5}
+";
+
+const JSON: &str = r#"{
+  "dt": 0.01,
+  "table": {"width": 127.0, "height": 254.0},
+  "ball": {"radius": 2.85, "mass": 0.17, "restitution": 0.9},
+  "entities": [{"kind": "Cue", "x": 20.0, "y": 60.0, "vx": 1.5}]
+}"#;
+
+#[test]
+fn config_is_read_from_an_in_memory_byte_slice() {
+    for (text, format) in [(YAML, Format::Yaml), (JSON, Format::Json)] {
+        let config = load_config_from_reader(text.as_bytes(), format).unwrap();
+        assert_eq!(config.dt, 0.01);
+        assert_eq!(config.table.width, 127.0);
+        assert_eq!(config.entities.len(), 1);
+        assert_eq!(config.entities[0].vx, 1.5);
+    }
+}
+
+#[test]
+fn invalid_input_is_an_error_instead_of_a_panic() {
+    assert!(load_config_from_reader("dt: [".as_bytes(), Format::Yaml).is_err());
+    assert!(load_config_from_reader(&b"{\"dt\": 0.01"[..], Format::Json).is_err());
+    assert!(load_config_from_reader(&b""[..], Format::Yaml).is_err());
+}
+
+#[test]
+fn load_config_infers_the_format_from_the_extension() {
+    let dir = env!("CARGO_TARGET_TMPDIR");
+    for (name, text) in [("config_loading.yaml", YAML), ("config_loading.json", JSON)] {
+        let path = format!("{dir}/{name}");
+        std::fs::write(&path, text).unwrap();
+        let config = load_config(&path).unwrap();
+        assert_eq!(config.entities[0].x, 20.0);
+    }
+}
@@ -0,0 +1,139 @@
+// tests/config_patch.rs
+//
+// Simulation::apply_config_patch() で実行中に摩擦を変えると、すでに動いているボールの減速が次のステップから変わり、
+// 構造に関わる項目や無効な値の差分、イベント駆動モードでの摩擦や重力の差分は拒否されることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::config::ConfigPatch;
+use my_specs_game::Simulation;
+
+/// 20 cm/s² の摩擦の台で、手球を +y 方向に 100 cm/s で転がす Simulation を生成します。
+fn rolling_ball() -> Simulation {
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: CmPerS}
+friction_xy: [0.2, 0.2]
+entities:
+  - {kind: Cue, x: 63.5, y: 40.0, vy: 100.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+/// 1 ステップ進め、その間の手球の速さの減少（cm/s）を返します。
+fn decrement(sim: &mut Simulation) -> f32 {
+    let before = sim.query_velocities()[0].1.y;
+    sim.step();
+    before - sim.query_velocities()[0].1.y
+}
+
+#[test]
+fn patched_friction_changes_the_deceleration_of_a_moving_ball() {
+    let mut sim = rolling_ball();
+    for _ in 0..10 {
+        assert!((decrement(&mut sim) - 0.2).abs() < 1e-4);
+    }
+    let entities = sim.query_positions().len();
+    sim.apply_config_patch(&ConfigPatch {
+        friction_xy: Some((0.5, 0.5)),
+        ..ConfigPatch::default()
+    })
+    .unwrap();
+    // エンティティは作り直されず、次のステップから 50 cm/s² で減速します。
+    assert_eq!(sim.query_positions().len(), entities);
+    assert_eq!(sim.steps(), 10);
+    for _ in 0..10 {
+        assert!((decrement(&mut sim) - 0.5).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn patched_dt_and_gravity_take_effect() {
+    let mut sim = rolling_ball();
+    sim.apply_config_patch(&ConfigPatch {
+        dt: Some(0.02),
+        friction_xy: Some((0.0, 0.0)),
+        gravity_xy: Some((0.0, -1.0)),
+        ..ConfigPatch::default()
+    })
+    .unwrap();
+    // 100 cm/s² の重力で、0.02 秒のステップごとに 2 cm/s ずつ減速します。
+    assert!((decrement(&mut sim) - 2.0).abs() < 1e-3);
+}
+
+#[test]
+fn invalid_and_structural_patches_are_rejected() {
+    let mut sim = rolling_ball();
+    let err = sim
+        .apply_config_patch(&ConfigPatch {
+            dt: Some(0.0),
+            friction_xy: Some((0.5, 0.5)),
+            ..ConfigPatch::default()
+        })
+        .unwrap_err();
+    assert!(err.to_string().starts_with("dt must be positive"), "{err}");
+    // 無効な差分では、有効な項目（摩擦）も変更されません。
+    assert!((decrement(&mut sim) - 0.2).abs() < 1e-4);
+    let err = sim
+        .apply_config_patch(&ConfigPatch {
+            restitution_override: Some(1.5),
+            ..ConfigPatch::default()
+        })
+        .unwrap_err();
+    assert!(err.to_string().starts_with("restitution_override"), "{err}");
+    // ボールの数やテーブルの寸法などのキーは、読み込みの時点で拒否されます。
+    for yaml in ["table: {width: 100.0, height: 200.0}", "entities: []"] {
+        assert!(serde_yaml::from_str::<ConfigPatch>(yaml).is_err(), "{yaml}");
+    }
+    assert_eq!(
+        serde_yaml::from_str::<ConfigPatch>("friction_xy: [0.1, 0.3]").unwrap(),
+        ConfigPatch {
+            friction_xy: Some((0.1, 0.3)),
+            ..ConfigPatch::default()
+        }
+    );
+}
+
+#[test]
+fn friction_and_gravity_patches_are_rejected_with_event_driven_stepping() {
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: CmPerS}
+stepping: EventDriven
+entities:
+  - {kind: Cue, x: 63.5, y: 40.0, vy: 100.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    for patch in [
+        ConfigPatch {
+            friction_xy: Some((0.5, 0.5)),
+            ..ConfigPatch::default()
+        },
+        ConfigPatch {
+            gravity_xy: Some((0.0, -1.0)),
+            ..ConfigPatch::default()
+        },
+    ] {
+        let err = sim.apply_config_patch(&patch).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("not supported with stepping: EventDriven"),
+            "{err}"
+        );
+    }
+    // 拒否された差分は適用されず、手球は等速で進み続けます。
+    assert_eq!(decrement(&mut sim), 0.0);
+    // 摩擦や重力を含まない差分は、イベント駆動モードでも適用できます。
+    sim.apply_config_patch(&ConfigPatch {
+        dt: Some(0.02),
+        ..ConfigPatch::default()
+    })
+    .unwrap();
+}
@@ -0,0 +1,80 @@
+// tests/constraints.rs
+//
+// 距離の拘束でつないだ 2 つのボールが、離れていこうとしても自然長のまわりで振動し続け、
+// 拘束がない場合は離れていくこと、イベント駆動モードでは拘束を指定できないことを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::config::{load_config_from_reader, Format};
+use my_specs_game::Simulation;
+
+/// 自然長（20 cm）の間隔で並べた 2 つのボールを、互いに 20 cm/s で離れる向きに動かし、
+/// `constraints` の設定で 1500 ステップ（1.5 秒）実行したときの中心間距離の列を返します。
+fn distances(constraints: &str) -> Vec<f32> {
+    let config = config_from_yaml(&yaml(constraints));
+    let mut sim = Simulation::new(&config).unwrap();
+    (0..1500)
+        .map(|_| {
+            sim.step();
+            let positions = sim.query_positions();
+            let (a, b) = (positions[0].1, positions[1].1);
+            ((b.x - a.x).powi(2) + (b.y - a.y).powi(2)).sqrt()
+        })
+        .collect()
+}
+
+/// 自然長（20 cm）の間隔で並べた 2 つのボールを互いに離れる向きに動かし、`extra` の設定を加えた YAML を返します。
+fn yaml(extra: &str) -> String {
+    standard_yaml(
+        1.0,
+        &format!(
+            "dt: 0.001
+units: {{velocity: CmPerS}}
+entities:
+  - {{kind: Cue, x: 53.5, y: 127.0, vx: -20.0}}
+  - {{kind: Object, x: 73.5, y: 127.0, vx: 20.0}}
+{extra}"
+        ),
+    )
+}
+
+#[test]
+fn tethered_balls_oscillate_around_the_rest_length() {
+    // 換算質量 0.085 とばね定数 0.85 から、角振動数は √10 rad/s、周期は約 2 秒です。
+    // 相対速度 40 cm/s から、振幅は 40 / √10 ≈ 12.6 cm になります。
+    let distances = distances("constraints: [{a: 0, b: 1, rest_length: 20.0, stiffness: 0.85}]");
+    let (max_at, max) =
+        distances
+            .iter()
+            .copied()
+            .enumerate()
+            .fold(
+                (0, f32::MIN),
+                |best, (i, d)| if d > best.1 { (i, d) } else { best },
+            );
+    assert!((max - 32.6).abs() < 0.5, "{max}");
+    // 最も伸びた後は、自然長より縮む向きへ引き戻されます。
+    let min_after = distances[max_at..].iter().copied().fold(f32::MAX, f32::min);
+    assert!(min_after < 20.0 && min_after > 2.0 * 2.85, "{min_after}");
+}
+
+#[test]
+fn untethered_balls_fly_apart() {
+    let distances = distances("");
+    let last = *distances.last().unwrap();
+    assert!((last - 80.0).abs() < 0.1, "{last}");
+}
+
+#[test]
+fn constraints_are_rejected_with_event_driven_stepping() {
+    let yaml = yaml(
+        "constraints: [{a: 0, b: 1, rest_length: 20.0, stiffness: 0.85}]
+stepping: EventDriven",
+    );
+    let err = load_config_from_reader(yaml.as_bytes(), Format::Yaml).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "constraints are not supported with stepping: EventDriven"
+    );
+}
@@ -0,0 +1,70 @@
+// tests/coordinate_origin.rs
+//
+// 設定ファイル上の座標の原点（coordinate_origin）を隅と中心のどちらにしても、同じ配置が同じ内部の座標になることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+
+/// `origin` の座標系で、`offset` だけずらした位置にボールとポケットを置いた Simulation を生成します。
+fn simulation(origin: &str, offset: (f32, f32)) -> Simulation {
+    let (ox, oy) = offset;
+    let yaml = standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+coordinate_origin: {origin}
+pockets: {{radius: 6.0, positions: [{{x: {}, y: {}}}]}}
+entities:
+  - {{kind: Cue, x: {}, y: {}, vx: -150.0, vy: 40.0}}
+  - {{kind: Object, x: {}, y: {}}}
+",
+            0.0 - ox,
+            0.0 - oy,
+            20.0 - ox,
+            60.0 - oy,
+            63.5 - ox,
+            190.0 - oy,
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+/// 各ボールの BallId の番号と、隅を原点とした内部の位置を返します。
+fn positions(sim: &Simulation) -> Vec<(u32, f32, f32)> {
+    sim.query_positions()
+        .into_iter()
+        .map(|(id, p)| (id.0, p.x, p.y))
+        .collect()
+}
+
+#[test]
+fn corner_and_center_origins_give_the_same_internal_positions() {
+    let mut corner = simulation("Corner", (0.0, 0.0));
+    let mut center = simulation("Center", (63.5, 127.0));
+    assert_eq!(positions(&corner), positions(&center));
+    // 壁との衝突も隅を原点とした内部の座標で判定されるため、実行した後も一致します。
+    for _ in 0..300 {
+        corner.step();
+        center.step();
+    }
+    assert_eq!(positions(&corner), positions(&center));
+    assert_eq!(corner.state_hash(), center.state_hash());
+}
+
+#[test]
+fn corner_is_the_default_origin() {
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+entities:
+  - {kind: Cue, x: 20.0, y: 60.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let p = Simulation::new(&config).unwrap().query_positions()[0].1;
+    assert_eq!((p.x, p.y), (20.0, 60.0));
+}
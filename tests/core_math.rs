@@ -0,0 +1,104 @@
+// tests/core_math.rs
+//
+// core_math の純粋関数を直接呼び出した結果が、ECS のシステムを通した結果と一致すること、
+// および core_math が std を使わずにビルドできることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::core_math;
+use my_specs_game::Simulation;
+use std::process::Command;
+
+#[test]
+fn core_math_builds_without_std() {
+    // core_math_no_std は、src/core_math.rs を #![no_std] のクレートに取り込んでビルドします。
+    let manifest = concat!(env!("CARGO_MANIFEST_DIR"), "/core_math_no_std/Cargo.toml");
+    let target_dir = concat!(env!("CARGO_TARGET_TMPDIR"), "/core_math_no_std");
+    let output = Command::new(env!("CARGO"))
+        .args(["build", "--offline", "--quiet", "--manifest-path", manifest])
+        .env("CARGO_TARGET_DIR", target_dir)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn physics_step_matches_apply_friction() {
+    let yaml = standard_yaml(
+        1.0,
+        "dt: 0.01
+units: {velocity: CmPerS}
+friction_xy: [0.3, 0.2]
+entities:
+  - {kind: Cue, x: 60.0, y: 100.0, vx: 100.0, vy: 50.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    sim.step();
+    let (vx, vy) = core_math::apply_friction((100.0, 50.0), (0.3 * 100.0, 0.2 * 100.0), 0.01);
+    let v = sim.query_velocities()[0].1;
+    assert_eq!((v.x, v.y), (vx, vy));
+    let p = sim.query_positions()[0].1;
+    assert_eq!((p.x, p.y), (60.0 + vx * 0.01, 100.0 + vy * 0.01));
+}
+
+#[test]
+fn impulse_with_restitution_one_matches_elastic_2ball() {
+    // (質量, 位置, 速度) の組を A と B について並べた、いくつかの衝突の配置です。
+    let cases = [
+        (
+            (0.17, (0.0, 0.0), (100.0, 0.0)),
+            (0.17, (5.6, 0.0), (0.0, 0.0)),
+        ),
+        (
+            (0.17, (0.0, 0.0), (80.0, 30.0)),
+            (0.17, (4.0, 3.9), (-10.0, 5.0)),
+        ),
+        (
+            (0.5, (10.0, 10.0), (0.0, -50.0)),
+            (0.17, (11.0, 4.5), (20.0, 40.0)),
+        ),
+        (
+            (0.1, (0.0, 0.0), (30.0, 30.0)),
+            (2.0, (3.0, 4.0), (0.0, 0.0)),
+        ),
+    ];
+    for ((ma, pa, va), (mb, pb, vb)) in cases {
+        let body = |mass: f32, (x, y): (f32, f32), (vx, vy): (f32, f32)| core_math::Body {
+            x,
+            y,
+            vx,
+            vy,
+            mass,
+            radius: 2.85,
+        };
+        let (a, b) = (body(ma, pa, va), body(mb, pb, vb));
+        let (jx, jy) = core_math::ball_collision_impulse(&a, &b, 1.0, 1e-6).unwrap();
+        let impulse_a = (va.0 + jx / ma, va.1 + jy / ma);
+        let impulse_b = (vb.0 - jx / mb, vb.1 - jy / mb);
+        let (elastic_a, elastic_b) =
+            core_math::elastic_2ball(ma, va, mb, vb, (pb.0 - pa.0, pb.1 - pa.1));
+        for (x, y) in [(impulse_a, elastic_a), (impulse_b, elastic_b)] {
+            assert!(
+                (x.0 - y.0).abs() < 1e-3 && (x.1 - y.1).abs() < 1e-3,
+                "{x:?} != {y:?}"
+            );
+        }
+        // 運動量と運動エネルギーが保存されることも確かめます。
+        let momentum =
+            |(a, b): ((f32, f32), (f32, f32))| (ma * a.0 + mb * b.0, ma * a.1 + mb * b.1);
+        let energy = |(a, b): ((f32, f32), (f32, f32))| {
+            ma * (a.0 * a.0 + a.1 * a.1) + mb * (b.0 * b.0 + b.1 * b.1)
+        };
+        let (before, after) = ((va, vb), (elastic_a, elastic_b));
+        assert!((momentum(before).0 - momentum(after).0).abs() < 1e-3);
+        assert!((momentum(before).1 - momentum(after).1).abs() < 1e-3);
+        assert!((energy(before) - energy(after)).abs() / energy(before) < 1e-5);
+    }
+}
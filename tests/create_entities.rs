@@ -0,0 +1,109 @@
+// tests/create_entities.rs
+//
+// create_entities() が、entities の一覧と従来の cue_ball・object_balls のどちらからも、必要なコンポーネントを持つボールを生成することを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::{
+    register_all_components, Ball, BallId, BallKind, CollisionMask, Position, Table, Velocity,
+};
+use my_specs_game::entities::create_entities;
+use specs::prelude::*;
+
+/// 生成されたボールの (BallId, 種類, 位置, 速度, Ball, 衝突レイヤー) です。
+type CreatedBall = (BallId, BallKind, (f32, f32), (f32, f32), Ball, u32);
+
+/// 設定から World を生成し、各ボールのコンポーネントを生成された順に返します。
+fn build(yaml: &str) -> Vec<CreatedBall> {
+    let config = config_from_yaml(yaml);
+    let mut world = World::new();
+    register_all_components(&mut world);
+    let entities = create_entities(&mut world, &config);
+    assert_eq!(world.read_storage::<Table>().join().count(), 1);
+    let (id, kind, pos, vel, ball, mask) = (
+        world.read_storage::<BallId>(),
+        world.read_storage::<BallKind>(),
+        world.read_storage::<Position>(),
+        world.read_storage::<Velocity>(),
+        world.read_storage::<Ball>(),
+        world.read_storage::<CollisionMask>(),
+    );
+    entities
+        .iter()
+        .map(|&e| {
+            let (p, v, b) = (
+                pos.get(e).unwrap(),
+                vel.get(e).unwrap(),
+                ball.get(e).unwrap(),
+            );
+            (
+                *id.get(e).unwrap(),
+                *kind.get(e).unwrap(),
+                (p.x, p.y),
+                (v.x, v.y),
+                *b,
+                mask.get(e).copied().unwrap_or_default().0,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn generic_entity_list_creates_balls_with_overrides() {
+    let balls = build(&standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: CmPerS}
+entities:
+  - {kind: Cue, x: 63.5, y: 40.0, vy: 30.0}
+  - {kind: Cue, x: 20.0, y: 40.0, radius: 3.0, collision_mask: 2}
+  - {kind: Object, x: 63.5, y: 150.0, mass: 0.2, restitution: 0.5}
+",
+    ));
+    assert_eq!(balls.len(), 3);
+    let (id, kind, pos, vel, ball, mask) = balls[0];
+    assert_eq!(
+        (id, kind, pos, vel, mask),
+        (
+            BallId(0),
+            BallKind::Cue,
+            (63.5, 40.0),
+            (0.0, 30.0),
+            u32::MAX
+        )
+    );
+    assert_eq!(
+        (ball.radius, ball.mass, ball.restitution),
+        (2.85, 0.17, 0.9)
+    );
+    let (id, kind, _, _, ball, mask) = balls[1];
+    assert_eq!(
+        (id, kind, ball.radius, mask),
+        (BallId(1), BallKind::Cue, 3.0, 2)
+    );
+    let (id, kind, _, _, ball, _) = balls[2];
+    assert_eq!((id, kind), (BallId(2), BallKind::Object));
+    assert_eq!((ball.radius, ball.mass, ball.restitution), (2.85, 0.2, 0.5));
+}
+
+#[test]
+fn legacy_sections_map_onto_the_entity_list() {
+    let balls = build(&standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: CmPerS}
+cue_ball: {x: 63.5, y: 40.0, vx: 0.0, vy: 30.0}
+object_balls: {positions: [{x: 60.0, y: 150.0}, {x: 67.0, y: 150.0}]}
+",
+    ));
+    let summary: Vec<_> = balls.iter().map(|b| (b.0, b.1, b.2, b.3)).collect();
+    assert_eq!(
+        summary,
+        [
+            (BallId(0), BallKind::Cue, (63.5, 40.0), (0.0, 30.0)),
+            (BallId(1), BallKind::Object, (60.0, 150.0), (0.0, 0.0)),
+            (BallId(2), BallKind::Object, (67.0, 150.0), (0.0, 0.0)),
+        ]
+    );
+}
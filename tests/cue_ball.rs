@@ -0,0 +1,82 @@
+// tests/cue_ball.rs
+//
+// 手球を BallId ではなく BallKind で探すことを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::{BallId, BallKind};
+use my_specs_game::simulation::ShotSpin;
+use my_specs_game::Simulation;
+use std::f32::consts::FRAC_PI_2;
+
+/// 的球を先頭に、手球を 2 番目に並べた `entities` の設定で Simulation を生成します。
+fn object_first(extra: &str) -> Simulation {
+    let yaml = standard_yaml(
+        1.0,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+entities:
+  - {{kind: Object, x: 30.0, y: 60.0}}
+  - {{kind: Cue, x: 90.0, y: 60.0}}
+{extra}"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn shot_moves_the_cue_even_when_it_is_not_the_first_entity() {
+    let mut sim = object_first("");
+    sim.shot(FRAC_PI_2, 100.0, ShotSpin::Stun).unwrap();
+    let velocities = sim.query_velocities();
+    let (object, cue) = (velocities[0].1, velocities[1].1);
+    assert_eq!((object.x, object.y), (0.0, 0.0));
+    assert!(
+        cue.x.abs() < 1e-4 && (cue.y - 100.0).abs() < 1e-4,
+        "{cue:?}"
+    );
+}
+
+#[test]
+fn shot_fails_without_exactly_one_cue() {
+    let yaml = standard_yaml(
+        1.0,
+        "dt: 0.01
+entities:
+  - {kind: Cue, x: 30.0, y: 60.0}
+  - {kind: Cue, x: 90.0, y: 60.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    assert!(sim.shot(0.0, 100.0, ShotSpin::Stun).is_err());
+}
+
+#[test]
+fn ball_in_hand_respawns_the_cue_with_its_own_id() {
+    // 手球を右側のポケットへ撞き落とし、次のショットの前に頭の位置へ置き直させます。
+    let mut sim = object_first(
+        "pockets: {radius: 6.0, drop_steps: 0, positions: [{x: 127.0, y: 60.0}]}
+shots:
+  - {angle_deg: 0.0, speed: 300.0}
+  - {angle_deg: 90.0, speed: 10.0}
+",
+    );
+    let outcomes = sim.run_shots();
+    assert_eq!(outcomes.len(), 2);
+    assert_eq!(outcomes[0].pocketed, vec![BallId(1)]);
+    assert!(outcomes[1].cue_replaced);
+    let kinds: Vec<_> = outcomes[1]
+        .snapshot
+        .balls
+        .iter()
+        .map(|b| (b.id, b.kind))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![(BallId(0), BallKind::Object), (BallId(1), BallKind::Cue)]
+    );
+}
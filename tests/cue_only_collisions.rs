@@ -0,0 +1,106 @@
+// tests/cue_only_collisions.rs
+//
+// cue_only_collisions を有効にすると、的球同士の衝突は解決されずにすり抜け、手球と的球、手球と壁の衝突は
+// これまでどおり解決されることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::BallId;
+use my_specs_game::Simulation;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// `stepping` のモードで、cue_only_collisions を `enabled` にした `entities` の Simulation を生成します。
+fn simulation(stepping: &str, enabled: bool, entities: &str) -> Simulation {
+    let yaml = standard_yaml(
+        1.0,
+        &format!(
+            "dt: 0.001
+units: {{velocity: CmPerS}}
+stepping: {stepping}
+cue_only_collisions: {enabled}
+entities:
+{entities}"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+/// 手球が静止した的球 1 に正面から当たり、動き出した的球 1 の進路上に静止した的球 2 がある配置です。
+const CHAIN: &str = "  - {kind: Cue, x: 63.5, y: 40.0, vy: 100.0}
+  - {kind: Object, x: 63.5, y: 80.0}
+  - {kind: Object, x: 63.5, y: 120.0}
+";
+
+#[test]
+fn cue_strikes_an_object_that_then_passes_through_another() {
+    for stepping in ["FixedStep", "EventDriven"] {
+        let mut sim = simulation(stepping, true, CHAIN);
+        let pairs = Rc::new(RefCell::new(Vec::new()));
+        let recorded = Rc::clone(&pairs);
+        sim.on_collision(Box::new(move |c| {
+            recorded.borrow_mut().push((c.id_a, c.id_b))
+        }));
+        // 手球は 0.343 秒後に的球 1 に当たり、的球 1 はさらに 0.343 秒後に的球 2 の位置を通り過ぎます。
+        sim.run(1000);
+        assert_eq!(*pairs.borrow(), [(BallId(0), BallId(1))], "{stepping}");
+        let v = sim.query_velocities();
+        assert!(v[0].1.y.abs() < 1e-2, "{stepping} {v:?}");
+        assert!((v[1].1.y - 100.0).abs() < 1e-2, "{stepping} {v:?}");
+        // 的球 2 は触れられずに静止したままで、的球 1 はその先へ進みます。
+        assert_eq!(v[2].1.y, 0.0, "{stepping}");
+        let p = sim.query_positions();
+        assert!(p[1].1.y > p[2].1.y + 10.0, "{stepping} {p:?}");
+    }
+}
+
+#[test]
+fn object_balls_on_a_collision_course_pass_through_each_other() {
+    for stepping in ["FixedStep", "EventDriven"] {
+        let mut sim = simulation(
+            stepping,
+            true,
+            "  - {kind: Cue, x: 63.5, y: 200.0}
+  - {kind: Object, x: 40.0, y: 60.0, vx: 20.0}
+  - {kind: Object, x: 60.0, y: 60.0, vx: -20.0}
+",
+        );
+        sim.run(1000);
+        let p = sim.query_positions();
+        assert!((p[1].1.x - 60.0).abs() < 1e-3, "{stepping} {p:?}");
+        assert!((p[2].1.x - 40.0).abs() < 1e-3, "{stepping} {p:?}");
+        assert_eq!(sim.metrics().ball_collisions, 0, "{stepping}");
+    }
+}
+
+#[test]
+fn cue_still_bounces_off_the_walls() {
+    for stepping in ["FixedStep", "EventDriven"] {
+        let mut sim = simulation(
+            stepping,
+            true,
+            "  - {kind: Cue, x: 10.0, y: 60.0, vx: -100.0}\n",
+        );
+        sim.run(200);
+        assert_eq!(sim.metrics().wall_collisions, 1, "{stepping}");
+        assert!(
+            (sim.query_velocities()[0].1.x - 100.0).abs() < 1e-2,
+            "{stepping}"
+        );
+    }
+}
+
+#[test]
+fn object_pairs_collide_when_disabled() {
+    let mut sim = simulation("FixedStep", false, CHAIN);
+    sim.run(1000);
+    let v = sim.query_velocities();
+    // 速度は的球 1 から的球 2 へ受け渡されます。
+    assert!(
+        v[1].1.y.abs() < 1e-2 && (v[2].1.y - 100.0).abs() < 1e-2,
+        "{v:?}"
+    );
+    assert_eq!(sim.metrics().ball_collisions, 2);
+}
@@ -0,0 +1,57 @@
+// tests/cue_velocity.rs
+//
+// 手球の初速度を、成分（vx, vy）の代わりに速さと向き（speed, angle_deg）で指定できることを確かめるテストです。
+
+mod common;
+
+use common::standard_yaml;
+use my_specs_game::config::{load_config_from_reader, Config, Format};
+use my_specs_game::Simulation;
+
+/// 手球の設定 `cue_ball` と速度の単位 `unit` で設定を読み込みます。
+fn load(unit: &str, cue_ball: &str) -> Result<Config, Box<dyn std::error::Error>> {
+    let yaml = standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.01
+units: {{velocity: {unit}}}
+cue_ball: {cue_ball}
+object_balls: {{positions: []}}
+"
+        ),
+    );
+    load_config_from_reader(yaml.as_bytes(), Format::Yaml)
+}
+
+/// 設定から生成した手球の、内部の単位（cm/s）での初速度を返します。
+fn cue_velocity(unit: &str, cue_ball: &str) -> (f32, f32) {
+    let sim = Simulation::new(&load(unit, cue_ball).unwrap()).unwrap();
+    let v = sim.query_velocities()[0].1;
+    (v.x, v.y)
+}
+
+#[test]
+fn speed_and_angle_match_the_component_form() {
+    let (vx, vy) = cue_velocity(
+        "CmPerS",
+        "{x: 63.5, y: 40.0, speed: 100.0, angle_deg: 90.0}",
+    );
+    assert!(vx.abs() < 1e-4 && (vy - 100.0).abs() < 1e-4, "({vx}, {vy})");
+    let (ex, ey) = cue_velocity("CmPerS", "{x: 63.5, y: 40.0, vx: 0.0, vy: 100.0}");
+    assert!((vx - ex).abs() < 1e-4 && (vy - ey).abs() < 1e-4);
+    // 角度 0 は +x 方向で、速さは units.velocity の単位から変換されます。
+    let (vx, vy) = cue_velocity("MPerS", "{x: 63.5, y: 40.0, speed: 1.5, angle_deg: 0.0}");
+    assert!((vx - 150.0).abs() < 1e-3 && vy.abs() < 1e-4, "({vx}, {vy})");
+}
+
+#[test]
+fn exactly_one_velocity_form_is_required() {
+    for cue_ball in [
+        "{x: 63.5, y: 40.0, vx: 0.0, vy: 100.0, speed: 100.0, angle_deg: 90.0}",
+        "{x: 63.5, y: 40.0, speed: 100.0}",
+        "{x: 63.5, y: 40.0, vx: 1.0}",
+        "{x: 63.5, y: 40.0}",
+    ] {
+        assert!(load("CmPerS", cue_ball).is_err(), "{cue_ball}");
+    }
+}
@@ -0,0 +1,47 @@
+// tests/current_contacts.rs
+//
+// Simulation::current_contacts() が、ステップの間に何度呼び出しても同じ保持済みの接触情報を返し、
+// 次のステップを実行すると新しい接触情報に置き換えられることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::BallId;
+use my_specs_game::Simulation;
+
+#[test]
+fn contacts_are_cached_between_steps_and_replaced_by_the_next_step() {
+    // 的球は、0.7 cm めり込んだ位置からゆっくり（10 cm/s）離れていきます。
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: CmPerS}
+entities:
+  - {kind: Cue, x: 60.0, y: 60.0}
+  - {kind: Object, x: 65.0, y: 60.0, vx: 10.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    assert!(sim.current_contacts().is_empty());
+
+    sim.step();
+    let first = sim.current_contacts();
+    assert_eq!(first.len(), 1);
+    let contact = first[0];
+    assert_eq!((contact.id_a, contact.id_b), (BallId(0), BallId(1)));
+    assert_eq!((contact.normal_x, contact.normal_y), (1.0, 0.0));
+    assert!((contact.penetration - 0.6).abs() < 1e-4, "{contact:?}");
+    // 2 回目の呼び出しも、計算し直さずに同じ領域を返します。
+    let again = sim.current_contacts();
+    assert_eq!(again.as_ptr(), first.as_ptr());
+    assert_eq!(again, [contact]);
+
+    sim.step();
+    let next = sim.current_contacts();
+    assert_eq!(next.len(), 1);
+    assert!((next[0].penetration - 0.5).abs() < 1e-4, "{:?}", next[0]);
+    // 離れきった後のステップでは、接触はなくなります。
+    sim.run(10);
+    assert!(sim.current_contacts().is_empty());
+}
@@ -0,0 +1,56 @@
+// tests/cushion_damping.rs
+//
+// cushion_tangential_damping が、クッションで跳ね返るときにレールに沿った速度成分だけを減らすことを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+
+/// 左のクッションに 45° で当たるボールを、`stepping` のモードと `extra` の設定で転がし、跳ね返った後の速度を返します。
+fn outgoing_velocity(stepping: &str, extra: &str) -> (f32, f32) {
+    let yaml = standard_yaml(
+        1.0,
+        &format!(
+            "dt: 0.001
+stepping: {stepping}
+units: {{velocity: CmPerS}}
+friction_xy: [0.0, 0.0]
+{extra}
+entities:
+  - {{kind: Cue, x: 20.0, y: 100.0, vx: -100.0, vy: 100.0}}
+"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    sim.run(300);
+    let v = sim.query_velocities()[0].1;
+    (v.x, v.y)
+}
+
+#[test]
+fn tangential_damping_only_reduces_the_along_rail_speed() {
+    for stepping in ["FixedStep", "EventDriven"] {
+        let (vx, vy) = outgoing_velocity(stepping, "");
+        assert!(
+            (vx - 100.0).abs() < 1e-3 && (vy - 100.0).abs() < 1e-3,
+            "{stepping}: ({vx}, {vy})"
+        );
+        let (vx, vy) = outgoing_velocity(stepping, "cushion_tangential_damping: 0.8");
+        // 法線方向の成分は反発係数 1.0 のまま反転し、レールに沿った成分だけが 0.8 倍になります。
+        assert!((vx - 100.0).abs() < 1e-3, "{stepping}: {vx}");
+        assert!((vy - 80.0).abs() < 1e-3, "{stepping}: {vy}");
+    }
+}
+
+#[test]
+fn damping_of_one_leaves_the_bounce_unchanged() {
+    for stepping in ["FixedStep", "EventDriven"] {
+        assert_eq!(
+            outgoing_velocity(stepping, "cushion_tangential_damping: 1.0"),
+            outgoing_velocity(stepping, ""),
+            "{stepping}"
+        );
+    }
+}
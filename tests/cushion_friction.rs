@@ -0,0 +1,56 @@
+// tests/cushion_friction.rs
+//
+// cushion_friction を指定すると、クッションに沿った速度を持ってぶつかったボールが、接点の摩擦によって
+// 沿う向きに応じた符号のひねり（鉛直軸まわりの回転）を持って跳ね返ることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+
+/// x = `x` から速度 (`vx`, `vy`) で左右のクッションへ向かうボールを、`cushion_friction` を指定して 300 ステップ実行し、
+/// 最後の速度 (vx, vy) と鉛直軸まわりの回転（rad/s）を返します。
+fn bounce(x: f32, vx: f32, vy: f32, cushion_friction: f32) -> ((f32, f32), f32) {
+    let yaml = standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.001
+units: {{velocity: CmPerS}}
+cushion_friction: {cushion_friction}
+entities:
+  - {{kind: Cue, x: {x}, y: 100.0, vx: {vx}, vy: {vy}}}
+"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    sim.run(300);
+    let v = sim.query_velocities()[0].1;
+    let spin = sim.query_spins().first().map_or(0.0, |(_, s)| s.z);
+    ((v.x, v.y), spin)
+}
+
+#[test]
+fn sliding_along_a_rail_induces_side_spin() {
+    // 左のクッション（x = 0）に、+y 向きに沿う速度を持ってぶつかると、接点の摩擦が -y 向きに働き、
+    // 反時計回り（+z）の回転を生じます。
+    // 摩擦の上限に達しないため、接点の滑りが止まるまで（ω r = vy になるまで）速度が回転に移ります。
+    // 球の慣性モーメント 2/5 m r² から、沿う速度は 50 / (1 + 5/2) だけ減ります。
+    let ((vx, vy), spin) = bounce(20.0, -100.0, 50.0, 0.2);
+    assert!((vx - 90.0).abs() < 1e-3, "{vx}");
+    assert!((vy - (50.0 - 50.0 / 3.5)).abs() < 1e-3, "{vy}");
+    assert!(spin > 0.0 && (spin * 2.85 - vy).abs() < 1e-3, "{spin}");
+    // 沿う向きが逆なら、回転の向きも逆になります。
+    let (_, spin) = bounce(20.0, -100.0, -50.0, 0.2);
+    assert!(spin < 0.0, "{spin}");
+    // 右のクッションでは、同じ +y 向きの速度で時計回り（-z）の回転になります。
+    let (_, spin) = bounce(107.0, 100.0, 50.0, 0.2);
+    assert!(spin < 0.0, "{spin}");
+}
+
+#[test]
+fn friction_is_off_by_default() {
+    let ((vx, vy), spin) = bounce(20.0, -100.0, 50.0, 0.0);
+    assert!((vx - 90.0).abs() < 1e-3, "{vx}");
+    assert_eq!((vy, spin), (50.0, 0.0));
+}
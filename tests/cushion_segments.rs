@@ -0,0 +1,92 @@
+// tests/cushion_segments.rs
+//
+// 線分のクッション（cushion_segments）との衝突が、ボールの中心と線分の距離が半径になる位置で起きることを確かめるテストです。
+
+mod common;
+
+use common::config_from_yaml;
+use my_specs_game::core_math::segment_contact;
+use my_specs_game::Simulation;
+
+const RADIUS: f32 = 2.85;
+
+/// 1 つの線分のクッションと、1 つのボールを置いた設定で Simulation を生成します。
+fn simulation(segment: &str, ball: &str) -> Simulation {
+    let yaml = format!(
+        "dt: 0.001
+table: {{width: 127.0, height: 254.0}}
+ball: {{radius: {RADIUS}, mass: 0.17, restitution: 1.0}}
+units: {{velocity: CmPerS}}
+cushion_segments: [{segment}]
+entities:
+  - {ball}
+"
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+/// `steps` ステップ実行し、各ステップの後のボールの中心から線分までの距離を `distance` で求めて、
+/// その最小値と最後の速度を返します。
+fn closest_approach(
+    sim: &mut Simulation,
+    steps: usize,
+    distance: impl Fn(f32, f32) -> f32,
+) -> (f32, (f32, f32)) {
+    let mut closest = f32::INFINITY;
+    for _ in 0..steps {
+        sim.step();
+        let p = sim.query_positions()[0].1;
+        closest = closest.min(distance(p.x, p.y));
+    }
+    let v = sim.query_velocities()[0].1;
+    (closest, (v.x, v.y))
+}
+
+#[test]
+fn contact_starts_when_the_center_is_one_radius_away() {
+    // 斜めの線分 x + y = 160 から、中心を法線方向に距離 d だけ離して置きます。
+    let at = |d: f32| {
+        let offset = d / std::f32::consts::SQRT_2;
+        (80.0 - offset, 80.0 - offset)
+    };
+    let (x, y) = at(RADIUS + 0.01);
+    assert!(segment_contact(x, y, RADIUS, (60.0, 100.0), (100.0, 60.0), 1e-6).is_none());
+    let (x, y) = at(RADIUS - 0.5);
+    let (nx, ny, depth) =
+        segment_contact(x, y, RADIUS, (60.0, 100.0), (100.0, 60.0), 1e-6).unwrap();
+    let inward = -std::f32::consts::FRAC_1_SQRT_2;
+    assert!((nx - inward).abs() < 1e-5 && (ny - inward).abs() < 1e-5);
+    assert!((depth - 0.5).abs() < 1e-3, "{depth}");
+}
+
+#[test]
+fn ball_bounces_off_a_diagonal_segment_at_one_radius() {
+    let mut sim = simulation(
+        "{x1: 60.0, y1: 100.0, x2: 100.0, y2: 60.0}",
+        "{kind: Cue, x: 60.0, y: 60.0, vx: 50.0, vy: 50.0}",
+    );
+    let (closest, (vx, vy)) = closest_approach(&mut sim, 1000, |x, y| {
+        (160.0 - x - y) / std::f32::consts::SQRT_2
+    });
+    // めり込みは押し戻されるため、最も近づいたときの距離はちょうど半径になります。
+    assert!((closest - RADIUS).abs() < 1e-3, "{closest}");
+    assert!(
+        (vx + 50.0).abs() < 1e-2 && (vy + 50.0).abs() < 1e-2,
+        "({vx}, {vy})"
+    );
+}
+
+#[test]
+fn ball_bounces_off_the_rounded_end_of_a_segment() {
+    // 線分の延長線上から端点へ向かって転がすと、端点を中心とした半円に当たって真後ろへ跳ね返ります。
+    let mut sim = simulation(
+        "{x1: 60.0, y1: 100.0, x2: 100.0, y2: 100.0}",
+        "{kind: Cue, x: 30.0, y: 100.0, vx: 50.0}",
+    );
+    let (closest, (vx, vy)) = closest_approach(&mut sim, 1000, |x, y| {
+        ((x - 60.0).powi(2) + (y - 100.0).powi(2)).sqrt()
+    });
+    assert!((closest - RADIUS).abs() < 1e-3, "{closest}");
+    assert!((vx + 50.0).abs() < 1e-2 && vy.abs() < 1e-2, "({vx}, {vy})");
+}
@@ -0,0 +1,60 @@
+// tests/cushion_wear.rs
+//
+// クッションの反発係数が、壁の衝突回数に応じて設定した量だけ変化すること（cushion_wear）を確かめるテストです。
+
+mod common;
+
+use common::config_from_yaml;
+use my_specs_game::resources::{Wall, WallImpacts};
+use my_specs_game::Simulation;
+use specs::prelude::*;
+
+#[test]
+fn restitution_drifts_with_the_impact_count() {
+    // 幅の狭いテーブルで、ボールを左右の壁の間で往復させます。
+    let yaml = "dt: 0.001
+table: {width: 20.0, height: 254.0}
+ball: {radius: 2.85, mass: 0.17, restitution: 0.9}
+units: {velocity: CmPerS}
+cushion_wear: {rate: 0.005, min: 0.5, max: 0.97}
+entities:
+  - {kind: Cue, x: 10.0, y: 100.0, vx: 300.0}
+";
+    let config = config_from_yaml(yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    let count = |sim: &Simulation| {
+        sim.observe(|world| {
+            let impacts = world.read_resource::<WallImpacts>();
+            impacts.count(Wall::Left) + impacts.count(Wall::Right)
+        })
+    };
+    let mut bounces = 0;
+    while bounces < 40 {
+        let before = (sim.query_velocities()[0].1.x, count(&sim));
+        sim.step();
+        let after = (sim.query_velocities()[0].1.x, count(&sim));
+        if after.1 == before.1 {
+            continue;
+        }
+        // 1 回の衝突ごとに、その壁のそれまでの衝突回数に応じた反発係数で跳ね返ります。
+        assert_eq!(after.1, before.1 + 1);
+        let wall_count = sim.observe(|world| {
+            let impacts = world.read_resource::<WallImpacts>();
+            if after.0 < 0.0 {
+                impacts.count(Wall::Right)
+            } else {
+                impacts.count(Wall::Left)
+            }
+        }) - 1;
+        let expected = (0.9 + 0.005 * wall_count as f32).clamp(0.5, 0.97);
+        let ratio = -after.0 / before.0;
+        assert!(
+            (ratio - expected).abs() < 1e-4,
+            "{wall_count}: {ratio} != {expected}"
+        );
+        bounces += 1;
+    }
+    // 左右の壁に 20 回ずつ当たり、後半の衝突では反発係数が上限（0.97）に達しています。
+    let last = sim.observe(|world| world.read_resource::<WallImpacts>().count(Wall::Right));
+    assert_eq!(last, 20);
+}
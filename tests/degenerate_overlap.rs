@@ -0,0 +1,54 @@
+// tests/degenerate_overlap.rs
+//
+// 中心がほぼ同じ位置にある 2 つのボールを、NaN を生じさせずに +x 方向の法線で引き離すことを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+
+/// 中心を `gap`（cm）だけ x 方向にずらした 2 つのボールを置き、`extra` を加えた設定で Simulation を生成します。
+fn coincident(gap: f32, extra: &str) -> Simulation {
+    let yaml = standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+{extra}
+entities:
+  - {{kind: Cue, x: 60.0, y: 100.0}}
+  - {{kind: Object, x: {}, y: 100.0}}
+",
+            60.0 + gap
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn nearly_coincident_balls_separate_without_nan() {
+    for extra in ["", "collision_epsilon: 0.01", "collision_solver: Colored"] {
+        for gap in [0.0, 1e-6, 1e-4] {
+            let mut sim = coincident(gap, extra);
+            for _ in 0..20 {
+                sim.step();
+                for ((_, p), (_, v)) in sim
+                    .query_positions()
+                    .into_iter()
+                    .zip(sim.query_velocities())
+                {
+                    assert!(
+                        p.x.is_finite() && p.y.is_finite() && v.x.is_finite() && v.y.is_finite(),
+                        "{extra:?} {gap}: {p:?} {v:?}"
+                    );
+                }
+            }
+            // 法線は +x 方向に定まるため、1 番目のボールが -x 側、2 番目のボールが +x 側へ離れます。
+            let positions = sim.query_positions();
+            let (a, b) = (positions[0].1, positions[1].1);
+            assert!(b.x - a.x > 1.0, "{extra:?} {gap}: {a:?} {b:?}");
+            assert_eq!((a.y, b.y), (100.0, 100.0));
+        }
+    }
+}
@@ -0,0 +1,48 @@
+// tests/determinism_check.rs
+//
+// check_determinism() が、決定的な設定では一致を報告し、実行ごとに乱数の種が変わる設定では最初のステップで
+// 不一致を報告することを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::config::{Config, SeedConfig, SeedKeyword};
+use my_specs_game::simulation::check_determinism;
+use my_specs_game::Simulation;
+
+/// 的球の初期位置を乱数で揺らすブレイクショットの設定を読み込みます。
+fn jittered_break() -> Config {
+    let yaml = standard_yaml(
+        0.95,
+        "dt: 0.002
+units: {velocity: CmPerS}
+scenario: break
+break_speed: 800.0
+rack_jitter: 0.3
+seed: 7
+",
+    );
+    config_from_yaml(&yaml)
+}
+
+#[test]
+fn deterministic_config_passes_the_self_check() {
+    let config = jittered_break();
+    let hash = check_determinism(&config, 20).unwrap().unwrap();
+    let mut sim = Simulation::new(&config).unwrap();
+    sim.run(20);
+    assert_eq!(hash, sim.state_hash());
+}
+
+#[test]
+fn unseeded_randomness_is_reported_as_a_mismatch() {
+    // 読み込み時の種の固定を経ずに "random" を設定し、Simulation を生成するたびに異なる種で的球を揺らします。
+    let mut config = jittered_break();
+    config.seed = SeedConfig::Keyword(SeedKeyword::Random);
+    let mismatch = check_determinism(&config, 20).unwrap().unwrap_err();
+    assert_eq!(mismatch.step, 0);
+    assert_ne!(mismatch.first, mismatch.second);
+    assert!(mismatch
+        .to_string()
+        .starts_with("simulation is not deterministic: state hash differs at step 0"));
+}
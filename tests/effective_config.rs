@@ -0,0 +1,61 @@
+// tests/effective_config.rs
+//
+// Simulation::effective_config_yaml() で書き出した設定が、既定値と確定した乱数の種を含み、
+// 読み込み直して生成すると同じ実行を再現することを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+
+/// 乱数の種を "random" にし、初速度を km/h で指定して、的球の初期位置を揺らすブレイクショットの設定を返します。
+fn random_break() -> String {
+    standard_yaml(
+        0.95,
+        "dt: 0.002
+units: {velocity: KmH}
+friction_xy: [0.2, 0.2]
+scenario: break
+break_speed: 28.8
+rack_jitter: 0.3
+seed: random
+",
+    )
+}
+
+/// `yaml` の設定から Simulation を生成します。
+fn simulation(yaml: &str) -> Simulation {
+    let config = config_from_yaml(yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn rerunning_from_the_dump_reproduces_the_run() {
+    let mut original = simulation(&random_break());
+    let dump = original.effective_config_yaml();
+    original.run(300);
+
+    let mut rerun = simulation(&dump);
+    assert_eq!(rerun.seed(), original.seed());
+    rerun.run(300);
+    assert_eq!(rerun.state_hash(), original.state_hash());
+    assert_eq!(
+        rerun.metrics().ball_collisions,
+        original.metrics().ball_collisions
+    );
+    // 読み込み直した設定から書き出しても、同じ内容になります。
+    assert_eq!(rerun.effective_config_yaml(), dump);
+}
+
+#[test]
+fn dump_holds_the_concrete_seed_and_defaults() {
+    let sim = simulation(&random_break());
+    let dump: serde_yaml::Value = serde_yaml::from_str(&sim.effective_config_yaml()).unwrap();
+    assert_eq!(dump["seed"].as_u64(), Some(sim.seed()));
+    // 単位は指定のまま明示され、値は変換されずに最短の表記で書き出されます。
+    assert_eq!(dump["units"]["velocity"].as_str(), Some("KmH"));
+    assert_eq!(dump["break_speed"].as_f64(), Some(28.8));
+    // 省略した項目も、既定値として書き出されます。
+    assert_eq!(dump["clamp_initial_positions"].as_bool(), Some(false));
+    assert!(dump.get("collision_solver").is_some());
+}
@@ -0,0 +1,64 @@
+// tests/effective_restitution.rs
+//
+// ボール同士の衝突の記録に、restitution_blend で組み合わせた実効的な反発係数が含まれることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::output::MemorySink;
+use my_specs_game::Simulation;
+
+/// 反発係数 0.9 と 0.5 のボールを `blend` の組み合わせ方で正面衝突させ、記録された衝突の (実効的な反発係数, 衝突後の速度) を返します。
+fn collide(stepping: &str, blend: &str) -> (Vec<f32>, (f32, f32)) {
+    let yaml = standard_yaml(
+        1.0,
+        &format!(
+            "dt: 0.001
+stepping: {stepping}
+units: {{velocity: CmPerS}}
+friction_xy: [0.0, 0.0]
+restitution_blend: {blend}
+entities:
+  - {{kind: Cue, x: 40.0, y: 60.0, vx: 20.0, restitution: 0.9}}
+  - {{kind: Object, x: 60.0, y: 60.0, vx: -20.0, restitution: 0.5}}
+"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    let memory = MemorySink::new();
+    let snapshots = memory.snapshots();
+    sim.add_sink(Box::new(memory));
+    sim.run(1000);
+    let logged = snapshots
+        .borrow()
+        .iter()
+        .flat_map(|s| s.collisions.iter().map(|c| c.effective_restitution))
+        .collect();
+    let v = sim.query_velocities();
+    (logged, (v[0].1.x, v[1].1.x))
+}
+
+#[test]
+fn average_blend_logs_the_mean_restitution() {
+    for stepping in ["FixedStep", "EventDriven"] {
+        let (logged, (va, vb)) = collide(stepping, "Average");
+        assert_eq!(logged.len(), 1, "{stepping}");
+        assert!((logged[0] - 0.7).abs() < 1e-6, "{stepping}: {}", logged[0]);
+        // 記録された値が実際の衝突に使われ、相対速度 40 cm/s が 0.7 倍で反転します。
+        assert!((vb - va - 28.0).abs() < 1e-3, "{stepping}: ({va}, {vb})");
+    }
+}
+
+#[test]
+fn other_blends_log_their_own_values() {
+    for (blend, expected) in [("Min", 0.5), ("Max", 0.9), ("Product", 0.45)] {
+        let (logged, _) = collide("FixedStep", blend);
+        assert_eq!(logged.len(), 1, "{blend}");
+        assert!(
+            (logged[0] - expected).abs() < 1e-6,
+            "{blend}: {}",
+            logged[0]
+        );
+    }
+}
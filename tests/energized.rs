@@ -0,0 +1,64 @@
+// tests/energized.rs
+//
+// allow_energized を指定すると 1 を超える反発係数が許可され、ボールが壁で跳ね返るたびにその倍率で速くなり、
+// 指定しない場合は読み込み時にエラーになることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::config::{load_config_from_reader, Format};
+use my_specs_game::Simulation;
+
+/// 反発係数 1.2 のボールを左右の壁の間で往復させる設定に `extra` を加えた YAML を返します。
+fn yaml(extra: &str) -> String {
+    standard_yaml(
+        1.2,
+        &format!(
+            "dt: 0.001
+units: {{velocity: CmPerS}}
+entities:
+  - {{kind: Cue, x: 20.0, y: 60.0, vx: -50.0}}
+{extra}"
+        ),
+    )
+}
+
+#[test]
+fn energized_ball_speeds_up_by_the_restitution_on_each_bounce() {
+    for stepping in ["FixedStep", "EventDriven"] {
+        let extra = format!("allow_energized: true\nstepping: {stepping}");
+        let config = config_from_yaml(&yaml(&extra));
+        let mut sim = Simulation::new(&config).unwrap();
+        // 跳ね返って向きが変わるたびに、その直後の速度を記録します。
+        let mut bounces = Vec::new();
+        let mut last = -50.0_f32;
+        while bounces.len() < 3 {
+            assert!(sim.steps() < 20_000, "{stepping}: {bounces:?}");
+            sim.step();
+            let vx = sim.query_velocities()[0].1.x;
+            if vx.signum() != last.signum() {
+                bounces.push(vx);
+            }
+            last = vx;
+        }
+        let expected = [60.0, -72.0, 86.4];
+        for (vx, expected) in bounces.iter().zip(expected) {
+            assert!((vx - expected).abs() < 1e-2, "{stepping}: {bounces:?}");
+        }
+        let p = sim.query_positions()[0].1;
+        assert!((2.85..=127.0 - 2.85).contains(&p.x), "{stepping}: {p:?}");
+    }
+}
+
+#[test]
+fn restitution_above_one_is_rejected_without_energized_mode() {
+    for extra in ["", "allow_energized: false"] {
+        let error = load_config_from_reader(yaml(extra).as_bytes(), Format::Yaml).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .starts_with("ball.restitution is 1.2, greater than 1"),
+            "{error}"
+        );
+    }
+}
@@ -0,0 +1,47 @@
+// tests/energy_log.rs
+//
+// systems.log_energy を有効にすると、ボールごとのログとは別に、ステップごとに 1 行の kinetic_energy のログが出力され、
+// 摩擦で減速する間はその値が単調に減ることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml, LogBuffer};
+use my_specs_game::Simulation;
+
+#[test]
+fn energy_is_logged_once_per_step_and_decreases_under_friction() {
+    // システムは parallel フィーチャーが有効な場合にスレッドプールで実行されるため、
+    // スレッドごとではなくプロセス全体の出力先を設定します。
+    let buffer = LogBuffer::default();
+    let writer = buffer.clone();
+    tracing::subscriber::set_global_default(
+        tracing_subscriber::fmt()
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish(),
+    )
+    .unwrap();
+
+    let yaml = standard_yaml(0.9, "dt: 0.01
+units: {velocity: CmPerS}
+friction_xy: [0.2, 0.2]
+systems: {physics: true, collision: true, pockets: true, logging: false, log_energy: true, stats: true}
+entities:
+  - {kind: Cue, x: 63.5, y: 40.0, vy: 60.0}
+  - {kind: Object, x: 30.0, y: 150.0, vx: 20.0}
+");
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    sim.run(200);
+
+    let log = buffer.contents();
+    let energies: Vec<f32> = log
+        .lines()
+        .filter_map(|line| line.split("kinetic_energy=").nth(1))
+        .map(|value| value.trim().parse().unwrap())
+        .collect();
+    assert_eq!(energies.len(), 200, "{log}");
+    // ボールごとのログ（logging）は無効にしているため、位置のログは出力されません。
+    assert!(!log.contains("Ball 0"), "{log}");
+    assert!(energies.windows(2).all(|w| w[1] < w[0]), "{energies:?}");
+}
@@ -0,0 +1,331 @@
+// tests/event_driven.rs
+//
+// イベント駆動モード（stepping: EventDriven）の振る舞いを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::Position;
+use my_specs_game::config::{load_config_from_reader, Config, Format};
+use my_specs_game::events::CollisionEvent;
+use my_specs_game::resources::SimClock;
+use my_specs_game::Simulation;
+use specs::prelude::*;
+
+/// 2 つのボールを x 軸に沿って向かい合わせに転がす設定で、`stepping` のモードごとに `steps` ステップ実行し、
+/// 最後の位置を返します。
+fn head_on_positions(stepping: &str, extra: &str, steps: usize) -> Vec<Position> {
+    let mut sim = Simulation::new(&config(&format!(
+        "dt: 0.001
+stepping: {stepping}
+entities:
+  - {{kind: Cue, x: 40.0, y: 60.0, vx: 20.0}}
+  - {{kind: Object, x: 60.0, y: 60.0, vx: -20.0}}
+{extra}"
+    )))
+    .unwrap();
+    for _ in 0..steps {
+        sim.step();
+    }
+    sim.query_positions().into_iter().map(|(_, p)| p).collect()
+}
+
+/// 2 つの位置の列が `tolerance`（cm）以内で一致することを確かめます。
+fn assert_close(a: &[Position], b: &[Position], tolerance: f32) {
+    assert_eq!(a.len(), b.len());
+    for (a, b) in a.iter().zip(b) {
+        assert!(
+            (a.x - b.x).abs() <= tolerance && (a.y - b.y).abs() <= tolerance,
+            "{a:?} != {b:?}"
+        );
+    }
+}
+
+/// 共通の台とボールの設定に `extra` を加えた設定を読み込みます。
+fn config(extra: &str) -> Config {
+    let yaml = standard_yaml(
+        1.0,
+        &format!(
+            "units: {{velocity: CmPerS}}
+{extra}"
+        ),
+    );
+    config_from_yaml(&yaml)
+}
+
+#[test]
+fn freezing_a_ball_does_not_spread_to_its_partner() {
+    // 重い A が軽い B を右の壁へ押し込み、B は A・壁・A の順に 3 回衝突して上限（2 回）を超えて凍結します。
+    // A は凍結した B を動かない障害物として跳ね返り、凍結しません。
+    let mut sim = Simulation::new(&config(
+        "dt: 0.5
+stepping: EventDriven
+max_events_per_second: 4.0
+entities:
+  - {kind: Cue, x: 110.0, y: 60.0, vx: 100.0, mass: 0.5}
+  - {kind: Object, x: 120.0, y: 60.0}
+",
+    ))
+    .unwrap();
+    sim.step();
+    let velocities = sim.query_velocities();
+    let (a, b) = (velocities[0].1, velocities[1].1);
+    assert_eq!((b.x, b.y), (0.0, 0.0));
+    assert!(a.x < -1.0, "A should bounce off the frozen ball: {a:?}");
+}
+
+#[test]
+fn ball_wedged_in_a_corner_is_frozen_and_time_advances() {
+    // 重い A が軽い B を右下の角へ押し込むと、B は A と 2 つの壁の間で短い時間に何度も衝突します。
+    // 上限（1 ステップあたり 10 回）で B が凍結し、ステップは先へ進みます。
+    let mut sim = Simulation::new(&config(
+        "dt: 0.1
+stepping: EventDriven
+max_events_per_second: 100.0
+entities:
+  - {kind: Cue, x: 115.0, y: 242.0, vx: 100.0, vy: 100.0, mass: 10.0}
+  - {kind: Object, x: 123.5, y: 250.5}
+",
+    ))
+    .unwrap();
+    sim.step();
+    let b = sim.query_velocities()[1].1;
+    assert_eq!((b.x, b.y), (0.0, 0.0));
+    let p = sim.query_positions()[1].1;
+    assert!(
+        p.x > 123.5 && p.y > 250.5,
+        "B should have been pushed first: {p:?}"
+    );
+    sim.run(10);
+    let elapsed = sim.observe(|world| world.read_resource::<SimClock>().elapsed);
+    assert!((elapsed.as_secs_f32() - 1.1).abs() < 1e-4, "{elapsed:?}");
+    for (_, p) in sim.query_positions() {
+        assert!(p.x.is_finite() && p.y.is_finite(), "{p:?}");
+    }
+}
+
+#[test]
+fn event_driven_matches_fixed_step_for_a_head_on_bounce() {
+    // 等しい質量の完全弾性衝突では速度が入れ替わり、2 つのボールは元の位置へ戻っていきます。
+    let fixed = head_on_positions("FixedStep", "", 1000);
+    let event = head_on_positions("EventDriven", "", 1000);
+    assert_close(&fixed, &event, 0.1);
+    assert!(event[0].x < 40.0 && event[1].x > 60.0, "{event:?}");
+}
+
+#[test]
+fn disabled_collisions_let_balls_pass_in_both_modes() {
+    let extra = "systems: {physics: true, collision: false, pockets: true, logging: false, log_energy: false, stats: true}";
+    let fixed = head_on_positions("FixedStep", extra, 1000);
+    let event = head_on_positions("EventDriven", extra, 1000);
+    assert_close(&fixed, &event, 1e-3);
+    assert_close(
+        &event,
+        &[Position { x: 60.0, y: 60.0 }, Position { x: 40.0, y: 60.0 }],
+        1e-3,
+    );
+}
+
+#[test]
+fn event_driven_honours_collision_rules() {
+    let extra = "collision_rules:\n  - {kinds: [Cue, Object], enabled: false}";
+    let fixed = head_on_positions("FixedStep", extra, 1000);
+    let event = head_on_positions("EventDriven", extra, 1000);
+    assert_close(&fixed, &event, 1e-3);
+    assert_close(
+        &event,
+        &[Position { x: 60.0, y: 60.0 }, Position { x: 40.0, y: 60.0 }],
+        1e-3,
+    );
+}
+
+#[test]
+fn event_driven_skips_object_pairs_with_cue_only_collisions() {
+    let positions = |stepping: &str| {
+        let mut sim = Simulation::new(&config(&format!(
+            "dt: 0.001
+stepping: {stepping}
+cue_only_collisions: true
+entities:
+  - {{kind: Object, x: 40.0, y: 60.0, vx: 20.0}}
+  - {{kind: Object, x: 60.0, y: 60.0, vx: -20.0}}"
+        )))
+        .unwrap();
+        // 次の衝突は、的球同士ではなく壁との衝突になります。
+        assert!(matches!(
+            sim.time_to_next_event(),
+            Some((_, CollisionEvent::Wall { .. }))
+        ));
+        for _ in 0..1000 {
+            sim.step();
+        }
+        sim.query_positions()
+            .into_iter()
+            .map(|(_, p)| p)
+            .collect::<Vec<_>>()
+    };
+    let fixed = positions("FixedStep");
+    let event = positions("EventDriven");
+    assert_close(&fixed, &event, 1e-3);
+    assert_close(
+        &event,
+        &[Position { x: 60.0, y: 60.0 }, Position { x: 40.0, y: 60.0 }],
+        1e-3,
+    );
+}
+
+/// 半径 30 cm の円形のテーブルで、1 つのボールを斜めに転がす設定です。
+fn circle_table(stepping: &str, dt: f32) -> Config {
+    config_from_yaml(&format!(
+        "dt: {dt}
+stepping: {stepping}
+table: {{width: 60.0, height: 60.0, shape: !Circle {{ radius: 30.0 }}}}
+ball: {{radius: 2.85, mass: 0.17, restitution: 1.0}}
+units: {{velocity: CmPerS}}
+entities:
+  - {{kind: Cue, x: 30.0, y: 30.0, vx: 30.0, vy: 40.0}}
+"
+    ))
+}
+
+#[test]
+fn event_driven_keeps_balls_inside_a_circle_table() {
+    let mut sim = Simulation::new(&circle_table("EventDriven", 0.01)).unwrap();
+    for _ in 0..500 {
+        sim.step();
+        let p = sim.query_positions()[0].1;
+        let distance = ((p.x - 30.0).powi(2) + (p.y - 30.0).powi(2)).sqrt();
+        assert!(
+            distance <= 30.0 - 2.85 + 1e-3,
+            "escaped to {distance} at {p:?}"
+        );
+    }
+    // 完全弾性の衝突だけなので、速さは変わりません。
+    let v = sim.query_velocities()[0].1;
+    assert!(
+        ((v.x * v.x + v.y * v.y).sqrt() - 50.0).abs() < 1e-2,
+        "{v:?}"
+    );
+}
+
+#[test]
+fn event_driven_matches_fixed_step_on_a_circle_table() {
+    let run = |stepping: &str| {
+        let mut sim = Simulation::new(&circle_table(stepping, 0.001)).unwrap();
+        for _ in 0..1000 {
+            sim.step();
+        }
+        sim.query_positions()
+            .into_iter()
+            .map(|(_, p)| p)
+            .collect::<Vec<_>>()
+    };
+    assert_close(&run("FixedStep"), &run("EventDriven"), 0.2);
+}
+
+#[test]
+fn event_driven_applies_table_defects_to_cushions() {
+    // すべてのクッションの反発係数を半分にする個体差で、右の壁に当てて跳ね返った速さを比べます。
+    let run = |stepping: &str| {
+        let mut sim = Simulation::new(&config(&format!(
+            "dt: 0.01
+stepping: {stepping}
+table_defects: {{cushion_restitution: {{mean: 0.5}}}}
+entities:
+  - {{kind: Cue, x: 110.0, y: 60.0, vx: 100.0}}
+"
+        )))
+        .unwrap();
+        for _ in 0..30 {
+            sim.step();
+        }
+        sim.query_velocities()[0].1
+    };
+    for stepping in ["FixedStep", "EventDriven"] {
+        let v = run(stepping);
+        assert!((v.x + 50.0).abs() < 1e-3, "{stepping}: {v:?}");
+    }
+}
+
+#[test]
+fn event_driven_rejects_obstacles_it_cannot_simulate() {
+    for extra in [
+        "pegs: {x: 20.0, y: 100.0, rows: 1, columns: 1, spacing_x: 12.0, spacing_y: 12.0, radius: 0.5}",
+        "cushion_segments: [{x1: 0.0, y1: 20.0, x2: 20.0, y2: 0.0}]",
+    ] {
+        let yaml = standard_yaml(1.0, &format!(
+            "dt: 0.01
+stepping: EventDriven
+entities:
+  - {{kind: Cue, x: 60.0, y: 60.0}}
+{extra}
+"
+        ));
+        assert!(
+            load_config_from_reader(yaml.as_bytes(), Format::Yaml).is_err(),
+            "{extra}"
+        );
+    }
+}
+
+/// 狭いテーブルで 6 つのボールを衝突させ続ける設定で、`stepping` のモードの Simulation を時間刻み `dt` で生成します。
+fn crowded_box(stepping: &str, dt: f32) -> Simulation {
+    let yaml = format!(
+        "dt: {dt}
+stepping: {stepping}
+table: {{width: 60.0, height: 60.0}}
+ball: {{radius: 2.85, mass: 0.17, restitution: 1.0}}
+units: {{velocity: CmPerS}}
+entities:
+  - {{kind: Cue, x: 10.0, y: 10.0, vx: 90.0, vy: 70.0}}
+  - {{kind: Object, x: 30.0, y: 12.0, vx: -40.0, vy: 30.0}}
+  - {{kind: Object, x: 50.0, y: 15.0, vx: 20.0, vy: -60.0}}
+  - {{kind: Object, x: 12.0, y: 40.0, vx: 50.0, vy: 10.0}}
+  - {{kind: Object, x: 30.0, y: 35.0, vx: -70.0, vy: -20.0}}
+  - {{kind: Object, x: 48.0, y: 48.0, vx: 30.0, vy: 80.0}}"
+    );
+    Simulation::new(&config_from_yaml(&yaml)).unwrap()
+}
+
+/// 全ボールの運動エネルギーの合計を返します。
+fn kinetic_energy(sim: &Simulation) -> f32 {
+    sim.query_velocities()
+        .iter()
+        .map(|(_, v)| 0.5 * 0.17 * (v.x * v.x + v.y * v.y))
+        .sum()
+}
+
+#[test]
+fn event_driven_conserves_energy_over_many_collisions() {
+    let mut sim = crowded_box("EventDriven", 0.02);
+    let before = kinetic_energy(&sim);
+    for _ in 0..250 {
+        sim.step();
+    }
+    let drift = (kinetic_energy(&sim) - before).abs() / before;
+    let metrics = sim.metrics();
+    assert!(metrics.ball_collisions >= 20, "{}", metrics.ball_collisions);
+    assert!(drift < 1e-5, "{drift}");
+}
+
+#[test]
+fn event_driven_result_does_not_depend_on_the_step_size() {
+    // 衝突の時刻まで正確に進めるため、時間刻みを 10 倍にしても同じ時刻の状態はほぼ一致します。
+    // 多数のボールの衝突は小さな誤差を増幅するため、衝突が数回起きる 0.4 秒後の状態で比べます。
+    let run = |dt: f32, steps: usize| {
+        let mut sim = crowded_box("EventDriven", dt);
+        for _ in 0..steps {
+            sim.step();
+        }
+        let positions = sim
+            .query_positions()
+            .into_iter()
+            .map(|(_, p)| p)
+            .collect::<Vec<_>>();
+        (positions, sim.metrics().ball_collisions)
+    };
+    let (coarse, collisions) = run(0.02, 20);
+    let (fine, _) = run(0.002, 200);
+    assert!(collisions >= 1, "{collisions}");
+    assert_close(&coarse, &fine, 0.01);
+}
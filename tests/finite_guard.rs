@@ -0,0 +1,76 @@
+// tests/finite_guard.rs
+//
+// 速度に NaN が現れた場合に、non_finite_policy に従って検出されることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::{BallId, Velocity};
+use my_specs_game::Simulation;
+use specs::prelude::*;
+
+/// `policy` の扱いで 2 つのボールを置き、BallId(1) の速度を NaN にした Simulation を、設定に `extra` を加えて生成します。
+fn simulation_with_nan(policy: &str, extra: &str) -> Simulation {
+    let yaml = standard_yaml(
+        1.0,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+non_finite_policy: {policy}
+{extra}
+entities:
+  - {{kind: Cue, x: 20.0, y: 60.0, vx: 30.0}}
+  - {{kind: Object, x: 80.0, y: 60.0, vx: 10.0}}
+"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    let sim = Simulation::new(&config).unwrap();
+    {
+        let world = sim.world();
+        let ids = world.read_storage::<BallId>();
+        let mut vel = world.write_storage::<Velocity>();
+        for (id, v) in (&ids, &mut vel).join() {
+            if *id == BallId(1) {
+                v.x = f32::NAN;
+            }
+        }
+    }
+    sim
+}
+
+#[test]
+fn reset_policy_stops_only_the_offending_ball() {
+    // NaN の位置との衝突判定が、正常なボールに NaN を波及させないことも確かめます。
+    for extra in ["", "collision_solver: Colored\nsolver_iterations: 4"] {
+        let mut sim = simulation_with_nan("Reset", extra);
+        sim.run(3);
+        assert!(!sim.is_aborted());
+        let positions = sim.query_positions();
+        assert_eq!(
+            (positions[1].1.x, positions[1].1.y),
+            (63.5, 127.0),
+            "{extra}"
+        );
+        let velocities = sim.query_velocities();
+        assert_eq!(
+            (velocities[1].1.x, velocities[1].1.y),
+            (0.0, 0.0),
+            "{extra}"
+        );
+        // 正常なボールはそのまま進みます。
+        assert_eq!(velocities[0].1.x, 30.0, "{extra}");
+        assert!(
+            (positions[0].1.x - 20.9).abs() < 1e-4,
+            "{extra}: {:?}",
+            positions[0].1
+        );
+    }
+}
+
+#[test]
+fn abort_policy_reports_the_run_as_aborted() {
+    let mut sim = simulation_with_nan("Abort", "");
+    sim.step();
+    assert!(sim.is_aborted());
+}
@@ -0,0 +1,81 @@
+// tests/flush_cadence.rs
+//
+// CSV と JSON Lines の出力先に flush_every を指定すると、実行の途中でも指定した間隔ごとに
+// 書き出した結果がファイルに残ることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::output::{CsvSink, JsonSink, OutputSink};
+use my_specs_game::Simulation;
+
+/// 1 つのボールが転がる Simulation に `sink` を登録して `steps` ステップ進め、その時点で `path` のファイルにある行数を返します。
+/// `finish` が false の場合はステップを step() で進めるため、出力先の finish() は呼び出されません。
+fn lines_on_disk(sink: Box<dyn OutputSink>, path: &str, steps: usize, finish: bool) -> usize {
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: CmPerS}
+entities:
+  - {kind: Cue, x: 63.5, y: 40.0, vy: 30.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    sim.add_sink(sink);
+    if finish {
+        sim.run(steps);
+    } else {
+        for _ in 0..steps {
+            sim.step();
+        }
+    }
+    std::fs::read_to_string(path).unwrap().lines().count()
+}
+
+/// テスト用の出力ファイルのパスを返します。
+fn output_path(name: &str) -> String {
+    format!("{}/flush_cadence_{name}", env!("CARGO_TARGET_TMPDIR"))
+}
+
+#[test]
+fn csv_rows_reach_the_disk_every_flush_interval() {
+    for (steps, expected) in [(4, 0), (5, 6), (9, 6), (10, 11)] {
+        let path = output_path(&format!("every5_{steps}.csv"));
+        let sink = CsvSink::create(&path).unwrap().with_flush_every(5);
+        // ヘッダ行と、フラッシュ済みのステップの各 1 行です。
+        assert_eq!(
+            lines_on_disk(Box::new(sink), &path, steps, false),
+            expected,
+            "{steps}"
+        );
+    }
+}
+
+#[test]
+fn json_lines_reach_the_disk_every_flush_interval() {
+    for (steps, expected) in [(2, 0), (3, 3), (7, 6)] {
+        let path = output_path(&format!("every3_{steps}.jsonl"));
+        let sink = JsonSink::create(&path).unwrap().with_flush_every(3);
+        assert_eq!(
+            lines_on_disk(Box::new(sink), &path, steps, false),
+            expected,
+            "{steps}"
+        );
+    }
+}
+
+#[test]
+fn without_a_cadence_rows_stay_buffered_until_the_end() {
+    let path = output_path("never.csv");
+    let sink = CsvSink::create(&path).unwrap();
+    assert_eq!(lines_on_disk(Box::new(sink), &path, 20, false), 0);
+    // 実行の終わりには、間隔に達していない分も含めてフラッシュします。
+    for flush_every in [0, 7] {
+        let path = output_path(&format!("finish_{flush_every}.csv"));
+        let sink = CsvSink::create(&path)
+            .unwrap()
+            .with_flush_every(flush_every);
+        assert_eq!(lines_on_disk(Box::new(sink), &path, 20, true), 21);
+    }
+}
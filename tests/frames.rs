@@ -0,0 +1,45 @@
+// tests/frames.rs
+//
+// Simulation::frames() が 1 ステップごとにスナップショットを返し、ボールが静止するか最大ステップ数に達したところで終わることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+
+/// 摩擦で 1 秒ほどで止まるボールを 1 つ置いた Simulation を生成します。
+fn rolling_ball() -> Simulation {
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: CmPerS}
+friction_xy: [0.5, 0.5]
+entities:
+  - {kind: Cue, x: 63.5, y: 60.0, vy: 50.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn frames_end_on_the_step_the_balls_come_to_rest() {
+    let frames: Vec<_> = rolling_ball().frames(10_000).collect();
+    // 50 cm/s を 50 cm/s^2 で減速するため、およそ 100 ステップで止まります。
+    assert!((95..=105).contains(&frames.len()), "{}", frames.len());
+    let last = frames.last().unwrap();
+    assert!(last.balls.iter().all(|b| b.vx == 0.0 && b.vy == 0.0));
+    assert!(frames[..frames.len() - 1]
+        .iter()
+        .all(|f| f.balls.iter().any(|b| b.vy != 0.0)));
+    for (index, frame) in frames.iter().enumerate() {
+        assert_eq!(frame.step, index);
+    }
+}
+
+#[test]
+fn frames_stop_at_max_steps() {
+    let mut frames = rolling_ball().frames(10);
+    assert_eq!(frames.by_ref().count(), 10);
+    assert_eq!(frames.into_simulation().steps(), 10);
+}
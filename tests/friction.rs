@@ -0,0 +1,110 @@
+// tests/friction.rs
+//
+// ラシャの摩擦（friction_xy など）による減速と、摩擦や重力などの PhysicsSystem の設定が
+// イベント駆動モードでは拒否されることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::config::{load_config_from_reader, Format};
+use my_specs_game::Simulation;
+
+/// 共通の台とボールの設定に `extra` を加え、`ball` の 1 つのボールを置いた Simulation を生成します。
+fn simulation(extra: &str, ball: &str) -> Simulation {
+    let yaml = standard_yaml(
+        1.0,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+{extra}
+entities:
+  - {ball}
+"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn diagonal_shot_curves_toward_the_lower_friction_axis() {
+    let mut sim = simulation(
+        "friction_xy: [0.5, 0.1]",
+        "{kind: Cue, x: 20.0, y: 20.0, vx: 80.0, vy: 80.0}",
+    );
+    let mut previous = sim.query_positions()[0].1;
+    let mut heading = 1.0;
+    for _ in 0..50 {
+        sim.step();
+        let p = sim.query_positions()[0].1;
+        // 進む向きの x 軸からの傾きは、x 方向の減速が大きいため次第に y 軸へ近づきます。
+        let slope = (p.y - previous.y) / (p.x - previous.x);
+        assert!(slope > heading, "{slope} <= {heading}");
+        heading = slope;
+        previous = p;
+    }
+    let v = sim.query_velocities()[0].1;
+    assert!(v.y > v.x && v.x > 0.0, "{v:?}");
+}
+
+#[test]
+fn isotropic_friction_keeps_a_straight_line() {
+    let mut sim = simulation(
+        "friction_xy: [0.3, 0.3]",
+        "{kind: Cue, x: 20.0, y: 20.0, vx: 80.0, vy: 80.0}",
+    );
+    for _ in 0..50 {
+        sim.step();
+        let p = sim.query_positions()[0].1;
+        let v = sim.query_velocities()[0].1;
+        assert_eq!(p.x, p.y);
+        assert_eq!(v.x, v.y);
+    }
+    let v = sim.query_velocities()[0].1;
+    assert!(v.x < 80.0 && v.x > 0.0, "{v:?}");
+}
+
+#[test]
+fn physics_settings_are_rejected_with_event_driven_stepping() {
+    for (extra, name) in [
+        ("friction_xy: [0.2, 0.2]", "friction_xy"),
+        ("gravity_xy: [0.0, -0.5]", "gravity_xy"),
+        ("slip_friction: 0.2", "slip_friction"),
+        ("ball_friction: 0.05", "ball_friction"),
+        ("cushion_friction: 0.2", "cushion_friction"),
+        (
+            "low_speed_damping: {threshold: 5.0, deceleration: 10.0}",
+            "low_speed_damping",
+        ),
+        (
+            "friction_regions: [{x: 63.5, y: 127.0, radius: 20.0, multiplier: 2.0}]",
+            "friction_regions",
+        ),
+    ] {
+        let yaml = standard_yaml(
+            1.0,
+            &format!(
+                "dt: 0.01
+stepping: EventDriven
+{extra}
+"
+            ),
+        );
+        let err = load_config_from_reader(yaml.as_bytes(), Format::Yaml).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "{name} is not supported with stepping: EventDriven (it assumes frictionless motion)"
+            )
+        );
+    }
+    // 効果のない 0 の摩擦は、イベント駆動モードでも指定できます。
+    let yaml = standard_yaml(
+        1.0,
+        "dt: 0.01
+stepping: EventDriven
+friction_xy: [0.0, 0.0]
+",
+    );
+    assert!(load_config_from_reader(yaml.as_bytes(), Format::Yaml).is_ok());
+}
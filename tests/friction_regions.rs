@@ -0,0 +1,51 @@
+// tests/friction_regions.rs
+//
+// friction_regions の円形の領域を横切るボールが、領域の中では倍率を掛けた摩擦で速く減速し、
+// 領域を出ると元の摩擦の減速に戻ることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+
+#[test]
+fn ball_decelerates_faster_only_inside_a_dirty_spot() {
+    // 摩擦は 10 cm/s²、(63.5, 60) を中心とする半径 10 cm の領域の中では 5 倍の 50 cm/s² です。
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: CmPerS}
+friction_xy: [0.1, 0.1]
+friction_regions:
+  - {x: 63.5, y: 60.0, radius: 10.0, multiplier: 5.0}
+entities:
+  - {kind: Cue, x: 63.5, y: 20.0, vy: 100.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    let (mut inside, mut outside_before, mut outside_after) = (Vec::new(), Vec::new(), Vec::new());
+    let mut last_speed = 100.0;
+    for _ in 0..100 {
+        let y = sim.query_positions()[0].1.y;
+        sim.step();
+        let speed = sim.query_velocities()[0].1.y;
+        let decrement = last_speed - speed;
+        last_speed = speed;
+        // 境界をまたぐステップは除き、領域の内側と外側で 1 ステップあたりの減速を比べます。
+        if (51.0..69.0).contains(&y) {
+            inside.push(decrement);
+        } else if y < 49.0 {
+            outside_before.push(decrement);
+        } else if y > 71.0 {
+            outside_after.push(decrement);
+        }
+    }
+    assert!(!inside.is_empty() && !outside_before.is_empty() && !outside_after.is_empty());
+    for d in inside {
+        assert!((d - 0.5).abs() < 1e-3, "inside: {d}");
+    }
+    for d in outside_before.into_iter().chain(outside_after) {
+        assert!((d - 0.1).abs() < 1e-3, "outside: {d}");
+    }
+}
@@ -0,0 +1,103 @@
+// tests/impact_speed.rs
+//
+// 正面衝突の予測（CollisionEvent）と解決された記録（BallCollision）が、正しい向きの法線と近づく速さを持つことを
+// 確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::BallId;
+use my_specs_game::events::CollisionEvent;
+use my_specs_game::resources::Wall;
+use my_specs_game::Simulation;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// `stepping` のモードで、`entities` のボールを置いた Simulation を生成します。
+fn simulation(stepping: &str, entities: &str) -> Simulation {
+    let yaml = standard_yaml(
+        1.0,
+        &format!(
+            "dt: 0.001
+units: {{velocity: CmPerS}}
+stepping: {stepping}
+entities:
+{entities}"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+/// 手球が +y 方向に 100 cm/s、的球が -y 方向に 50 cm/s で正面から近づく配置です。
+const HEAD_ON: &str = "  - {kind: Cue, x: 63.5, y: 100.0, vy: 100.0}
+  - {kind: Object, x: 63.5, y: 130.0, vy: -50.0}
+";
+
+#[test]
+fn head_on_prediction_reports_normal_and_closing_speed() {
+    let sim = simulation("FixedStep", HEAD_ON);
+    match sim.time_to_next_event().unwrap().1 {
+        CollisionEvent::Ball {
+            a,
+            b,
+            normal,
+            impact_speed,
+        } => {
+            assert_eq!((a, b), (BallId(0), BallId(1)));
+            // 法線は a（手球）の中心から b（的球）の中心への向きです。
+            assert!(
+                normal.0.abs() < 1e-6 && (normal.1 - 1.0).abs() < 1e-6,
+                "{normal:?}"
+            );
+            assert!((impact_speed - 150.0).abs() < 1e-3, "{impact_speed}");
+        }
+        other => panic!("unexpected event: {other:?}"),
+    }
+}
+
+#[test]
+fn resolved_head_on_bounce_records_normal_and_closing_speed() {
+    for stepping in ["FixedStep", "EventDriven"] {
+        let mut sim = simulation(stepping, HEAD_ON);
+        let records = Rc::new(RefCell::new(Vec::new()));
+        let recorded = Rc::clone(&records);
+        sim.on_collision(Box::new(move |c| recorded.borrow_mut().push(*c)));
+        sim.run(300);
+        let records = records.borrow();
+        assert_eq!(records.len(), 1, "{stepping}");
+        let c = records[0];
+        assert_eq!((c.id_a, c.id_b), (BallId(0), BallId(1)), "{stepping}");
+        assert!(
+            c.normal_x.abs() < 1e-3 && (c.normal_y - 1.0).abs() < 1e-3,
+            "{stepping} {c:?}"
+        );
+        assert!((c.impact_speed - 150.0).abs() < 1e-2, "{stepping} {c:?}");
+        // 速度の交換後、手球は -50 cm/s、的球は 100 cm/s で離れます。
+        let v = sim.query_velocities();
+        assert!((v[0].1.y + 50.0).abs() < 1e-2, "{stepping} {v:?}");
+        assert!((v[1].1.y - 100.0).abs() < 1e-2, "{stepping} {v:?}");
+    }
+}
+
+#[test]
+fn wall_normal_points_out_of_the_table() {
+    let sim = simulation(
+        "FixedStep",
+        "  - {kind: Cue, x: 63.5, y: 200.0, vx: 10.0, vy: 80.0}\n",
+    );
+    match sim.time_to_next_event().unwrap().1 {
+        CollisionEvent::Wall {
+            wall,
+            normal,
+            impact_speed,
+            ..
+        } => {
+            assert_eq!(wall, Wall::Top);
+            assert_eq!(normal, (0.0, 1.0));
+            // 法線方向の成分だけが近づく速さになります。
+            assert!((impact_speed - 80.0).abs() < 1e-5, "{impact_speed}");
+        }
+        other => panic!("unexpected event: {other:?}"),
+    }
+}
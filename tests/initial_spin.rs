@@ -0,0 +1,92 @@
+// tests/initial_spin.rs
+//
+// cue_ball・object_balls と entities の各ボールに指定した初期の回転が、units.spin の単位から変換されて
+// 生成されたボールの Spin コンポーネントに設定されることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::{BallId, Spin};
+use my_specs_game::Simulation;
+
+/// 従来の cue_ball・object_balls の形式の設定に `units` を加えて Simulation を生成し、各ボールの回転を返します。
+fn spins(units: &str) -> Vec<(BallId, Spin)> {
+    let yaml = standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS{units}}}
+cue_ball: {{x: 63.5, y: 40.0, vx: 0.0, vy: 0.0, spin: [0.0, 0.0, 5.0]}}
+object_balls:
+  positions:
+    - {{x: 63.5, y: 150.0, spin: [1.0, -2.0, 3.0]}}
+    - {{x: 40.0, y: 150.0}}
+"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap().query_spins()
+}
+
+#[test]
+fn configured_spin_is_set_on_the_created_balls() {
+    let spins = spins("");
+    let spin_of = |id: u32| spins.iter().find(|(b, _)| b.0 == id).map(|(_, s)| *s);
+    assert_eq!(
+        spin_of(0),
+        Some(Spin {
+            x: 0.0,
+            y: 0.0,
+            z: 5.0
+        })
+    );
+    assert_eq!(
+        spin_of(1),
+        Some(Spin {
+            x: 1.0,
+            y: -2.0,
+            z: 3.0
+        })
+    );
+    // 省略したボールは回転なしとして扱います。
+    assert_eq!(spin_of(2).unwrap_or_default(), Spin::default());
+}
+
+#[test]
+fn spin_is_converted_from_revolutions_per_second() {
+    let spins = spins(", spin: RevPerS");
+    let cue = spins.iter().find(|(b, _)| b.0 == 0).unwrap().1;
+    assert!(
+        (cue.z - 5.0 * std::f32::consts::TAU).abs() < 1e-4,
+        "{cue:?}"
+    );
+}
+
+#[test]
+fn one_revolution_per_second_is_two_pi_radians_per_second() {
+    // entities 形式のボールでも、すべての成分が units.spin に従って変換されます。
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: CmPerS, spin: RevPerS}
+entities:
+  - {kind: Cue, x: 63.5, y: 40.0, spin: [1.0, -1.0, 1.0]}
+  - {kind: Object, x: 63.5, y: 150.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let spins = Simulation::new(&config).unwrap().query_spins();
+    let tau = std::f32::consts::TAU;
+    assert_eq!(
+        spins[0],
+        (
+            BallId(0),
+            Spin {
+                x: tau,
+                y: -tau,
+                z: tau
+            }
+        )
+    );
+    assert_eq!(spins[1].1, Spin::default());
+}
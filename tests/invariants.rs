@@ -0,0 +1,49 @@
+// tests/invariants.rs
+//
+// デバッグビルドで debug_invariants を設定すると、各ステップの後に物理的な不変条件が検査されることを確かめるテストです。
+// 検査はリリースビルドでは行われないため、テストもデバッグビルドでのみ実行します。
+#![cfg(debug_assertions)]
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+
+/// 2 つのボールを正面衝突させる設定に、不変条件の検査と `systems` の設定を加えた Simulation を生成します。
+fn head_on(systems: &str) -> Simulation {
+    let yaml = standard_yaml(
+        1.0,
+        &format!(
+            "dt: 0.001
+units: {{velocity: CmPerS}}
+friction_xy: [0.0, 0.0]
+debug_invariants: {{penetration_tolerance: 0.5, momentum_tolerance: 1e-4}}
+systems: {systems}
+entities:
+  - {{kind: Cue, x: 40.0, y: 60.0, vx: 50.0}}
+  - {{kind: Object, x: 60.0, y: 60.0, vx: -50.0}}
+"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn resolved_collisions_satisfy_the_invariants() {
+    let mut sim = head_on(
+        "{physics: true, collision: true, pockets: true, logging: false, log_energy: false, stats: true}",
+    );
+    sim.run(400);
+    assert!(sim.metrics().ball_collisions >= 1);
+}
+
+#[test]
+#[should_panic(expected = "balls 0 and 1 interpenetrate")]
+fn missing_impulse_triggers_the_interpenetration_check() {
+    // 衝突処理を無効にして、インパルスが加わらない壊れた衝突処理の代わりとします。
+    let mut sim = head_on(
+        "{physics: true, collision: false, pockets: true, logging: false, log_energy: false, stats: true}",
+    );
+    sim.run(400);
+}
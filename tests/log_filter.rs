@@ -0,0 +1,62 @@
+// tests/log_filter.rs
+//
+// log_filter で、出力先に渡すボールを BallKind や BallId で絞り込めることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::output::{CsvSink, MemorySink};
+use my_specs_game::Simulation;
+
+/// `log_filter` の設定で、的球 2 つと手球 1 つを置いた Simulation を生成します。
+fn simulation(filter: &str) -> Simulation {
+    let yaml = standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+log_filter: {filter}
+entities:
+  - {{kind: Object, x: 30.0, y: 60.0, vx: 5.0}}
+  - {{kind: Cue, x: 60.0, y: 60.0, vy: 20.0}}
+  - {{kind: Object, x: 90.0, y: 60.0, vx: -5.0}}
+"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn cue_filter_writes_one_csv_row_per_step() {
+    let path = format!("{}/log_filter_cue.csv", env!("CARGO_TARGET_TMPDIR"));
+    let mut sim = simulation("Cue");
+    sim.add_sink(Box::new(CsvSink::create(&path).unwrap()));
+    sim.run(20);
+    let text = std::fs::read_to_string(&path).unwrap();
+    let rows: Vec<Vec<&str>> = text
+        .lines()
+        .skip(1)
+        .map(|l| l.split(',').collect())
+        .collect();
+    assert_eq!(rows.len(), 20);
+    for (step, row) in rows.iter().enumerate() {
+        assert_eq!(row[0], step.to_string());
+        assert_eq!((row[2], row[3]), ("1", "Cue"));
+    }
+}
+
+#[test]
+fn id_filter_keeps_only_that_ball() {
+    let mut sim = simulation("!Id 2");
+    let memory = MemorySink::new();
+    let snapshots = memory.snapshots();
+    sim.add_sink(Box::new(memory));
+    sim.run(10);
+    let snapshots = snapshots.borrow();
+    assert_eq!(snapshots.len(), 10);
+    for snapshot in snapshots.iter() {
+        let ids: Vec<u32> = snapshot.balls.iter().map(|b| b.id.0).collect();
+        assert_eq!(ids, [2]);
+    }
+}
@@ -0,0 +1,51 @@
+// tests/low_speed_damping.rs
+//
+// low_speed_damping を指定すると、閾値より遅くなったボールにだけ追加の減速が働き、閾値より速い間は
+// 指定しない場合と同じ減速のまま、より早く止まることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+
+/// 60 cm/s で転がり出し、摩擦で 20 cm/s² ずつ減速するボールを、`extra` を加えた設定で止まるまで実行し、
+/// 各ステップの後の速さの列を返します。
+fn speeds(extra: &str) -> Vec<f32> {
+    let yaml = standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+friction_xy: [0.2, 0.2]
+entities:
+  - {{kind: Cue, x: 20.0, y: 100.0, vx: 60.0}}
+{extra}"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    let mut speeds = Vec::new();
+    while !sim.is_at_rest() {
+        assert!(speeds.len() < 1000, "{extra}: never came to rest");
+        sim.step();
+        let v = sim.query_velocities()[0].1;
+        speeds.push((v.x * v.x + v.y * v.y).sqrt());
+    }
+    speeds
+}
+
+#[test]
+fn damped_ball_stops_sooner_with_identical_high_speed_friction() {
+    let plain = speeds("");
+    let damped = speeds("low_speed_damping: {threshold: 20.0, deceleration: 0.8}");
+    // 閾値（20 cm/s）より速い間は、どちらも同じように減速します。
+    let fast = plain.iter().take_while(|&&s| s > 20.0).count();
+    assert_eq!(damped[..fast], plain[..fast]);
+    // 閾値より遅くなると、20 + 80 cm/s² で減速します。
+    let (after, before) = (damped[fast + 1], damped[fast]);
+    assert!((before - after - 1.0).abs() < 1e-3, "{before} -> {after}");
+    // 摩擦だけの場合は 3 秒で止まり（壁までは届きません）、追加の減速がある場合は
+    // 20 cm/s まで 2 秒、そこから 0.2 秒で止まります。
+    assert!((299..=301).contains(&plain.len()), "{}", plain.len());
+    assert!((219..=221).contains(&damped.len()), "{}", damped.len());
+}
@@ -0,0 +1,86 @@
+// tests/max_balls_capacity.rs
+//
+// max_balls の見込みを指定すると、衝突の記録に使う領域があらかじめ確保され、ブレイクショットの実行中の
+// メモリの確保が減ることを、確保の回数を数えるアロケータで確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::systems::{CollisionSystem, PhysicsSystem};
+use my_specs_game::Simulation;
+use specs::prelude::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+thread_local! {
+    /// このスレッドでメモリを確保した回数
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// 確保（と再確保）の回数をスレッドごとに数えながら、System に処理を委ねるアロケータです。
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// このスレッドでこれまでにメモリを確保した回数を返します。
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+/// `extra` を加えたブレイクショットの設定で、PhysicsSystem と CollisionSystem をこのスレッドで 200 ステップ実行し、
+/// CollisionSystem がメモリを確保した回数を返します。
+///
+/// 並列のディスパッチャーではシステムがスレッドプールで実行され、確保の回数がスケジュールによって揺れるため、
+/// システムを直接実行します。
+fn break_allocations(extra: &str) -> usize {
+    let yaml = standard_yaml(
+        0.95,
+        &format!(
+            "dt: 0.002
+units: {{velocity: CmPerS}}
+scenario: break
+break_speed: 800.0
+{extra}"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    let sim = Simulation::new(&config).unwrap();
+    let world = sim.world();
+    let mut physics = PhysicsSystem;
+    let mut collision = CollisionSystem::default();
+    let mut count = 0;
+    for _ in 0..200 {
+        physics.run_now(world);
+        let before = allocations();
+        collision.run_now(world);
+        count += allocations() - before;
+    }
+    count
+}
+
+#[test]
+fn max_balls_hint_reduces_allocations_during_a_break() {
+    let without = break_allocations("");
+    let with = break_allocations("max_balls: 16");
+    assert!(
+        with < without,
+        "{with} allocations with the hint, {without} without"
+    );
+}
@@ -0,0 +1,61 @@
+// tests/max_speed.rs
+//
+// max_speed を指定すると、衝突処理の後のボールの速さが向きを保ったまま上限に抑えられ、
+// 省略した場合は抑えられないことを確かめるテストです。
+
+mod common;
+
+use common::standard_yaml;
+use my_specs_game::config::{load_config_from_reader, Format};
+use my_specs_game::Simulation;
+
+/// 手球に 30000 cm/s、40000 cm/s の速度を与えた設定に `extra` を加えて Simulation を生成します。
+fn simulation(extra: &str) -> Result<Simulation, Box<dyn std::error::Error>> {
+    let yaml = standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.0001
+units: {{velocity: CmPerS}}
+{extra}
+entities:
+  - {{kind: Cue, x: 63.5, y: 40.0, vx: 30000.0, vy: 40000.0}}
+"
+        ),
+    );
+    let config = load_config_from_reader(yaml.as_bytes(), Format::Yaml)?;
+    Ok(Simulation::new(&config).unwrap())
+}
+
+#[test]
+fn absurd_velocity_is_clamped_to_max_speed_after_a_step() {
+    let mut sim = simulation("max_speed: 500.0").unwrap();
+    sim.step();
+    let v = sim.query_velocities()[0].1;
+    assert!((v.x.hypot(v.y) - 500.0).abs() < 1e-2, "{v:?}");
+    // 向きは (3, 4) のまま保たれます。
+    assert!(
+        (v.x - 300.0).abs() < 1e-2 && (v.y - 400.0).abs() < 1e-2,
+        "{v:?}"
+    );
+}
+
+#[test]
+fn speed_is_unlimited_by_default() {
+    let mut sim = simulation("").unwrap();
+    sim.step();
+    let v = sim.query_velocities()[0].1;
+    assert_eq!((v.x, v.y), (30000.0, 40000.0));
+}
+
+#[test]
+fn non_positive_max_speed_is_rejected() {
+    for max_speed in ["0.0", "-1.0"] {
+        let err = simulation(&format!("max_speed: {max_speed}"))
+            .err()
+            .unwrap();
+        assert!(
+            err.to_string().starts_with("max_speed must be positive"),
+            "{err}"
+        );
+    }
+}
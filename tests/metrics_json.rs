@@ -0,0 +1,59 @@
+// tests/metrics_json.rs
+//
+// Simulation::metrics_json() が、実行の集計値を決まったキーの JSON として返すことを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+
+#[test]
+fn metrics_json_has_the_expected_keys_and_counts() {
+    // 2 つのボールが 1 回だけ正面衝突して入れ替わり、壁には届かない設定です。
+    let yaml = standard_yaml(
+        1.0,
+        "dt: 0.001
+units: {velocity: CmPerS}
+friction_xy: [0.0, 0.0]
+seed: 7
+entities:
+  - {kind: Cue, x: 40.0, y: 60.0, vx: 20.0}
+  - {kind: Object, x: 60.0, y: 60.0, vx: -20.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    sim.run(1000);
+    let json: serde_json::Value = serde_json::from_str(&sim.metrics_json()).unwrap();
+    let mut keys: Vec<&str> = json
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    keys.sort();
+    assert_eq!(
+        keys,
+        [
+            "ball_collisions",
+            "balls_pocketed",
+            "distance",
+            "energy_dissipated",
+            "seed",
+            "steps",
+            "wall_clock_seconds",
+            "wall_collisions",
+        ]
+    );
+    assert_eq!(json["steps"], 1000);
+    assert_eq!(json["ball_collisions"], 1);
+    assert_eq!(json["wall_collisions"], 0);
+    assert_eq!(json["balls_pocketed"], 0);
+    assert_eq!(json["seed"], 7);
+    // 各ボールは 1 秒間に 20 cm 動きます。
+    for id in ["0", "1"] {
+        let distance = json["distance"][id].as_f64().unwrap();
+        assert!((distance - 20.0).abs() < 0.1, "{id}: {distance}");
+    }
+    assert!(json["energy_dissipated"].as_f64().unwrap().abs() < 1e-2);
+}
@@ -0,0 +1,70 @@
+// tests/moved_balls.rs
+//
+// Simulation::moved_balls() が、基準のスナップショットから動いたボールと、ポケットに入ったボールだけを返し、
+// 触れられなかったボールを含めないことを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::BallId;
+use my_specs_game::simulation::ShotSpin;
+use my_specs_game::Simulation;
+
+#[test]
+fn only_struck_and_pocketed_balls_are_reported() {
+    // 手球は真上の的球 1 に当たり、的球 2 と 3 は離れた場所で静止したままです。
+    // 的球 4 は左下のポケットへ向かって転がり、テーブルから取り除かれます。
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: CmPerS}
+friction_xy: [0.2, 0.2]
+pockets: {radius: 6.0, positions: [{x: 0.0, y: 20.0}]}
+entities:
+  - {kind: Cue, x: 63.5, y: 60.0}
+  - {kind: Object, x: 63.5, y: 100.0}
+  - {kind: Object, x: 20.0, y: 200.0}
+  - {kind: Object, x: 110.0, y: 200.0}
+  - {kind: Object, x: 20.0, y: 20.0, vx: -100.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    let baseline = sim.snapshot();
+    assert!(sim.moved_balls(&baseline).is_empty());
+
+    sim.shot(std::f32::consts::FRAC_PI_2, 150.0, ShotSpin::Follow)
+        .unwrap();
+    sim.run(1000);
+    assert!(sim.is_at_rest());
+    assert!(sim.ball_state(BallId(4)).is_none());
+    assert_eq!(
+        sim.moved_balls(&baseline),
+        [BallId(0), BallId(1), BallId(4)]
+    );
+
+    // 静止した後に取り直した基準からは、どのボールも動いていません。
+    let settled = sim.snapshot();
+    sim.run(10);
+    assert!(sim.moved_balls(&settled).is_empty());
+}
+
+#[test]
+fn movement_within_the_tolerance_is_ignored() {
+    // 0.5 cm/s のボールは 1 ステップで 0.005 cm しか動かず、MOVED_BALL_TOLERANCE（0.01 cm）以下です。
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: CmPerS}
+entities:
+  - {kind: Cue, x: 63.5, y: 60.0, vx: 0.5}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    let baseline = sim.snapshot();
+    sim.step();
+    assert!(sim.moved_balls(&baseline).is_empty());
+    sim.run(2);
+    assert_eq!(sim.moved_balls(&baseline), [BallId(0)]);
+}
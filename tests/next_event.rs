@@ -0,0 +1,73 @@
+// tests/next_event.rs
+//
+// 現在の状態から次に起きる衝突とその時刻を求める Simulation::time_to_next_event() を確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::BallId;
+use my_specs_game::events::CollisionEvent;
+use my_specs_game::resources::Wall;
+use my_specs_game::Simulation;
+
+/// `entities` のボールを置いた Simulation を生成します。
+fn simulation(entities: &str) -> Simulation {
+    let yaml = standard_yaml(
+        1.0,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+entities:
+{entities}"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn time_to_a_wall_is_distance_over_speed() {
+    let sim = simulation("  - {kind: Cue, x: 60.0, y: 100.0, vx: -50.0}\n");
+    let (t, event) = sim.time_to_next_event().unwrap();
+    // 中心が壁から半径の距離に達するまでの時間です。
+    assert!((t - (60.0 - 2.85) / 50.0).abs() < 1e-5, "{t}");
+    match event {
+        CollisionEvent::Wall {
+            ball,
+            wall,
+            normal,
+            impact_speed,
+        } => {
+            assert_eq!((ball, wall), (BallId(0), Wall::Left));
+            assert_eq!(normal, (-1.0, 0.0));
+            assert!((impact_speed - 50.0).abs() < 1e-5);
+        }
+        other => panic!("unexpected event: {other:?}"),
+    }
+}
+
+#[test]
+fn ball_pair_that_meets_first_is_returned() {
+    let sim = simulation(
+        "  - {kind: Cue, x: 40.0, y: 100.0, vx: 30.0}
+  - {kind: Object, x: 70.0, y: 100.0, vx: -10.0}
+",
+    );
+    let (t, event) = sim.time_to_next_event().unwrap();
+    // 中心間の距離が 30 − 2 × 半径 だけ縮まるまでの時間です。
+    assert!((t - (30.0 - 5.7) / 40.0).abs() < 1e-5, "{t}");
+    assert!(matches!(
+        event,
+        CollisionEvent::Ball {
+            a: BallId(0),
+            b: BallId(1),
+            ..
+        }
+    ));
+}
+
+#[test]
+fn resting_balls_have_no_next_event() {
+    let sim = simulation("  - {kind: Cue, x: 60.0, y: 100.0}\n");
+    assert!(sim.time_to_next_event().is_none());
+}
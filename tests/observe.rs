@@ -0,0 +1,61 @@
+// tests/observe.rs
+//
+// Simulation::observe() で World を読み取る関数を実行し、すべてのボールの重心のような
+// 組み込みの集計値にない量を、ステップごとに正しく計算できることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::{Ball, Position};
+use my_specs_game::Simulation;
+use specs::prelude::*;
+
+/// すべてのボールの位置の平均（重心）を求めます。
+fn centroid(world: &World) -> (f32, f32) {
+    let pos = world.read_storage::<Position>();
+    let ball = world.read_storage::<Ball>();
+    let (mut x, mut y, mut count) = (0.0, 0.0, 0.0);
+    for (p, _) in (&pos, &ball).join() {
+        x += p.x;
+        y += p.y;
+        count += 1.0;
+    }
+    (x / count, y / count)
+}
+
+#[test]
+fn observer_computes_the_centroid_of_all_balls() {
+    // 同じ質量の 3 つのボールの重心は、(50, 60) から (10, 20) cm/s で等速に動きます。
+    let yaml = standard_yaml(
+        1.0,
+        "dt: 0.01
+units: {velocity: CmPerS}
+entities:
+  - {kind: Cue, x: 20.0, y: 30.0, vx: 30.0}
+  - {kind: Object, x: 50.0, y: 90.0, vy: 60.0}
+  - {kind: Object, x: 80.0, y: 60.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    let (x, y) = sim.observe(centroid);
+    assert!(
+        (x - 50.0).abs() < 1e-4 && (y - 60.0).abs() < 1e-4,
+        "({x}, {y})"
+    );
+
+    let mut history = Vec::new();
+    for _ in 0..50 {
+        sim.step();
+        history.push(sim.observe(centroid));
+    }
+    for (i, (x, y)) in history.iter().enumerate() {
+        let t = (i + 1) as f32 * 0.01;
+        assert!((x - (50.0 + 10.0 * t)).abs() < 1e-3, "{i} {x}");
+        assert!((y - (60.0 + 20.0 * t)).abs() < 1e-3, "{i} {y}");
+    }
+    // 読み取るだけのため、呼び出しても状態は変わりません。
+    let hash = sim.state_hash();
+    sim.observe(centroid);
+    assert_eq!(sim.state_hash(), hash);
+}
@@ -0,0 +1,67 @@
+// tests/output_sink.rs
+//
+// Simulation に登録した出力先（OutputSink）が、ステップごとに 1 回ずつスナップショットを受け取ることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::output::{MemorySink, OutputSink, WorldSnapshot};
+use my_specs_game::Simulation;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// 受け取ったステップ番号と、終了処理が呼び出された回数を記録する出力先です。
+#[derive(Default)]
+struct Recorder {
+    steps: Rc<RefCell<Vec<usize>>>,
+    finished: Rc<RefCell<usize>>,
+}
+
+impl OutputSink for Recorder {
+    fn on_step(&mut self, step: usize, snapshot: &WorldSnapshot) {
+        assert_eq!(snapshot.step, step);
+        self.steps.borrow_mut().push(step);
+    }
+
+    fn finish(&mut self) {
+        *self.finished.borrow_mut() += 1;
+    }
+}
+
+fn simulation() -> Simulation {
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+entities:
+  - {kind: Cue, x: 20.0, y: 60.0, vx: 1.0}
+  - {kind: Object, x: 60.0, y: 60.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn every_sink_receives_one_snapshot_per_step() {
+    let mut sim = simulation();
+    let recorder = Recorder::default();
+    let (steps, finished) = (Rc::clone(&recorder.steps), Rc::clone(&recorder.finished));
+    let memory = MemorySink::new();
+    let snapshots = memory.snapshots();
+    sim.add_sink(Box::new(recorder));
+    sim.add_sink(Box::new(memory));
+    sim.run(30);
+
+    assert_eq!(*steps.borrow(), (0..30).collect::<Vec<_>>());
+    assert_eq!(*finished.borrow(), 1);
+    let snapshots = snapshots.borrow();
+    assert_eq!(snapshots.len(), 30);
+    // 最後のスナップショットは、実行後の World の状態と一致します。
+    let last = snapshots.last().unwrap();
+    let positions = sim.query_positions();
+    assert_eq!(last.balls.len(), positions.len());
+    for (ball, (id, p)) in last.balls.iter().zip(positions) {
+        assert_eq!(ball.id, id);
+        assert_eq!((ball.x, ball.y), (p.x, p.y));
+    }
+}
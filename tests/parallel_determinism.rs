@@ -0,0 +1,83 @@
+// tests/parallel_determinism.rs
+//
+// parallel フィーチャーの有無で、同じ設定のシミュレーション結果がビット単位で一致することを確かめるテストです。
+// テストの中で、現在のビルドとは逆のフィーチャーの組み合わせで同じテストをビルドし直して実行し、結果を比べます。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+use std::process::Command;
+
+/// 逆のフィーチャーでビルドしたテストに、結果を書き出すファイルのパスを渡す環境変数の名前です。
+const DUMP_ENV: &str = "MY_SPECS_GAME_CROSS_FEATURE_DUMP";
+
+/// ブレイクショットを、ボール同士の衝突を色分けして並列に処理する設定で実行し、
+/// 最後の各ボールの位置と速度をビット列で表した文字列を返します。
+fn break_shot_state() -> String {
+    let yaml = standard_yaml(0.95, "dt: 0.002
+units: {velocity: CmPerS}
+friction_xy: [0.2, 0.2]
+scenario: break
+break_speed: 800.0
+collision_solver: Colored
+solver_iterations: 4
+threads: 4
+systems: {physics: true, collision: true, pockets: true, logging: false, log_energy: false, stats: true}
+");
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    sim.run(1500);
+    let velocities = sim.query_velocities();
+    sim.query_positions()
+        .into_iter()
+        .zip(velocities)
+        .map(|((id, p), (_, v))| {
+            format!(
+                "{} {:08x} {:08x} {:08x} {:08x}\n",
+                id.0,
+                p.x.to_bits(),
+                p.y.to_bits(),
+                v.x.to_bits(),
+                v.y.to_bits()
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn break_shot_is_identical_with_and_without_parallel() {
+    let state = break_shot_state();
+    // 逆のフィーチャーでビルドされた側では、結果をファイルに書き出すだけにします。
+    if let Ok(path) = std::env::var(DUMP_ENV) {
+        std::fs::write(path, state).unwrap();
+        return;
+    }
+    let features: &[&str] = if cfg!(feature = "parallel") {
+        &["--no-default-features"]
+    } else {
+        &["--features", "parallel"]
+    };
+    let target_dir = concat!(env!("CARGO_TARGET_TMPDIR"), "/cross_feature");
+    let dump = format!("{target_dir}/state.txt");
+    std::fs::create_dir_all(target_dir).unwrap();
+    let output = Command::new(env!("CARGO"))
+        .args(["test", "--offline", "--quiet", "--manifest-path"])
+        .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"))
+        .args(features)
+        .args(["--test", "parallel_determinism", "--"])
+        .args([
+            "--exact",
+            "break_shot_is_identical_with_and_without_parallel",
+        ])
+        .env("CARGO_TARGET_DIR", target_dir)
+        .env(DUMP_ENV, &dump)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(std::fs::read_to_string(&dump).unwrap(), state);
+}
@@ -0,0 +1,106 @@
+// tests/peg_grid.rs
+//
+// 重力で落下したボールがペグに当たり、ペグの中心からのずれの向きに応じて左右へ決定的にそらされ、
+// ペグ自体は動かないこと、ペグの半径や行と列の数が不正な格子は読み込み時に拒否されることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::{Peg, Position};
+use my_specs_game::config::{load_config_from_reader, Format};
+use my_specs_game::Simulation;
+use specs::prelude::*;
+
+/// 落下させたボールの (位置, 速度, 各ペグの位置) です。
+type DropResult = ((f32, f32), (f32, f32), Vec<(f32, f32)>);
+
+/// (63.5, 100) に 1 つだけペグを置き、その真上から x 方向に `offset` だけずらした位置でボールを落として
+/// 500 ステップ（0.5 秒）実行し、ボールの位置と速度、ペグの位置を返します。
+fn drop_onto_peg(offset: f32) -> DropResult {
+    let yaml = standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.001
+units: {{velocity: CmPerS}}
+gravity_xy: [0.0, -9.8]
+pegs: {{x: 63.5, y: 100.0, rows: 1, columns: 1, spacing_x: 10.0, spacing_y: 10.0, radius: 1.0}}
+entities:
+  - {{kind: Object, x: {x}, y: 150.0}}
+",
+            x = 63.5 + offset
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    sim.run(500);
+    let p = sim.query_positions()[0].1;
+    let v = sim.query_velocities()[0].1;
+    let pegs = sim.observe(|world| {
+        let pos = world.read_storage::<Position>();
+        let peg = world.read_storage::<Peg>();
+        (&pos, &peg).join().map(|(p, _)| (p.x, p.y)).collect()
+    });
+    ((p.x, p.y), (v.x, v.y), pegs)
+}
+
+#[test]
+fn ball_deflects_towards_the_side_of_its_offset() {
+    let (right, right_velocity, pegs) = drop_onto_peg(1.0);
+    assert!(
+        right.0 > 64.5 && right_velocity.0 > 0.0,
+        "{right:?} {right_velocity:?}"
+    );
+    let (left, left_velocity, _) = drop_onto_peg(-1.0);
+    assert!(
+        left.0 < 62.5 && left_velocity.0 < 0.0,
+        "{left:?} {left_velocity:?}"
+    );
+    // 左右対称の配置のため、そらされ方も左右対称になります。
+    assert!(
+        (right.0 - 63.5 + left.0 - 63.5).abs() < 1e-2,
+        "{right:?} {left:?}"
+    );
+    assert_eq!(pegs, [(63.5, 100.0)]);
+}
+
+#[test]
+fn same_offset_deflects_identically() {
+    assert_eq!(drop_onto_peg(0.5), drop_onto_peg(0.5));
+}
+
+#[test]
+fn invalid_peg_grids_are_rejected() {
+    for (pegs, message) in [
+        (
+            "rows: 1, columns: 1, radius: 0.0",
+            "pegs.radius must be finite and positive",
+        ),
+        (
+            "rows: 1, columns: 1, radius: -1.0",
+            "pegs.radius must be finite and positive",
+        ),
+        (
+            "rows: 1, columns: 1, radius: .nan",
+            "pegs.radius must be finite and positive",
+        ),
+        (
+            "rows: 0, columns: 1, radius: 1.0",
+            "pegs.rows and pegs.columns must be at least 1",
+        ),
+        (
+            "rows: 1, columns: 0, radius: 1.0",
+            "pegs.rows and pegs.columns must be at least 1",
+        ),
+    ] {
+        let yaml = standard_yaml(
+            0.9,
+            &format!(
+                "dt: 0.001
+pegs: {{x: 63.5, y: 100.0, spacing_x: 10.0, spacing_y: 10.0, {pegs}}}
+"
+            ),
+        );
+        let err = load_config_from_reader(yaml.as_bytes(), Format::Yaml).unwrap_err();
+        assert!(err.to_string().starts_with(message), "{pegs}: {err}");
+    }
+}
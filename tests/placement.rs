@@ -0,0 +1,113 @@
+// tests/placement.rs
+//
+// ポケットされた的球を戻す位置を探す find_free_spot() と、その前提となるボールとポケットの半径の検証を確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::Table;
+use my_specs_game::config::{load_config_from_reader, Format, TableShape};
+use my_specs_game::entities::find_free_spot;
+use my_specs_game::Simulation;
+
+const RADIUS: f32 = 2.85;
+
+#[test]
+fn occupied_spot_moves_the_ball_behind_it() {
+    let table = Table {
+        width: 127.0,
+        height: 254.0,
+        shape: TableShape::Rectangle,
+    };
+    let (x, y) = find_free_spot((63.5, 190.5), RADIUS, &[(63.5, 190.5, RADIUS)], &table);
+    assert_eq!(x, 63.5);
+    let behind = 190.5 + 2.0 * RADIUS;
+    assert!((behind..behind + 0.5).contains(&y), "{y}");
+}
+
+#[test]
+fn zero_radius_returns_the_spot_without_searching() {
+    let table = Table {
+        width: 127.0,
+        height: 254.0,
+        shape: TableShape::Rectangle,
+    };
+    let spot = (63.5, 190.5);
+    assert_eq!(
+        find_free_spot(spot, 0.0, &[(63.5, 190.5, RADIUS)], &table),
+        spot
+    );
+}
+
+#[test]
+fn search_stays_inside_a_circle_table() {
+    // 中心 (30, 30)、半径 30 cm の円形のテーブルで、中心から外れた位置が塞がっている場合です。
+    // 後方（+y）の空き位置は外接する長方形には収まりますが、円のクッションからははみ出します。
+    let table = Table {
+        width: 60.0,
+        height: 60.0,
+        shape: TableShape::Circle { radius: 30.0 },
+    };
+    let (x, y) = find_free_spot((40.0, 50.0), RADIUS, &[(40.0, 50.0, RADIUS)], &table);
+    let (dx, dy) = (x - 30.0, y - 30.0);
+    assert!((dx * dx + dy * dy).sqrt() <= 30.0 - RADIUS, "({x}, {y})");
+    assert!(y <= 50.0 - 2.0 * RADIUS, "({x}, {y})");
+}
+
+#[test]
+fn non_positive_radii_are_rejected() {
+    for extra in [
+        "ball: {radius: 0.0, mass: 0.17, restitution: 1.0}\nentities:\n  - {kind: Cue, x: 30.0, y: 60.0}",
+        "ball: {radius: 2.85, mass: 0.17, restitution: 1.0}\nentities:\n  - {kind: Cue, x: 30.0, y: 60.0, radius: 0.0}",
+        "ball: {radius: .nan, mass: 0.17, restitution: 1.0}\nentities:\n  - {kind: Cue, x: 30.0, y: 60.0}",
+    ] {
+        let yaml = format!("dt: 0.01\ntable: {{width: 127.0, height: 254.0}}\n{extra}");
+        assert!(
+            load_config_from_reader(yaml.as_bytes(), Format::Yaml).is_err(),
+            "{extra}"
+        );
+    }
+}
+
+#[test]
+fn non_positive_pocket_radius_is_rejected() {
+    for radius in ["0.0", "-6.0", ".nan"] {
+        let yaml = format!(
+            "dt: 0.01
+table: {{width: 127.0, height: 254.0}}
+ball: {{radius: {RADIUS}, mass: 0.17, restitution: 1.0}}
+pockets: {{radius: {radius}, respot: true, positions: [{{x: 0.0, y: 60.0}}]}}
+"
+        );
+        let err = load_config_from_reader(yaml.as_bytes(), Format::Yaml).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("pockets.radius must be finite and positive"),
+            "{radius}: {err}"
+        );
+    }
+}
+
+#[test]
+fn respotted_ball_reappears_at_the_foot_spot_at_rest() {
+    let yaml = standard_yaml(
+        1.0,
+        "dt: 0.01
+units: {velocity: CmPerS}
+pockets: {radius: 6.0, respot: true, positions: [{x: 0.0, y: 60.0}]}
+entities:
+  - {kind: Object, x: 30.0, y: 60.0, vx: -300.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    for _ in 0..50 {
+        sim.step();
+    }
+    let positions = sim.query_positions();
+    assert_eq!(positions.len(), 1);
+    let p = positions[0].1;
+    assert_eq!((p.x, p.y), (63.5, 190.5));
+    let v = sim.query_velocities()[0].1;
+    assert_eq!((v.x, v.y), (0.0, 0.0));
+}
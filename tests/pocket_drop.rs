@@ -0,0 +1,85 @@
+// tests/pocket_drop.rs
+//
+// pockets.drop_steps を指定すると、ポケットされたボールがその間だけ中心へ引き寄せられながら縮み、その後に削除されることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::{BallId, Pocketing, Position, Render};
+use my_specs_game::Simulation;
+use specs::prelude::*;
+
+const DROP_STEPS: usize = 5;
+const POCKET: (f32, f32) = (127.0, 60.0);
+
+/// 的球を右の壁のポケットへ向けて転がす Simulation を、落下の演出のステップ数 `drop_steps` で生成します。
+fn simulation(drop_steps: usize) -> Simulation {
+    let yaml = standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+pockets: {{radius: 6.0, drop_steps: {drop_steps}, positions: [{{x: {}, y: {}}}]}}
+entities:
+  - {{kind: Cue, x: 30.0, y: 200.0}}
+  - {{kind: Object, x: 100.0, y: 60.0, vx: 200.0}}
+",
+            POCKET.0, POCKET.1
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+/// 落下中の的球の位置と描画の倍率を返します。落下中でない場合は None を返します。
+fn dropping(sim: &Simulation) -> Option<(Position, f32)> {
+    sim.observe(|world| {
+        let ids = world.read_storage::<BallId>();
+        let pos = world.read_storage::<Position>();
+        let pocketing = world.read_storage::<Pocketing>();
+        let render = world.read_storage::<Render>();
+        (&ids, &pos, &pocketing, &render)
+            .join()
+            .find(|(id, ..)| **id == BallId(1))
+            .map(|(_, p, _, r)| (*p, r.scale))
+    })
+}
+
+#[test]
+fn pocketed_ball_persists_for_the_drop_duration() {
+    let mut sim = simulation(DROP_STEPS);
+    // ポケットに入って落下が始まるまで進めます。
+    let mut steps = 0;
+    while dropping(&sim).is_none() {
+        sim.step();
+        steps += 1;
+        assert!(steps < 100, "the ball never reached the pocket");
+    }
+    // 落下を始めたステップを含め、DROP_STEPS ステップの間はテーブル上に残り、縮みながら中心へ近づきます。
+    let mut scales = vec![dropping(&sim).unwrap().1];
+    for _ in 1..DROP_STEPS {
+        sim.step();
+        let (p, scale) = dropping(&sim).expect("the ball should still be dropping");
+        assert!(p.x <= POCKET.0 && (p.y - POCKET.1).abs() < 1e-3, "{p:?}");
+        scales.push(scale);
+    }
+    assert!(scales.windows(2).all(|w| w[1] < w[0]), "{scales:?}");
+    assert_eq!(sim.query_positions().len(), 2);
+    sim.step();
+    let ids: Vec<BallId> = sim
+        .query_positions()
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect();
+    assert_eq!(ids, [BallId(0)]);
+}
+
+#[test]
+fn without_drop_steps_the_ball_is_removed_at_once() {
+    let mut sim = simulation(0);
+    for _ in 0..100 {
+        sim.step();
+        assert!(dropping(&sim).is_none());
+    }
+    assert_eq!(sim.query_positions().len(), 1);
+}
@@ -0,0 +1,66 @@
+// tests/pocket_index.rs
+//
+// ボールが入ったポケットの番号が、設定の pockets.positions での順番として、PocketEvents の記録と
+// Pocketed マーカーの両方に残ることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::{BallId, Pocketed};
+use my_specs_game::resources::PocketEvents;
+use my_specs_game::Simulation;
+use specs::prelude::*;
+
+/// 3 つのポケットのうち 3 番目（左下）へ向かう的球を置き、`respot` を指定した Simulation を生成します。
+fn simulation(respot: bool) -> Simulation {
+    let yaml = standard_yaml(0.9, &format!(
+        "dt: 0.01
+units: {{velocity: CmPerS}}
+pockets: {{radius: 6.0, respot: {respot}, positions: [{{x: 127.0, y: 60.0}}, {{x: 0.0, y: 200.0}}, {{x: 0.0, y: 60.0}}]}}
+entities:
+  - {{kind: Cue, x: 63.5, y: 130.0}}
+  - {{kind: Object, x: 30.0, y: 60.0, vx: -300.0}}
+"
+    ));
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+/// ボールがポケットに入るまで進め、記録された (BallId, ポケットの番号, ステップ番号) を返します。
+fn run_until_pocketed(sim: &mut Simulation) -> (BallId, usize, usize) {
+    for _ in 0..50 {
+        sim.step();
+        let events = sim.world().read_resource::<PocketEvents>();
+        if let [event] = events.events.as_slice() {
+            return (event.id, event.pocket, event.step);
+        }
+    }
+    panic!("no ball was pocketed");
+}
+
+#[test]
+fn ball_in_the_third_pocket_reports_index_two() {
+    let mut sim = simulation(false);
+    let (id, pocket, step) = run_until_pocketed(&mut sim);
+    assert_eq!((id, pocket), (BallId(1), 2));
+    // 30 cm 先のポケットの縁（6 cm 手前）まで 300 cm/s で転がるため、8 ステップ目（0 始まりで 7）に入ります。
+    assert_eq!(step, 7);
+    assert!(sim.ball_state(BallId(1)).is_none());
+}
+
+#[test]
+fn respotted_ball_keeps_the_pocketed_marker() {
+    let mut sim = simulation(true);
+    let (id, pocket, step) = run_until_pocketed(&mut sim);
+    assert_eq!((id, pocket), (BallId(1), 2));
+    let markers = sim.query::<Pocketed>();
+    assert_eq!(markers, [(BallId(1), Pocketed { pocket: 2, step })]);
+    // 次のステップでは記録は空になりますが、マーカーは残ります。
+    sim.step();
+    assert!(sim
+        .world()
+        .read_resource::<PocketEvents>()
+        .events
+        .is_empty());
+    assert_eq!(sim.query::<Pocketed>().len(), 1);
+}
@@ -0,0 +1,56 @@
+// tests/pocket_order.rs
+//
+// 同じステップで複数のボールがポケットに入った場合に、PocketEvents の記録が毎回 BallId の昇順に並ぶことを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::BallId;
+use my_specs_game::resources::PocketEvents;
+use my_specs_game::Simulation;
+use specs::prelude::*;
+
+/// 左右のポケットへ同じ距離から同じ速さで向かう 3 つの的球の Simulation を、最初にポケットへ入るステップまで進め、
+/// そのステップの記録の (BallId, ポケットの番号, ステップ番号) を返します。
+fn simultaneous_pockets() -> Vec<(BallId, usize, usize)> {
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: CmPerS}
+pockets: {radius: 6.0, positions: [{x: 127.0, y: 60.0}, {x: 0.0, y: 60.0}, {x: 0.0, y: 200.0}]}
+entities:
+  - {kind: Cue, x: 63.5, y: 130.0}
+  - {kind: Object, x: 30.0, y: 60.0, vx: -300.0}
+  - {kind: Object, x: 30.0, y: 200.0, vx: -300.0}
+  - {kind: Object, x: 97.0, y: 60.0, vx: 300.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    for _ in 0..50 {
+        sim.step();
+        let events = sim.world().read_resource::<PocketEvents>();
+        if !events.events.is_empty() {
+            return events
+                .events
+                .iter()
+                .map(|e| (e.id, e.pocket, e.step))
+                .collect();
+        }
+    }
+    panic!("no ball was pocketed");
+}
+
+#[test]
+fn simultaneous_pocket_events_are_in_ball_id_order() {
+    let first = simultaneous_pockets();
+    // ポケットの番号の順（BallId 3 が先）ではなく、BallId の昇順に並びます。
+    let ids: Vec<BallId> = first.iter().map(|&(id, _, _)| id).collect();
+    assert_eq!(ids, [BallId(1), BallId(2), BallId(3)]);
+    let pockets: Vec<usize> = first.iter().map(|&(_, pocket, _)| pocket).collect();
+    assert_eq!(pockets, [1, 2, 0]);
+    assert!(first.iter().all(|&(_, _, step)| step == first[0].2));
+    for _ in 0..5 {
+        assert_eq!(simultaneous_pockets(), first);
+    }
+}
@@ -0,0 +1,64 @@
+// tests/pocket_tie.rs
+//
+// 2 つ以上のポケットの内側にあるボールが、中心が最も近いポケットに入り、距離が等しい場合は常に番号の小さいポケットに
+// 入ることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::resources::PocketEvents;
+use my_specs_game::Simulation;
+use specs::prelude::*;
+
+/// `pockets` の位置に半径 12 cm のポケットを置き、(`x`, 100) に静止した的球を 1 ステップ進めて、
+/// 的球が入ったポケットの番号を返します。
+fn claiming_pocket(pockets: &str, x: f32) -> usize {
+    let yaml = standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+pockets: {{radius: 12.0, positions: [{pockets}]}}
+entities:
+  - {{kind: Cue, x: 63.5, y: 200.0}}
+  - {{kind: Object, x: {x}, y: 100.0}}
+"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    sim.step();
+    let events = sim.world().read_resource::<PocketEvents>();
+    assert_eq!(events.events.len(), 1, "{pockets}");
+    events.events[0].pocket
+}
+
+#[test]
+fn equidistant_ball_goes_to_the_lowest_index() {
+    // (60, 100) は、(50, 100) と (70, 100) のどちらのポケットからも 10 cm の位置です。
+    let cases = [
+        ("{x: 50.0, y: 100.0}, {x: 70.0, y: 100.0}", 0),
+        ("{x: 70.0, y: 100.0}, {x: 50.0, y: 100.0}", 0),
+        (
+            "{x: 0.0, y: 0.0}, {x: 70.0, y: 100.0}, {x: 50.0, y: 100.0}",
+            1,
+        ),
+        // (60, 90) を加えた 3 つのポケットからも、等しく 10 cm です。
+        (
+            "{x: 0.0, y: 0.0}, {x: 70.0, y: 100.0}, {x: 60.0, y: 90.0}, {x: 50.0, y: 100.0}",
+            1,
+        ),
+    ];
+    for (pockets, expected) in cases {
+        for _ in 0..5 {
+            assert_eq!(claiming_pocket(pockets, 60.0), expected, "{pockets}");
+        }
+    }
+}
+
+#[test]
+fn nearest_pocket_wins_when_the_distances_differ() {
+    let pockets = "{x: 50.0, y: 100.0}, {x: 70.0, y: 100.0}";
+    assert_eq!(claiming_pocket(pockets, 59.0), 0);
+    assert_eq!(claiming_pocket(pockets, 61.0), 1);
+}
@@ -0,0 +1,54 @@
+// tests/progress.rs
+//
+// Simulation::progress() が、撞いた直後はほぼ 0 で、失われた運動エネルギーの割合に沿って増え、
+// ボールが止まると 1 に近づくことを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+
+/// 摩擦が 20 cm/s² の台に `entities` のボールを置いた Simulation を生成します。
+fn simulation(entities: &str) -> Simulation {
+    let yaml = standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+friction_xy: [0.2, 0.2]
+entities:
+{entities}"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn progress_rises_from_zero_to_one_as_the_balls_stop() {
+    let mut sim = simulation(
+        "  - {kind: Cue, x: 63.5, y: 40.0, vy: 80.0}
+  - {kind: Object, x: 63.5, y: 80.0}
+",
+    );
+    assert_eq!(sim.progress(), 0.0);
+    sim.step();
+    // 1 ステップで 80 cm/s から 79.8 cm/s になり、運動エネルギーの約 0.5 % が失われます。
+    let first = sim.progress();
+    assert!(first > 0.0 && first < 0.01, "{first}");
+    let mut last = first;
+    while !sim.is_at_rest() {
+        assert!(sim.steps() < 2000, "never came to rest");
+        sim.step();
+        let progress = sim.progress();
+        assert!(progress >= last, "{last} -> {progress}");
+        last = progress;
+    }
+    assert!(last > 0.99, "{last}");
+}
+
+#[test]
+fn table_at_rest_is_already_done() {
+    let sim = simulation("  - {kind: Cue, x: 63.5, y: 40.0}\n");
+    assert_eq!(sim.progress(), 1.0);
+}
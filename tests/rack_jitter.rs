@@ -0,0 +1,64 @@
+// tests/rack_jitter.rs
+//
+// rack_jitter が、同じ seed では同じ揺らぎを的球の初期位置に加え、揺らいだ後もボール同士が重ならないことを確かめるテストです。
+
+mod common;
+
+use common::config_from_yaml;
+use my_specs_game::Simulation;
+
+const RADIUS: f32 = 2.85;
+
+/// ブレイクショットのラックに `rack_jitter` と `seed` を指定した設定で、生成した時点の各ボールの位置を返します。
+fn rack(rack_jitter: f32, seed: u64) -> Vec<(f32, f32)> {
+    let yaml = format!(
+        "dt: 0.002
+table: {{width: 127.0, height: 254.0}}
+ball: {{radius: {RADIUS}, mass: 0.17, restitution: 0.95}}
+units: {{velocity: CmPerS}}
+scenario: break
+break_speed: 800.0
+rack_jitter: {rack_jitter}
+seed: {seed}
+"
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config)
+        .unwrap()
+        .query_positions()
+        .into_iter()
+        .map(|(_, p)| (p.x, p.y))
+        .collect()
+}
+
+#[test]
+fn same_seed_jitters_identically() {
+    let exact = rack(0.0, 42);
+    let jittered = rack(0.3, 42);
+    assert_eq!(rack(0.3, 42), jittered);
+    assert_ne!(rack(0.3, 43), jittered);
+    // 手球は動かさず、的球のみを揺らします。
+    assert_eq!(jittered[0], exact[0]);
+    assert!(jittered[1..].iter().zip(&exact[1..]).any(|(j, e)| j != e));
+}
+
+#[test]
+fn zero_jitter_gives_the_exact_rack() {
+    assert_eq!(rack(0.0, 42), rack(0.0, 43));
+}
+
+#[test]
+fn jittered_balls_do_not_overlap() {
+    for seed in 0..20 {
+        let balls = rack(0.5, seed);
+        for (i, a) in balls.iter().enumerate() {
+            for b in &balls[i + 1..] {
+                let distance = ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt();
+                assert!(
+                    distance >= 2.0 * RADIUS - 1e-4,
+                    "seed {seed}: {a:?} and {b:?} are {distance} apart"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+// tests/random_seed.rs
+//
+// seed に "random" を指定すると、選ばれた具体的な種がログと集計値に記録され、その種を指定し直すと
+// 同じ実行が再現されることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml, LogBuffer};
+use my_specs_game::Simulation;
+
+/// 的球の初期位置を乱数で揺らすブレイクショットの設定に `seed` を指定し、ログを記録しながら読み込んで
+/// 100 ステップ実行します。Simulation と、読み込み中に出力されたログを返します。
+fn run(seed: &str) -> (Simulation, String) {
+    let yaml = standard_yaml(
+        0.95,
+        &format!(
+            "dt: 0.002
+units: {{velocity: CmPerS}}
+scenario: break
+break_speed: 800.0
+rack_jitter: 0.3
+seed: {seed}
+"
+        ),
+    );
+    let buffer = LogBuffer::default();
+    let writer = buffer.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+    let config = tracing::subscriber::with_default(subscriber, || config_from_yaml(&yaml));
+    let mut sim = Simulation::new(&config).unwrap();
+    sim.run(100);
+    let log = buffer.contents();
+    (sim, log)
+}
+
+#[test]
+fn random_seed_is_logged_and_reproduces_the_run() {
+    let (random, log) = run("random");
+    let logged: u64 = log
+        .split("Using random seed ")
+        .nth(1)
+        .and_then(|rest| rest.split_whitespace().next())
+        .unwrap_or_else(|| panic!("seed is not logged: {log}"))
+        .parse()
+        .unwrap();
+    assert_eq!(random.seed(), logged);
+    assert_eq!(random.metrics().seed, logged);
+    let (fixed, log) = run(&logged.to_string());
+    assert!(!log.contains("Using random seed"), "{log}");
+    assert_eq!(fixed.seed(), logged);
+    assert_eq!(fixed.state_hash(), random.state_hash());
+}
+
+#[test]
+fn each_random_run_chooses_a_new_seed() {
+    let (first, _) = run("random");
+    let (second, _) = run("random");
+    assert_ne!(first.seed(), second.seed());
+    assert_ne!(first.state_hash(), second.state_hash());
+}
@@ -0,0 +1,92 @@
+// tests/register_all.rs
+//
+// register_all_components() を呼び出した World で、クレートが定義するすべてのコンポーネントを持つエンティティを
+// 生成でき、各ストレージから読み出せることを確かめるテストです。
+
+use my_specs_game::components::{
+    register_all_components, Ball, BallId, BallKind, CollisionMask, Color, CushionSegment, Peg,
+    Pocket, Pocketed, Pocketing, Position, Render, Sleeping, Spin, StillSteps, Table, Trail,
+    Velocity,
+};
+use my_specs_game::config::TableShape;
+use specs::prelude::*;
+
+/// World の `T` のストレージに、`entity` のコンポーネントがあるかどうかを返します。
+/// `T` が登録されていない場合は、読み出しの時点でパニックします。
+fn has<T: Component>(world: &World, entity: Entity) -> bool {
+    world.read_storage::<T>().contains(entity)
+}
+
+#[test]
+fn entity_with_every_component_can_be_created() {
+    let mut world = World::new();
+    register_all_components(&mut world);
+    let position = Position { x: 10.0, y: 20.0 };
+    let entity = world
+        .create_entity()
+        .with(position)
+        .with(Velocity { x: 1.0, y: 2.0 })
+        .with(Ball {
+            radius: 2.85,
+            mass: 0.17,
+            restitution: 0.9,
+        })
+        .with(Table {
+            width: 127.0,
+            height: 254.0,
+            shape: TableShape::Rectangle,
+        })
+        .with(Pocket {
+            radius: 6.0,
+            index: 0,
+        })
+        .with(BallKind::Object)
+        .with(CollisionMask(1))
+        .with(BallId(3))
+        .with(Trail::new(4))
+        .with(Color { r: 1, g: 2, b: 3 })
+        .with(Spin::default())
+        .with(Pocketing {
+            target: (0.0, 0.0),
+            remaining: 5,
+            duration: 5,
+        })
+        .with(Pocketed { pocket: 0, step: 7 })
+        .with(Render { scale: 1.0 })
+        .with(Sleeping)
+        .with(StillSteps::default())
+        .with(Peg {
+            radius: 1.0,
+            restitution: 1.0,
+        })
+        .with(CushionSegment {
+            start: position,
+            end: Position { x: 30.0, y: 20.0 },
+            restitution: 1.0,
+        })
+        .build();
+    world.maintain();
+
+    let present = [
+        has::<Position>(&world, entity),
+        has::<Velocity>(&world, entity),
+        has::<Ball>(&world, entity),
+        has::<Table>(&world, entity),
+        has::<Pocket>(&world, entity),
+        has::<BallKind>(&world, entity),
+        has::<CollisionMask>(&world, entity),
+        has::<BallId>(&world, entity),
+        has::<Trail>(&world, entity),
+        has::<Color>(&world, entity),
+        has::<Spin>(&world, entity),
+        has::<Pocketing>(&world, entity),
+        has::<Pocketed>(&world, entity),
+        has::<Render>(&world, entity),
+        has::<Sleeping>(&world, entity),
+        has::<StillSteps>(&world, entity),
+        has::<Peg>(&world, entity),
+        has::<CushionSegment>(&world, entity),
+    ];
+    assert!(present.iter().all(|&p| p), "{present:?}");
+    assert_eq!(world.read_storage::<BallId>().get(entity), Some(&BallId(3)));
+}
@@ -0,0 +1,51 @@
+// tests/replay_fixture.rs
+//
+// examples/fixtures の記録と設定で verify_replay() を実行し、物理演算が変わっていなければ一致し、
+// 記録や設定が異なる場合は最初に一致しなかったステップが報告されることを確かめるテストです。
+
+use my_specs_game::config::{load_config, Config};
+use my_specs_game::output::WorldSnapshot;
+use my_specs_game::simulation::{load_recording, verify_replay};
+
+/// verify_replay の例と同じ許容誤差です。
+const TOLERANCE: f32 = 1.0e-3;
+
+/// 例で使うブレイクショットの設定と記録を読み込みます。
+fn fixture() -> (Config, Vec<WorldSnapshot>) {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/fixtures");
+    let config = load_config(&format!("{dir}/replay_break.yaml")).unwrap();
+    let recording = load_recording(&format!("{dir}/replay_break.jsonl")).unwrap();
+    (config, recording)
+}
+
+#[test]
+fn recorded_fixture_matches_the_live_run() {
+    let (config, recording) = fixture();
+    assert_eq!(recording.len(), 60);
+    assert_eq!(
+        verify_replay(&config, &recording, TOLERANCE).unwrap(),
+        Ok(())
+    );
+}
+
+#[test]
+fn altered_recording_reports_the_first_divergent_step() {
+    let (config, mut recording) = fixture();
+    recording[25].balls[1].vy += 1.0;
+    recording[40].balls[0].x += 1.0;
+    let divergence = verify_replay(&config, &recording, TOLERANCE)
+        .unwrap()
+        .unwrap_err();
+    assert_eq!(divergence.step, 25);
+    assert!(divergence.detail.starts_with("ball 1 vy"), "{divergence}");
+}
+
+#[test]
+fn changed_physics_diverges_from_the_recording() {
+    let (mut config, recording) = fixture();
+    config.ball.restitution = 0.5;
+    let divergence = verify_replay(&config, &recording, TOLERANCE)
+        .unwrap()
+        .unwrap_err();
+    assert!(divergence.step > 0 && divergence.step < 60, "{divergence}");
+}
@@ -0,0 +1,98 @@
+// tests/resample.rs
+//
+// resample_trajectory() が、等速直線運動の記録を半分の時間間隔に並べ直した際に中間の位置を線形に補間し、
+// ポケットに入って消えたボールもパニックせずに扱うことを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::BallId;
+use my_specs_game::output::{resample_trajectory, MemorySink, WorldSnapshot};
+use my_specs_game::Simulation;
+use std::time::Duration;
+
+/// 0.02 秒刻みで `steps` ステップ進めた、摩擦のない台の `entities` の記録を返します。
+fn record(entities: &str, pockets: &str, steps: usize) -> Vec<WorldSnapshot> {
+    let yaml = standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.02
+units: {{velocity: CmPerS}}
+{pockets}
+entities:
+{entities}"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    let sink = MemorySink::new();
+    let snapshots = sink.snapshots();
+    sim.add_sink(Box::new(sink));
+    sim.run(steps);
+    let recorded = snapshots.borrow().clone();
+    recorded
+}
+
+#[test]
+fn half_interval_resampling_interpolates_midpoints() {
+    let snapshots = record(
+        "  - {kind: Cue, x: 20.0, y: 40.0, vx: 50.0, vy: 25.0}\n",
+        "",
+        10,
+    );
+    assert_eq!(snapshots.len(), 10);
+    let resampled = resample_trajectory(&snapshots, Duration::from_millis(10));
+    // 10 個の記録の間に 1 つずつ中間の時刻が加わります。
+    assert_eq!(resampled.len(), 19);
+    for (i, snapshot) in resampled.iter().enumerate() {
+        let expected = snapshots[0].elapsed + Duration::from_millis(10) * i as u32;
+        assert_eq!(snapshot.elapsed, expected);
+        let ball = &snapshot.balls[0];
+        let t = expected.as_secs_f32();
+        // 経過時間 t の位置は、初期位置から 50 cm/s と 25 cm/s で等速に動いた位置です。
+        assert!((ball.x - (20.0 + 50.0 * t)).abs() < 1e-3, "{i} {}", ball.x);
+        assert!((ball.y - (40.0 + 25.0 * t)).abs() < 1e-3, "{i} {}", ball.y);
+        assert_eq!((ball.vx, ball.vy), (50.0, 25.0));
+    }
+    // 記録にあった時刻では記録そのままの位置に、その間の時刻では前後の記録の中点になります。
+    for (i, snapshot) in snapshots.iter().enumerate() {
+        assert_eq!(resampled[2 * i].balls, snapshot.balls);
+    }
+    let middle = &resampled[1].balls[0];
+    let (a, b) = (&snapshots[0].balls[0], &snapshots[1].balls[0]);
+    assert!((middle.x - (a.x + b.x) / 2.0).abs() < 1e-4);
+    assert!((middle.y - (a.y + b.y) / 2.0).abs() < 1e-4);
+}
+
+#[test]
+fn pocketed_ball_disappears_without_panicking() {
+    // 的球は左のポケットに入り、手球は転がり続けます。
+    let snapshots = record(
+        "  - {kind: Cue, x: 63.5, y: 40.0, vy: 50.0}\n  - {kind: Object, x: 20.0, y: 60.0, vx: -300.0}\n",
+        "pockets: {radius: 6.0, positions: [{x: 0.0, y: 60.0}]}",
+        20,
+    );
+    let last_with_object = snapshots
+        .iter()
+        .rposition(|s| s.balls.iter().any(|b| b.id == BallId(1)))
+        .unwrap();
+    assert!(last_with_object < snapshots.len() - 1);
+    let resampled = resample_trajectory(&snapshots, Duration::from_millis(10));
+    assert_eq!(resampled.len(), 2 * snapshots.len() - 1);
+    let last_time_with_object = resampled
+        .iter()
+        .rposition(|s| s.balls.iter().any(|b| b.id == BallId(1)))
+        .unwrap();
+    // ポケットに入った後の記録の時刻より前までは残り、その時刻からは含まれません。
+    assert_eq!(last_time_with_object, 2 * last_with_object + 1);
+    assert!(resampled
+        .iter()
+        .all(|s| s.balls.iter().any(|b| b.id == BallId(0))));
+}
+
+#[test]
+fn empty_input_or_zero_interval_yields_nothing() {
+    assert!(resample_trajectory(&[], Duration::from_millis(10)).is_empty());
+    let snapshots = record("  - {kind: Cue, x: 20.0, y: 40.0, vx: 50.0}\n", "", 3);
+    assert!(resample_trajectory(&snapshots, Duration::ZERO).is_empty());
+}
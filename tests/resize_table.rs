@@ -0,0 +1,74 @@
+// tests/resize_table.rs
+//
+// Simulation::resize_table() でテーブルを縮めたとき、新しい範囲の外にあるボールが次の衝突処理で内側へ押し戻されること、
+// 正の有限の値でない寸法は拒否されることを確かめるテストです。
+
+mod common;
+
+use common::config_from_yaml;
+use my_specs_game::Simulation;
+
+const RADIUS: f32 = 2.85;
+
+/// 右上の隅の近くと中央にボールを置いた Simulation を、`stepping` のモードで生成します。
+fn simulation(stepping: &str) -> Simulation {
+    let yaml = format!(
+        "dt: 0.01
+stepping: {stepping}
+table: {{width: 127.0, height: 254.0}}
+ball: {{radius: {RADIUS}, mass: 0.17, restitution: 0.9}}
+units: {{velocity: CmPerS}}
+entities:
+  - {{kind: Cue, x: 120.0, y: 250.0, vx: 10.0, vy: 5.0}}
+  - {{kind: Object, x: 60.0, y: 100.0}}
+"
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn shrinking_the_table_pushes_balls_inside_the_new_bounds() {
+    for stepping in ["FixedStep", "EventDriven"] {
+        let mut sim = simulation(stepping);
+        sim.resize_table(100.0, 200.0).unwrap();
+        // 縮めた時点ではボールを動かしません。
+        let p = sim.query_positions()[0].1;
+        assert_eq!((p.x, p.y), (120.0, 250.0));
+        sim.step();
+        for (id, p) in sim.query_positions() {
+            assert!(
+                (RADIUS..=100.0 - RADIUS).contains(&p.x)
+                    && (RADIUS..=200.0 - RADIUS).contains(&p.y),
+                "{stepping}: {id:?} is outside the new bounds: {p:?}"
+            );
+        }
+        // 押し戻されたボールは、新しいクッションから離れる向きに動いています。
+        let v = sim.query_velocities()[0].1;
+        assert!(v.x <= 0.0 && v.y <= 0.0, "{stepping}: {v:?}");
+        // 範囲内にあったボールは動きません。
+        let p = sim.query_positions()[1].1;
+        assert_eq!((p.x, p.y), (60.0, 100.0), "{stepping}");
+    }
+}
+
+#[test]
+fn non_positive_sizes_are_rejected_without_changing_the_table() {
+    let mut sim = simulation("FixedStep");
+    for (width, height) in [
+        (0.0, 200.0),
+        (100.0, -1.0),
+        (f32::NAN, 200.0),
+        (100.0, f32::INFINITY),
+    ] {
+        let err = sim.resize_table(width, height).unwrap_err();
+        assert!(
+            err.to_string().contains("must be finite and positive"),
+            "({width}, {height}): {err}"
+        );
+    }
+    // テーブルは元の寸法のままで、隅の近くのボールは押し戻されません。
+    sim.step();
+    let p = sim.query_positions()[0].1;
+    assert!(p.x > 120.0 && p.y > 250.0, "{p:?}");
+}
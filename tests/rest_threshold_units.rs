@@ -0,0 +1,48 @@
+// tests/rest_threshold_units.rs
+//
+// rest_threshold が units.velocity の単位で解釈され、同じ物理的な閾値を異なる単位で指定した場合に、
+// ボールが同じ速さ（同じステップ）で静止とみなされることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+
+/// 200 cm/s で動き出し 100 cm/s² で減速するボールを、`unit` の単位で初速度 `vy` と閾値 `threshold` を指定して実行し、
+/// 静止とみなされたステップ数とそのときの速さ（cm/s）を返します。
+fn steps_until_rest(unit: &str, vy: f32, threshold: f32) -> (usize, f32) {
+    let yaml = standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.01
+units: {{velocity: {unit}}}
+friction_xy: [1.0, 1.0]
+rest_threshold: {threshold}
+entities:
+  - {{kind: Cue, x: 63.5, y: 20.0, vy: {vy}}}
+"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    while !sim.is_at_rest() {
+        assert!(sim.steps() < 1000, "{unit}: never came to rest");
+        sim.step();
+    }
+    let v = sim.query_velocities()[0].1;
+    (sim.steps(), (v.x * v.x + v.y * v.y).sqrt())
+}
+
+#[test]
+fn same_physical_threshold_stops_the_ball_at_the_same_speed() {
+    // 閾値はいずれも 50 cm/s = 0.5 m/s = 1.8 km/h です。
+    let (steps, speed) = steps_until_rest("CmPerS", 200.0, 50.0);
+    assert!(speed <= 50.0 && speed > 49.0, "{speed}");
+    // 200 cm/s から 50 cm/s まで減速するのに 1.5 秒かかります（その間に奥のクッションへは届きません）。
+    assert!((149..=151).contains(&steps), "{steps}");
+    for (unit, vy, threshold) in [("MPerS", 2.0, 0.5), ("KmH", 7.2, 1.8)] {
+        let (other_steps, other_speed) = steps_until_rest(unit, vy, threshold);
+        assert_eq!(other_steps, steps, "{unit}");
+        assert!((other_speed - speed).abs() < 1e-3, "{unit}: {other_speed}");
+    }
+}
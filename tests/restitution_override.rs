@@ -0,0 +1,57 @@
+// tests/restitution_override.rs
+//
+// restitution_override が、ボールごとの反発係数によらず、ボール同士と壁の衝突の両方に適用されることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+
+/// 反発係数 0.3 のボールに `restitution_override` を加えた設定で、`stepping` のモードの Simulation を生成します。
+fn simulation(stepping: &str, restitution_override: f32, entities: &str) -> Simulation {
+    let yaml = standard_yaml(
+        0.3,
+        &format!(
+            "dt: 0.001
+stepping: {stepping}
+units: {{velocity: CmPerS}}
+restitution_override: {restitution_override}
+entities:
+{entities}"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn forced_elastic_head_on_collision_exchanges_velocities() {
+    let entities = "  - {kind: Cue, x: 40.0, y: 60.0, vx: 20.0}
+  - {kind: Object, x: 60.0, y: 60.0, vx: -20.0, restitution: 0.1}
+";
+    for stepping in ["FixedStep", "EventDriven"] {
+        let mut sim = simulation(stepping, 1.0, entities);
+        sim.run(1000);
+        let velocities = sim.query_velocities();
+        let (a, b) = (velocities[0].1, velocities[1].1);
+        assert!(
+            (a.x + 20.0).abs() < 1e-3 && a.y.abs() < 1e-3,
+            "{stepping}: {a:?}"
+        );
+        assert!(
+            (b.x - 20.0).abs() < 1e-3 && b.y.abs() < 1e-3,
+            "{stepping}: {b:?}"
+        );
+    }
+}
+
+#[test]
+fn forced_inelastic_wall_collision_stops_the_ball() {
+    let entities = "  - {kind: Cue, x: 10.0, y: 60.0, vx: -50.0}\n";
+    for stepping in ["FixedStep", "EventDriven"] {
+        let mut sim = simulation(stepping, 0.0, entities);
+        sim.run(1000);
+        let v = sim.query_velocities()[0].1;
+        assert!(v.x.abs() < 1e-3, "{stepping}: {v:?}");
+    }
+}
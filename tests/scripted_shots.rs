@@ -0,0 +1,57 @@
+// tests/scripted_shots.rs
+//
+// 設定の shots に並べた 2 つのショットを run_shots() で順に撞き、各ショットの後の状態が、
+// 前のショットで静止した状態から計算した期待どおりになることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::{BallId, BallKind};
+use my_specs_game::Simulation;
+
+#[test]
+fn two_scripted_shots_leave_the_expected_state() {
+    // 1 つ目のショットで手球を真上の的球に当てて上のポケットへ落とし、
+    // 2 つ目のショットで止まった手球を +x 方向に 40 cm/s で転がします。
+    let yaml = standard_yaml(
+        1.0,
+        "dt: 0.01
+units: {velocity: CmPerS}
+friction_xy: [0.2, 0.2]
+pockets: {radius: 6.0, drop_steps: 0, positions: [{x: 63.5, y: 254.0}]}
+entities:
+  - {kind: Cue, x: 63.5, y: 40.0}
+  - {kind: Object, x: 63.5, y: 100.0}
+shots:
+  - {angle_deg: 90.0, speed: 150.0}
+  - {angle_deg: 0.0, speed: 40.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    let outcomes = sim.run_shots();
+    assert_eq!(outcomes.len(), 2);
+
+    let first = &outcomes[0];
+    assert!(first.settled && !first.cue_replaced);
+    assert_eq!(first.pocketed, [BallId(1)]);
+    // 同じ質量の完全弾性の正面衝突で速度を受け渡すため、手球は的球に接した位置から、
+    // 衝突を検出するまでの 1 ステップ分の移動量（約 1.4 cm）の範囲で止まります。
+    let balls = &first.snapshot.balls;
+    assert_eq!(balls.len(), 1);
+    let cue = &balls[0];
+    assert_eq!((cue.id, cue.kind), (BallId(0), BallKind::Cue));
+    assert!((cue.x - 63.5).abs() < 1e-3, "{cue:?}");
+    assert!((cue.y - (100.0 - 5.7)).abs() < 1.5, "{cue:?}");
+    assert_eq!((cue.vx, cue.vy), (0.0, 0.0));
+
+    let second = &outcomes[1];
+    assert!(second.settled && !second.cue_replaced);
+    assert!(second.pocketed.is_empty());
+    assert_eq!(second.snapshot.step, first.snapshot.step + second.steps);
+    // 40 cm/s の手球は 20 cm/s² の摩擦で 40 cm 転がります。
+    let after = &second.snapshot.balls[0];
+    assert!((after.x - (cue.x + 40.0)).abs() < 0.5, "{after:?}");
+    assert_eq!(after.y, cue.y);
+    assert_eq!(sim.metrics().balls_pocketed, 1);
+}
@@ -0,0 +1,72 @@
+// tests/settle.rs
+//
+// Simulation::settle() が、わずかに重なって静止したボールを接した状態まで引き離して静止させ、
+// その間のステップや衝突を記録しないことを確かめるテストです。
+
+mod common;
+
+use common::config_from_yaml;
+use my_specs_game::output::MemorySink;
+use my_specs_game::Simulation;
+use std::cell::Cell;
+use std::rc::Rc;
+
+const RADIUS: f32 = 2.85;
+
+#[test]
+fn overlapping_rack_settles_touching_and_at_rest_without_events() {
+    // 3 つの的球を、中心間の距離が直径より 0.2 cm 短い三角形に並べます。
+    let spacing = 2.0 * RADIUS - 0.2;
+    let row = spacing * 3f32.sqrt() / 2.0;
+    let yaml = format!(
+        "dt: 0.01
+table: {{width: 127.0, height: 254.0}}
+ball: {{radius: {RADIUS}, mass: 0.17, restitution: 0.9}}
+units: {{velocity: CmPerS}}
+friction_xy: [0.2, 0.2]
+entities:
+  - {{kind: Object, x: 63.5, y: 190.5}}
+  - {{kind: Object, x: {}, y: {}}}
+  - {{kind: Object, x: {}, y: {}}}
+",
+        63.5 - spacing / 2.0,
+        190.5 + row,
+        63.5 + spacing / 2.0,
+        190.5 + row
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    let sink = MemorySink::new();
+    let snapshots = sink.snapshots();
+    sim.add_sink(Box::new(sink));
+    let callbacks = Rc::new(Cell::new(0));
+    let counter = Rc::clone(&callbacks);
+    sim.on_collision(Box::new(move |_| counter.set(counter.get() + 1)));
+
+    assert!(sim.settle(500));
+    assert!(sim.is_at_rest());
+    let positions = sim.query_positions();
+    for (i, (_, a)) in positions.iter().enumerate() {
+        for (_, b) in &positions[i + 1..] {
+            let distance = (a.x - b.x).hypot(a.y - b.y);
+            // 重なりは解消され、引き離す際のわずかな速度が摩擦で止まるまでの 0.1 cm 未満の隙間で接した状態になります。
+            assert!(
+                (2.0 * RADIUS - 1e-3..2.0 * RADIUS + 0.1).contains(&distance),
+                "{distance}"
+            );
+        }
+    }
+    assert_eq!(sim.steps(), 0);
+    assert_eq!(callbacks.get(), 0);
+    assert!(snapshots.borrow().is_empty());
+    let metrics = sim.metrics();
+    assert_eq!((metrics.ball_collisions, metrics.wall_collisions), (0, 0));
+    assert_eq!(metrics.energy_dissipated, 0.0);
+    assert!(sim.current_contacts().iter().all(|c| c.penetration < 1e-3));
+
+    // 計測の実行は落ち着いた状態から始まり、静止したボールは動きません。
+    let report = sim.step();
+    assert!(!report.any_movement);
+    assert_eq!(report.collisions, 0);
+    assert_eq!(sim.steps(), 1);
+}
@@ -0,0 +1,61 @@
+// tests/shot_spin.rs
+//
+// Simulation::shot() の押し球・引き球の回転が、的球に当たった後の手球の動きに影響することを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::simulation::ShotSpin;
+use my_specs_game::Simulation;
+use std::f32::consts::FRAC_PI_2;
+
+/// 手球を、正面にある的球に向けて `spin_kind` の撞点で撞き、的球に当たってから十分に時間が経った後の
+/// 手球の (y 座標, y 方向の速度) と、衝突した位置（手球の y 座標）を返します。
+fn cue_after_contact(spin_kind: ShotSpin) -> ((f32, f32), f32) {
+    let yaml = standard_yaml(
+        1.0,
+        "dt: 0.001
+units: {velocity: CmPerS}
+friction_xy: [0.05, 0.05]
+slip_friction: 0.2
+entities:
+  - {kind: Cue, x: 63.5, y: 40.0}
+  - {kind: Object, x: 63.5, y: 70.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    sim.shot(FRAC_PI_2, 150.0, spin_kind).unwrap();
+    let mut contact_y = None;
+    for _ in 0..800 {
+        sim.step();
+        if contact_y.is_none() && sim.metrics().ball_collisions > 0 {
+            contact_y = Some(sim.query_positions()[0].1.y);
+        }
+    }
+    let cue = (sim.query_positions()[0].1.y, sim.query_velocities()[0].1.y);
+    (
+        cue,
+        contact_y.expect("the cue ball should hit the object ball"),
+    )
+}
+
+#[test]
+fn draw_shot_pulls_the_cue_ball_back_after_contact() {
+    let ((y, vy), contact_y) = cue_after_contact(ShotSpin::Draw);
+    assert!(vy < 0.0, "{vy}");
+    assert!(
+        y < contact_y - 1.0,
+        "{y} should be behind the contact at {contact_y}"
+    );
+}
+
+#[test]
+fn follow_shot_carries_the_cue_ball_forward_after_contact() {
+    let ((y, vy), contact_y) = cue_after_contact(ShotSpin::Follow);
+    assert!(vy > 0.0, "{vy}");
+    assert!(
+        y > contact_y + 1.0,
+        "{y} should be past the contact at {contact_y}"
+    );
+}
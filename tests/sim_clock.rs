@@ -0,0 +1,52 @@
+// tests/sim_clock.rs
+//
+// シミュレーション上の経過時間（SimClock）が、ステップごとに dt ずつ進むことを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::output::{OutputSink, WorldSnapshot};
+use my_specs_game::resources::SimClock;
+use my_specs_game::Simulation;
+use specs::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// 受け取ったスナップショットの経過時間を記録する出力先です。
+struct Timestamps(Rc<RefCell<Vec<Duration>>>);
+
+impl OutputSink for Timestamps {
+    fn on_step(&mut self, _step: usize, snapshot: &WorldSnapshot) {
+        self.0.borrow_mut().push(snapshot.elapsed);
+    }
+}
+
+#[test]
+fn clock_equals_steps_times_dt() {
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.004
+entities:
+  - {kind: Cue, x: 20.0, y: 60.0, vx: 1.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    let timestamps = Rc::new(RefCell::new(Vec::new()));
+    sim.add_sink(Box::new(Timestamps(Rc::clone(&timestamps))));
+    let steps = 250;
+    for _ in 0..steps {
+        sim.step();
+    }
+    let clock = *sim.world().read_resource::<SimClock>();
+    assert_eq!(clock.steps, steps);
+    assert!((clock.elapsed.as_secs_f64() - steps as f64 * 0.004).abs() < 1e-6);
+    // 出力先に渡すスナップショットにも、そのステップの終了時の経過時間が含まれます。
+    let timestamps = timestamps.borrow();
+    assert_eq!(timestamps.len(), steps);
+    for (n, elapsed) in timestamps.iter().enumerate() {
+        assert!((elapsed.as_secs_f64() - (n + 1) as f64 * 0.004).abs() < 1e-6);
+    }
+    assert_eq!(sim.snapshot().elapsed, clock.elapsed);
+}
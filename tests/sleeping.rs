@@ -0,0 +1,52 @@
+// tests/sleeping.rs
+//
+// 静止が sleep_steps だけ続いたボールが休止し、ほかのボールにぶつかられると起きて動き出すことを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::{BallId, Sleeping};
+use my_specs_game::Simulation;
+use specs::prelude::*;
+
+/// 休止しているボールの BallId を返します。
+fn sleeping_ids(sim: &Simulation) -> Vec<BallId> {
+    sim.observe(|world| {
+        let ids = world.read_storage::<BallId>();
+        let sleeping = world.read_storage::<Sleeping>();
+        let mut ids: Vec<BallId> = (&ids, &sleeping).join().map(|(id, _)| *id).collect();
+        ids.sort();
+        ids
+    })
+}
+
+#[test]
+fn settled_ball_sleeps_until_an_impact_wakes_it() {
+    // 手球は 40 cm 離れた静止した的球へ 100 cm/s で向かい、およそ 0.35 秒後に当たります。
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: CmPerS}
+friction_xy: [0.0, 0.0]
+sleep_steps: 10
+entities:
+  - {kind: Cue, x: 20.0, y: 60.0, vx: 100.0}
+  - {kind: Object, x: 60.0, y: 60.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    sim.run(9);
+    assert!(sleeping_ids(&sim).is_empty());
+    sim.step();
+    assert_eq!(sleeping_ids(&sim), [BallId(1)]);
+    sim.run(30);
+    assert!(sleeping_ids(&sim).is_empty());
+    let v = sim.query_velocities()[1].1;
+    assert!(
+        v.x > 50.0,
+        "the object ball should move after the impact: {v:?}"
+    );
+    let p = sim.query_positions()[1].1;
+    assert!(p.x > 60.0, "{p:?}");
+}
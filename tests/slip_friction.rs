@@ -0,0 +1,69 @@
+// tests/slip_friction.rs
+//
+// 滑り摩擦（slip_friction）が並進と回転を 1 つの更新で結びつけ、接点まわりの角運動量を保存することを確かめるテストです。
+
+mod common;
+
+use common::config_from_yaml;
+use my_specs_game::Simulation;
+
+const RADIUS: f32 = 2.85;
+
+/// 転がり摩擦を 0 にし、滑り摩擦だけを働かせて、速度と回転が合わない（滑っている）ボールを置いた Simulation を生成します。
+fn sliding_ball() -> Simulation {
+    let yaml = format!(
+        "dt: 0.001
+table: {{width: 1000.0, height: 1000.0}}
+ball: {{radius: {RADIUS}, mass: 0.17, restitution: 1.0}}
+units: {{velocity: CmPerS}}
+friction_xy: [0.0, 0.0]
+slip_friction: 0.2
+entities:
+  - {{kind: Cue, x: 300.0, y: 300.0, vx: 150.0, vy: 40.0, spin: [10.0, -30.0, 5.0]}}
+"
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+/// 接点まわりの角運動量を質量で割った値 `2/5 r^2 ω + r ẑ × v`（ẑ は鉛直上向き）を返します。
+fn contact_angular_momentum(sim: &Simulation) -> (f32, f32, f32) {
+    let v = sim.query_velocities()[0].1;
+    let s = sim.query_spins()[0].1;
+    let inertia = 0.4 * RADIUS * RADIUS;
+    (
+        inertia * s.x - RADIUS * v.y,
+        inertia * s.y + RADIUS * v.x,
+        inertia * s.z,
+    )
+}
+
+/// 接点の滑り速度の大きさを返します。
+fn slip_speed(sim: &Simulation) -> f32 {
+    let v = sim.query_velocities()[0].1;
+    let s = sim.query_spins()[0].1;
+    let (ux, uy) = (v.x - RADIUS * s.y, v.y + RADIUS * s.x);
+    (ux * ux + uy * uy).sqrt()
+}
+
+#[test]
+fn pure_slip_conserves_angular_momentum_about_the_contact_point() {
+    let mut sim = sliding_ball();
+    let initial = contact_angular_momentum(&sim);
+    let initial_slip = slip_speed(&sim);
+    let mut rolled = false;
+    for _ in 0..1000 {
+        sim.step();
+        let l = contact_angular_momentum(&sim);
+        let tolerance = 1e-4 * (initial.0.abs() + initial.1.abs());
+        assert!(
+            (l.0 - initial.0).abs() < tolerance
+                && (l.1 - initial.1).abs() < tolerance
+                && (l.2 - initial.2).abs() < 1e-4,
+            "{l:?} != {initial:?}"
+        );
+        rolled |= slip_speed(&sim) < 1e-3 * initial_slip;
+    }
+    // 滑りが収まって転がり状態になった後も保存されていることを確かめます。
+    assert!(rolled);
+}
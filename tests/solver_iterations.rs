@@ -0,0 +1,63 @@
+// tests/solver_iterations.rs
+//
+// 互いにめり込んだボールの密集で、solver_iterations を増やすほど 1 ステップ後に残るめり込みが小さくなることを確かめるテストです。
+
+mod common;
+
+use common::config_from_yaml;
+use my_specs_game::Simulation;
+
+const RADIUS: f32 = 2.85;
+
+/// 中心間距離 5 cm（直径より 0.7 cm 短い）の 4 × 4 の格子に静止したボールを並べ、`extra` を加えた設定で
+/// 1 ステップ実行した後の、すべてのペアのめり込みの深さの合計を返します。
+fn residual_overlap(extra: &str) -> f32 {
+    let entities: String = (0..16)
+        .map(|i| {
+            let (x, y) = (50.0 + (i % 4) as f32 * 5.0, 100.0 + (i / 4) as f32 * 5.0);
+            let kind = if i == 0 { "Cue" } else { "Object" };
+            format!("  - {{kind: {kind}, x: {x}, y: {y}}}\n")
+        })
+        .collect();
+    let yaml = format!(
+        "dt: 0.01
+table: {{width: 127.0, height: 254.0}}
+ball: {{radius: {RADIUS}, mass: 0.17, restitution: 0.9}}
+units: {{velocity: CmPerS}}
+{extra}
+entities:
+{entities}"
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    sim.step();
+    let positions = sim.query_positions();
+    let mut total = 0.0;
+    for (i, (_, a)) in positions.iter().enumerate() {
+        for (_, b) in &positions[i + 1..] {
+            let distance = ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt();
+            total += (2.0 * RADIUS - distance).max(0.0);
+        }
+    }
+    total
+}
+
+#[test]
+fn more_iterations_leave_less_overlap_in_a_dense_cluster() {
+    let overlaps: Vec<f32> = [0, 1, 4, 16]
+        .into_iter()
+        .map(|n| residual_overlap(&format!("solver_iterations: {n}")))
+        .collect();
+    // 補正しない場合は、格子の隣り合う 24 組のペアのめり込みがそのまま残ります。
+    assert!((overlaps[0] - 24.0 * 0.7).abs() < 1e-3, "{overlaps:?}");
+    assert!(overlaps.windows(2).all(|w| w[1] < w[0]), "{overlaps:?}");
+    assert!(overlaps[3] < 0.1 * overlaps[0], "{overlaps:?}");
+}
+
+#[test]
+fn omitted_iterations_do_not_correct_positions() {
+    assert_eq!(
+        residual_overlap(""),
+        residual_overlap("solver_iterations: 0")
+    );
+}
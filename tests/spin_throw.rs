@@ -0,0 +1,73 @@
+// tests/spin_throw.rs
+//
+// ボール同士の接点の滑りに各ボールの回転による表面の速さを含めるため、正面からの当たりでもひねり（鉛直軸まわりの回転）を
+// 加えた手球は的球を真っすぐな線からそらし、押し球の回転は厚みによるスローを減らすことを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::simulation::ShotSpin;
+use my_specs_game::Simulation;
+use std::cell::Cell;
+use std::f32::consts::FRAC_PI_2;
+use std::rc::Rc;
+
+/// (63.5, 80) の手球を +y 方向へ 150 cm/s で `object_x` に置いた的球へ送り、600 ステップ実行します。
+/// 手球の設定に `cue` を加え、`shot` を指定した場合はその撞点で撞き直します。
+/// 的球が衝突の法線（中心線）から横にそれた距離を、法線を時計回りに 90 度回した向き（法線が +y の場合は +x）を正として返します。
+fn throw(object_x: f32, cue: &str, shot: Option<ShotSpin>) -> f32 {
+    let yaml = standard_yaml(
+        1.0,
+        &format!(
+            "dt: 0.001
+units: {{velocity: CmPerS}}
+ball_friction: 0.2
+entities:
+  - {{kind: Cue, x: 63.5, y: 80.0, vy: 150.0{cue}}}
+  - {{kind: Object, x: {object_x}, y: 100.0}}
+"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    let normal = Rc::new(Cell::new(None));
+    let recorded = Rc::clone(&normal);
+    sim.on_collision(Box::new(move |collision| {
+        if recorded.get().is_none() {
+            recorded.set(Some((collision.normal_x, collision.normal_y)));
+        }
+    }));
+    if let Some(spin_kind) = shot {
+        sim.shot(FRAC_PI_2, 150.0, spin_kind).unwrap();
+    }
+    sim.run(600);
+    let (nx, ny) = normal
+        .get()
+        .expect("the cue ball should hit the object ball");
+    let object = sim.query_positions()[1].1;
+    let (dx, dy) = (object.x - object_x, object.y - 100.0);
+    ny * dx - nx * dy
+}
+
+#[test]
+fn side_spin_throws_a_full_ball_hit_off_the_straight_line() {
+    let straight = throw(63.5, "", None);
+    assert!(straight.abs() < 1e-3, "{straight}");
+    // 反時計回り（+z）のひねりでは、接点で手球の表面が -x 向きに動くため、的球は -x 向きへそれます。
+    let counter_clockwise = throw(63.5, ", spin: [0.0, 0.0, 30.0]", None);
+    let clockwise = throw(63.5, ", spin: [0.0, 0.0, -30.0]", None);
+    assert!(counter_clockwise < -1.0, "{counter_clockwise}");
+    assert!(
+        (counter_clockwise + clockwise).abs() < 1e-3,
+        "{counter_clockwise} {clockwise}"
+    );
+}
+
+#[test]
+fn follow_spin_reduces_cut_induced_throw() {
+    // 半径だけ横にずらした的球（ハーフボール）では、回転がなくても厚みによって的球がそれます。
+    let stun = throw(66.35, "", Some(ShotSpin::Stun));
+    let follow = throw(66.35, "", Some(ShotSpin::Follow));
+    assert!(stun.abs() > 1.0, "{stun}");
+    assert!(follow.abs() < stun.abs(), "{follow} {stun}");
+}
@@ -0,0 +1,95 @@
+// tests/spring_contact.rs
+//
+// contact_model を Spring にすると、重なったボール同士がばねの力で数ステップかけて滑らかに離れ、
+// 既定の Impulse のように 1 ステップで引き離されないこと、イベント駆動モードとは組み合わせられないことを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::config::{load_config_from_reader, Format};
+use my_specs_game::Simulation;
+
+const RADIUS: f32 = 2.85;
+
+/// 0.5 cm 重ねて静止させた 2 つのボールを置き、`extra` を加えた設定で Simulation を生成します。
+fn overlapping_pair(extra: &str) -> Simulation {
+    let yaml = format!(
+        "dt: 0.01
+table: {{width: 127.0, height: 254.0}}
+ball: {{radius: {RADIUS}, mass: 0.17, restitution: 1.0}}
+units: {{velocity: CmPerS}}
+{extra}
+entities:
+  - {{kind: Cue, x: 61.0, y: 127.0}}
+  - {{kind: Object, x: 66.2, y: 127.0}}
+"
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+/// 2 つのボールの中心間の距離と、離れていく向きの相対速度を返します。
+fn separation(sim: &Simulation) -> (f32, f32) {
+    let p = sim.query_positions();
+    let v = sim.query_velocities();
+    (p[1].1.x - p[0].1.x, v[1].1.x - v[0].1.x)
+}
+
+#[test]
+fn spring_contact_separates_over_several_steps() {
+    let mut sim = overlapping_pair("contact_model: !Spring {stiffness: 50.0, damping: 0.0}");
+    let mut distances = vec![separation(&sim).0];
+    let mut speeds = vec![0.0];
+    while distances.last().unwrap() < &(2.0 * RADIUS) {
+        assert!(distances.len() < 100, "{distances:?}");
+        sim.step();
+        let (distance, speed) = separation(&sim);
+        distances.push(distance);
+        speeds.push(speed);
+    }
+    // 換算質量 0.085 kg とばね定数 50 N/m の振動（ω ≈ 24.3 rad/s）の 1/4 周期、約 0.065 秒をかけて離れます。
+    assert!((5..=9).contains(&(distances.len() - 1)), "{distances:?}");
+    assert!(distances.windows(2).all(|w| w[1] > w[0]), "{distances:?}");
+    // 離れていく速さは、重なりが浅くなるにつれて少しずつ増えていきます。
+    assert!(speeds.windows(2).all(|w| w[1] >= w[0]), "{speeds:?}");
+    assert!(speeds[1] < speeds[speeds.len() - 1] / 2.0, "{speeds:?}");
+    // 減衰がないため、重なりに蓄えられたエネルギーがすべて運動に変わります（ω × 0.5 cm ≈ 12.1 cm/s）。
+    let last = *speeds.last().unwrap();
+    assert!((last - 12.1).abs() < 0.5, "{speeds:?}");
+}
+
+#[test]
+fn impulse_contact_separates_within_one_step() {
+    let mut sim = overlapping_pair("solver_iterations: 4");
+    sim.step();
+    let (distance, _) = separation(&sim);
+    assert!(distance >= 2.0 * RADIUS - 1e-3, "{distance}");
+}
+
+#[test]
+fn damping_reduces_the_separation_speed() {
+    let final_speed = |model: &str| {
+        let mut sim = overlapping_pair(model);
+        sim.run(50);
+        separation(&sim).1
+    };
+    let undamped = final_speed("contact_model: !Spring {stiffness: 50.0, damping: 0.0}");
+    let damped = final_speed("contact_model: !Spring {stiffness: 50.0, damping: 0.5}");
+    assert!(damped < undamped, "{damped} {undamped}");
+}
+
+#[test]
+fn spring_contact_is_rejected_with_event_driven_stepping() {
+    let yaml = standard_yaml(
+        1.0,
+        "dt: 0.01
+contact_model: !Spring {stiffness: 50.0, damping: 0.0}
+stepping: EventDriven
+",
+    );
+    let err = load_config_from_reader(yaml.as_bytes(), Format::Yaml).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "contact_model: Spring is not supported with stepping: EventDriven"
+    );
+}
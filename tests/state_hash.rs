@@ -0,0 +1,49 @@
+// tests/state_hash.rs
+//
+// Simulation::state_hash() が、決まった設定を決まったステップ数だけ実行したときに固定の値になり、
+// 丸めの単位より小さい誤差では変わらないことを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::Position;
+use my_specs_game::Simulation;
+use specs::prelude::*;
+
+/// 2 つのボールが正面衝突し、一方が壁で跳ね返る設定で Simulation を生成します。
+fn simulation() -> Simulation {
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: CmPerS}
+entities:
+  - {kind: Cue, x: 63.5, y: 40.0, vy: 150.0}
+  - {kind: Object, x: 63.5, y: 100.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn fixed_scenario_has_a_pinned_hash() {
+    let mut sim = simulation();
+    sim.run(300);
+    assert!(sim.metrics().ball_collisions >= 1);
+    // 物理の計算を変更してこの値が変わった場合は、変更が意図したものかを確かめてから値を更新します。
+    assert_eq!(sim.state_hash(), 995472690649735931);
+}
+
+#[test]
+fn noise_below_the_resolution_does_not_change_the_hash() {
+    let sim = simulation();
+    let before = sim.state_hash();
+    for p in (&mut sim.world().write_storage::<Position>()).join() {
+        p.x += 1.0e-5;
+    }
+    assert_eq!(sim.state_hash(), before);
+    for p in (&mut sim.world().write_storage::<Position>()).join() {
+        p.x += 1.0e-2;
+    }
+    assert_ne!(sim.state_hash(), before);
+}
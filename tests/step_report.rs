@@ -0,0 +1,71 @@
+// tests/step_report.rs
+//
+// Simulation::step() が返す StepReport に、ボールが動いたかどうか、衝突の数、削除されたボールの数が
+// 正しく集計されることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::simulation::StepReport;
+use my_specs_game::Simulation;
+
+/// 共通の台とボールの設定に `extra` を加えた設定で Simulation を生成します。
+fn simulation(extra: &str) -> Simulation {
+    let yaml = standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+{extra}"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn step_with_all_balls_asleep_reports_no_movement() {
+    let mut sim = simulation(
+        "sleep_steps: 3
+entities:
+  - {kind: Cue, x: 20.0, y: 60.0}
+  - {kind: Object, x: 60.0, y: 60.0}
+",
+    );
+    sim.run(5);
+    let report: StepReport = sim.step();
+    assert!(!report.any_movement);
+    assert_eq!((report.collisions, report.balls_removed), (0, 0));
+}
+
+#[test]
+fn active_steps_report_movement_and_collisions() {
+    // 手球は 40 cm 離れた静止した的球へ 100 cm/s で向かい、およそ 0.35 秒後に当たります。
+    let mut sim = simulation(
+        "entities:
+  - {kind: Cue, x: 20.0, y: 60.0, vx: 100.0}
+  - {kind: Object, x: 60.0, y: 60.0}
+",
+    );
+    let reports: Vec<StepReport> = (0..50).map(|_| sim.step()).collect();
+    assert!(reports.iter().all(|r| r.any_movement));
+    let collided: Vec<usize> = (0..reports.len())
+        .filter(|&i| reports[i].collisions > 0)
+        .collect();
+    assert_eq!(collided.len(), 1, "{collided:?}");
+    assert_eq!(reports[collided[0]].collisions, 1);
+}
+
+#[test]
+fn pocketing_step_reports_the_removed_ball() {
+    let mut sim = simulation(
+        "pockets: {radius: 6.0, positions: [{x: 0.0, y: 60.0}]}
+entities:
+  - {kind: Cue, x: 63.5, y: 200.0}
+  - {kind: Object, x: 30.0, y: 60.0, vx: -300.0}
+",
+    );
+    let removed: Vec<usize> = (0..50).map(|_| sim.step().balls_removed).collect();
+    assert_eq!(removed.iter().sum::<usize>(), 1, "{removed:?}");
+    assert_eq!(sim.query_positions().len(), 1);
+}
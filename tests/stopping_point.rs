@@ -0,0 +1,86 @@
+// tests/stopping_point.rs
+//
+// stopping_point() で求めた止まる位置が、障害物のない台で摩擦だけを受けて転がるボールを
+// シミュレーションで止まるまで進めた位置と、1 ステップ分の移動量の範囲で一致することを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::{Position, Velocity};
+use my_specs_game::math::stopping_point;
+use my_specs_game::resources::Friction;
+use my_specs_game::Simulation;
+
+/// `friction` の摩擦（m/s^2）の台で、(30, 40) から `velocity` で転がした手球が止まった位置を返します。
+fn simulated_stop(friction: (f32, f32), velocity: (f32, f32)) -> Position {
+    let yaml = standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+friction_xy: [{}, {}]
+entities:
+  - {{kind: Cue, x: 30.0, y: 40.0, vx: {}, vy: {}}}
+",
+            friction.0, friction.1, velocity.0, velocity.1
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    for _ in 0..2000 {
+        sim.step();
+        if sim.is_at_rest() {
+            break;
+        }
+    }
+    assert!(sim.is_at_rest());
+    assert_eq!(sim.metrics().wall_collisions, 0);
+    sim.query_positions()[0].1
+}
+
+/// 摩擦による減速を受けて止まる位置を、stopping_point() とシミュレーションの両方で求めて比べます。
+fn assert_prediction_matches(friction: (f32, f32), velocity: (f32, f32)) -> Position {
+    let predicted = stopping_point(
+        Position { x: 30.0, y: 40.0 },
+        Velocity {
+            x: velocity.0,
+            y: velocity.1,
+        },
+        &Friction {
+            x: friction.0 * 100.0,
+            y: friction.1 * 100.0,
+        },
+    )
+    .unwrap();
+    let simulated = simulated_stop(friction, velocity);
+    // シミュレーションでは、1 ステップ分の移動量（速さ × 0.01 秒）の半分ほど手前で止まります。
+    let tolerance = velocity.0.hypot(velocity.1) * 0.01;
+    let error = (predicted.x - simulated.x).hypot(predicted.y - simulated.y);
+    assert!(error < tolerance, "{predicted:?} {simulated:?}");
+    predicted
+}
+
+#[test]
+fn isotropic_prediction_matches_the_simulation() {
+    // 50 cm/s の手球は 20 cm/s² の摩擦で 62.5 cm 転がり、(30 + 37.5, 40 + 50) で止まります。
+    let predicted = assert_prediction_matches((0.2, 0.2), (30.0, 40.0));
+    assert!((predicted.x - 67.5).abs() < 1e-3 && (predicted.y - 90.0).abs() < 1e-3);
+}
+
+#[test]
+fn anisotropic_prediction_matches_the_simulation() {
+    for velocity in [(30.0, 20.0), (-25.0, 35.0), (40.0, 0.0)] {
+        assert_prediction_matches((0.3, 0.1), velocity);
+    }
+}
+
+#[test]
+fn resting_or_frictionless_balls() {
+    let pos = Position { x: 30.0, y: 40.0 };
+    let friction = Friction { x: 20.0, y: 20.0 };
+    let stop = stopping_point(pos, Velocity { x: 0.0, y: 0.0 }, &friction).unwrap();
+    assert_eq!((stop.x, stop.y), (pos.x, pos.y));
+    // 動いている軸に摩擦がない場合は、いつまでも止まりません。
+    let frictionless = Friction { x: 20.0, y: 0.0 };
+    assert!(stopping_point(pos, Velocity { x: 10.0, y: 5.0 }, &frictionless).is_none());
+}
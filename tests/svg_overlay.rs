@@ -0,0 +1,70 @@
+// tests/svg_overlay.rs
+//
+// Simulation::set_overlay() で補助表示を設定すると、SVG の書き出しにエイムラインの line 要素と
+// ゴーストボールの circle 要素が加わることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::output::Overlay;
+use my_specs_game::Simulation;
+use std::f32::consts::FRAC_PI_2;
+
+/// 手球と的球を 1 つずつ置いた Simulation を生成します。
+fn simulation() -> Simulation {
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: CmPerS}
+entities:
+  - {kind: Cue, x: 63.5, y: 40.0}
+  - {kind: Object, x: 63.5, y: 150.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn overlay_adds_an_aim_line_and_a_ghost_ball() {
+    let mut sim = simulation();
+    let plain = sim.render_svg();
+    assert_eq!(plain.matches("<line").count(), 0);
+    sim.set_overlay(Some(Overlay {
+        aim: FRAC_PI_2,
+        contact: Some((63.5, 150.0 - 2.0 * 2.85)),
+    }));
+    let svg = sim.render_svg();
+    // 手球の中心（SVG の座標では y = 254 - 40）から、接触位置まで線を引きます。
+    assert_eq!(svg.matches("<line").count(), 1);
+    assert!(
+        svg.contains(r#"<line x1="63.5" y1="214" x2="63.5" y2="109.7""#),
+        "{svg}"
+    );
+    assert_eq!(
+        svg.matches("<circle").count(),
+        plain.matches("<circle").count() + 1
+    );
+    assert!(
+        svg.contains(r#"<circle cx="63.5" cy="109.7" r="2.85" fill="none""#),
+        "{svg}"
+    );
+}
+
+#[test]
+fn overlay_without_a_contact_draws_only_the_aim_line() {
+    let mut sim = simulation();
+    let plain = sim.render_svg();
+    sim.set_overlay(Some(Overlay {
+        aim: 0.0,
+        contact: None,
+    }));
+    let svg = sim.render_svg();
+    assert_eq!(svg.matches("<line").count(), 1);
+    assert_eq!(
+        svg.matches("<circle").count(),
+        plain.matches("<circle").count()
+    );
+    sim.set_overlay(None);
+    assert_eq!(sim.render_svg(), plain);
+}
@@ -0,0 +1,52 @@
+// tests/swept_aabb.rs
+//
+// swept_aabb() が、1 ステップの始点から終点までの移動を半径だけ広げた範囲を返し、
+// 重ならない swept AABB のペアが接触しないことを確かめるテストです。
+
+use my_specs_game::components::{Position, Velocity};
+use my_specs_game::math::{ball_time_of_impact, swept_aabb, Aabb};
+
+const RADIUS: f32 = 2.85;
+
+#[test]
+fn fast_ball_sweeps_the_whole_span_of_its_step() {
+    // 1 ステップで x 方向に 10 cm、y 方向に -3 cm 進む、速いボールです。
+    let start = Position { x: 20.0, y: 50.0 };
+    let end = Position { x: 30.0, y: 47.0 };
+    let expected = Aabb {
+        min_x: 20.0 - RADIUS,
+        min_y: 47.0 - RADIUS,
+        max_x: 30.0 + RADIUS,
+        max_y: 50.0 + RADIUS,
+    };
+    assert_eq!(swept_aabb(start, end, RADIUS), expected);
+    // 始点と終点を入れ替えても同じ範囲になります。
+    assert_eq!(swept_aabb(end, start, RADIUS), expected);
+}
+
+#[test]
+fn disjoint_boxes_never_touch_within_the_step() {
+    let dt = 1.0 / 60.0;
+    let moving = |x: f32, y: f32, vx: f32, vy: f32| {
+        let start = Position { x, y };
+        let end = Position {
+            x: x + vx * dt,
+            y: y + vy * dt,
+        };
+        (
+            start,
+            Velocity { x: vx, y: vy },
+            swept_aabb(start, end, RADIUS),
+        )
+    };
+    // 互いに近づくが、ステップの終わりまでに接しない 2 つのボールです。
+    let (a, va, box_a) = moving(20.0, 50.0, 300.0, 0.0);
+    let (b, vb, box_b) = moving(40.0, 50.0, -300.0, 0.0);
+    assert!(!box_a.overlaps(&box_b));
+    let toi = ball_time_of_impact(a, va, RADIUS, b, vb, RADIUS);
+    assert!(toi.is_none_or(|t| t > dt), "{toi:?}");
+    // 速ければステップ内で接するため、swept AABB も重なります。
+    let (_, _, box_a) = moving(20.0, 50.0, 600.0, 0.0);
+    let (_, _, box_b) = moving(40.0, 50.0, -600.0, 0.0);
+    assert!(box_a.overlaps(&box_b));
+}
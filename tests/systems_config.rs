@@ -0,0 +1,62 @@
+// tests/systems_config.rs
+//
+// systems の設定で、ディスパッチャーに組み込むシステムを切り替えられることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::Simulation;
+
+/// `systems` の設定で、2 つのボールを向かい合わせに転がす Simulation を `stepping` のモードで生成します。
+fn simulation(stepping: &str, systems: &str) -> Simulation {
+    let yaml = standard_yaml(
+        1.0,
+        &format!(
+            "dt: 0.001
+stepping: {stepping}
+units: {{velocity: CmPerS}}
+systems: {systems}
+entities:
+  - {{kind: Cue, x: 40.0, y: 60.0, vx: 20.0}}
+  - {{kind: Object, x: 60.0, y: 60.0, vx: -20.0}}
+"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+#[test]
+fn disabled_physics_leaves_positions_static() {
+    let systems = "{physics: false, collision: true, pockets: true, logging: false, log_energy: false, stats: true}";
+    for stepping in ["FixedStep", "EventDriven"] {
+        let mut sim = simulation(stepping, systems);
+        sim.run(1000);
+        let positions: Vec<(f32, f32)> = sim
+            .query_positions()
+            .into_iter()
+            .map(|(_, p)| (p.x, p.y))
+            .collect();
+        assert_eq!(positions, [(40.0, 60.0), (60.0, 60.0)], "{stepping}");
+    }
+}
+
+#[test]
+fn disabled_collision_lets_balls_pass_through_each_other() {
+    let systems = "{physics: true, collision: false, pockets: true, logging: false, log_energy: false, stats: true}";
+    let mut sim = simulation("FixedStep", systems);
+    sim.run(1000);
+    let positions = sim.query_positions();
+    // 1 秒で 20 cm 進み、互いの初期位置に入れ替わります。
+    assert!(
+        (positions[0].1.x - 60.0).abs() < 1e-3,
+        "{:?}",
+        positions[0].1
+    );
+    assert!(
+        (positions[1].1.x - 40.0).abs() < 1e-3,
+        "{:?}",
+        positions[1].1
+    );
+    assert_eq!(sim.metrics().ball_collisions, 0);
+}
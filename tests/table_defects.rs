@@ -0,0 +1,69 @@
+// tests/table_defects.rs
+//
+// table_defects の個体差が、同じ seed では同じ値に、異なる seed では異なる値に引かれ、
+// 引いたクッションの反発係数の倍率が壁での跳ね返りに使われることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::resources::{TableDefects, Wall};
+use my_specs_game::Simulation;
+use specs::prelude::*;
+
+/// 左の壁へ向かうボールの設定に `seed` と `defects` の個体差を加えて Simulation を生成します。
+fn simulation(seed: u64, defects: &str) -> Simulation {
+    let yaml = standard_yaml(
+        0.8,
+        &format!(
+            "dt: 0.001
+units: {{velocity: CmPerS}}
+seed: {seed}
+{defects}
+entities:
+  - {{kind: Cue, x: 20.0, y: 60.0, vx: -50.0}}
+"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+/// Simulation に登録されたテーブルの個体差を返します。
+fn drawn(sim: &Simulation) -> TableDefects {
+    *sim.world().read_resource::<TableDefects>()
+}
+
+const VARIED: &str = "table_defects:
+  cushion_restitution: {std_dev: 0.05}
+  cloth_friction: {std_dev: 0.1}";
+
+#[test]
+fn same_seed_draws_the_same_defects() {
+    let first = drawn(&simulation(3, VARIED));
+    assert_eq!(drawn(&simulation(3, VARIED)), first);
+    assert_ne!(drawn(&simulation(4, VARIED)), first);
+    assert_ne!(first, TableDefects::default());
+}
+
+#[test]
+fn zero_variance_gives_identical_runs() {
+    let zero =
+        "table_defects: {cushion_restitution: {std_dev: 0.0}, cloth_friction: {std_dev: 0.0}}";
+    let mut runs = [simulation(3, zero), simulation(4, zero), simulation(3, "")];
+    for sim in &mut runs {
+        assert_eq!(drawn(sim), TableDefects::default());
+        sim.run(1000);
+    }
+    assert_eq!(runs[0].state_hash(), runs[1].state_hash());
+    assert_eq!(runs[0].state_hash(), runs[2].state_hash());
+}
+
+#[test]
+fn bounce_uses_the_drawn_cushion_restitution() {
+    let mut sim = simulation(3, VARIED);
+    let multiplier = drawn(&sim).cushion_restitution[Wall::Left.index()];
+    sim.run(1000);
+    let vx = sim.query_velocities()[0].1.x;
+    let expected = 50.0 * (0.8 * multiplier).min(1.0);
+    assert!((vx - expected).abs() < 1e-3, "{vx} != {expected}");
+}
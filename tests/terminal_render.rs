@@ -0,0 +1,49 @@
+// tests/terminal_render.rs
+//
+// render_ascii() が、既知の配置の各ボールを BallId の数字で期待どおりの文字の位置に描き、
+// 同じマスに入ったボールを `*` で表すことを確かめるテストです。
+
+use my_specs_game::components::BallId;
+use my_specs_game::output::render_ascii;
+
+#[test]
+fn balls_appear_at_the_expected_character_positions() {
+    // 100 cm × 50 cm のテーブルを 10 文字 × 5 行に分けるため、1 文字は 10 cm 四方です。
+    let balls = [
+        (BallId(0), 5.0, 5.0),
+        (BallId(1), 95.0, 45.0),
+        (BallId(12), 42.0, 27.0),
+    ];
+    let text = render_ascii((100.0, 50.0), &balls, 10, 5);
+    let expected = "\
++----------+
+|         1|
+|          |
+|    2     |
+|          |
+|0         |
++----------+
+";
+    assert_eq!(text, expected);
+}
+
+#[test]
+fn balls_sharing_a_cell_are_drawn_as_an_asterisk() {
+    // BallId 3 と 4 は同じ 10 cm 四方のマスに入り、BallId 5 はテーブルの右下の外にあります。
+    let balls = [
+        (BallId(3), 12.0, 12.0),
+        (BallId(4), 18.0, 18.0),
+        (BallId(5), 150.0, -3.0),
+    ];
+    let text = render_ascii((100.0, 50.0), &balls, 10, 5);
+    let expected = "\
++----------+
+|          |
+|          |
+|          |
+| *        |
+|         5|
++----------+
+";
+    assert_eq!(text, expected);
+}
@@ -0,0 +1,64 @@
+// tests/trail.rs
+//
+// TrailSystem が各ボールの最近の位置を trail_capacity 個まで保持することを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::components::{BallId, Position, Trail};
+use my_specs_game::Simulation;
+use specs::prelude::*;
+
+/// 軌跡の容量 `capacity` で、1 つのボールを転がす Simulation を生成します。
+fn simulation(capacity: usize) -> Simulation {
+    let yaml = standard_yaml(
+        1.0,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+trail_capacity: {capacity}
+entities:
+  - {{kind: Cue, x: 20.0, y: 60.0, vx: 30.0, vy: 10.0}}
+"
+        ),
+    );
+    let config = config_from_yaml(&yaml);
+    Simulation::new(&config).unwrap()
+}
+
+/// 各ボールの軌跡と現在の位置を返します。
+fn trails(sim: &Simulation) -> Vec<(Trail, Position)> {
+    sim.observe(|world| {
+        let ids = world.read_storage::<BallId>();
+        let trails = world.read_storage::<Trail>();
+        let pos = world.read_storage::<Position>();
+        (&ids, &trails, &pos)
+            .join()
+            .map(|(_, t, p)| (t.clone(), *p))
+            .collect()
+    })
+}
+
+#[test]
+fn trail_keeps_the_most_recent_capacity_points() {
+    let mut sim = simulation(5);
+    sim.run(12);
+    let trails = trails(&sim);
+    assert_eq!(trails.len(), 1);
+    let (trail, p) = &trails[0];
+    assert_eq!(trail.points.len(), 5);
+    assert_eq!(trail.points.back(), Some(&(p.x, p.y)));
+    // 古いものから順に並び、x 座標は単調に増えます。
+    assert!(trail
+        .points
+        .iter()
+        .zip(trail.points.iter().skip(1))
+        .all(|(a, b)| a.0 < b.0));
+}
+
+#[test]
+fn zero_capacity_records_no_trail() {
+    let mut sim = simulation(0);
+    sim.run(12);
+    assert!(trails(&sim).iter().all(|(t, _)| t.points.is_empty()));
+}
@@ -0,0 +1,42 @@
+// tests/velocity_units.rs
+//
+// units.velocity に KmH を指定した場合に、設定の速度が内部の単位（cm/s）へ正しく変換されることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::config::{load_config_from_reader, Format, VelocityUnit};
+use my_specs_game::Simulation;
+
+#[test]
+fn velocity_in_km_per_hour_is_converted_to_cm_per_second() {
+    // 36 km/h = 10 m/s = 1000 cm/s です。
+    assert!((VelocityUnit::KmH.to_cm_per_s(36.0) - 1000.0).abs() < 1e-3);
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: KmH}
+entities:
+  - {kind: Cue, x: 63.5, y: 40.0, vx: 36.0, vy: -18.0}
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let v = Simulation::new(&config).unwrap().query_velocities()[0].1;
+    assert!(
+        (v.x - 1000.0).abs() < 1e-3 && (v.y + 500.0).abs() < 1e-3,
+        "{v:?}"
+    );
+}
+
+#[test]
+fn unknown_velocity_unit_is_rejected() {
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: Knots}
+entities:
+  - {kind: Cue, x: 63.5, y: 40.0, vx: 36.0}
+",
+    );
+    assert!(load_config_from_reader(yaml.as_bytes(), Format::Yaml).is_err());
+}
@@ -0,0 +1,73 @@
+// tests/wall_overlap.rs
+//
+// 半分だけ壁にめり込んだ初期位置が、既定では読み込み時に拒否され、clamp_initial_positions では警告とともに
+// クッションにちょうど接する位置へ移されて、最初のステップで壁との衝突として扱われないことを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml, LogBuffer};
+use my_specs_game::config::{load_config_from_reader, Config, Format};
+use my_specs_game::Simulation;
+
+/// 中心が左の壁から 1 cm（半径 2.85 cm の半分ほど）の位置で、壁に沿って +y 方向に転がる手球の設定です。
+fn yaml(stepping: &str, extra: &str) -> String {
+    standard_yaml(
+        0.9,
+        &format!(
+            "dt: 0.01
+units: {{velocity: CmPerS}}
+stepping: {stepping}
+{extra}
+entities:
+  - {{kind: Cue, x: 1.0, y: 100.0, vy: 50.0}}
+"
+        ),
+    )
+}
+
+/// 設定から、ログを記録しながら Simulation を生成し、Simulation と出力されたログを返します。
+fn create_with_log(config: &Config) -> (Simulation, String) {
+    let buffer = LogBuffer::default();
+    let writer = buffer.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+    let sim = tracing::subscriber::with_default(subscriber, || Simulation::new(config).unwrap());
+    let log = buffer.contents();
+    (sim, log)
+}
+
+#[test]
+fn half_inside_ball_is_rejected_by_default() {
+    for stepping in ["FixedStep", "EventDriven"] {
+        let error =
+            load_config_from_reader(yaml(stepping, "").as_bytes(), Format::Yaml).unwrap_err();
+        assert!(
+            error.to_string().contains("outside the table"),
+            "{stepping} {error}"
+        );
+    }
+}
+
+#[test]
+fn clamped_ball_touches_the_wall_without_a_collision() {
+    for stepping in ["FixedStep", "EventDriven"] {
+        let config = config_from_yaml(&yaml(stepping, "clamp_initial_positions: true"));
+        let (mut sim, log) = create_with_log(&config);
+        assert!(log.contains("WARN"), "{stepping} {log}");
+        assert!(
+            log.contains("Ball 0: initial position (1, 100) overlaps the cushion, moved by ("),
+            "{stepping} {log}"
+        );
+        let p = sim.query_positions()[0].1;
+        assert_eq!((p.x, p.y), (2.85, 100.0), "{stepping}");
+        sim.step();
+        assert_eq!(sim.metrics().wall_collisions, 0, "{stepping}");
+        // 跳ね返らず、壁に接したまま壁に沿って転がります。
+        let p = sim.query_positions()[0].1;
+        let v = sim.query_velocities()[0].1;
+        assert_eq!(p.x, 2.85, "{stepping}");
+        assert_eq!((v.x, v.y), (0.0, 50.0), "{stepping}");
+    }
+}
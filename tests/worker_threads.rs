@@ -0,0 +1,108 @@
+// tests/worker_threads.rs
+//
+// threads でスレッドプールの大きさを固定できること、threads: 1 ではシステムが 1 つのスレッドだけで実行されること、
+// スレッドの数によらず結果がビット単位で一致すること、上限を超えるスレッド数は読み込み時に拒否されることを確かめるテストです。
+// parallel フィーチャーの有無による結果の一致は、parallel_determinism.rs で確かめます。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml, LogBuffer};
+use my_specs_game::config::{load_config_from_reader, Format, MAX_THREADS_PER_CORE};
+use my_specs_game::Simulation;
+use std::collections::BTreeSet;
+
+/// スレッドの数を `threads` に、ボールごとのログを `logging` にしたブレイクショットの Simulation を生成します。
+fn break_shot(threads: usize, logging: bool) -> Simulation {
+    let config = config_from_yaml(&break_yaml(threads, logging));
+    Simulation::new(&config).unwrap()
+}
+
+/// スレッドの数を `threads` に、ボールごとのログを `logging` にしたブレイクショットの YAML を返します。
+fn break_yaml(threads: usize, logging: bool) -> String {
+    standard_yaml(0.95, &format!(
+        "dt: 0.002
+units: {{velocity: CmPerS}}
+friction_xy: [0.2, 0.2]
+scenario: break
+break_speed: 800.0
+collision_solver: Colored
+solver_iterations: 4
+threads: {threads}
+systems: {{physics: true, collision: true, pockets: true, logging: {logging}, log_energy: false, stats: true}}
+"
+    ))
+}
+
+#[test]
+fn thread_count_above_the_limit_is_rejected() {
+    let cores = std::thread::available_parallelism().unwrap().get();
+    let limit = cores * MAX_THREADS_PER_CORE;
+    assert!(load_config_from_reader(break_yaml(limit, false).as_bytes(), Format::Yaml).is_ok());
+    let err = load_config_from_reader(break_yaml(100_000, false).as_bytes(), Format::Yaml)
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.starts_with("threads (100000) exceeds the limit"),
+        "{err}"
+    );
+}
+
+#[test]
+fn configured_thread_count_sizes_the_pool() {
+    let expected = |threads: usize| {
+        if cfg!(feature = "parallel") {
+            threads
+        } else {
+            1
+        }
+    };
+    for threads in [1, 3] {
+        assert_eq!(
+            break_shot(threads, false).worker_threads(),
+            expected(threads)
+        );
+    }
+    // 0 の場合は CPU のコア数などの既定値を使います。
+    assert!(break_shot(0, false).worker_threads() >= 1);
+}
+
+#[test]
+fn results_do_not_depend_on_the_thread_count() {
+    let hashes: Vec<u64> = [1, 2, 4, 0]
+        .into_iter()
+        .map(|threads| {
+            let mut sim = break_shot(threads, false);
+            sim.run(500);
+            sim.state_hash()
+        })
+        .collect();
+    assert!(hashes.windows(2).all(|w| w[0] == w[1]), "{hashes:?}");
+}
+
+#[test]
+fn single_thread_pool_runs_systems_on_one_worker() {
+    // システムはスレッドプールで実行されるため、プロセス全体の出力先を設定し、スレッドの ID を含めて記録します。
+    let buffer = LogBuffer::default();
+    let writer = buffer.clone();
+    tracing::subscriber::set_global_default(
+        tracing_subscriber::fmt()
+            .with_ansi(false)
+            .with_thread_ids(true)
+            .with_writer(move || writer.clone())
+            .finish(),
+    )
+    .unwrap();
+    let mut sim = break_shot(1, true);
+    sim.run(20);
+    let log = buffer.contents();
+    // LoggingSystem のログの行だけを取り出し、出力したスレッドの ID を集めます。
+    let threads: BTreeSet<&str> = log
+        .lines()
+        .filter(|line| line.contains("my_specs_game::systems::logging_system"))
+        .filter_map(|line| {
+            line.split_whitespace()
+                .find(|word| word.starts_with("ThreadId("))
+        })
+        .collect();
+    assert_eq!(threads.len(), 1, "{log}");
+}
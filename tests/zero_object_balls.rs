@@ -0,0 +1,109 @@
+// tests/zero_object_balls.rs
+//
+// object_balls の positions が空の、手球だけのシミュレーションを、すべてのシステムと出力先を有効にして実行しても
+// パニックせず、集計値や静止の判定が妥当な値になることを確かめるテストです。
+
+mod common;
+
+use common::{config_from_yaml, standard_yaml};
+use my_specs_game::config::Config;
+use my_specs_game::events::CollisionEvent;
+use my_specs_game::output::{CsvSink, JsonSink, MemorySink};
+use my_specs_game::simulation::check_determinism;
+use my_specs_game::Simulation;
+
+/// 手球だけを置き、すべてのシステムを有効にした `stepping` のモードの設定を読み込みます。
+fn cue_only(stepping: &str) -> Config {
+    // イベント駆動モードは摩擦と組み合わせられないため、摩擦は固定ステップのモードでのみ指定します。
+    let friction = if stepping == "FixedStep" {
+        "friction_xy: [0.2, 0.2]"
+    } else {
+        ""
+    };
+    let yaml = standard_yaml(0.9, &format!(
+        "dt: 0.01
+units: {{velocity: CmPerS}}
+{friction}
+stepping: {stepping}
+sleep_steps: 10
+trail_capacity: 8
+seed: 3
+pockets: {{radius: 6.0, positions: [{{x: 0.0, y: 0.0}}]}}
+systems: {{physics: true, collision: true, pockets: true, logging: true, log_energy: true, stats: true}}
+cue_ball: {{x: 63.5, y: 40.0, vx: 0.0, vy: 30.0}}
+object_balls: {{positions: []}}
+"
+    ));
+    config_from_yaml(&yaml)
+}
+
+#[test]
+fn cue_only_run_completes_with_sensible_summaries() {
+    // イベント駆動モードでは摩擦が働かないため、手球は 3 秒間 30 cm/s で転がり続けます。
+    for (stepping, distance, at_rest) in [("FixedStep", 22.5, true), ("EventDriven", 90.0, false)] {
+        let config = cue_only(stepping);
+        let mut sim = Simulation::new(&config).unwrap();
+        assert_eq!(sim.query_positions().len(), 1, "{stepping}");
+        let dir = env!("CARGO_TARGET_TMPDIR");
+        sim.add_sink(Box::new(MemorySink::default()));
+        sim.add_sink(Box::new(
+            CsvSink::create(&format!("{dir}/zero_object_balls_{stepping}.csv")).unwrap(),
+        ));
+        sim.add_sink(Box::new(
+            JsonSink::create(&format!("{dir}/zero_object_balls_{stepping}.jsonl")).unwrap(),
+        ));
+        // 固定ステップでは、30 cm/s の手球は 20 cm/s² の摩擦で 1.5 秒後に止まり、22.5 cm 転がります。
+        sim.run(300);
+        assert_eq!(sim.is_at_rest(), at_rest, "{stepping}");
+        if at_rest {
+            assert!(sim.progress() > 0.999, "{stepping}");
+        }
+        let metrics = sim.metrics();
+        assert_eq!(metrics.steps, 300, "{stepping}");
+        assert_eq!(metrics.ball_collisions, 0, "{stepping}");
+        assert_eq!(metrics.wall_collisions, 0, "{stepping}");
+        assert_eq!(metrics.balls_pocketed, 0, "{stepping}");
+        assert_eq!(metrics.distance.len(), 1, "{stepping}");
+        assert!((metrics.distance[&0] - distance).abs() < 0.5, "{stepping}");
+        assert!(metrics.energy_dissipated.is_finite(), "{stepping}");
+        let json: serde_json::Value = serde_json::from_str(&sim.metrics_json()).unwrap();
+        assert_eq!(json["ball_collisions"], 0, "{stepping}");
+        assert!(sim.render_svg().contains("<circle"), "{stepping}");
+        let frame = sim.render_raster(1.0);
+        assert_eq!((frame.width, frame.height), (127, 254), "{stepping}");
+        assert!(sim.current_contacts().is_empty(), "{stepping}");
+        // 転がり続ける手球の次のイベントは、ほかのボールではなくクッションとの衝突だけです。
+        match sim.time_to_next_event() {
+            Some((_, event)) => assert!(
+                !at_rest && matches!(event, CollisionEvent::Wall { .. }),
+                "{stepping} {event:?}"
+            ),
+            None => assert!(at_rest, "{stepping}"),
+        }
+        assert!(
+            check_determinism(&config, 50).unwrap().is_ok(),
+            "{stepping}"
+        );
+    }
+}
+
+#[test]
+fn table_without_balls_is_at_rest_from_the_start() {
+    let yaml = standard_yaml(
+        0.9,
+        "dt: 0.01
+units: {velocity: CmPerS}
+entities: []
+",
+    );
+    let config = config_from_yaml(&yaml);
+    let mut sim = Simulation::new(&config).unwrap();
+    assert!(sim.is_at_rest());
+    assert_eq!(sim.progress(), 1.0);
+    sim.run(10);
+    let metrics = sim.metrics();
+    assert_eq!(metrics.steps, 10);
+    assert!(metrics.distance.is_empty());
+    assert_eq!(metrics.energy_dissipated, 0.0);
+    assert!(sim.render_svg().starts_with("<svg"));
+}